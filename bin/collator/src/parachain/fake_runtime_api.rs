@@ -21,9 +21,16 @@
 #[cfg(feature = "runtime-benchmarks")]
 extern crate alloc;
 
-use astar_primitives::{AccountId, Balance, Block, Nonce};
+use astar_primitives::{
+    dapp_staking::{
+        DAppId, DAppMetadata, DAppTierHistoryEntry, EraNumber, InflationRewardProjection,
+        PeriodNumber, RankedTier, SmartContract,
+    },
+    AccountId, Balance, Block, BlockNumber, Nonce,
+};
 use ethereum::AuthorizationList;
 use frame_support::weights::Weight;
+use pallet_inflation::{InflationConfiguration, InflationParameters};
 use pallet_transaction_payment::{FeeDetails, RuntimeDispatchInfo};
 use sp_api::impl_runtime_apis;
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
@@ -32,7 +39,7 @@ use sp_inherents::{CheckInherentsResult, InherentData};
 use sp_runtime::{
     traits::Block as BlockT,
     transaction_validity::{TransactionSource, TransactionValidity},
-    ApplyExtrinsicResult, Permill,
+    ApplyExtrinsicResult, Permill, Perquintill,
 };
 use sp_version::RuntimeVersion;
 
@@ -147,6 +154,66 @@ impl_runtime_apis! {
         }
     }
 
+    impl dapp_staking_runtime_api::DappStakingApi<Block, AccountId, SmartContract<AccountId>> for Runtime {
+        fn periods_per_cycle() -> PeriodNumber {
+            unimplemented!()
+        }
+
+        fn eras_per_voting_subperiod() -> EraNumber {
+            unimplemented!()
+        }
+
+        fn eras_per_build_and_earn_subperiod() -> EraNumber {
+            unimplemented!()
+        }
+
+        fn blocks_per_era() -> BlockNumber {
+            unimplemented!()
+        }
+
+        fn get_dapp_tier_assignment() -> std::collections::BTreeMap<DAppId, RankedTier> {
+            unimplemented!()
+        }
+
+        fn get_claimable_staker_rewards(_account: AccountId) -> Vec<(EraNumber, Balance)> {
+            unimplemented!()
+        }
+
+        fn get_claimable_bonus_rewards(_account: AccountId) -> Vec<(SmartContract<AccountId>, Balance)> {
+            unimplemented!()
+        }
+
+        fn get_unclaimed_dapp_rewards(_smart_contract: SmartContract<AccountId>) -> Vec<(EraNumber, Balance)> {
+            unimplemented!()
+        }
+
+        fn get_dapp_tier_history(_dapp_id: DAppId) -> Vec<DAppTierHistoryEntry> {
+            unimplemented!()
+        }
+
+        fn get_dapp_metadata(_smart_contract: SmartContract<AccountId>) -> Option<DAppMetadata> {
+            unimplemented!()
+        }
+    }
+
+    impl inflation_runtime_api::InflationApi<Block, InflationConfiguration, InflationParameters> for Runtime {
+        fn active_inflation_config() -> InflationConfiguration {
+            unimplemented!()
+        }
+
+        fn inflation_params() -> InflationParameters {
+            unimplemented!()
+        }
+
+        fn decay_factor() -> Perquintill {
+            unimplemented!()
+        }
+
+        fn reward_projection(_total_value_staked: Balance) -> InflationRewardProjection {
+            unimplemented!()
+        }
+    }
+
     impl fp_rpc::EthereumRuntimeRPCApi<Block> for Runtime {
         fn chain_id() -> u64 {
             unimplemented!()
@@ -18,6 +18,7 @@
 
 //! Astar RPCs implementation.
 
+use dapp_staking_rpc::{DappStaking, DappStakingApiServer};
 use fc_rpc::{
     Eth, EthApiServer, EthBlockDataCacheTask, EthFilter, EthFilterApiServer, EthPubSub,
     EthPubSubApiServer, LogsJournal, Net, NetApiServer, TxPool, TxPoolApiServer, Web3,
@@ -25,7 +26,9 @@ use fc_rpc::{
 };
 use fc_rpc_core::types::{FeeHistoryCache, FilterPool};
 use fc_storage::{StorageOverride, StorageOverrideHandler};
+use inflation_rpc::{Inflation, InflationApiServer};
 use jsonrpsee::RpcModule;
+use pallet_inflation::{InflationConfiguration, InflationParameters};
 use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
 use std::path::Path;
 
@@ -54,7 +57,7 @@ use moonbeam_rpc_debug::{Debug, DebugServer};
 use moonbeam_rpc_trace::{Trace, TraceServer};
 
 use crate::evm_tracing_types::{FrontierBackendConfig, FrontierConfig};
-use astar_primitives::*;
+use astar_primitives::{dapp_staking::SmartContract, *};
 
 pub mod tracing;
 
@@ -213,6 +216,8 @@ where
     C: sc_client_api::BlockBackend<Block>,
     C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>
         + pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>
+        + dapp_staking_rpc::DappStakingRuntimeApi<Block, AccountId, SmartContract<AccountId>>
+        + inflation_rpc::InflationRuntimeApi<Block, InflationConfiguration, InflationParameters>
         + fp_rpc::ConvertTransactionRuntimeApi<Block>
         + fp_rpc::EthereumRuntimeRPCApi<Block>
         + BlockBuilder<Block>
@@ -283,6 +288,8 @@ where
     C: sc_client_api::BlockBackend<Block>,
     C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>
         + pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>
+        + dapp_staking_rpc::DappStakingRuntimeApi<Block, AccountId, SmartContract<AccountId>>
+        + inflation_rpc::InflationRuntimeApi<Block, InflationConfiguration, InflationParameters>
         + fp_rpc::ConvertTransactionRuntimeApi<Block>
         + fp_rpc::EthereumRuntimeRPCApi<Block>
         + BlockBuilder<Block>
@@ -353,6 +360,8 @@ where
     C: sc_client_api::BlockBackend<Block>,
     C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>
         + pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>
+        + dapp_staking_rpc::DappStakingRuntimeApi<Block, AccountId, SmartContract<AccountId>>
+        + inflation_rpc::InflationRuntimeApi<Block, InflationConfiguration, InflationParameters>
         + fp_rpc::ConvertTransactionRuntimeApi<Block>
         + fp_rpc::EthereumRuntimeRPCApi<Block>
         + BlockBuilder<Block>
@@ -395,6 +404,8 @@ where
     C: sc_client_api::BlockBackend<Block>,
     C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>
         + pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>
+        + dapp_staking_rpc::DappStakingRuntimeApi<Block, AccountId, SmartContract<AccountId>>
+        + inflation_rpc::InflationRuntimeApi<Block, InflationConfiguration, InflationParameters>
         + fp_rpc::ConvertTransactionRuntimeApi<Block>
         + fp_rpc::EthereumRuntimeRPCApi<Block>
         + BlockBuilder<Block>
@@ -438,6 +449,8 @@ where
     C: sc_client_api::BlockBackend<Block>,
     C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>
         + pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>
+        + dapp_staking_rpc::DappStakingRuntimeApi<Block, AccountId, SmartContract<AccountId>>
+        + inflation_rpc::InflationRuntimeApi<Block, InflationConfiguration, InflationParameters>
         + fp_rpc::ConvertTransactionRuntimeApi<Block>
         + fp_rpc::EthereumRuntimeRPCApi<Block>
         + BlockBuilder<Block>
@@ -470,6 +483,8 @@ where
 
     io.merge(System::new(client.clone(), pool.clone()).into_rpc())?;
     io.merge(TransactionPayment::new(client.clone()).into_rpc())?;
+    io.merge(DappStaking::new(client.clone()).into_rpc())?;
+    io.merge(Inflation::new(client.clone()).into_rpc())?;
     io.merge(sc_rpc::dev::Dev::new(client.clone()).into_rpc())?;
 
     if let Some(command_sink) = command_sink {
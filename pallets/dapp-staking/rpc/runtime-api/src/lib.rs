@@ -20,17 +20,25 @@
 
 #[allow(unused_imports)]
 use astar_primitives::dapp_staking::TierId;
-use astar_primitives::dapp_staking::{DAppId, EraNumber, PeriodNumber, RankedTier};
-use astar_primitives::BlockNumber;
-pub use sp_std::collections::btree_map::BTreeMap;
+use astar_primitives::dapp_staking::{
+    DAppId, DAppMetadata, DAppTierHistoryEntry, EraNumber, PeriodNumber, RankedTier,
+    TierAssignmentPreview,
+};
+use astar_primitives::{Balance, BlockNumber};
+use parity_scale_codec::Codec;
+pub use sp_std::{collections::btree_map::BTreeMap, vec::Vec};
 
 sp_api::decl_runtime_apis! {
 
     /// dApp Staking Api.
     ///
     /// Used to provide information otherwise not available via RPC.
-    #[api_version(2)]
-    pub trait DappStakingApi {
+    #[api_version(5)]
+    pub trait DappStakingApi<AccountId, SmartContract>
+    where
+        AccountId: Codec,
+        SmartContract: Codec,
+    {
 
         /// How many periods are there in one cycle.
         fn periods_per_cycle() -> PeriodNumber;
@@ -50,5 +58,38 @@ sp_api::decl_runtime_apis! {
 
         /// Get dApp ranked tier assignment for the given dApp.
         fn get_dapp_tier_assignment() -> BTreeMap<DAppId, RankedTier>;
+
+        /// Get staker rewards the account can currently claim, per era.
+        #[api_version(3)]
+        fn get_claimable_staker_rewards(account: AccountId) -> Vec<(EraNumber, Balance)>;
+
+        /// Get bonus rewards the account can currently claim, per smart contract.
+        #[api_version(3)]
+        fn get_claimable_bonus_rewards(account: AccountId) -> Vec<(SmartContract, Balance)>;
+
+        /// Get unclaimed dApp rewards for the smart contract, per era.
+        #[api_version(3)]
+        fn get_unclaimed_dapp_rewards(smart_contract: SmartContract) -> Vec<(EraNumber, Balance)>;
+
+        /// Get tier assignment history of the given dApp, from the oldest to the latest entry.
+        #[api_version(4)]
+        fn get_dapp_tier_history(dapp_id: DAppId) -> Vec<DAppTierHistoryEntry>;
+
+        /// Get metadata of the given smart contract, if it has been set by the dApp owner.
+        #[api_version(5)]
+        fn get_dapp_metadata(smart_contract: SmartContract) -> Option<DAppMetadata>;
+    }
+
+    /// dApp Staking Tier Parameters Api.
+    ///
+    /// Used to preview the effects of tier parameters before they are applied, e.g. by governance.
+    pub trait DappStakingTierParamsApi<TierParameters>
+    where
+        TierParameters: Codec,
+    {
+        /// Preview dApp tier assignment for the ongoing era, as if the given tier parameters were already in use.
+        ///
+        /// Returns `None` if the tier parameters are invalid.
+        fn preview_dapp_tier_assignment(params: TierParameters) -> Option<TierAssignmentPreview>;
     }
 }
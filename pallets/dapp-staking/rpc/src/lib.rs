@@ -0,0 +1,155 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! RPC interface for the dApp staking pallet.
+//!
+//! Exposes pending & claimable reward information, computed by the runtime via the `DappStakingApi`.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use jsonrpsee::{
+    core::RpcResult,
+    proc_macros::rpc,
+    types::error::{ErrorObject, ErrorObjectOwned},
+};
+use parity_scale_codec::Codec;
+use serde::{de::DeserializeOwned, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Block as BlockT;
+
+use astar_primitives::{dapp_staking::EraNumber, Balance};
+
+pub use dapp_staking_runtime_api::DappStakingApi as DappStakingRuntimeApi;
+
+/// dApp staking RPC methods.
+#[rpc(server)]
+pub trait DappStakingApi<BlockHash, AccountId, SmartContract> {
+    /// Staker rewards the account can currently claim, per era.
+    #[method(name = "dappStaking_claimableStakerRewards")]
+    fn claimable_staker_rewards(
+        &self,
+        account: AccountId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<(EraNumber, Balance)>>;
+
+    /// Bonus rewards the account can currently claim, per smart contract.
+    #[method(name = "dappStaking_claimableBonusRewards")]
+    fn claimable_bonus_rewards(
+        &self,
+        account: AccountId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<(SmartContract, Balance)>>;
+
+    /// Unclaimed dApp rewards for the smart contract, per era.
+    #[method(name = "dappStaking_unclaimedDAppRewards")]
+    fn unclaimed_dapp_rewards(
+        &self,
+        smart_contract: SmartContract,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<(EraNumber, Balance)>>;
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+    /// The call to runtime failed.
+    RuntimeError,
+}
+
+impl From<Error> for i32 {
+    fn from(e: Error) -> i32 {
+        match e {
+            Error::RuntimeError => 1,
+        }
+    }
+}
+
+/// Provides RPC methods to query dApp staking information.
+pub struct DappStaking<C, B> {
+    client: Arc<C>,
+    _marker: PhantomData<B>,
+}
+
+impl<C, B> DappStaking<C, B> {
+    /// Creates a new instance of the dApp staking RPC helper.
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            _marker: Default::default(),
+        }
+    }
+}
+
+/// Converts a runtime API error into an RPC error.
+fn runtime_error(error: impl ToString) -> ErrorObjectOwned {
+    ErrorObject::owned(
+        Error::RuntimeError.into(),
+        "Unable to query dApp staking information.",
+        Some(error.to_string()),
+    )
+}
+
+impl<C, Block, AccountId, SmartContract>
+    DappStakingApiServer<<Block as BlockT>::Hash, AccountId, SmartContract>
+    for DappStaking<C, Block>
+where
+    Block: BlockT,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: DappStakingRuntimeApi<Block, AccountId, SmartContract>,
+    AccountId: Codec + DeserializeOwned + Send + Sync + 'static,
+    SmartContract: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+{
+    fn claimable_staker_rewards(
+        &self,
+        account: AccountId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<(EraNumber, Balance)>> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        self.client
+            .runtime_api()
+            .get_claimable_staker_rewards(at, account)
+            .map_err(runtime_error)
+    }
+
+    fn claimable_bonus_rewards(
+        &self,
+        account: AccountId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<(SmartContract, Balance)>> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        self.client
+            .runtime_api()
+            .get_claimable_bonus_rewards(at, account)
+            .map_err(runtime_error)
+    }
+
+    fn unclaimed_dapp_rewards(
+        &self,
+        smart_contract: SmartContract,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<(EraNumber, Balance)>> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        self.client
+            .runtime_api()
+            .get_unclaimed_dapp_rewards(at, smart_contract)
+            .map_err(runtime_error)
+    }
+}
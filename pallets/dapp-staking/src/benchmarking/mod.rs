@@ -98,6 +98,46 @@ mod benchmarks {
         );
     }
 
+    #[benchmark]
+    fn set_dapp_reward_splits() {
+        initial_config::<T>();
+
+        let owner: T::AccountId = whitelisted_caller();
+        let smart_contract = T::BenchmarkHelper::get_smart_contract(1);
+        assert_ok!(DappStaking::<T>::register(
+            RawOrigin::Root.into(),
+            owner.clone().into(),
+            smart_contract.clone(),
+        ));
+
+        let max_splits = T::MaxNumberOfRewardSplits::get();
+        let splits: BoundedVec<_, _> = (0..max_splits)
+            .map(|idx| {
+                (
+                    account("split_beneficiary", idx, SEED),
+                    Permill::from_rational(1, max_splits),
+                )
+            })
+            .collect::<Vec<_>>()
+            .try_into()
+            .expect("Cannot exceed the limit.");
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(owner),
+            smart_contract.clone(),
+            splits.clone(),
+        );
+
+        assert_last_event::<T>(
+            Event::<T>::DAppRewardSplitsUpdated {
+                smart_contract,
+                splits,
+            }
+            .into(),
+        );
+    }
+
     #[benchmark]
     fn set_dapp_owner() {
         initial_config::<T>();
@@ -154,6 +194,7 @@ mod benchmarks {
     #[benchmark]
     fn lock_new_account() {
         initial_config::<T>();
+        enable_lock_receipts::<T>();
 
         let staker: T::AccountId = whitelisted_caller();
         let owner: T::AccountId = account("dapp_owner", 0, SEED);
@@ -182,6 +223,7 @@ mod benchmarks {
     #[benchmark]
     fn lock_existing_account() {
         initial_config::<T>();
+        enable_lock_receipts::<T>();
 
         let staker: T::AccountId = whitelisted_caller();
         let owner: T::AccountId = account("dapp_owner", 0, SEED);
@@ -215,6 +257,7 @@ mod benchmarks {
     #[benchmark]
     fn unlock() {
         initial_config::<T>();
+        enable_lock_receipts::<T>();
 
         let staker: T::AccountId = whitelisted_caller();
         let owner: T::AccountId = account("dapp_owner", 0, SEED);
@@ -336,9 +379,92 @@ mod benchmarks {
         );
     }
 
+    #[benchmark]
+    fn instant_unlock(x: Linear<0, { T::MaxNumberOfStakedContracts::get() }>) {
+        initial_config::<T>();
+        enable_lock_receipts::<T>();
+        InstantUnlockPenalty::<T>::put(Perbill::from_percent(10));
+
+        // Prepare staker account and lock some amount
+        let staker: T::AccountId = whitelisted_caller();
+        let amount = (T::MinimumStakeAmount::get() + 1)
+            * Into::<Balance>::into(max_number_of_contracts::<T>())
+            + Into::<Balance>::into(T::MaxUnlockingChunks::get());
+        T::BenchmarkHelper::set_balance(&staker, amount);
+        assert_ok!(DappStaking::<T>::lock(
+            RawOrigin::Signed(staker.clone()).into(),
+            amount,
+        ));
+
+        // Move over to the build&earn subperiod to ensure staking without a bonus status.
+        // This is needed so we can achieve staker entry cleanup after the full instant unlock.
+        force_advance_to_next_subperiod::<T>();
+
+        // Register required number of contracts and have staker stake on them.
+        // This is needed to achieve the cleanup functionality.
+        for idx in 0..x {
+            let smart_contract = T::BenchmarkHelper::get_smart_contract(idx as u32);
+            let owner: T::AccountId = account("dapp_owner", idx.into(), SEED);
+
+            assert_ok!(DappStaking::<T>::register(
+                RawOrigin::Root.into(),
+                owner.clone().into(),
+                smart_contract.clone(),
+            ));
+
+            assert_ok!(DappStaking::<T>::stake(
+                RawOrigin::Signed(staker.clone()).into(),
+                smart_contract,
+                T::MinimumStakeAmount::get() + 1,
+            ));
+        }
+
+        // Unlock some amount - but we want to fill up the whole vector with chunks.
+        for _ in 0..T::MaxUnlockingChunks::get() {
+            assert_ok!(DappStaking::<T>::unlock(
+                RawOrigin::Signed(staker.clone()).into(),
+                1,
+            ));
+            run_for_blocks::<T>(One::one());
+        }
+
+        // Advance to next period to ensure the old stake entries are expired, and can be cleaned up.
+        force_advance_to_next_period::<T>();
+        assert!(
+            !Ledger::<T>::get(&staker).unlocking.is_empty(),
+            "Sanity check - unlocking chunks must still be pending."
+        );
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(staker.clone()), amount);
+
+        assert_last_event::<T>(
+            Event::<T>::InstantUnlock {
+                account: staker,
+                amount,
+                penalty: Perbill::from_percent(10) * amount,
+            }
+            .into(),
+        );
+    }
+
+    #[benchmark]
+    fn set_instant_unlock_penalty() {
+        initial_config::<T>();
+
+        let penalty = Some(Perbill::from_percent(10));
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, penalty);
+
+        assert_eq!(InstantUnlockPenalty::<T>::get(), penalty);
+        assert_last_event::<T>(Event::<T>::InstantUnlockPenaltySet { penalty }.into());
+    }
+
     #[benchmark]
     fn relock_unlocking() {
         initial_config::<T>();
+        enable_lock_receipts::<T>();
 
         let staker: T::AccountId = whitelisted_caller();
         let owner: T::AccountId = account("dapp_owner", 0, SEED);
@@ -649,7 +775,7 @@ mod benchmarks {
     }
 
     #[benchmark]
-    fn claim_dapp_reward() {
+    fn claim_dapp_reward(x: Linear<0, { T::MaxNumberOfRewardSplits::get() }>) {
         initial_config::<T>();
 
         // Register a dApp & stake on it.
@@ -674,9 +800,9 @@ mod benchmarks {
             amount
         ));
 
-        // Register & stake up to max number of contracts.
+        // Register & stake up to max number of tier slots.
         // The reason is we want to have reward vector filled up to the capacity.
-        for idx in 1..T::MaxNumberOfContracts::get() {
+        for idx in 1..T::MaxNumberOfTierSlots::get() {
             let owner: T::AccountId = account("dapp_owner", idx.into(), SEED);
             let smart_contract = T::BenchmarkHelper::get_smart_contract(idx as u32);
             assert_ok!(DappStaking::<T>::register(
@@ -701,10 +827,10 @@ mod benchmarks {
         // Advance enough eras so dApp reward can be claimed.
         force_advance_to_next_subperiod::<T>();
 
-        // This is a hacky part to ensure we accommodate max number of contracts.
+        // This is a hacky part to ensure we accommodate max number of tier slots.
         TierConfig::<T>::mutate(|config| {
-            let max_number_of_contracts: u16 = T::MaxNumberOfContracts::get().try_into().unwrap();
-            config.slots_per_tier[0] = max_number_of_contracts;
+            let max_number_of_tier_slots: u16 = T::MaxNumberOfTierSlots::get().try_into().unwrap();
+            config.slots_per_tier[0] = max_number_of_tier_slots;
             config.slots_per_tier[1..].iter_mut().for_each(|x| *x = 0);
             config.tier_thresholds[0] = 1;
         });
@@ -716,10 +842,25 @@ mod benchmarks {
                 .expect("Must exist since it's from past build&earn era.")
                 .dapps
                 .len(),
-            T::MaxNumberOfContracts::get() as usize,
+            T::MaxNumberOfTierSlots::get() as usize,
             "Sanity check to ensure we have filled up the vector completely."
         );
 
+        // Split the reward between `x` beneficiaries, leaving the remainder to the owner.
+        let splits: Vec<_> = (0..x)
+            .map(|idx| {
+                (
+                    account("split_beneficiary", idx, SEED),
+                    Permill::from_rational(1, x + 1),
+                )
+            })
+            .collect();
+        assert_ok!(DappStaking::<T>::set_dapp_reward_splits(
+            RawOrigin::Signed(owner.clone()).into(),
+            smart_contract.clone(),
+            BoundedVec::try_from(splits).expect("Cannot exceed the limit."),
+        ));
+
         #[extrinsic_call]
         _(
             RawOrigin::Signed(owner.clone()),
@@ -734,6 +875,103 @@ mod benchmarks {
         );
     }
 
+    #[benchmark]
+    fn claim_dapp_rewards(x: Linear<1, { T::MaxNumberOfDAppRewardClaimEras::get() }>) {
+        initial_config::<T>();
+
+        // Register a dApp & stake on it.
+        // This is the dApp for which we'll claim rewards for.
+        let owner: T::AccountId = whitelisted_caller();
+        let smart_contract = T::BenchmarkHelper::get_smart_contract(0);
+        assert_ok!(DappStaking::<T>::register(
+            RawOrigin::Root.into(),
+            owner.clone().into(),
+            smart_contract.clone(),
+        ));
+
+        let amount = MIN_TIER_THRESHOLD * 1000;
+        T::BenchmarkHelper::set_balance(&owner, amount);
+        assert_ok!(DappStaking::<T>::lock(
+            RawOrigin::Signed(owner.clone()).into(),
+            amount,
+        ));
+        assert_ok!(DappStaking::<T>::stake(
+            RawOrigin::Signed(owner.clone()).into(),
+            smart_contract.clone(),
+            amount
+        ));
+
+        // Register & stake up to max number of tier slots.
+        // The reason is we want to have reward vectors filled up to the capacity.
+        for idx in 1..T::MaxNumberOfTierSlots::get() {
+            let owner: T::AccountId = account("dapp_owner", idx.into(), SEED);
+            let smart_contract = T::BenchmarkHelper::get_smart_contract(idx as u32);
+            assert_ok!(DappStaking::<T>::register(
+                RawOrigin::Root.into(),
+                owner.clone().into(),
+                smart_contract.clone(),
+            ));
+
+            let staker: T::AccountId = account("staker", idx.into(), SEED);
+            T::BenchmarkHelper::set_balance(&staker, amount);
+            assert_ok!(DappStaking::<T>::lock(
+                RawOrigin::Signed(staker.clone()).into(),
+                amount,
+            ));
+            assert_ok!(DappStaking::<T>::stake(
+                RawOrigin::Signed(staker.clone()).into(),
+                smart_contract.clone(),
+                amount
+            ));
+        }
+
+        // Advance enough eras so dApp rewards can be claimed for `x` eras.
+        force_advance_to_next_subperiod::<T>();
+        for _ in 0..x {
+            // This is a hacky part to ensure we accommodate max number of tier slots.
+            TierConfig::<T>::mutate(|config| {
+                let max_number_of_tier_slots: u16 =
+                    T::MaxNumberOfTierSlots::get().try_into().unwrap();
+                config.slots_per_tier[0] = max_number_of_tier_slots;
+                config.slots_per_tier[1..].iter_mut().for_each(|x| *x = 0);
+                config.tier_thresholds[0] = 1;
+            });
+            force_advance_to_next_era::<T>();
+        }
+        let last_era = ActiveProtocolState::<T>::get().era - 1;
+        let first_era = last_era - (x - 1);
+
+        // Split the reward between max number of beneficiaries, leaving the remainder to the owner.
+        let max_splits = T::MaxNumberOfRewardSplits::get();
+        let splits: Vec<_> = (0..max_splits)
+            .map(|idx| {
+                (
+                    account("split_beneficiary", idx, SEED),
+                    Permill::from_rational(1, max_splits + 1),
+                )
+            })
+            .collect();
+        assert_ok!(DappStaking::<T>::set_dapp_reward_splits(
+            RawOrigin::Signed(owner.clone()).into(),
+            smart_contract.clone(),
+            BoundedVec::try_from(splits).expect("Cannot exceed the limit."),
+        ));
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(owner.clone()),
+            smart_contract.clone(),
+            first_era,
+            last_era,
+        );
+
+        // No need to do precise check of values, but last event must be 'DAppReward'.
+        assert_matches!(
+            dapp_staking_events::<T>().last(),
+            Some(Event::DAppReward { .. })
+        );
+    }
+
     #[benchmark]
     fn unstake_from_unregistered() {
         initial_config::<T>();
@@ -1117,6 +1355,75 @@ mod benchmarks {
         }
     }
 
+    #[benchmark]
+    fn dapp_tier_assignment_page(x: Linear<0, { max_tier_assignment_page_size::<T>() }>) {
+        // Prepare init config (protocol state, tier params & config, etc.)
+        initial_config::<T>();
+
+        // Register & stake contracts, to prepare for tier assignment.
+        prepare_contracts_for_tier_assignment::<T>(x);
+        force_advance_to_next_era::<T>();
+
+        // Need to ensure settings remain unchanged even after the era change
+        init_tier_settings::<T>();
+
+        // Ensure the page is read in the next block, right before the era change.
+        let mut protocol_state = ActiveProtocolState::<T>::get();
+        let now = protocol_state.next_era_start - 1;
+        protocol_state.next_era_start = now + 1;
+        ActiveProtocolState::<T>::put(protocol_state);
+
+        #[block]
+        {
+            DappStaking::<T>::tier_assignment_page_handler(now, &protocol_state);
+        }
+
+        let progress = TierAssignmentState::<T>::get().expect("Page must have been read.");
+        assert_eq!(progress.candidates.len(), x as usize);
+    }
+
+    #[benchmark]
+    fn update_dapp_tier_history(x: Linear<0, { max_number_of_contracts::<T>() }>) {
+        // Prepare init config (protocol state, tier params & config, etc.)
+        initial_config::<T>();
+
+        // Register & stake contracts, to prepare for tier assignment.
+        prepare_contracts_for_tier_assignment::<T>(x);
+        force_advance_to_next_era::<T>();
+
+        // Need to ensure settings remain unchanged even after the era change
+        init_tier_settings::<T>();
+
+        let reward_era = ActiveProtocolState::<T>::get().era;
+        let reward_period = ActiveProtocolState::<T>::get().period_number();
+        let reward_pool = Balance::from(10_000 * UNIT as u128);
+        let (dapp_tiers, _count) = Pallet::<T>::get_dapp_tier_assignment_and_rewards(
+            reward_era,
+            reward_period,
+            reward_pool,
+        );
+
+        // Worst case is when the histories are full, and the oldest entries must be overwritten.
+        for dapp_id in dapp_tiers.dapps.keys() {
+            DAppTierHistory::<T>::mutate(dapp_id, |history| {
+                (0..T::TierHistoryLength::get()).for_each(|era| {
+                    history.push(DAppTierHistoryEntry {
+                        era,
+                        ranked_tier: RankedTier::new_saturated(0, 0),
+                        stake: 1_000_000_000_000,
+                        reward: 1_000_000_000_000,
+                    })
+                });
+            });
+        }
+
+        #[block]
+        {
+            let updated = Pallet::<T>::update_dapp_tier_history(reward_era, &dapp_tiers);
+            assert_eq!(updated, x);
+        }
+    }
+
     #[benchmark]
     fn on_idle_cleanup() {
         // Prepare init config (protocol state, tier params & config, etc.)
@@ -1149,11 +1456,11 @@ mod benchmarks {
         DAppTiers::<T>::insert(
             &cleanup_marker.dapp_tiers_index,
             DAppTierRewardsFor::<T> {
-                dapps: (0..T::MaxNumberOfContracts::get())
+                dapps: (0..T::MaxNumberOfTierSlots::get())
                     .map(|dapp_id| (dapp_id as DAppId, RankedTier::new_saturated(0, 0)))
                     .collect::<BTreeMap<DAppId, RankedTier>>()
                     .try_into()
-                    .expect("Using `MaxNumberOfContracts` as length; QED."),
+                    .expect("Using `MaxNumberOfTierSlots` as length; QED."),
                 rewards: vec![1_000_000_000_000; T::NumberOfTiers::get() as usize]
                     .try_into()
                     .expect("Using `NumberOfTiers` as length; QED."),
@@ -1202,6 +1509,478 @@ mod benchmarks {
         );
     }
 
+    #[benchmark]
+    fn set_auto_compound() {
+        initial_config::<T>();
+
+        let staker: T::AccountId = whitelisted_caller();
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(staker.clone()), true);
+
+        assert!(AutoCompound::<T>::contains_key(&staker));
+        assert_last_event::<T>(
+            Event::<T>::AutoCompoundSet {
+                account: staker,
+                enabled: true,
+            }
+            .into(),
+        );
+    }
+
+    #[benchmark]
+    fn set_auto_claim() {
+        initial_config::<T>();
+
+        let staker: T::AccountId = whitelisted_caller();
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(staker.clone()), true);
+
+        assert!(AutoClaim::<T>::contains_key(&staker));
+        assert_last_event::<T>(
+            Event::<T>::AutoClaimSet {
+                account: staker,
+                enabled: true,
+            }
+            .into(),
+        );
+    }
+
+    #[benchmark]
+    fn set_staker_reward_destination() {
+        initial_config::<T>();
+
+        let staker: T::AccountId = whitelisted_caller();
+        let destination: T::AccountId = account("destination", 0, SEED);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(staker.clone()), Some(destination.clone()));
+
+        assert_eq!(
+            StakerRewardDestination::<T>::get(&staker),
+            Some(destination.clone())
+        );
+        assert_last_event::<T>(
+            Event::<T>::StakerRewardDestinationSet {
+                account: staker,
+                destination: Some(destination),
+            }
+            .into(),
+        );
+    }
+
+    #[benchmark]
+    fn set_delegate() {
+        initial_config::<T>();
+
+        let staker: T::AccountId = whitelisted_caller();
+        let delegate: T::AccountId = account("delegate", 0, SEED);
+
+        // Worst case is when the delegate list is almost full.
+        let mut delegates = DelegatesFor::<T>::default();
+        for idx in 1..T::MaxNumberOfDelegates::get() {
+            assert_ok!(
+                delegates.try_insert(account("delegate", idx, SEED), DelegatePermissions::all())
+            );
+        }
+        StakeDelegates::<T>::insert(&staker, delegates);
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(staker.clone()),
+            delegate.clone(),
+            DelegatePermissions::all(),
+        );
+
+        assert_eq!(
+            StakeDelegates::<T>::get(&staker).get(&delegate),
+            Some(&DelegatePermissions::all())
+        );
+        assert_last_event::<T>(
+            Event::<T>::DelegateSet {
+                account: staker,
+                delegate,
+                permissions: DelegatePermissions::all(),
+            }
+            .into(),
+        );
+    }
+
+    #[benchmark]
+    fn revoke_delegate() {
+        initial_config::<T>();
+
+        let staker: T::AccountId = whitelisted_caller();
+        let delegate: T::AccountId = account("delegate", 0, SEED);
+
+        // Worst case is when the delegate list is full.
+        let mut delegates = DelegatesFor::<T>::default();
+        for idx in 0..T::MaxNumberOfDelegates::get() {
+            assert_ok!(
+                delegates.try_insert(account("delegate", idx, SEED), DelegatePermissions::all())
+            );
+        }
+        StakeDelegates::<T>::insert(&staker, delegates);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(staker.clone()), delegate.clone());
+
+        assert!(!StakeDelegates::<T>::get(&staker).contains_key(&delegate));
+        assert_last_event::<T>(
+            Event::<T>::DelegateRevoked {
+                account: staker,
+                delegate,
+            }
+            .into(),
+        );
+    }
+
+    #[benchmark]
+    fn set_carry_over_plan(x: Linear<1, { T::MaxNumberOfStakedContracts::get() }>) {
+        initial_config::<T>();
+
+        let staker: T::AccountId = whitelisted_caller();
+        let stake_amount = T::MinimumStakeAmount::get();
+
+        // Register required number of contracts and plan to stake on each of them.
+        let mut entries = CarryOverEntriesFor::<T>::default();
+        for idx in 0..x {
+            let smart_contract = T::BenchmarkHelper::get_smart_contract(idx as u32);
+            let owner: T::AccountId = account("dapp_owner", idx.into(), SEED);
+
+            assert_ok!(DappStaking::<T>::register(
+                RawOrigin::Root.into(),
+                owner.clone().into(),
+                smart_contract.clone(),
+            ));
+            assert_ok!(entries.try_push((smart_contract, stake_amount)));
+        }
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(staker.clone()), entries.clone());
+
+        assert_last_event::<T>(
+            Event::<T>::CarryOverPlanSet {
+                account: staker,
+                entries,
+                start_period: ActiveProtocolState::<T>::get().period_number() + 1,
+            }
+            .into(),
+        );
+    }
+
+    #[benchmark]
+    fn remove_carry_over_plan() {
+        initial_config::<T>();
+
+        let staker: T::AccountId = whitelisted_caller();
+        let smart_contract = T::BenchmarkHelper::get_smart_contract(1);
+        let owner: T::AccountId = account("dapp_owner", 0, SEED);
+        assert_ok!(DappStaking::<T>::register(
+            RawOrigin::Root.into(),
+            owner.into(),
+            smart_contract.clone(),
+        ));
+        assert_ok!(DappStaking::<T>::set_carry_over_plan(
+            RawOrigin::Signed(staker.clone()).into(),
+            vec![(smart_contract, T::MinimumStakeAmount::get())]
+                .try_into()
+                .expect("Must fit into the plan."),
+        ));
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(staker.clone()));
+
+        assert!(!CarryOverPlans::<T>::contains_key(&staker));
+        assert_last_event::<T>(Event::<T>::CarryOverPlanRemoved { account: staker }.into());
+    }
+
+    #[benchmark]
+    fn set_dapp_metadata() {
+        initial_config::<T>();
+
+        let owner: T::AccountId = whitelisted_caller();
+        let smart_contract = T::BenchmarkHelper::get_smart_contract(1);
+        assert_ok!(DappStaking::<T>::register(
+            RawOrigin::Root.into(),
+            owner.clone().into(),
+            smart_contract.clone(),
+        ));
+
+        // Worst case is when the previous metadata deposit needs to be released first.
+        let metadata = max_dapp_metadata::<T>();
+        let deposit =
+            T::DAppMetadataDepositPerByte::get().saturating_mul(metadata.encoded_size() as Balance);
+        T::BenchmarkHelper::set_balance(&owner, deposit.saturating_mul(10));
+        assert_ok!(DappStaking::<T>::set_dapp_metadata(
+            RawOrigin::Signed(owner.clone()).into(),
+            smart_contract.clone(),
+            metadata.clone(),
+        ));
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(owner.clone()),
+            smart_contract.clone(),
+            metadata.clone(),
+        );
+
+        assert_last_event::<T>(
+            Event::<T>::DAppMetadataSet {
+                smart_contract,
+                metadata,
+                depositor: owner,
+                deposit,
+            }
+            .into(),
+        );
+    }
+
+    #[benchmark]
+    fn clear_dapp_metadata() {
+        initial_config::<T>();
+
+        let owner: T::AccountId = whitelisted_caller();
+        let smart_contract = T::BenchmarkHelper::get_smart_contract(1);
+        assert_ok!(DappStaking::<T>::register(
+            RawOrigin::Root.into(),
+            owner.clone().into(),
+            smart_contract.clone(),
+        ));
+
+        let metadata = max_dapp_metadata::<T>();
+        let deposit =
+            T::DAppMetadataDepositPerByte::get().saturating_mul(metadata.encoded_size() as Balance);
+        T::BenchmarkHelper::set_balance(&owner, deposit.saturating_mul(10));
+        assert_ok!(DappStaking::<T>::set_dapp_metadata(
+            RawOrigin::Signed(owner.clone()).into(),
+            smart_contract.clone(),
+            metadata,
+        ));
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(owner.clone()), smart_contract.clone());
+
+        assert!(!DAppMetadataOf::<T>::contains_key(&smart_contract));
+        assert_last_event::<T>(
+            Event::<T>::DAppMetadataCleared {
+                smart_contract,
+                depositor: owner,
+                deposit,
+            }
+            .into(),
+        );
+    }
+
+    #[benchmark]
+    fn schedule_static_tier_params() {
+        initial_config::<T>();
+
+        let mut tier_params = StaticTierParams::<T>::get();
+        tier_params.reward_portion[0] = Permill::zero();
+        let period = ActiveProtocolState::<T>::get().period_number() + 1;
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, period, tier_params.clone());
+
+        assert_eq!(
+            ScheduledTierParams::<T>::get(),
+            Some((period, tier_params.clone()))
+        );
+        assert_last_event::<T>(
+            Event::<T>::TierParametersScheduled {
+                period,
+                params: tier_params,
+            }
+            .into(),
+        );
+    }
+
+    #[benchmark]
+    fn cancel_scheduled_tier_params() {
+        initial_config::<T>();
+
+        let period = ActiveProtocolState::<T>::get().period_number() + 1;
+        assert_ok!(DappStaking::<T>::schedule_static_tier_params(
+            RawOrigin::Root.into(),
+            period,
+            StaticTierParams::<T>::get(),
+        ));
+
+        #[extrinsic_call]
+        _(RawOrigin::Root);
+
+        assert!(ScheduledTierParams::<T>::get().is_none());
+        assert_last_event::<T>(Event::<T>::ScheduledTierParametersCancelled { period }.into());
+    }
+
+    #[benchmark]
+    fn unstake_from_all_unregistered(x: Linear<1, { T::MaxNumberOfStakedContracts::get() }>) {
+        initial_config::<T>();
+
+        // Prepare staker & lock some amount
+        let staker: T::AccountId = whitelisted_caller();
+        let amount = T::MinimumLockedAmount::get()
+            * Into::<Balance>::into(T::MaxNumberOfStakedContracts::get());
+        T::BenchmarkHelper::set_balance(&staker, amount);
+        assert_ok!(DappStaking::<T>::lock(
+            RawOrigin::Signed(staker.clone()).into(),
+            amount,
+        ));
+
+        // Stake on dApps up to the limit, and unregister all of them
+        for idx in 0..x {
+            let owner: T::AccountId = account("dapp_owner", idx.into(), SEED);
+            let smart_contract = T::BenchmarkHelper::get_smart_contract(idx as u32);
+            assert_ok!(DappStaking::<T>::register(
+                RawOrigin::Root.into(),
+                owner.clone().into(),
+                smart_contract.clone(),
+            ));
+
+            assert_ok!(DappStaking::<T>::stake(
+                RawOrigin::Signed(staker.clone()).into(),
+                smart_contract.clone(),
+                T::MinimumStakeAmount::get(),
+            ));
+
+            assert_ok!(DappStaking::<T>::unregister(
+                RawOrigin::Root.into(),
+                smart_contract.clone(),
+            ));
+        }
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(staker.clone()));
+
+        assert_eq!(StakerInfo::<T>::iter_prefix(&staker).count(), 0);
+        let period = ActiveProtocolState::<T>::get().period_number();
+        assert_eq!(Ledger::<T>::get(&staker).staked_amount(period), 0);
+    }
+
+    #[benchmark]
+    fn set_lock_receipts_enabled() {
+        initial_config::<T>();
+        T::BenchmarkHelper::create_lock_receipt_asset();
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, true);
+
+        assert!(LockReceiptsEnabled::<T>::get());
+        assert_last_event::<T>(Event::<T>::LockReceiptsToggled { enabled: true }.into());
+    }
+
+    #[benchmark]
+    fn transfer_lock(x: Linear<0, { T::MaxNumberOfStakedContracts::get() }>) {
+        initial_config::<T>();
+        enable_lock_receipts::<T>();
+
+        // Prepare staker account and lock some amount, minting the receipt for it
+        let staker: T::AccountId = whitelisted_caller();
+        let amount = (T::MinimumStakeAmount::get() + 1)
+            * Into::<Balance>::into(max_number_of_contracts::<T>())
+            + T::MinimumLockedAmount::get();
+        T::BenchmarkHelper::set_balance(&staker, amount * 2);
+        assert_ok!(DappStaking::<T>::lock(
+            RawOrigin::Signed(staker.clone()).into(),
+            amount,
+        ));
+
+        // Move over to the build&earn subperiod to ensure staking without a bonus status.
+        // This is needed so we can achieve staker entry cleanup after the entire lock is transferred.
+        force_advance_to_next_subperiod::<T>();
+
+        // Register required number of contracts and have staker stake on them.
+        for idx in 0..x {
+            let smart_contract = T::BenchmarkHelper::get_smart_contract(idx as u32);
+            let owner: T::AccountId = account("dapp_owner", idx.into(), SEED);
+
+            assert_ok!(DappStaking::<T>::register(
+                RawOrigin::Root.into(),
+                owner.clone().into(),
+                smart_contract.clone(),
+            ));
+
+            assert_ok!(DappStaking::<T>::stake(
+                RawOrigin::Signed(staker.clone()).into(),
+                smart_contract,
+                T::MinimumStakeAmount::get() + 1,
+            ));
+        }
+
+        // Advance to next period to ensure the old stake entries are expired, and can be cleaned up.
+        force_advance_to_next_period::<T>();
+
+        let dest: T::AccountId = account("dest", 0, SEED);
+        T::BenchmarkHelper::set_balance(&dest, amount);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(staker.clone()), dest.clone(), amount);
+
+        assert_last_event::<T>(
+            Event::<T>::LockTransferred {
+                from: staker,
+                to: dest,
+                amount,
+                receipt: amount,
+            }
+            .into(),
+        );
+    }
+
+    #[benchmark]
+    fn compound_reward(x: Linear<0, { T::MaxNumberOfStakedContracts::get() }>) {
+        initial_config::<T>();
+
+        // Prepare staker account with enough funds to stake on all contracts, and some extra 'reward' balance.
+        let staker: T::AccountId = whitelisted_caller();
+        let stake_amount = T::MinimumStakeAmount::get() + 1;
+        let lock_amount = stake_amount * Into::<Balance>::into(x) + T::MinimumLockedAmount::get();
+        let reward = stake_amount * Into::<Balance>::into(x) + 1;
+        T::BenchmarkHelper::set_balance(&staker, lock_amount + reward);
+        assert_ok!(DappStaking::<T>::lock(
+            RawOrigin::Signed(staker.clone()).into(),
+            lock_amount,
+        ));
+
+        // Register required number of contracts and have staker stake on them.
+        for idx in 0..x {
+            let smart_contract = T::BenchmarkHelper::get_smart_contract(idx as u32);
+            let owner: T::AccountId = account("dapp_owner", idx.into(), SEED);
+
+            assert_ok!(DappStaking::<T>::register(
+                RawOrigin::Root.into(),
+                owner.clone().into(),
+                smart_contract.clone(),
+            ));
+
+            assert_ok!(DappStaking::<T>::stake(
+                RawOrigin::Signed(staker.clone()).into(),
+                smart_contract,
+                stake_amount,
+            ));
+        }
+
+        assert_ok!(DappStaking::<T>::set_auto_compound(
+            RawOrigin::Signed(staker.clone()).into(),
+            true,
+        ));
+
+        #[block]
+        {
+            let _ = DappStaking::<T>::maybe_compound_reward(&staker, reward);
+        }
+
+        assert_eq!(
+            Ledger::<T>::get(&staker).active_locked_amount(),
+            lock_amount + reward
+        );
+        if x > 0 {
+            assert_matches!(dapp_staking_events::<T>().last(), Some(Event::Stake { .. }));
+        }
+    }
+
     impl_benchmark_test_suite!(
         Pallet,
         crate::benchmarking::tests::new_test_ext(),
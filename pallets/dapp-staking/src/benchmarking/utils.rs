@@ -18,7 +18,10 @@
 
 use super::{Pallet as DappStaking, *};
 
-use astar_primitives::{dapp_staking::FIXED_NUMBER_OF_TIER_SLOTS, Balance};
+use astar_primitives::{
+    dapp_staking::{DAppCategory, FIXED_NUMBER_OF_TIER_SLOTS},
+    Balance,
+};
 
 use frame_system::Pallet as System;
 use sp_arithmetic::Permill;
@@ -160,6 +163,14 @@ pub(super) fn initial_config<T: Config>() {
     Safeguard::<T>::put(false);
 }
 
+/// Makes the lock receipt asset available and enables lock receipts.
+///
+/// Used to measure the worst case of operations which lock or unlock funds.
+pub(super) fn enable_lock_receipts<T: Config>() {
+    T::BenchmarkHelper::create_lock_receipt_asset();
+    LockReceiptsEnabled::<T>::put(true);
+}
+
 /// Set initial tier param & config
 pub(super) fn init_tier_settings<T: Config>() {
     let tier_params = TierParameters::<T::NumberOfTiers> {
@@ -200,7 +211,7 @@ pub(super) fn init_tier_settings<T: Config>() {
     let tier_thresholds = tier_params
         .tier_thresholds
         .iter()
-        .map(|t| t.threshold(total_issuance))
+        .map(|t| t.threshold(total_issuance, 0))
         .collect::<Vec<Balance>>()
         .try_into()
         .expect("Invalid number of tier thresholds provided.");
@@ -224,6 +235,11 @@ pub(super) fn max_number_of_contracts<T: Config>() -> u32 {
     T::MaxNumberOfContracts::get().min(NUMBER_OF_SLOTS).into()
 }
 
+/// Maximum number of contract stake entries read in a single tier assignment page, considering the contract number limit.
+pub(super) fn max_tier_assignment_page_size<T: Config>() -> u32 {
+    T::TierAssignmentPageSize::get().min(max_number_of_contracts::<T>())
+}
+
 /// Registers & staked on the specified number of smart contracts
 ///
 /// Stake amounts are decided in such a way to maximize tier filling rate.
@@ -289,3 +305,18 @@ pub(super) fn max_claim_size_ongoing_period<T: Config>() -> u32 {
     T::EraRewardSpanLength::get()
         .min(T::CycleConfiguration::eras_per_build_and_earn_subperiod() - 1)
 }
+
+/// Returns dApp metadata with all of the fields filled up to the maximum length.
+pub(super) fn max_dapp_metadata<T: Config>() -> DAppMetadataFor<T> {
+    let max_field = || {
+        BoundedVec::try_from(vec![b'a'; T::MaxDAppMetadataLength::get() as usize])
+            .expect("Must fit into the field.")
+    };
+
+    BoundedDAppMetadata {
+        name: max_field(),
+        url: max_field(),
+        logo_cid: max_field(),
+        category: DAppCategory::DeFi,
+    }
+}
@@ -40,23 +40,31 @@ extern crate alloc;
 use alloc::vec;
 pub use alloc::vec::Vec;
 use frame_support::{
+    dispatch::PostDispatchInfo,
     pallet_prelude::*,
+    storage::{with_storage_layer, with_transaction, TransactionOutcome},
     traits::{
-        fungible::{Inspect as FunInspect, MutateFreeze as FunMutateFreeze},
-        SafeModeNotify, StorageVersion,
+        fungible::{
+            Balanced as FunBalanced, Credit, Inspect as FunInspect,
+            MutateFreeze as FunMutateFreeze, MutateHold as FunMutateHold,
+        },
+        tokens::{Fortitude, Precision, Preservation},
+        OnUnbalanced, SafeModeNotify, StorageVersion,
     },
     weights::Weight,
 };
 use frame_system::pallet_prelude::*;
 use sp_runtime::{
     traits::{One, Saturating, UniqueSaturatedInto, Zero},
-    Perbill, Permill, SaturatedConversion,
+    Perbill, Permill, SaturatedConversion, TokenError,
 };
 
 use astar_primitives::{
     dapp_staking::{
-        AccountCheck, CycleConfiguration, DAppId, EraNumber, Observer as DAppStakingObserver,
-        PeriodNumber, Rank, RankedTier, SmartContractHandle, StakingRewardHandler, TierId,
+        AccountCheck, CycleConfiguration, DAppId, DAppMetadata, DAppTierHistoryEntry, EraNumber,
+        LockReceiptHandler, Observer as DAppStakingObserver, PeriodNumber, Rank, RankedTier,
+        SmartContractHandle, StakingRewardHandler, TierAssignmentPreview, TierId,
+        TierSlotsProvider, FIXED_NUMBER_OF_TIER_SLOTS,
     },
     Balance, BlockNumber,
 };
@@ -94,7 +102,7 @@ pub mod pallet {
     use super::*;
 
     /// The current storage version.
-    pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(12);
+    pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(13);
 
     #[pallet::pallet]
     #[pallet::storage_version(STORAGE_VERSION)]
@@ -105,6 +113,9 @@ pub mod pallet {
         fn get_smart_contract(id: u32) -> SmartContract;
 
         fn set_balance(account: &AccountId, balance: Balance);
+
+        /// Make the lock receipt asset available, so lock receipts can be enabled.
+        fn create_lock_receipt_asset() {}
     }
 
     #[pallet::config]
@@ -118,13 +129,14 @@ pub mod pallet {
         /// The overarching freeze reason.
         type RuntimeFreezeReason: From<FreezeReason>;
 
-        /// Currency used for staking.
+        /// The overarching hold reason.
+        type RuntimeHoldReason: From<HoldReason>;
+
+        /// Currency used for staking & dApp metadata deposits.
         /// Reference: <https://github.com/paritytech/substrate/pull/12951/>
-        type Currency: FunMutateFreeze<
-            Self::AccountId,
-            Id = Self::RuntimeFreezeReason,
-            Balance = Balance,
-        >;
+        type Currency: FunMutateFreeze<Self::AccountId, Id = Self::RuntimeFreezeReason, Balance = Balance>
+            + FunMutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason, Balance = Balance>
+            + FunBalanced<Self::AccountId>;
 
         /// Describes smart contract in the context required by dApp staking.
         type SmartContract: Parameter
@@ -138,6 +150,22 @@ pub mod pallet {
         /// Privileged origin that is allowed to unregister smart contracts from the protocol.
         type ContractUnregisterOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
 
+        /// Deposit held per byte of the dApp metadata, from the account which set it.
+        #[pallet::constant]
+        type DAppMetadataDepositPerByte: Get<Balance>;
+
+        /// Maximum length, in bytes, of each dApp metadata field, e.g. name or URL.
+        #[pallet::constant]
+        type MaxDAppMetadataLength: Get<u32>;
+
+        /// Handler for the penalty charged when locked funds are unlocked instantly, e.g. routing it to the treasury.
+        /// In case of `()`, the penalty is burned.
+        type InstantUnlockPenaltyHandler: OnUnbalanced<Credit<Self::AccountId, Self::Currency>>;
+
+        /// Used to mint & burn the receipt token representing the locked amount, once lock receipts are enabled.
+        /// In case of `()`, lock receipts are unavailable.
+        type LockReceipt: LockReceiptHandler<Self::AccountId>;
+
         /// Privileged origin for managing dApp staking pallet.
         type ManagerOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
 
@@ -148,7 +176,7 @@ pub mod pallet {
         type CycleConfiguration: CycleConfiguration;
 
         /// dApp staking event observers, notified when certain events occur.
-        type Observers: DAppStakingObserver;
+        type Observers: DAppStakingObserver<Self::AccountId, Self::SmartContract>;
 
         /// Used to check whether an account is allowed to participate in dApp staking.
         type AccountCheck: AccountCheck<Self::AccountId>;
@@ -166,6 +194,22 @@ pub mod pallet {
         #[pallet::constant]
         type MaxNumberOfContracts: Get<u32>;
 
+        /// Maximum number of tier slots, i.e. the maximum number of dApps which can be assigned to tiers in a single era.
+        #[pallet::constant]
+        type MaxNumberOfTierSlots: Get<u32>;
+
+        /// Maximum number of contract stake entries read in a single block while preparing the tier assignment.
+        ///
+        /// Tier assignment is prepared over multiple blocks, right before the era change.
+        #[pallet::constant]
+        type TierAssignmentPageSize: Get<u32>;
+
+        /// Maximum number of tier assignment entries kept in the history of each dApp.
+        ///
+        /// Once the limit is reached, the oldest entry is overwritten by the new one.
+        #[pallet::constant]
+        type TierHistoryLength: Get<u32>;
+
         /// Maximum number of unlocking chunks that can exist per account at a time.
         #[pallet::constant]
         type MaxUnlockingChunks: Get<u32>;
@@ -183,6 +227,10 @@ pub mod pallet {
         #[pallet::constant]
         type MaxNumberOfStakedContracts: Get<u32>;
 
+        /// Maximum number of delegates an account can have at once.
+        #[pallet::constant]
+        type MaxNumberOfDelegates: Get<u32>;
+
         /// Minimum amount staker can stake on a contract.
         #[pallet::constant]
         type MinimumStakeAmount: Get<Balance>;
@@ -191,6 +239,10 @@ pub mod pallet {
         #[pallet::constant]
         type NumberOfTiers: Get<u32>;
 
+        /// Used to derive the total number of tier slots at the start of each new period.
+        /// The provided value is capped by `MaxNumberOfTierSlots`.
+        type TierSlotsProvider: TierSlotsProvider;
+
         /// Tier ranking enabled.
         #[pallet::constant]
         type RankingEnabled: Get<bool>;
@@ -201,6 +253,23 @@ pub mod pallet {
         #[pallet::constant]
         type MaxBonusSafeMovesPerPeriod: Get<u8>;
 
+        /// Maximum number of additional beneficiaries between which a dApp reward can be split.
+        #[pallet::constant]
+        type MaxNumberOfRewardSplits: Get<u32>;
+
+        /// Maximum number of eras for which dApp rewards can be claimed in a single call.
+        #[pallet::constant]
+        type MaxNumberOfDAppRewardClaimEras: Get<u32>;
+
+        /// Maximum number of accounts registered for automatic reward claiming which are inspected in a single block.
+        #[pallet::constant]
+        type AutoClaimScanLimit: Get<u32>;
+
+        /// Number of eras after a dApp is unregistered, during which stakers can handle the stake left on it themselves.
+        /// Once it passes, the remaining stake is swept automatically, when there is enough spare block weight.
+        #[pallet::constant]
+        type UnregisteredStakeGracePeriod: Get<EraNumber>;
+
         /// Weight info for various calls & operations in the pallet.
         type WeightInfo: WeightInfo;
 
@@ -274,18 +343,20 @@ pub mod pallet {
             smart_contract: T::SmartContract,
             amount: Balance,
         },
-        /// Account has claimed some stake rewards.
+        /// Account has claimed some stake rewards, paid out to the destination account.
         Reward {
             account: T::AccountId,
             era: EraNumber,
             amount: Balance,
+            destination: T::AccountId,
         },
-        /// Bonus reward has been paid out to a staker with an eligible bonus status.
+        /// Bonus reward has been paid out to the destination account of a staker with an eligible bonus status.
         BonusReward {
             account: T::AccountId,
             smart_contract: T::SmartContract,
             period: PeriodNumber,
             amount: Balance,
+            destination: T::AccountId,
         },
         /// dApp reward has been paid out to a beneficiary.
         DAppReward {
@@ -317,6 +388,116 @@ pub mod pallet {
         NewTierParameters {
             params: TierParameters<T::NumberOfTiers>,
         },
+        /// Account has instantly unlocked some amount, paying the penalty for it.
+        InstantUnlock {
+            account: T::AccountId,
+            amount: Balance,
+            penalty: Balance,
+        },
+        /// Instant unlock penalty has been updated, `None` means instant unlocking is disabled.
+        InstantUnlockPenaltySet { penalty: Option<Perbill> },
+        /// Account has enabled or disabled auto-compounding of staker & bonus rewards.
+        AutoCompoundSet {
+            account: T::AccountId,
+            enabled: bool,
+        },
+        /// Account has enabled or disabled automatic claiming of staker & bonus rewards.
+        AutoClaimSet {
+            account: T::AccountId,
+            enabled: bool,
+        },
+        /// Staker reward destination has been updated, `None` means rewards are paid out to the staker.
+        StakerRewardDestinationSet {
+            account: T::AccountId,
+            destination: Option<T::AccountId>,
+        },
+        /// Account has granted or updated stake delegation permissions of a delegate.
+        DelegateSet {
+            account: T::AccountId,
+            delegate: T::AccountId,
+            permissions: DelegatePermissions,
+        },
+        /// Account has revoked all stake delegation permissions of a delegate.
+        DelegateRevoked {
+            account: T::AccountId,
+            delegate: T::AccountId,
+        },
+        /// Account has set a carry-over plan, applied from the specified period onwards.
+        CarryOverPlanSet {
+            account: T::AccountId,
+            entries: CarryOverEntriesFor<T>,
+            start_period: PeriodNumber,
+        },
+        /// Account has removed its carry-over plan.
+        CarryOverPlanRemoved { account: T::AccountId },
+        /// Carry-over plan of the account has been applied for the specified period.
+        CarryOverPlanApplied {
+            account: T::AccountId,
+            period: PeriodNumber,
+        },
+        /// Planned carry-over stake couldn't be applied.
+        CarryOverStakeFailed {
+            account: T::AccountId,
+            smart_contract: T::SmartContract,
+            amount: Balance,
+            error: DispatchError,
+        },
+        /// dApp reward splits have been updated.
+        DAppRewardSplitsUpdated {
+            smart_contract: T::SmartContract,
+            splits: BoundedVec<(T::AccountId, Permill), T::MaxNumberOfRewardSplits>,
+        },
+        /// dApp metadata has been set, and the deposit for it has been held from the depositor.
+        DAppMetadataSet {
+            smart_contract: T::SmartContract,
+            metadata: DAppMetadataFor<T>,
+            depositor: T::AccountId,
+            deposit: Balance,
+        },
+        /// dApp metadata has been cleared, and the deposit for it has been released back to the depositor.
+        DAppMetadataCleared {
+            smart_contract: T::SmartContract,
+            depositor: T::AccountId,
+            deposit: Balance,
+        },
+        /// Tier parameters have been scheduled to be applied at the start of the specified period.
+        TierParametersScheduled {
+            period: PeriodNumber,
+            params: TierParameters<T::NumberOfTiers>,
+        },
+        /// Scheduled tier parameters have been cancelled.
+        ScheduledTierParametersCancelled { period: PeriodNumber },
+        /// Scheduled tier parameters have been applied at the start of the period, changing the tier thresholds.
+        ScheduledTierParametersApplied {
+            period: PeriodNumber,
+            old_tier_thresholds: BoundedVec<Balance, T::NumberOfTiers>,
+            new_tier_thresholds: BoundedVec<Balance, T::NumberOfTiers>,
+        },
+        /// Stake left on an unregistered smart contract has been unstaked automatically, after the grace period.
+        UnregisteredStakeSwept {
+            account: T::AccountId,
+            smart_contract: T::SmartContract,
+            amount: Balance,
+        },
+        /// Lock receipts have been either enabled or disabled.
+        LockReceiptsToggled { enabled: bool },
+        /// Lock receipt has been minted to the account, for the newly locked amount.
+        LockReceiptMinted {
+            account: T::AccountId,
+            amount: Balance,
+        },
+        /// Lock receipt has been burned from the account, for the amount which is no longer locked.
+        LockReceiptBurned {
+            account: T::AccountId,
+            amount: Balance,
+        },
+        /// Locked funds have been transferred to another account, together with the lock receipt covering them.
+        LockTransferred {
+            from: T::AccountId,
+            to: T::AccountId,
+            amount: Balance,
+            receipt: Balance,
+        },
     }
 
     #[pallet::error]
@@ -397,6 +578,48 @@ pub mod pallet {
         InvalidTierParams,
         /// Same contract specified as source and destination.
         SameContracts,
+        /// Invalid reward splits were provided. This can happen if any share is zero, a beneficiary is duplicated,
+        /// or the sum of all shares exceeds 100%.
+        InvalidRewardSplits,
+        /// Account doesn't have enough transferable balance to cover the deposit.
+        InsufficientBalanceForDeposit,
+        /// Provided era range is either empty, or longer than allowed.
+        InvalidClaimEraRange,
+        /// Instant unlocking is disabled, since no penalty has been set for it.
+        InstantUnlockDisabled,
+        /// Account cannot be its own delegate.
+        SelfDelegation,
+        /// Delegate permissions must allow at least one action.
+        NoDelegatePermissions,
+        /// Maximum number of delegates for the account has been reached.
+        TooManyDelegates,
+        /// Specified account is not a delegate of the staker.
+        DelegateNotFound,
+        /// Caller isn't a delegate of the staker permitted to perform the action.
+        DelegatedActionNotPermitted,
+        /// Carry-over plan must contain at least one entry.
+        EmptyCarryOverPlan,
+        /// Same smart contract is specified more than once in the carry-over plan.
+        DuplicateCarryOverContract,
+        /// Account has no carry-over plan.
+        NoCarryOverPlan,
+        /// dApp metadata must contain a name.
+        EmptyDAppName,
+        /// dApp has no metadata.
+        NoDAppMetadata,
+        /// Tier parameters can only be scheduled for a future period.
+        InvalidTierParamsPeriod,
+        /// There are no scheduled tier parameters.
+        NoScheduledTierParams,
+        /// Account has no stake on unregistered smart contracts which can be unstaked.
+        NoUnregisteredStake,
+        /// Lock receipts cannot be enabled since the receipt asset is unavailable.
+        LockReceiptUnavailable,
+        /// Locked funds cannot be transferred to the same account.
+        LockTransferToSelf,
+        /// The amount being transferred is larger than the locked amount which isn't staked or unlocking,
+        /// or the transfer would leave the sender with less than the minimum locked amount.
+        InsufficientTransferableLock,
     }
 
     /// General information about dApp staking protocol state.
@@ -416,7 +639,7 @@ pub mod pallet {
     pub type IntegratedDApps<T: Config> = CountedStorageMap<
         Hasher = Blake2_128Concat,
         Key = T::SmartContract,
-        Value = DAppInfo<T::AccountId>,
+        Value = DAppInfoFor<T>,
         QueryKind = OptionQuery,
         MaxValues = ConstU32<{ DAppId::MAX as u32 }>,
     >;
@@ -475,20 +698,124 @@ pub mod pallet {
     pub type StaticTierParams<T: Config> =
         StorageValue<_, TierParameters<T::NumberOfTiers>, ValueQuery>;
 
+    /// Tier parameters scheduled to replace the static tier parameters at the start of the specified period.
+    #[pallet::storage]
+    pub type ScheduledTierParams<T: Config> =
+        StorageValue<_, (PeriodNumber, TierParameters<T::NumberOfTiers>), OptionQuery>;
+
     /// Tier configuration user for current & preceding eras.
     #[pallet::storage]
     pub type TierConfig<T: Config> =
         StorageValue<_, TiersConfiguration<T::NumberOfTiers>, ValueQuery>;
 
+    #[pallet::type_value]
+    pub fn DefaultNumberOfTierSlots<T: Config>() -> u16 {
+        FIXED_NUMBER_OF_TIER_SLOTS
+    }
+
+    /// Total number of tier slots for the ongoing period, derived at the start of each period.
+    #[pallet::storage]
+    pub type NumberOfTierSlots<T: Config> =
+        StorageValue<_, u16, ValueQuery, DefaultNumberOfTierSlots<T>>;
+
     /// Information about which tier a dApp belonged to in a specific era.
     #[pallet::storage]
     pub type DAppTiers<T: Config> =
         StorageMap<_, Twox64Concat, EraNumber, DAppTierRewardsFor<T>, OptionQuery>;
 
+    /// Progress of the paged tier assignment for the ongoing era, if it has been started.
+    #[pallet::storage]
+    pub type TierAssignmentState<T: Config> =
+        StorageValue<_, TierAssignmentProgressFor<T>, OptionQuery>;
+
+    /// Tier assignment history of each dApp, covering the latest eras in which the dApp was assigned to a tier.
+    #[pallet::storage]
+    pub type DAppTierHistory<T: Config> =
+        StorageMap<_, Twox64Concat, DAppId, TierHistoryFor<T>, ValueQuery>;
+
     /// History cleanup marker - holds information about which DB entries should be cleaned up next, when applicable.
     #[pallet::storage]
     pub type HistoryCleanupMarker<T: Config> = StorageValue<_, CleanupMarker, ValueQuery>;
 
+    /// Accounts which opted-in for auto-compounding of staker & bonus rewards.
+    #[pallet::storage]
+    pub type AutoCompound<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, (), OptionQuery>;
+
+    /// Accounts to which staker & bonus rewards are paid out, instead of the staker account.
+    #[pallet::storage]
+    pub type StakerRewardDestination<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, T::AccountId, OptionQuery>;
+
+    /// Delegates of each account, with the stake-changing actions they are permitted to perform on its behalf.
+    #[pallet::storage]
+    pub type StakeDelegates<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, DelegatesFor<T>, ValueQuery>;
+
+    /// Carry-over plans of stakers, automatically applied at the start of each new period's `Voting` subperiod.
+    #[pallet::storage]
+    pub type CarryOverPlans<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, CarryOverPlanFor<T>, OptionQuery>;
+
+    /// Progress of the carry-over plans application for a period.
+    ///
+    /// Holds the last account whose plan was inspected, or `None` once all of the plans have been inspected.
+    #[pallet::storage]
+    pub type CarryOverCursor<T: Config> =
+        StorageValue<_, (PeriodNumber, Option<T::AccountId>), OptionQuery>;
+
+    /// Accounts which opted-in for automatic claiming of staker & bonus rewards, before they expire.
+    #[pallet::storage]
+    pub type AutoClaim<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, (), OptionQuery>;
+
+    /// Last account inspected by the automatic reward claiming routine.
+    /// Next inspection continues from the account following it.
+    #[pallet::storage]
+    pub type AutoClaimCursor<T: Config> = StorageValue<_, T::AccountId, OptionQuery>;
+
+    /// Unregistered smart contracts whose remaining stake is pending to be swept.
+    ///
+    /// Holds the period in which the contract was unregistered, and the era from which the stake can be swept.
+    #[pallet::storage]
+    pub type UnregisteredStakeSweeps<T: Config> = CountedStorageMap<
+        Hasher = Blake2_128Concat,
+        Key = T::SmartContract,
+        Value = (PeriodNumber, EraNumber),
+        QueryKind = OptionQuery,
+    >;
+
+    /// Progress of the unregistered stake sweep.
+    ///
+    /// Holds the era in which the ongoing pass over the stake entries began, and the last inspected stake entry.
+    /// Pass is complete once all of the stake entries have been inspected.
+    #[pallet::storage]
+    pub type UnregisteredStakeSweepCursor<T: Config> =
+        StorageValue<_, (EraNumber, T::AccountId, T::SmartContract), OptionQuery>;
+
+    /// Penalty charged when locked funds are unlocked instantly, as a ratio of the unlocked amount.
+    /// Instant unlocking is disabled if it isn't set.
+    #[pallet::storage]
+    pub type InstantUnlockPenalty<T: Config> = StorageValue<_, Perbill, OptionQuery>;
+
+    /// Metadata of integrated dApps, with the account which provided the deposit for it and the deposit amount.
+    #[pallet::storage]
+    pub type DAppMetadataOf<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::SmartContract,
+        (DAppMetadataFor<T>, T::AccountId, Balance),
+        OptionQuery,
+    >;
+
+    /// Whether lock receipts are minted for newly locked funds.
+    #[pallet::storage]
+    pub type LockReceiptsEnabled<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+    /// Amount of lock receipts minted to each account, which is backed by the account's locked funds.
+    #[pallet::storage]
+    pub type LockReceipts<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, Balance, ValueQuery>;
+
     #[pallet::type_value]
     pub fn DefaultSafeguard<T: Config>() -> bool {
         // In production, safeguard is enabled by default.
@@ -580,10 +907,11 @@ pub mod pallet {
             );
 
             let total_issuance = T::Currency::total_issuance();
+            let total_value_staked = CurrentEraInfo::<T>::get().total_staked_amount();
             let tier_thresholds = tier_params
                 .tier_thresholds
                 .iter()
-                .map(|t| t.threshold(total_issuance))
+                .map(|t| t.threshold(total_issuance, total_value_staked))
                 .collect::<Vec<Balance>>()
                 .try_into()
                 .expect("Invalid number of tier thresholds provided.");
@@ -634,7 +962,18 @@ pub mod pallet {
         }
 
         fn on_idle(_block: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
-            Self::expired_entry_cleanup(&remaining_weight)
+            let mut consumed_weight = Self::expired_entry_cleanup(&remaining_weight);
+            consumed_weight.saturating_accrue(Self::auto_claim_rewards(
+                &remaining_weight.saturating_sub(consumed_weight),
+            ));
+            consumed_weight.saturating_accrue(Self::apply_carry_over_plans(
+                &remaining_weight.saturating_sub(consumed_weight),
+            ));
+            consumed_weight.saturating_accrue(Self::sweep_unregistered_stake(
+                &remaining_weight.saturating_sub(consumed_weight),
+            ));
+
+            consumed_weight
         }
 
         fn integrity_test() {
@@ -643,6 +982,8 @@ pub mod pallet {
             assert!(T::EraRewardSpanLength::get() > 0);
             assert!(T::RewardRetentionInPeriods::get() > 0);
             assert!(T::MaxNumberOfContracts::get() > 0);
+            assert!(T::MaxNumberOfTierSlots::get() > 0);
+            assert!(T::TierAssignmentPageSize::get() > 0);
             assert!(T::MaxUnlockingChunks::get() > 0);
             assert!(T::UnlockingPeriod::get() > 0);
             assert!(T::MaxNumberOfStakedContracts::get() > 0);
@@ -673,6 +1014,14 @@ pub mod pallet {
         DAppStaking,
     }
 
+    /// A reason for placing a hold on funds.
+    #[pallet::composite_enum]
+    pub enum HoldReason {
+        /// Funds are held as a deposit for the dApp metadata.
+        #[codec(index = 0)]
+        DAppMetadata,
+    }
+
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         /// Wrapper around _legacy-like_ `unbond_and_unstake`.
@@ -743,6 +1092,7 @@ pub mod pallet {
                     owner: owner.clone(),
                     id: dapp_id,
                     reward_beneficiary: None,
+                    reward_splits: Default::default(),
                 },
             );
 
@@ -842,11 +1192,15 @@ pub mod pallet {
         ///
         /// Can be called by dApp staking manager origin.
         #[pallet::call_index(6)]
-        #[pallet::weight(T::WeightInfo::unregister())]
+        #[pallet::weight(
+            T::WeightInfo::unregister()
+                .saturating_add(T::WeightInfo::clear_dapp_metadata())
+                .saturating_add(T::Observers::max_weight())
+        )]
         pub fn unregister(
             origin: OriginFor<T>,
             smart_contract: T::SmartContract,
-        ) -> DispatchResult {
+        ) -> DispatchResultWithPostInfo {
             Self::ensure_pallet_enabled()?;
             T::ContractUnregisterOrigin::ensure_origin(origin)?;
 
@@ -856,13 +1210,31 @@ pub mod pallet {
             ContractStake::<T>::remove(&dapp_info.id);
             IntegratedDApps::<T>::remove(&smart_contract);
 
-            let current_era = ActiveProtocolState::<T>::get().era;
+            let observer_weight = T::Observers::on_dapp_unregistered(&smart_contract);
+
+            let protocol_state = ActiveProtocolState::<T>::get();
+            let current_era = protocol_state.era;
+            UnregisteredStakeSweeps::<T>::insert(
+                &smart_contract,
+                (
+                    protocol_state.period_number(),
+                    current_era.saturating_add(T::UnregisteredStakeGracePeriod::get()),
+                ),
+            );
+
             Self::deposit_event(Event::<T>::DAppUnregistered {
-                smart_contract,
+                smart_contract: smart_contract.clone(),
                 era: current_era,
             });
 
-            Ok(())
+            Self::remove_dapp_metadata(&smart_contract);
+
+            Ok(Some(
+                T::WeightInfo::unregister()
+                    .saturating_add(T::WeightInfo::clear_dapp_metadata())
+                    .saturating_add(observer_weight),
+            )
+            .into())
         }
 
         /// Locks additional funds into dApp staking.
@@ -911,6 +1283,10 @@ pub mod pallet {
                 era_info.add_locked(amount_to_lock);
             });
 
+            if LockReceiptsEnabled::<T>::get() {
+                Self::mint_lock_receipt(&account, amount_to_lock)?;
+            }
+
             Self::deposit_event(Event::<T>::Locked {
                 account,
                 amount: amount_to_lock,
@@ -972,6 +1348,7 @@ pub mod pallet {
             CurrentEraInfo::<T>::mutate(|era_info| {
                 era_info.unlocking_started(amount_to_unlock);
             });
+            Self::burn_lock_receipt(&account, amount_to_unlock)?;
 
             Self::deposit_event(Event::<T>::Unlocking {
                 account,
@@ -1015,6 +1392,10 @@ pub mod pallet {
                 era_info.unlocking_removed(amount);
             });
 
+            if LockReceiptsEnabled::<T>::get() {
+                Self::mint_lock_receipt(&account, amount)?;
+            }
+
             Self::deposit_event(Event::<T>::Relock { account, amount });
 
             Ok(())
@@ -1029,52 +1410,16 @@ pub mod pallet {
         ///
         /// Staked amount is only eligible for rewards from the next era onwards.
         #[pallet::call_index(11)]
-        #[pallet::weight(T::WeightInfo::stake())]
+        #[pallet::weight(T::WeightInfo::stake().saturating_add(T::Observers::max_weight()))]
         pub fn stake(
             origin: OriginFor<T>,
             smart_contract: T::SmartContract,
             #[pallet::compact] amount: Balance,
-        ) -> DispatchResult {
+        ) -> DispatchResultWithPostInfo {
             Self::ensure_pallet_enabled()?;
             let account = ensure_signed(origin)?;
 
-            // User is only eligible for the bonus reward if their first time stake is in the `Voting` subperiod.
-            //
-            // `StakeAmount` is prepared based on the current subperiod.
-            // If the user is staking for the first time in the `Voting` subperiod, they are eligible for the bonus reward, and the max number of bonus moves is set.
-            // If the user is staking for the first time in the `Build&Earn` subperiod, they are not eligible for the bonus reward, and the bonus moves are set to 0.
-            let protocol_state = ActiveProtocolState::<T>::get();
-            let (stake_amount, bonus_status) = match protocol_state.subperiod() {
-                Subperiod::Voting => (
-                    StakeAmount {
-                        voting: amount,
-                        build_and_earn: 0,
-                        era: protocol_state.era,
-                        period: protocol_state.period_number(),
-                    },
-                    *BonusStatusWrapperFor::<T>::default(),
-                ),
-                Subperiod::BuildAndEarn => (
-                    StakeAmount {
-                        voting: 0,
-                        build_and_earn: amount,
-                        era: protocol_state.era,
-                        period: protocol_state.period_number(),
-                    },
-                    0,
-                ),
-            };
-
-            // The `inner_stake` function takes a `StakeAmount` struct allowing modification of both `voting` and `build_and_earn` amounts at the same time.
-            Self::inner_stake(&account, &smart_contract, stake_amount, bonus_status)?;
-
-            Self::deposit_event(Event::<T>::Stake {
-                account,
-                smart_contract,
-                amount,
-            });
-
-            Ok(())
+            Self::internal_stake(account, smart_contract, amount)
         }
 
         /// Unstake the specified amount from a smart contract.
@@ -1087,24 +1432,16 @@ pub mod pallet {
         /// In case amount is unstaked during `Build&Earn` subperiod, first the `build_and_earn` is reduced,
         /// and any spillover is subtracted from the `voting` amount.
         #[pallet::call_index(12)]
-        #[pallet::weight(T::WeightInfo::unstake())]
+        #[pallet::weight(T::WeightInfo::unstake().saturating_add(T::Observers::max_weight()))]
         pub fn unstake(
             origin: OriginFor<T>,
             smart_contract: T::SmartContract,
             #[pallet::compact] amount: Balance,
-        ) -> DispatchResult {
+        ) -> DispatchResultWithPostInfo {
             Self::ensure_pallet_enabled()?;
             let account = ensure_signed(origin)?;
 
-            let (unstake_amount, _) = Self::inner_unstake(&account, &smart_contract, amount)?;
-
-            Self::deposit_event(Event::<T>::Unstake {
-                account,
-                smart_contract,
-                amount: unstake_amount.total(),
-            });
-
-            Ok(())
+            Self::internal_unstake(account, smart_contract, amount)
         }
 
         /// Claims some staker rewards, if user has any.
@@ -1114,6 +1451,10 @@ pub mod pallet {
             let max_span_length = T::EraRewardSpanLength::get();
             T::WeightInfo::claim_staker_rewards_ongoing_period(max_span_length)
                 .max(T::WeightInfo::claim_staker_rewards_past_period(max_span_length))
+                .saturating_add(T::WeightInfo::compound_reward(
+                    T::MaxNumberOfStakedContracts::get(),
+                ))
+                .saturating_add(Pallet::<T>::max_claim_observer_weight())
         })]
         pub fn claim_staker_rewards(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
             Self::ensure_pallet_enabled()?;
@@ -1124,11 +1465,17 @@ pub mod pallet {
 
         /// Used to claim bonus reward for a smart contract, if eligible.
         #[pallet::call_index(14)]
-        #[pallet::weight(T::WeightInfo::claim_bonus_reward())]
+        #[pallet::weight(
+            T::WeightInfo::claim_bonus_reward()
+                .saturating_add(T::WeightInfo::compound_reward(
+                    T::MaxNumberOfStakedContracts::get(),
+                ))
+                .saturating_add(Pallet::<T>::max_claim_observer_weight())
+        )]
         pub fn claim_bonus_reward(
             origin: OriginFor<T>,
             smart_contract: T::SmartContract,
-        ) -> DispatchResult {
+        ) -> DispatchResultWithPostInfo {
             Self::ensure_pallet_enabled()?;
             let account = ensure_signed(origin)?;
 
@@ -1136,13 +1483,19 @@ pub mod pallet {
         }
 
         /// Used to claim dApp reward for the specified era.
+        ///
+        /// If the dApp has reward splits configured, each split beneficiary is paid its share directly,
+        /// and the remainder goes to the reward beneficiary. A separate event is emitted for each payout.
         #[pallet::call_index(15)]
-        #[pallet::weight(T::WeightInfo::claim_dapp_reward())]
+        #[pallet::weight(
+            T::WeightInfo::claim_dapp_reward(T::MaxNumberOfRewardSplits::get())
+                .saturating_add(Pallet::<T>::max_dapp_claim_observer_weight())
+        )]
         pub fn claim_dapp_reward(
             origin: OriginFor<T>,
             smart_contract: T::SmartContract,
             #[pallet::compact] era: EraNumber,
-        ) -> DispatchResult {
+        ) -> DispatchResultWithPostInfo {
             Self::ensure_pallet_enabled()?;
 
             // To keep in line with legacy behavior, dApp rewards can be claimed by anyone.
@@ -1151,60 +1504,61 @@ pub mod pallet {
             let dapp_info =
                 IntegratedDApps::<T>::get(&smart_contract).ok_or(Error::<T>::ContractNotFound)?;
 
-            // Make sure provided era has ended
-            let protocol_state = ActiveProtocolState::<T>::get();
-            ensure!(era < protocol_state.era, Error::<T>::InvalidClaimEra);
-
             // 'Consume' dApp reward for the specified era, if possible.
-            let mut dapp_tiers = DAppTiers::<T>::get(&era).ok_or(Error::<T>::NoDAppTierInfo)?;
-            ensure!(
-                dapp_tiers.period >= Self::oldest_claimable_period(protocol_state.period_number()),
-                Error::<T>::RewardExpired
-            );
-
-            let (amount, ranked_tier) =
-                dapp_tiers
-                    .try_claim(dapp_info.id)
-                    .map_err(|error| match error {
-                        DAppTierError::NoDAppInTiers => Error::<T>::NoClaimableRewards,
-                        _ => Error::<T>::InternalClaimDAppError,
-                    })?;
+            let protocol_state = ActiveProtocolState::<T>::get();
+            let (dapp_tiers, amount, ranked_tier) =
+                Self::consume_dapp_reward(dapp_info.id, era, &protocol_state)?;
 
             let (tier_id, rank) = ranked_tier.deconstruct();
 
-            // Get reward destination, and deposit the reward.
-            let beneficiary = dapp_info.reward_beneficiary();
-            T::StakingRewardHandler::payout_reward(&beneficiary, amount)
-                .map_err(|_| Error::<T>::RewardPayoutFailed)?;
+            // Split the reward between the beneficiaries, and deposit each share.
+            let payouts = dapp_info.reward_payouts(amount);
+            for (beneficiary, amount) in payouts.iter() {
+                T::StakingRewardHandler::payout_reward(beneficiary, *amount)
+                    .map_err(|_| Error::<T>::RewardPayoutFailed)?;
+            }
 
             // Write back updated struct to prevent double reward claims
             DAppTiers::<T>::insert(&era, dapp_tiers);
 
-            Self::deposit_event(Event::<T>::DAppReward {
-                beneficiary: beneficiary.clone(),
-                smart_contract,
-                tier_id,
-                rank,
-                era,
-                amount,
-            });
+            let mut observer_weight = Weight::zero();
+            for (beneficiary, amount) in payouts {
+                observer_weight
+                    .saturating_accrue(T::Observers::on_reward_paid(&beneficiary, amount));
+                Self::deposit_event(Event::<T>::DAppReward {
+                    beneficiary,
+                    smart_contract: smart_contract.clone(),
+                    tier_id,
+                    rank,
+                    era,
+                    amount,
+                });
+            }
 
-            Ok(())
+            Ok(Some(
+                T::WeightInfo::claim_dapp_reward(
+                    dapp_info.reward_splits().len().unique_saturated_into(),
+                )
+                .saturating_add(observer_weight),
+            )
+            .into())
         }
 
         /// Used to unstake funds from a contract that was unregistered after an account staked on it.
         /// This is required if staker wants to re-stake these funds on another active contract during the ongoing period.
         #[pallet::call_index(16)]
-        #[pallet::weight(T::WeightInfo::unstake_from_unregistered())]
+        #[pallet::weight(
+            T::WeightInfo::unstake_from_unregistered().saturating_add(T::Observers::max_weight())
+        )]
         pub fn unstake_from_unregistered(
             origin: OriginFor<T>,
             smart_contract: T::SmartContract,
-        ) -> DispatchResult {
+        ) -> DispatchResultWithPostInfo {
             Self::ensure_pallet_enabled()?;
             let account = ensure_signed(origin)?;
 
-            let (unstake_amount, _) =
-                Self::inner_unstake_from_unregistered(&account, &smart_contract)?;
+            let (unstake_amount, _, observer_weight) =
+                Self::inner_unstake_from_unregistered(&account, &smart_contract, None)?;
 
             Self::deposit_event(Event::<T>::UnstakeFromUnregistered {
                 account,
@@ -1212,7 +1566,10 @@ pub mod pallet {
                 amount: unstake_amount.total(),
             });
 
-            Ok(())
+            Ok(
+                Some(T::WeightInfo::unstake_from_unregistered().saturating_add(observer_weight))
+                    .into(),
+            )
         }
 
         /// Cleanup expired stake entries for the contract.
@@ -1326,6 +1683,10 @@ pub mod pallet {
             let max_span_length = T::EraRewardSpanLength::get();
             T::WeightInfo::claim_staker_rewards_ongoing_period(max_span_length)
                 .max(T::WeightInfo::claim_staker_rewards_past_period(max_span_length))
+                .saturating_add(T::WeightInfo::compound_reward(
+                    T::MaxNumberOfStakedContracts::get(),
+                ))
+                .saturating_add(Pallet::<T>::max_claim_observer_weight())
         })]
         pub fn claim_staker_rewards_for(
             origin: OriginFor<T>,
@@ -1339,12 +1700,18 @@ pub mod pallet {
 
         /// Used to claim bonus reward for a smart contract on behalf of the specified account, if eligible.
         #[pallet::call_index(20)]
-        #[pallet::weight(T::WeightInfo::claim_bonus_reward())]
+        #[pallet::weight(
+            T::WeightInfo::claim_bonus_reward()
+                .saturating_add(T::WeightInfo::compound_reward(
+                    T::MaxNumberOfStakedContracts::get(),
+                ))
+                .saturating_add(Pallet::<T>::max_claim_observer_weight())
+        )]
         pub fn claim_bonus_reward_for(
             origin: OriginFor<T>,
             account: T::AccountId,
             smart_contract: T::SmartContract,
-        ) -> DispatchResult {
+        ) -> DispatchResultWithPostInfo {
             Self::ensure_pallet_enabled()?;
             ensure_signed(origin)?;
 
@@ -1354,7 +1721,11 @@ pub mod pallet {
         /// Transfers stake between two smart contracts, ensuring bonus status preservation if eligible.
         /// Emits a `StakeMoved` event.
         #[pallet::call_index(21)]
-        #[pallet::weight(T::WeightInfo::move_stake_unregistered_source().max(T::WeightInfo::move_stake_from_registered_source()))]
+        #[pallet::weight(
+            T::WeightInfo::move_stake_unregistered_source()
+                .max(T::WeightInfo::move_stake_from_registered_source())
+                .saturating_add(T::Observers::max_weight().saturating_mul(2))
+        )]
         pub fn move_stake(
             origin: OriginFor<T>,
             source_contract: T::SmartContract,
@@ -1364,906 +1735,3026 @@ pub mod pallet {
             Self::ensure_pallet_enabled()?;
             let account = ensure_signed(origin)?;
 
-            ensure!(
-                !source_contract.eq(&destination_contract),
-                Error::<T>::SameContracts
-            );
+            Self::internal_move_stake(account, source_contract, destination_contract, amount)
+        }
 
-            ensure!(
-                IntegratedDApps::<T>::contains_key(&destination_contract),
-                Error::<T>::ContractNotFound
-            );
+        /// Used to set static tier parameters, which are used to calculate tier configuration.
+        /// Tier configuration defines tier entry threshold values, number of slots, and reward portions.
+        ///
+        /// This is a delicate call and great care should be taken when changing these
+        /// values since it has a significant impact on the reward system.
+        #[pallet::call_index(22)]
+        #[pallet::weight(T::WeightInfo::set_static_tier_params())]
+        pub fn set_static_tier_params(
+            origin: OriginFor<T>,
+            params: TierParameters<T::NumberOfTiers>,
+        ) -> DispatchResult {
+            Self::ensure_pallet_enabled()?;
+            ensure_root(origin)?;
+            ensure!(params.is_valid(), Error::<T>::InvalidTierParams);
 
-            let maybe_source_dapp_info = IntegratedDApps::<T>::get(&source_contract);
-            let is_source_unregistered = maybe_source_dapp_info.is_none();
+            StaticTierParams::<T>::set(params.clone());
 
-            let (mut move_amount, bonus_status) = if is_source_unregistered {
-                Self::inner_unstake_from_unregistered(&account, &source_contract)?
-            } else {
-                Self::inner_unstake(&account, &source_contract, amount)?
-            };
+            Self::deposit_event(Event::<T>::NewTierParameters { params });
 
-            // When bonus is forfeited, voting stake must be merged into b&e stake
-            if bonus_status == 0 && move_amount.voting > 0 {
-                move_amount.convert_bonus_into_regular_stake();
+            Ok(())
+        }
+
+        /// Enables or disables auto-compounding of the caller's staker & bonus rewards.
+        ///
+        /// When enabled, claimed rewards are locked into dApp staking and restaked on the contracts
+        /// the account is staking on in the ongoing period, proportionally to the existing stake.
+        /// This also applies when rewards are claimed on behalf of the account by a third party.
+        #[pallet::call_index(23)]
+        #[pallet::weight(T::WeightInfo::set_auto_compound())]
+        pub fn set_auto_compound(origin: OriginFor<T>, enabled: bool) -> DispatchResult {
+            Self::ensure_pallet_enabled()?;
+            let account = ensure_signed(origin)?;
+
+            if enabled {
+                AutoCompound::<T>::insert(&account, ());
+            } else {
+                AutoCompound::<T>::remove(&account);
             }
 
-            Self::inner_stake(&account, &destination_contract, move_amount, bonus_status)?;
+            Self::deposit_event(Event::<T>::AutoCompoundSet { account, enabled });
 
-            Self::deposit_event(Event::<T>::StakeMoved {
-                account,
-                source_contract,
-                destination_contract,
-                amount: move_amount.total(),
-            });
+            Ok(())
+        }
+
+        /// Enables or disables automatic claiming of the caller's staker & bonus rewards.
+        ///
+        /// When enabled, rewards which are about to expire are claimed on behalf of the account
+        /// in `on_idle`, when there is enough spare block weight.
+        #[pallet::call_index(26)]
+        #[pallet::weight(T::WeightInfo::set_auto_claim())]
+        pub fn set_auto_claim(origin: OriginFor<T>, enabled: bool) -> DispatchResult {
+            Self::ensure_pallet_enabled()?;
+            let account = ensure_signed(origin)?;
 
-            Ok(Some(if is_source_unregistered {
-                T::WeightInfo::move_stake_unregistered_source()
+            if enabled {
+                AutoClaim::<T>::insert(&account, ());
             } else {
-                T::WeightInfo::move_stake_from_registered_source()
-            })
-            .into())
+                AutoClaim::<T>::remove(&account);
+            }
+
+            Self::deposit_event(Event::<T>::AutoClaimSet { account, enabled });
+
+            Ok(())
         }
 
-        /// Used to set static tier parameters, which are used to calculate tier configuration.
-        /// Tier configuration defines tier entry threshold values, number of slots, and reward portions.
+        /// Used to set additional reward beneficiaries for a dApp, and their shares of the dApp reward.
         ///
-        /// This is a delicate call and great care should be taken when changing these
-        /// values since it has a significant impact on the reward system.
-        #[pallet::call_index(22)]
-        #[pallet::weight(T::WeightInfo::set_static_tier_params())]
-        pub fn set_static_tier_params(
+        /// Caller has to be dApp owner.
+        /// Each share must be non-zero, beneficiaries must be unique, and the sum of all shares must not exceed 100%.
+        /// Whatever remains after the splits are paid out goes to the reward beneficiary.
+        /// An empty list removes all the splits.
+        #[pallet::call_index(24)]
+        #[pallet::weight(T::WeightInfo::set_dapp_reward_splits())]
+        pub fn set_dapp_reward_splits(
             origin: OriginFor<T>,
-            params: TierParameters<T::NumberOfTiers>,
+            smart_contract: T::SmartContract,
+            splits: BoundedVec<(T::AccountId, Permill), T::MaxNumberOfRewardSplits>,
         ) -> DispatchResult {
             Self::ensure_pallet_enabled()?;
-            ensure_root(origin)?;
-            ensure!(params.is_valid(), Error::<T>::InvalidTierParams);
+            let dev_account = ensure_signed(origin)?;
 
-            StaticTierParams::<T>::set(params.clone());
+            ensure!(
+                Self::reward_splits_valid(&splits),
+                Error::<T>::InvalidRewardSplits
+            );
 
-            Self::deposit_event(Event::<T>::NewTierParameters { params });
+            IntegratedDApps::<T>::try_mutate(
+                &smart_contract,
+                |maybe_dapp_info| -> DispatchResult {
+                    let dapp_info = maybe_dapp_info
+                        .as_mut()
+                        .ok_or(Error::<T>::ContractNotFound)?;
+
+                    ensure!(dapp_info.owner == dev_account, Error::<T>::OriginNotOwner);
+
+                    dapp_info.reward_splits = splits.clone();
+
+                    Ok(())
+                },
+            )?;
+
+            Self::deposit_event(Event::<T>::DAppRewardSplitsUpdated {
+                smart_contract,
+                splits,
+            });
 
             Ok(())
         }
-    }
 
-    impl<T: Config> Pallet<T> {
-        /// Inner `unstake` functionality for an **active** smart contract.
-        /// If successful returns the `StakeAmount` that was unstaked, and the updated bonus status.
+        /// Used to claim dApp rewards for all eras in the specified range, including both ends.
         ///
-        /// - Ensures the contract is still registered.
-        /// - Updates staker info, ledger, and contract stake info.
-        /// - Returns the unstaked amount and updated bonus status.
-        pub fn inner_unstake(
-            account: &T::AccountId,
-            smart_contract: &T::SmartContract,
-            amount: Balance,
-        ) -> Result<(StakeAmount, BonusStatus), DispatchError> {
-            ensure!(amount > 0, Error::<T>::ZeroAmount);
+        /// Eras for which the dApp has no claimable reward are skipped, but at least one era must be claimable.
+        /// Rewards are paid out to each beneficiary in aggregate, while an event is emitted for each claimed era.
+        #[pallet::call_index(25)]
+        #[pallet::weight(
+            T::WeightInfo::claim_dapp_rewards(T::MaxNumberOfDAppRewardClaimEras::get())
+                .saturating_add(Pallet::<T>::max_dapp_claim_observer_weight())
+        )]
+        pub fn claim_dapp_rewards(
+            origin: OriginFor<T>,
+            smart_contract: T::SmartContract,
+            #[pallet::compact] first_era: EraNumber,
+            #[pallet::compact] last_era: EraNumber,
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_pallet_enabled()?;
+
+            // Same as with `claim_dapp_reward`, dApp rewards can be claimed by anyone.
+            let _ = ensure_signed(origin)?;
+
+            ensure!(
+                first_era <= last_era
+                    && last_era.saturating_sub(first_era)
+                        < T::MaxNumberOfDAppRewardClaimEras::get(),
+                Error::<T>::InvalidClaimEraRange
+            );
+
             let dapp_info =
                 IntegratedDApps::<T>::get(&smart_contract).ok_or(Error::<T>::ContractNotFound)?;
 
             let protocol_state = ActiveProtocolState::<T>::get();
-            let current_era = protocol_state.era;
+            ensure!(last_era < protocol_state.era, Error::<T>::InvalidClaimEra);
+
+            // 'Consume' dApp reward for each era in the range, aggregating the payouts per beneficiary.
+            let mut payouts: Vec<(T::AccountId, Balance)> = Vec::new();
+            for era in first_era..=last_era {
+                let (dapp_tiers, amount, ranked_tier) =
+                    match Self::consume_dapp_reward(dapp_info.id, era, &protocol_state) {
+                        Ok(claimed) => claimed,
+                        Err(
+                            Error::<T>::NoDAppTierInfo
+                            | Error::<T>::NoClaimableRewards
+                            | Error::<T>::RewardExpired,
+                        ) => continue,
+                        Err(error) => return Err(error.into()),
+                    };
 
-            let mut ledger = Ledger::<T>::get(&account);
+                // Write back updated struct to prevent double reward claims
+                DAppTiers::<T>::insert(&era, dapp_tiers);
 
-            // 1.
-            // Update `StakerInfo` storage with the reduced stake amount on the specified contract.
-            let (new_staking_info, amount, stake_amount_iter, updated_bonus_status) =
-                match StakerInfo::<T>::get(&account, &smart_contract) {
-                    Some(mut staking_info) => {
-                        ensure!(
-                            staking_info.period_number() == protocol_state.period_number(),
-                            Error::<T>::UnstakeFromPastPeriod
-                        );
-                        ensure!(
-                            staking_info.total_staked_amount() >= amount,
-                            Error::<T>::UnstakeAmountTooLarge
-                        );
+                let (tier_id, rank) = ranked_tier.deconstruct();
+                for (beneficiary, amount) in dapp_info.reward_payouts(amount) {
+                    match payouts
+                        .iter_mut()
+                        .find(|(account, _)| *account == beneficiary)
+                    {
+                        Some((_, total)) => total.saturating_accrue(amount),
+                        None => payouts.push((beneficiary.clone(), amount)),
+                    }
 
-                        // If unstaking would take the total staked amount below the minimum required value,
-                        // unstake everything.
-                        let amount = if staking_info.total_staked_amount().saturating_sub(amount)
-                            < T::MinimumStakeAmount::get()
-                        {
-                            staking_info.total_staked_amount()
-                        } else {
-                            amount
-                        };
+                    Self::deposit_event(Event::<T>::DAppReward {
+                        beneficiary,
+                        smart_contract: smart_contract.clone(),
+                        tier_id,
+                        rank,
+                        era,
+                        amount,
+                    });
+                }
+            }
+            ensure!(!payouts.is_empty(), Error::<T>::NoClaimableRewards);
+
+            let mut observer_weight = Weight::zero();
+            for (beneficiary, amount) in payouts {
+                T::StakingRewardHandler::payout_reward(&beneficiary, amount)
+                    .map_err(|_| Error::<T>::RewardPayoutFailed)?;
+                observer_weight
+                    .saturating_accrue(T::Observers::on_reward_paid(&beneficiary, amount));
+            }
 
-                        let (stake_amount_iter, updated_bonus_status) =
-                            staking_info.unstake(amount, current_era, protocol_state.subperiod());
+            Ok(Some(
+                T::WeightInfo::claim_dapp_rewards(
+                    last_era.saturating_sub(first_era).saturating_add(1),
+                )
+                .saturating_add(observer_weight),
+            )
+            .into())
+        }
 
-                        (
-                            staking_info,
-                            amount,
-                            stake_amount_iter,
-                            updated_bonus_status,
-                        )
-                    }
-                    None => {
-                        return Err(Error::<T>::NoStakingInfo.into());
-                    }
-                };
+        /// Instantly unlocks up to the specified amount, paying a penalty for it.
+        ///
+        /// Amount is first taken from the unlocking chunks which haven't been fully unlocked yet, starting with the
+        /// one which would be unlocked last. The rest is taken from the locked amount which isn't staked,
+        /// with the same rules as for the regular `unlock`.
+        ///
+        /// Penalty is charged from the unlocked amount, and handled by the `InstantUnlockPenaltyHandler`.
+        #[pallet::call_index(27)]
+        #[pallet::weight(T::WeightInfo::instant_unlock(T::MaxNumberOfStakedContracts::get()))]
+        pub fn instant_unlock(
+            origin: OriginFor<T>,
+            #[pallet::compact] amount: Balance,
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_pallet_enabled()?;
+            let account = ensure_signed(origin)?;
+            let penalty_ratio =
+                InstantUnlockPenalty::<T>::get().ok_or(Error::<T>::InstantUnlockDisabled)?;
 
-            // 2.
-            // Reduce stake amount
-            ledger
-                .unstake_amount(amount, current_era, protocol_state.period_info)
-                .map_err(|err| match err {
-                    AccountLedgerError::InvalidPeriod | AccountLedgerError::InvalidEra => {
-                        Error::<T>::UnclaimedRewards
-                    }
-                    // This is a defensive check, which should never happen since we calculate the correct value above.
-                    AccountLedgerError::UnstakeAmountLargerThanStake => {
-                        Error::<T>::UnstakeAmountTooLarge
-                    }
-                    _ => Error::<T>::InternalUnstakeError,
-                })?;
+            let state = ActiveProtocolState::<T>::get();
+            let mut ledger = Ledger::<T>::get(&account);
 
-            // 3.
-            // Update `ContractStake` storage with the reduced stake amount on the specified contract.
-            let mut contract_stake_info = ContractStake::<T>::get(&dapp_info.id);
-            contract_stake_info.unstake(
-                &stake_amount_iter,
-                protocol_state.period_info,
-                current_era,
-            );
+            // Pending unlocking chunks are consumed first.
+            let current_block = frame_system::Pallet::<T>::block_number();
+            let unlocking_amount =
+                ledger.consume_pending_unlocking_amount(amount, current_block.saturated_into());
 
-            // 4.
-            // Update total staked amount for the next era.
-            CurrentEraInfo::<T>::mutate(|era_info| {
-                era_info.unstake_amount(stake_amount_iter.clone());
-            });
+            // The rest is unlocked from the locked amount.
+            let remaining_request = amount.saturating_sub(unlocking_amount);
+            let locked_amount = if remaining_request.is_zero() {
+                Balance::zero()
+            } else {
+                let available_for_unlocking = ledger.unlockable_amount(state.period_info.number);
+                let amount_to_unlock = available_for_unlocking.min(remaining_request);
+
+                // Ensure we unlock everything if remaining amount is below threshold.
+                let remaining_amount = ledger
+                    .active_locked_amount()
+                    .saturating_sub(amount_to_unlock);
+                if remaining_amount < T::MinimumLockedAmount::get() {
+                    ensure!(
+                        ledger.staked_amount(state.period_info.number).is_zero(),
+                        Error::<T>::RemainingStakePreventsFullUnlock
+                    );
+                    ledger.active_locked_amount()
+                } else {
+                    amount_to_unlock
+                }
+            };
+            ledger.subtract_lock_amount(locked_amount);
 
-            // 5.
-            // Update remaining storage entries
-            ContractStake::<T>::insert(&dapp_info.id, contract_stake_info);
+            let amount = unlocking_amount.saturating_add(locked_amount);
+            ensure!(!amount.is_zero(), Error::<T>::ZeroAmount);
 
-            if new_staking_info.is_empty() {
-                ledger.contract_stake_count.saturating_dec();
-                StakerInfo::<T>::remove(&account, &smart_contract);
+            // In case it's full unlock, account is exiting dApp staking, ensure all storage is cleaned up.
+            let removed_entries = if ledger.is_empty() {
+                let _ = StakerInfo::<T>::clear_prefix(&account, ledger.contract_stake_count, None);
+                ledger.contract_stake_count
             } else {
-                StakerInfo::<T>::insert(&account, &smart_contract, new_staking_info);
-            }
+                0
+            };
 
+            // Update storage
             Self::update_ledger(&account, ledger)?;
+            CurrentEraInfo::<T>::mutate(|era_info| {
+                era_info.unlocking_started(locked_amount);
+                era_info.unlocking_removed(amount);
+            });
+            Self::burn_lock_receipt(&account, locked_amount)?;
+
+            // Charge the penalty from the now unlocked funds.
+            let penalty = penalty_ratio * amount;
+            let credit = T::Currency::withdraw(
+                &account,
+                penalty,
+                Precision::Exact,
+                Preservation::Expendable,
+                Fortitude::Polite,
+            )?;
+            T::InstantUnlockPenaltyHandler::on_unbalanced(credit);
 
-            // Return the `StakeAmount` that has max total value.
-            let mut unstake_amount = stake_amount_iter
-                .iter()
-                .max_by(|a, b| a.total().cmp(&b.total()))
-                // At least one value exists, otherwise we wouldn't be here.
-                .ok_or(Error::<T>::InternalUnstakeError)?
-                .clone();
-
-            // Ensure we use the current era instead of potentially next era
-            unstake_amount.era = current_era;
+            Self::deposit_event(Event::<T>::InstantUnlock {
+                account,
+                amount,
+                penalty,
+            });
 
-            Ok((unstake_amount, updated_bonus_status))
+            Ok(Some(T::WeightInfo::instant_unlock(removed_entries)).into())
         }
 
-        /// Handles unstaking from an **unregistered** smart contract.
+        /// Used to set the penalty ratio charged for instant unlocking.
         ///
-        /// - Ensures the contract is no longer active.
-        /// - Updates staker info and ledger.
-        /// - Returns the unstaked amount and preserves the original bonus status.
-        pub fn inner_unstake_from_unregistered(
-            account: &T::AccountId,
-            smart_contract: &T::SmartContract,
-        ) -> Result<(StakeAmount, BonusStatus), DispatchError> {
-            ensure!(
-                !IntegratedDApps::<T>::contains_key(&smart_contract),
-                Error::<T>::ContractStillActive
-            );
+        /// In case `None` is provided, instant unlocking is disabled.
+        #[pallet::call_index(28)]
+        #[pallet::weight(T::WeightInfo::set_instant_unlock_penalty())]
+        pub fn set_instant_unlock_penalty(
+            origin: OriginFor<T>,
+            penalty: Option<Perbill>,
+        ) -> DispatchResult {
+            Self::ensure_pallet_enabled()?;
+            ensure_root(origin)?;
 
-            let protocol_state = ActiveProtocolState::<T>::get();
-            let current_era = protocol_state.era;
+            InstantUnlockPenalty::<T>::set(penalty);
 
-            // Extract total staked amount on the specified unregistered contract
-            let (amount, unstake_amount_iter, preserved_bonus_status) =
-                match StakerInfo::<T>::get(&account, &smart_contract) {
-                    Some(mut staking_info) => {
-                        ensure!(
-                            staking_info.period_number() == protocol_state.period_number(),
-                            Error::<T>::UnstakeFromPastPeriod
-                        );
+            Self::deposit_event(Event::<T>::InstantUnlockPenaltySet { penalty });
 
-                        let preserved_bonus_status = staking_info.bonus_status;
-                        let amount = staking_info.staked.total();
+            Ok(())
+        }
 
-                        let (unstake_amount_iter, _) =
-                            staking_info.unstake(amount, current_era, protocol_state.subperiod());
+        /// Used to set the account to which the caller's staker & bonus rewards are paid out.
+        ///
+        /// In case `None` is provided, or the destination is the caller itself, rewards are paid out to the caller.
+        /// Auto-compounding only applies to rewards paid out to the caller.
+        #[pallet::call_index(29)]
+        #[pallet::weight(T::WeightInfo::set_staker_reward_destination())]
+        pub fn set_staker_reward_destination(
+            origin: OriginFor<T>,
+            destination: Option<T::AccountId>,
+        ) -> DispatchResult {
+            Self::ensure_pallet_enabled()?;
+            let account = ensure_signed(origin)?;
 
-                        (amount, unstake_amount_iter, preserved_bonus_status)
-                    }
-                    None => {
-                        return Err(Error::<T>::NoStakingInfo.into());
-                    }
-                };
+            let destination = destination.filter(|destination| *destination != account);
+            match &destination {
+                Some(destination) => StakerRewardDestination::<T>::insert(&account, destination),
+                None => StakerRewardDestination::<T>::remove(&account),
+            }
 
-            // Reduce stake amount in ledger
-            let mut ledger = Ledger::<T>::get(&account);
-            ledger
-                .unstake_amount(amount, current_era, protocol_state.period_info)
-                .map_err(|err| match err {
-                    // These are all defensive checks, which should never fail since we already checked them above.
-                    AccountLedgerError::InvalidPeriod | AccountLedgerError::InvalidEra => {
-                        Error::<T>::UnclaimedRewards
-                    }
-                    _ => Error::<T>::InternalUnstakeError,
-                })?;
-            ledger.contract_stake_count.saturating_dec();
+            Self::deposit_event(Event::<T>::StakerRewardDestinationSet {
+                account,
+                destination,
+            });
 
-            // Update total staked amount for the next era.
-            // This means 'fake' stake total amount has been kept until now, even though contract was unregistered.
-            // Although strange, it's been requested to keep it like this from the team.
-            CurrentEraInfo::<T>::mutate(|era_info| {
-                era_info.unstake_amount(unstake_amount_iter.clone());
+            Ok(())
+        }
+
+        /// Grants `delegate` the specified permissions to rearrange the caller's stake on their behalf,
+        /// without giving it access to the locked funds.
+        ///
+        /// In case `delegate` already exists, its permissions are replaced with the new ones.
+        #[pallet::call_index(30)]
+        #[pallet::weight(T::WeightInfo::set_delegate())]
+        pub fn set_delegate(
+            origin: OriginFor<T>,
+            delegate: T::AccountId,
+            permissions: DelegatePermissions,
+        ) -> DispatchResult {
+            Self::ensure_pallet_enabled()?;
+            let account = ensure_signed(origin)?;
+
+            ensure!(account != delegate, Error::<T>::SelfDelegation);
+            ensure!(!permissions.is_empty(), Error::<T>::NoDelegatePermissions);
+
+            StakeDelegates::<T>::try_mutate(&account, |delegates| {
+                delegates
+                    .try_insert(delegate.clone(), permissions)
+                    .map_err(|_| Error::<T>::TooManyDelegates)
+            })?;
+
+            Self::deposit_event(Event::<T>::DelegateSet {
+                account,
+                delegate,
+                permissions,
             });
 
-            // Update remaining storage entries
-            Self::update_ledger(&account, ledger)?;
-            StakerInfo::<T>::remove(&account, &smart_contract);
+            Ok(())
+        }
 
-            // Return the `StakeAmount` that has max total value.
-            let mut unstake_amount = unstake_amount_iter
-                .iter()
-                .max_by(|a, b| a.total().cmp(&b.total()))
-                // At least one value exists, otherwise we wouldn't be here.
-                .ok_or(Error::<T>::InternalUnstakeError)?
-                .clone();
+        /// Revokes all of the permissions previously granted to `delegate`.
+        #[pallet::call_index(31)]
+        #[pallet::weight(T::WeightInfo::revoke_delegate())]
+        pub fn revoke_delegate(origin: OriginFor<T>, delegate: T::AccountId) -> DispatchResult {
+            Self::ensure_pallet_enabled()?;
+            let account = ensure_signed(origin)?;
 
-            // Ensure we use the current era instead of potentially next era
-            unstake_amount.era = current_era;
+            StakeDelegates::<T>::try_mutate_exists(&account, |maybe_delegates| {
+                let delegates = maybe_delegates
+                    .as_mut()
+                    .ok_or(Error::<T>::DelegateNotFound)?;
+                delegates
+                    .remove(&delegate)
+                    .ok_or(Error::<T>::DelegateNotFound)?;
+
+                if delegates.is_empty() {
+                    *maybe_delegates = None;
+                }
+
+                Ok::<_, Error<T>>(())
+            })?;
 
-            Ok((unstake_amount, preserved_bonus_status))
+            Self::deposit_event(Event::<T>::DelegateRevoked { account, delegate });
+
+            Ok(())
         }
 
-        /// Inner `stake` functionality.
+        /// Same as `stake`, but performed by a delegate on behalf of `for_account`, using its locked funds.
         ///
-        /// Specifies the amount in the form of the `StakeAmount` struct, allowing simultaneous update of both `voting` and `build_and_earn` amounts.
-        /// The `bonus_status` is used to determine if the staker is still eligible for the bonus reward. This is useful for the `move` extrinsic.
-        pub fn inner_stake(
-            account: &T::AccountId,
-            smart_contract: &T::SmartContract,
-            amount: StakeAmount,
-            bonus_status: BonusStatus,
-        ) -> Result<(), DispatchError> {
-            ensure!(amount.total() > 0, Error::<T>::ZeroAmount);
-
-            let dapp_info =
-                IntegratedDApps::<T>::get(&smart_contract).ok_or(Error::<T>::ContractNotFound)?;
+        /// Caller must be a delegate of `for_account`, permitted to stake.
+        #[pallet::call_index(32)]
+        #[pallet::weight(
+            T::WeightInfo::stake()
+                .saturating_add(T::DbWeight::get().reads(1))
+                .saturating_add(T::Observers::max_weight())
+        )]
+        pub fn stake_for(
+            origin: OriginFor<T>,
+            for_account: T::AccountId,
+            smart_contract: T::SmartContract,
+            #[pallet::compact] amount: Balance,
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_pallet_enabled()?;
+            let delegate = ensure_signed(origin)?;
+            Self::ensure_delegated(&for_account, &delegate, DelegatedAction::Stake)?;
 
-            let protocol_state = ActiveProtocolState::<T>::get();
-            let current_era = protocol_state.era;
-            let period_number = protocol_state.period_info.number;
-            ensure!(
-                !protocol_state
-                    .period_info
-                    .is_next_period(current_era.saturating_add(1)),
-                Error::<T>::PeriodEndsInNextEra
-            );
+            Self::internal_stake(for_account, smart_contract, amount)
+                .map(Self::with_delegation_check_weight)
+        }
 
-            let mut ledger = Ledger::<T>::get(&account);
+        /// Same as `unstake`, but performed by a delegate on behalf of `for_account`.
+        ///
+        /// Caller must be a delegate of `for_account`, permitted to unstake.
+        #[pallet::call_index(33)]
+        #[pallet::weight(
+            T::WeightInfo::unstake()
+                .saturating_add(T::DbWeight::get().reads(1))
+                .saturating_add(T::Observers::max_weight())
+        )]
+        pub fn unstake_for(
+            origin: OriginFor<T>,
+            for_account: T::AccountId,
+            smart_contract: T::SmartContract,
+            #[pallet::compact] amount: Balance,
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_pallet_enabled()?;
+            let delegate = ensure_signed(origin)?;
+            Self::ensure_delegated(&for_account, &delegate, DelegatedAction::Unstake)?;
 
-            // In case old stake rewards are unclaimed & have expired, clean them up.
-            let threshold_period = Self::oldest_claimable_period(protocol_state.period_number());
-            let _ignore = ledger.maybe_cleanup_expired(threshold_period);
+            Self::internal_unstake(for_account, smart_contract, amount)
+                .map(Self::with_delegation_check_weight)
+        }
 
-            // 1.
-            // Increase stake amount for the next era & current period in staker's ledger
-            ledger
-                .add_stake_amount(amount, current_era, protocol_state.period_info)
-                .map_err(|err| match err {
-                    AccountLedgerError::InvalidPeriod | AccountLedgerError::InvalidEra => {
-                        Error::<T>::UnclaimedRewards
-                    }
-                    AccountLedgerError::UnavailableStakeFunds => Error::<T>::UnavailableStakeFunds,
-                    // Defensive check, should never happen
-                    _ => Error::<T>::InternalStakeError,
-                })?;
+        /// Same as `move_stake`, but performed by a delegate on behalf of `for_account`.
+        ///
+        /// Caller must be a delegate of `for_account`, permitted to move stake.
+        #[pallet::call_index(34)]
+        #[pallet::weight(
+            T::WeightInfo::move_stake_unregistered_source()
+                .max(T::WeightInfo::move_stake_from_registered_source())
+                .saturating_add(T::DbWeight::get().reads(1))
+                .saturating_add(T::Observers::max_weight().saturating_mul(2))
+        )]
+        pub fn move_stake_for(
+            origin: OriginFor<T>,
+            for_account: T::AccountId,
+            source_contract: T::SmartContract,
+            destination_contract: T::SmartContract,
+            #[pallet::compact] amount: Balance,
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_pallet_enabled()?;
+            let delegate = ensure_signed(origin)?;
+            Self::ensure_delegated(&for_account, &delegate, DelegatedAction::MoveStake)?;
 
-            // 2.
-            // Update `StakerInfo` storage with the new stake amount on the specified contract.
-            //
-            // There are three distinct scenarios:
-            // 1. Existing entry matches the current period number - just update it.
-            // 2. Existing entry is expired/orphaned - replace it.
-            // 3. Entry doesn't exist or it's for an older period - create a new one.
-            //
-            // This is ok since we only use this storage entry to keep track of how much each staker
-            // has staked on each contract in the current period. We only ever need the latest information.
-            // This is because `AccountLedger` is the one keeping information about how much was staked when.
-            let (mut new_staking_info, is_new_entry, replacing_old_entry) =
-                match StakerInfo::<T>::get(&account, &smart_contract) {
-                    // Entry with matching period exists
-                    Some(staking_info)
-                        if staking_info.period_number() == protocol_state.period_number() =>
-                    {
-                        (staking_info, false, false)
-                    }
-                    // Entry exists but period doesn't match. Bonus reward might still be claimable.
-                    Some(staking_info)
-                        if staking_info.period_number() >= threshold_period
-                            && staking_info.is_bonus_eligible() =>
-                    {
-                        return Err(Error::<T>::UnclaimedRewards.into());
-                    }
-                    // Entry exists but is expired/orphaned - we're replacing it, not adding new
-                    Some(_old_entry) => {
-                        // Remove the old orphaned entry explicitly
-                        StakerInfo::<T>::remove(&account, &smart_contract);
-                        (
-                            SingularStakingInfo::new(protocol_state.period_number(), bonus_status),
-                            true, // is_new_entry (for storage write)
-                            true, // replacing_old_entry (don't increment counter)
-                        )
-                    }
-                    // No entry exists at all - truly new
-                    None => (
-                        SingularStakingInfo::new(
-                            protocol_state.period_number(),
-                            // Important to account for the remaining bonus moves since it's possible to retain the bonus
-                            // after moving the stake to another contract.
-                            bonus_status,
-                        ),
-                        true,
-                        false,
-                    ),
-                };
+            Self::internal_move_stake(for_account, source_contract, destination_contract, amount)
+                .map(Self::with_delegation_check_weight)
+        }
 
-            new_staking_info.stake(amount, current_era, bonus_status);
-            ensure!(
-                new_staking_info.total_staked_amount() >= T::MinimumStakeAmount::get(),
-                Error::<T>::InsufficientStakeAmount
-            );
+        /// Sets the caller's carry-over plan, replacing the existing one.
+        ///
+        /// Starting with the next period, the planned amounts are automatically staked on the specified
+        /// smart contracts at the start of each `Voting` subperiod, on behalf of the caller, and count as voting stake.
+        /// Planned stake which cannot be applied (e.g. due to insufficient locked funds or unclaimed rewards)
+        /// is reported via an event.
+        #[pallet::call_index(35)]
+        #[pallet::weight(T::WeightInfo::set_carry_over_plan(entries.len() as u32))]
+        pub fn set_carry_over_plan(
+            origin: OriginFor<T>,
+            entries: CarryOverEntriesFor<T>,
+        ) -> DispatchResult {
+            Self::ensure_pallet_enabled()?;
+            let account = ensure_signed(origin)?;
 
-            // Only increment if we're truly adding a new contract, not replacing an orphaned entry
-            if is_new_entry && !replacing_old_entry {
-                ledger.contract_stake_count.saturating_inc();
+            ensure!(!entries.is_empty(), Error::<T>::EmptyCarryOverPlan);
+            for (idx, (smart_contract, amount)) in entries.iter().enumerate() {
+                ensure!(!amount.is_zero(), Error::<T>::ZeroAmount);
                 ensure!(
-                    ledger.contract_stake_count <= T::MaxNumberOfStakedContracts::get(),
-                    Error::<T>::TooManyStakedContracts
+                    !entries
+                        .iter()
+                        .take(idx)
+                        .any(|(other, _)| other == smart_contract),
+                    Error::<T>::DuplicateCarryOverContract
+                );
+                ensure!(
+                    IntegratedDApps::<T>::contains_key(smart_contract),
+                    Error::<T>::ContractNotFound
                 );
             }
 
-            // 3.
-            // Update `ContractStake` storage with the new stake amount on the specified contract.
-            let mut contract_stake_info = ContractStake::<T>::get(&dapp_info.id);
-            contract_stake_info.stake(amount, current_era, period_number);
+            let start_period = ActiveProtocolState::<T>::get()
+                .period_number()
+                .saturating_add(1);
+            CarryOverPlans::<T>::insert(
+                &account,
+                CarryOverPlan {
+                    entries: entries.clone(),
+                    start_period,
+                },
+            );
 
-            // 4.
-            // Update total staked amount for the next era.
-            CurrentEraInfo::<T>::mutate(|era_info| {
-                era_info.add_stake_amount(amount);
+            Self::deposit_event(Event::<T>::CarryOverPlanSet {
+                account,
+                entries,
+                start_period,
             });
 
-            // 5.
-            // Update remaining storage entries
-            Self::update_ledger(&account, ledger)?;
-            StakerInfo::<T>::insert(&account, &smart_contract, new_staking_info);
-            ContractStake::<T>::insert(&dapp_info.id, contract_stake_info);
-
             Ok(())
         }
 
-        /// `true` if the account is a staker, `false` otherwise.
-        pub fn is_staker(account: &T::AccountId) -> bool {
-            Ledger::<T>::contains_key(account)
-        }
+        /// Removes the caller's carry-over plan.
+        #[pallet::call_index(36)]
+        #[pallet::weight(T::WeightInfo::remove_carry_over_plan())]
+        pub fn remove_carry_over_plan(origin: OriginFor<T>) -> DispatchResult {
+            Self::ensure_pallet_enabled()?;
+            let account = ensure_signed(origin)?;
 
-        /// `Err` if pallet disabled for maintenance, `Ok` otherwise.
-        pub(crate) fn ensure_pallet_enabled() -> Result<(), Error<T>> {
-            if ActiveProtocolState::<T>::get().maintenance {
-                Err(Error::<T>::Disabled)
-            } else {
-                Ok(())
-            }
-        }
+            ensure!(
+                CarryOverPlans::<T>::contains_key(&account),
+                Error::<T>::NoCarryOverPlan
+            );
+            CarryOverPlans::<T>::remove(&account);
 
-        /// Update the account ledger, and dApp staking balance freeze.
-        ///
-        /// In case account ledger is empty, entries from the DB are removed and freeze is thawed.
-        ///
-        /// This call can fail if the `freeze` or `thaw` operations fail. This should never happen since
-        /// runtime definition must ensure it supports necessary freezes.
-        pub(crate) fn update_ledger(
-            account: &T::AccountId,
-            ledger: AccountLedgerFor<T>,
-        ) -> Result<(), DispatchError> {
-            if ledger.is_empty() {
-                Ledger::<T>::remove(&account);
-                T::Currency::thaw(&FreezeReason::DAppStaking.into(), account)?;
-            } else {
-                T::Currency::set_freeze(
-                    &FreezeReason::DAppStaking.into(),
-                    account,
-                    ledger.total_locked_amount(),
-                )?;
-                Ledger::<T>::insert(account, ledger);
-            }
+            Self::deposit_event(Event::<T>::CarryOverPlanRemoved { account });
 
             Ok(())
         }
 
-        /// Returns the number of blocks per voting period.
-        pub(crate) fn blocks_per_voting_period() -> BlockNumber {
-            T::CycleConfiguration::blocks_per_era()
-                .saturating_mul(T::CycleConfiguration::eras_per_voting_subperiod().into())
-        }
-
-        /// Calculates the `EraRewardSpan` index for the specified era.
-        pub fn era_reward_span_index(era: EraNumber) -> EraNumber {
-            era.saturating_sub(era % T::EraRewardSpanLength::get())
-        }
+        /// Used by the dApp owner to set the metadata of their dApp, e.g. name, URL, logo & category.
+        ///
+        /// A deposit proportional to the encoded size of the metadata is held from the caller.
+        /// The deposit held for the previous metadata, if any, is released back to its depositor.
+        #[pallet::call_index(37)]
+        #[pallet::weight(T::WeightInfo::set_dapp_metadata())]
+        pub fn set_dapp_metadata(
+            origin: OriginFor<T>,
+            smart_contract: T::SmartContract,
+            metadata: DAppMetadataFor<T>,
+        ) -> DispatchResult {
+            Self::ensure_pallet_enabled()?;
+            let dev_account = ensure_signed(origin)?;
 
-        /// Return the oldest period for which rewards can be claimed.
-        /// All rewards before that period are considered to be expired.
-        pub(crate) fn oldest_claimable_period(current_period: PeriodNumber) -> PeriodNumber {
-            current_period.saturating_sub(T::RewardRetentionInPeriods::get())
-        }
+            let dapp_info =
+                IntegratedDApps::<T>::get(&smart_contract).ok_or(Error::<T>::ContractNotFound)?;
+            ensure!(dapp_info.owner == dev_account, Error::<T>::OriginNotOwner);
+            ensure!(!metadata.name.is_empty(), Error::<T>::EmptyDAppName);
 
-        /// Unlocking period expressed in the number of blocks.
-        pub fn unlocking_period() -> BlockNumber {
-            T::CycleConfiguration::blocks_per_era().saturating_mul(T::UnlockingPeriod::get().into())
-        }
+            // Previous deposit is released first, so it can be reused for the new metadata.
+            let _ = Self::take_dapp_metadata(&smart_contract);
 
-        /// Returns the dApp tier assignment for the current era, based on the current stake amounts.
-        pub fn get_dapp_tier_assignment() -> BTreeMap<DAppId, RankedTier> {
-            let protocol_state = ActiveProtocolState::<T>::get();
+            // Deposit must not be covered by the funds locked in dApp staking.
+            let deposit = T::DAppMetadataDepositPerByte::get()
+                .saturating_mul(metadata.encoded_size().saturated_into());
+            ensure!(
+                T::Currency::reducible_balance(
+                    &dev_account,
+                    Preservation::Preserve,
+                    Fortitude::Polite
+                ) >= deposit,
+                Error::<T>::InsufficientBalanceForDeposit
+            );
+            T::Currency::hold(&HoldReason::DAppMetadata.into(), &dev_account, deposit)
+                .map_err(|_| Error::<T>::InsufficientBalanceForDeposit)?;
 
-            let (dapp_tiers, _count) = Self::get_dapp_tier_assignment_and_rewards(
-                protocol_state.era,
-                protocol_state.period_number(),
-                Balance::zero(),
+            DAppMetadataOf::<T>::insert(
+                &smart_contract,
+                (metadata.clone(), dev_account.clone(), deposit),
             );
 
-            dapp_tiers.dapps.into_inner()
+            Self::deposit_event(Event::<T>::DAppMetadataSet {
+                smart_contract,
+                metadata,
+                depositor: dev_account,
+                deposit,
+            });
+
+            Ok(())
         }
 
-        /// Assign eligible dApps into appropriate tiers, and calculate reward for each tier.
+        /// Clears the metadata of the dApp, releasing the deposit held for it back to the depositor.
         ///
-        /// ### Algorithm
+        /// Can be called by the dApp owner, or by the contract unregister origin, e.g. to remove inappropriate content.
+        #[pallet::call_index(38)]
+        #[pallet::weight(T::WeightInfo::clear_dapp_metadata())]
+        pub fn clear_dapp_metadata(
+            origin: OriginFor<T>,
+            smart_contract: T::SmartContract,
+        ) -> DispatchResult {
+            Self::ensure_pallet_enabled()?;
+
+            // If contract unregister origin, no need to check if caller is the owner.
+            if let Err(origin) = T::ContractUnregisterOrigin::try_origin(origin) {
+                let dev_account = ensure_signed(origin)?;
+                let dapp_info = IntegratedDApps::<T>::get(&smart_contract)
+                    .ok_or(Error::<T>::ContractNotFound)?;
+                ensure!(dapp_info.owner == dev_account, Error::<T>::OriginNotOwner);
+            }
+
+            ensure!(
+                Self::remove_dapp_metadata(&smart_contract),
+                Error::<T>::NoDAppMetadata
+            );
+
+            Ok(())
+        }
+
+        /// Used to schedule static tier parameters, which replace the existing ones at the start of the specified period.
         ///
-        /// 1. Read in over all contract stake entries. In case staked amount is zero for the current era, ignore it.
-        ///    This information is used to calculate 'score' per dApp, which is used to determine the tier.
+        /// Only one set of tier parameters can be scheduled at a time, scheduling new ones replaces the previous schedule.
+        /// Once applied, an event describing the old & new tier thresholds is emitted.
+        #[pallet::call_index(39)]
+        #[pallet::weight(T::WeightInfo::schedule_static_tier_params())]
+        pub fn schedule_static_tier_params(
+            origin: OriginFor<T>,
+            #[pallet::compact] period: PeriodNumber,
+            params: TierParameters<T::NumberOfTiers>,
+        ) -> DispatchResult {
+            Self::ensure_pallet_enabled()?;
+            ensure_root(origin)?;
+            ensure!(params.is_valid(), Error::<T>::InvalidTierParams);
+            ensure!(
+                period > ActiveProtocolState::<T>::get().period_number(),
+                Error::<T>::InvalidTierParamsPeriod
+            );
+
+            ScheduledTierParams::<T>::put((period, params.clone()));
+
+            Self::deposit_event(Event::<T>::TierParametersScheduled { period, params });
+
+            Ok(())
+        }
+
+        /// Used to cancel the scheduled static tier parameters.
+        #[pallet::call_index(40)]
+        #[pallet::weight(T::WeightInfo::cancel_scheduled_tier_params())]
+        pub fn cancel_scheduled_tier_params(origin: OriginFor<T>) -> DispatchResult {
+            Self::ensure_pallet_enabled()?;
+            ensure_root(origin)?;
+
+            let (period, _) =
+                ScheduledTierParams::<T>::take().ok_or(Error::<T>::NoScheduledTierParams)?;
+
+            Self::deposit_event(Event::<T>::ScheduledTierParametersCancelled { period });
+
+            Ok(())
+        }
+
+        /// Used to unstake funds from all of the unregistered contracts on which the account has staked during the ongoing period.
         ///
-        /// 2. Sort the entries by the score, in descending order - the top score dApp comes first.
+        /// Same as calling `unstake_from_unregistered` for each such contract, but in a single call.
+        #[pallet::call_index(41)]
+        #[pallet::weight(
+            T::WeightInfo::unstake_from_all_unregistered(T::MaxNumberOfStakedContracts::get())
+                .saturating_add(
+                    T::Observers::max_weight()
+                        .saturating_mul(T::MaxNumberOfStakedContracts::get().into()),
+                )
+        )]
+        pub fn unstake_from_all_unregistered(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+            Self::ensure_pallet_enabled()?;
+            let account = ensure_signed(origin)?;
+
+            let current_period = ActiveProtocolState::<T>::get().period_number();
+
+            // Bounded by max allowed number of stake entries per account.
+            let mut number_of_entries: u32 = 0;
+            let mut unregistered_contracts = Vec::new();
+            for (smart_contract, staking_info) in StakerInfo::<T>::iter_prefix(&account) {
+                number_of_entries.saturating_inc();
+
+                if staking_info.period_number() == current_period
+                    && !IntegratedDApps::<T>::contains_key(&smart_contract)
+                {
+                    unregistered_contracts.push(smart_contract);
+                }
+            }
+
+            ensure!(
+                !unregistered_contracts.is_empty(),
+                Error::<T>::NoUnregisteredStake
+            );
+
+            let mut observer_weight = Weight::zero();
+            for smart_contract in unregistered_contracts {
+                let (unstake_amount, _, unstake_observer_weight) =
+                    Self::inner_unstake_from_unregistered(&account, &smart_contract, None)?;
+                observer_weight.saturating_accrue(unstake_observer_weight);
+
+                Self::deposit_event(Event::<T>::UnstakeFromUnregistered {
+                    account: account.clone(),
+                    smart_contract,
+                    amount: unstake_amount.total(),
+                });
+            }
+
+            Ok(Some(
+                T::WeightInfo::unstake_from_all_unregistered(number_of_entries)
+                    .saturating_add(observer_weight),
+            )
+            .into())
+        }
+
+        /// Used to enable or disable lock receipts.
+        ///
+        /// While enabled, a receipt token is minted for each newly locked amount.
+        /// Receipts which were already minted are burned once the locked funds are unlocked, regardless of this setting.
+        ///
+        /// Can only be called by manager origin.
+        #[pallet::call_index(42)]
+        #[pallet::weight(T::WeightInfo::set_lock_receipts_enabled())]
+        pub fn set_lock_receipts_enabled(origin: OriginFor<T>, enabled: bool) -> DispatchResult {
+            T::ManagerOrigin::ensure_origin(origin)?;
+            ensure!(
+                !enabled || T::LockReceipt::is_available(),
+                Error::<T>::LockReceiptUnavailable
+            );
+
+            LockReceiptsEnabled::<T>::put(enabled);
+            Self::deposit_event(Event::<T>::LockReceiptsToggled { enabled });
+
+            Ok(())
+        }
+
+        /// Transfers the specified amount of locked funds to the destination account, where they remain locked.
+        ///
+        /// Only the locked amount which isn't staked can be transferred.
+        /// Remaining locked amount of the sender must either be zero, or not below the minimum locked amount,
+        /// and the destination account must have at least the minimum locked amount after the transfer.
+        ///
+        /// Lock receipts held by the sender are moved together with the locked funds, up to the transferred amount.
+        #[pallet::call_index(43)]
+        #[pallet::weight(T::WeightInfo::transfer_lock(T::MaxNumberOfStakedContracts::get()))]
+        pub fn transfer_lock(
+            origin: OriginFor<T>,
+            dest: T::AccountId,
+            #[pallet::compact] amount: Balance,
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_pallet_enabled()?;
+            let from = ensure_signed(origin)?;
+            ensure!(from != dest, Error::<T>::LockTransferToSelf);
+            ensure!(!amount.is_zero(), Error::<T>::ZeroAmount);
+
+            let current_period = ActiveProtocolState::<T>::get().period_number();
+            let mut from_ledger = Ledger::<T>::get(&from);
+            ensure!(
+                amount <= from_ledger.unlockable_amount(current_period),
+                Error::<T>::InsufficientTransferableLock
+            );
+            from_ledger.subtract_lock_amount(amount);
+            let remaining_amount = from_ledger.active_locked_amount();
+            ensure!(
+                remaining_amount.is_zero() || remaining_amount >= T::MinimumLockedAmount::get(),
+                Error::<T>::InsufficientTransferableLock
+            );
+
+            let mut dest_ledger = Ledger::<T>::get(&dest);
+            if dest_ledger.is_empty() {
+                ensure!(
+                    T::AccountCheck::allowed_to_stake(&dest),
+                    Error::<T>::AccountNotAvailableForDappStaking
+                );
+            }
+            dest_ledger.add_lock_amount(amount);
+            ensure!(
+                dest_ledger.active_locked_amount() >= T::MinimumLockedAmount::get(),
+                Error::<T>::LockedAmountBelowThreshold
+            );
+
+            // In case sender is left without anything, it's exiting dApp staking, ensure all storage is cleaned up.
+            let removed_entries = if from_ledger.is_empty() {
+                let _ =
+                    StakerInfo::<T>::clear_prefix(&from, from_ledger.contract_stake_count, None);
+                from_ledger.contract_stake_count
+            } else {
+                0
+            };
+
+            // Sender's freeze must be reduced first, so the funds can be moved.
+            Self::update_ledger(&from, from_ledger)?;
+            let credit = T::Currency::withdraw(
+                &from,
+                amount,
+                Precision::Exact,
+                Preservation::Preserve,
+                Fortitude::Polite,
+            )?;
+            T::Currency::resolve(&dest, credit).map_err(|_| TokenError::BelowMinimum)?;
+            Self::update_ledger(&dest, dest_ledger)?;
+
+            let receipt = Self::burn_lock_receipt(&from, amount)?;
+            Self::mint_lock_receipt(&dest, receipt)?;
+
+            Self::deposit_event(Event::<T>::LockTransferred {
+                from,
+                to: dest,
+                amount,
+                receipt,
+            });
+
+            Ok(Some(T::WeightInfo::transfer_lock(removed_entries)).into())
+        }
+
+        /// Used to unstake part of the funds from a contract that was unregistered after an account staked on it.
+        /// The `amount` specified **must** not exceed what's staked, otherwise the call will fail.
+        ///
+        /// If unstaking the specified `amount` would take staker below the minimum stake threshold, everything is unstaked.
+        /// Bonus status of the remaining stake is preserved, same as with `unstake_from_unregistered`.
+        #[pallet::call_index(44)]
+        #[pallet::weight(
+            T::WeightInfo::unstake_from_unregistered().saturating_add(T::Observers::max_weight())
+        )]
+        pub fn partial_unstake_from_unregistered(
+            origin: OriginFor<T>,
+            smart_contract: T::SmartContract,
+            #[pallet::compact] amount: Balance,
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_pallet_enabled()?;
+            let account = ensure_signed(origin)?;
+
+            let (unstake_amount, _, observer_weight) =
+                Self::inner_unstake_from_unregistered(&account, &smart_contract, Some(amount))?;
+
+            Self::deposit_event(Event::<T>::UnstakeFromUnregistered {
+                account,
+                smart_contract,
+                amount: unstake_amount.total(),
+            });
+
+            Ok(
+                Some(T::WeightInfo::unstake_from_unregistered().saturating_add(observer_weight))
+                    .into(),
+            )
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Removes the metadata of the specified smart contract, releasing the deposit held for it.
+        ///
+        /// Returns the depositor and the released amount, if the metadata existed.
+        fn take_dapp_metadata(
+            smart_contract: &T::SmartContract,
+        ) -> Option<(T::AccountId, Balance)> {
+            DAppMetadataOf::<T>::take(smart_contract).map(|(_, depositor, deposit)| {
+                let released = T::Currency::release(
+                    &HoldReason::DAppMetadata.into(),
+                    &depositor,
+                    deposit,
+                    Precision::BestEffort,
+                )
+                .unwrap_or_default();
+
+                (depositor, released)
+            })
+        }
+
+        /// Removes the metadata of the specified smart contract, releasing the deposit held for it back to the depositor.
+        ///
+        /// Returns `true` if the metadata existed, `false` otherwise.
+        fn remove_dapp_metadata(smart_contract: &T::SmartContract) -> bool {
+            match Self::take_dapp_metadata(smart_contract) {
+                Some((depositor, deposit)) => {
+                    Self::deposit_event(Event::<T>::DAppMetadataCleared {
+                        smart_contract: smart_contract.clone(),
+                        depositor,
+                        deposit,
+                    });
+                    true
+                }
+                None => false,
+            }
+        }
+
+        /// Returns the metadata of the specified smart contract, if it exists.
+        pub fn get_dapp_metadata(smart_contract: &T::SmartContract) -> Option<DAppMetadata> {
+            DAppMetadataOf::<T>::get(smart_contract)
+                .map(|(metadata, _, deposit)| metadata.into_dapp_metadata(deposit))
+        }
+
+        /// Inner `unstake` functionality for an **active** smart contract.
+        /// If successful returns the `StakeAmount` that was unstaked, and the updated bonus status.
+        ///
+        /// - Ensures the contract is still registered.
+        /// - Updates staker info, ledger, and contract stake info.
+        /// - Returns the unstaked amount, updated bonus status and the weight consumed by the observers.
+        pub fn inner_unstake(
+            account: &T::AccountId,
+            smart_contract: &T::SmartContract,
+            amount: Balance,
+        ) -> Result<(StakeAmount, BonusStatus, Weight), DispatchError> {
+            ensure!(amount > 0, Error::<T>::ZeroAmount);
+            let dapp_info =
+                IntegratedDApps::<T>::get(&smart_contract).ok_or(Error::<T>::ContractNotFound)?;
+
+            let protocol_state = ActiveProtocolState::<T>::get();
+            let current_era = protocol_state.era;
+
+            let mut ledger = Ledger::<T>::get(&account);
+
+            // 1.
+            // Update `StakerInfo` storage with the reduced stake amount on the specified contract.
+            let (new_staking_info, amount, stake_amount_iter, updated_bonus_status) =
+                match StakerInfo::<T>::get(&account, &smart_contract) {
+                    Some(mut staking_info) => {
+                        ensure!(
+                            staking_info.period_number() == protocol_state.period_number(),
+                            Error::<T>::UnstakeFromPastPeriod
+                        );
+                        ensure!(
+                            staking_info.total_staked_amount() >= amount,
+                            Error::<T>::UnstakeAmountTooLarge
+                        );
+
+                        // If unstaking would take the total staked amount below the minimum required value,
+                        // unstake everything.
+                        let amount = if staking_info.total_staked_amount().saturating_sub(amount)
+                            < T::MinimumStakeAmount::get()
+                        {
+                            staking_info.total_staked_amount()
+                        } else {
+                            amount
+                        };
+
+                        let (stake_amount_iter, updated_bonus_status) =
+                            staking_info.unstake(amount, current_era, protocol_state.subperiod());
+
+                        (
+                            staking_info,
+                            amount,
+                            stake_amount_iter,
+                            updated_bonus_status,
+                        )
+                    }
+                    None => {
+                        return Err(Error::<T>::NoStakingInfo.into());
+                    }
+                };
+
+            // 2.
+            // Reduce stake amount
+            ledger
+                .unstake_amount(amount, current_era, protocol_state.period_info)
+                .map_err(|err| match err {
+                    AccountLedgerError::InvalidPeriod | AccountLedgerError::InvalidEra => {
+                        Error::<T>::UnclaimedRewards
+                    }
+                    // This is a defensive check, which should never happen since we calculate the correct value above.
+                    AccountLedgerError::UnstakeAmountLargerThanStake => {
+                        Error::<T>::UnstakeAmountTooLarge
+                    }
+                    _ => Error::<T>::InternalUnstakeError,
+                })?;
+
+            // 3.
+            // Update `ContractStake` storage with the reduced stake amount on the specified contract.
+            let mut contract_stake_info = ContractStake::<T>::get(&dapp_info.id);
+            contract_stake_info.unstake(
+                &stake_amount_iter,
+                protocol_state.period_info,
+                current_era,
+            );
+
+            // 4.
+            // Update total staked amount for the next era.
+            CurrentEraInfo::<T>::mutate(|era_info| {
+                era_info.unstake_amount(stake_amount_iter.clone());
+            });
+
+            // 5.
+            // Update remaining storage entries
+            ContractStake::<T>::insert(&dapp_info.id, contract_stake_info);
+
+            if new_staking_info.is_empty() {
+                ledger.contract_stake_count.saturating_dec();
+                StakerInfo::<T>::remove(&account, &smart_contract);
+            } else {
+                StakerInfo::<T>::insert(&account, &smart_contract, new_staking_info);
+            }
+
+            Self::update_ledger(&account, ledger)?;
+
+            // Return the `StakeAmount` that has max total value.
+            let mut unstake_amount = stake_amount_iter
+                .iter()
+                .max_by(|a, b| a.total().cmp(&b.total()))
+                // At least one value exists, otherwise we wouldn't be here.
+                .ok_or(Error::<T>::InternalUnstakeError)?
+                .clone();
+
+            // Ensure we use the current era instead of potentially next era
+            unstake_amount.era = current_era;
+
+            let observer_weight = T::Observers::on_unstake(account, smart_contract, amount);
+
+            Ok((unstake_amount, updated_bonus_status, observer_weight))
+        }
+
+        /// Handles unstaking from an **unregistered** smart contract.
+        ///
+        /// - Ensures the contract is no longer active.
+        /// - Unstakes the specified `amount`, or everything in case `amount` is `None`.
+        /// - Updates staker info and ledger.
+        /// - Returns the unstaked amount, the preserved original bonus status and the weight consumed by the observers.
+        pub fn inner_unstake_from_unregistered(
+            account: &T::AccountId,
+            smart_contract: &T::SmartContract,
+            amount: Option<Balance>,
+        ) -> Result<(StakeAmount, BonusStatus, Weight), DispatchError> {
+            ensure!(amount != Some(0), Error::<T>::ZeroAmount);
+            ensure!(
+                !IntegratedDApps::<T>::contains_key(&smart_contract),
+                Error::<T>::ContractStillActive
+            );
+
+            let protocol_state = ActiveProtocolState::<T>::get();
+            let current_era = protocol_state.era;
+
+            // Extract the amount to unstake from the specified unregistered contract
+            let (new_staking_info, amount, unstake_amount_iter, preserved_bonus_status) =
+                match StakerInfo::<T>::get(&account, &smart_contract) {
+                    Some(mut staking_info) => {
+                        ensure!(
+                            staking_info.period_number() == protocol_state.period_number(),
+                            Error::<T>::UnstakeFromPastPeriod
+                        );
+
+                        let preserved_bonus_status = staking_info.bonus_status;
+                        let total_amount = staking_info.total_staked_amount();
+                        let amount = amount.unwrap_or(total_amount);
+                        ensure!(total_amount >= amount, Error::<T>::UnstakeAmountTooLarge);
+
+                        // If unstaking would take the total staked amount below the minimum required value,
+                        // unstake everything.
+                        let amount =
+                            if total_amount.saturating_sub(amount) < T::MinimumStakeAmount::get() {
+                                total_amount
+                            } else {
+                                amount
+                            };
+
+                        let (unstake_amount_iter, _) =
+                            staking_info.unstake(amount, current_era, protocol_state.subperiod());
+                        // Contract was unregistered without the staker's involvement, so the bonus isn't forfeited.
+                        staking_info.bonus_status = preserved_bonus_status;
+
+                        (
+                            staking_info,
+                            amount,
+                            unstake_amount_iter,
+                            preserved_bonus_status,
+                        )
+                    }
+                    None => {
+                        return Err(Error::<T>::NoStakingInfo.into());
+                    }
+                };
+
+            // Reduce stake amount in ledger
+            let mut ledger = Ledger::<T>::get(&account);
+            ledger
+                .unstake_amount(amount, current_era, protocol_state.period_info)
+                .map_err(|err| match err {
+                    // These are all defensive checks, which should never fail since we already checked them above.
+                    AccountLedgerError::InvalidPeriod | AccountLedgerError::InvalidEra => {
+                        Error::<T>::UnclaimedRewards
+                    }
+                    _ => Error::<T>::InternalUnstakeError,
+                })?;
+
+            // Update total staked amount for the next era.
+            // This means 'fake' stake total amount has been kept until now, even though contract was unregistered.
+            // Although strange, it's been requested to keep it like this from the team.
+            CurrentEraInfo::<T>::mutate(|era_info| {
+                era_info.unstake_amount(unstake_amount_iter.clone());
+            });
+
+            // Update remaining storage entries
+            if new_staking_info.is_empty() {
+                ledger.contract_stake_count.saturating_dec();
+                StakerInfo::<T>::remove(&account, &smart_contract);
+            } else {
+                StakerInfo::<T>::insert(&account, &smart_contract, new_staking_info);
+            }
+
+            Self::update_ledger(&account, ledger)?;
+
+            // Return the `StakeAmount` that has max total value.
+            let mut unstake_amount = unstake_amount_iter
+                .iter()
+                .max_by(|a, b| a.total().cmp(&b.total()))
+                // At least one value exists, otherwise we wouldn't be here.
+                .ok_or(Error::<T>::InternalUnstakeError)?
+                .clone();
+
+            // Ensure we use the current era instead of potentially next era
+            unstake_amount.era = current_era;
+
+            let observer_weight = T::Observers::on_unstake(account, smart_contract, amount);
+
+            Ok((unstake_amount, preserved_bonus_status, observer_weight))
+        }
+
+        /// Inner `stake` functionality.
+        ///
+        /// Specifies the amount in the form of the `StakeAmount` struct, allowing simultaneous update of both `voting` and `build_and_earn` amounts.
+        /// The `bonus_status` is used to determine if the staker is still eligible for the bonus reward. This is useful for the `move` extrinsic.
+        ///
+        /// Returns the weight consumed by the observers.
+        pub fn inner_stake(
+            account: &T::AccountId,
+            smart_contract: &T::SmartContract,
+            amount: StakeAmount,
+            bonus_status: BonusStatus,
+        ) -> Result<Weight, DispatchError> {
+            ensure!(amount.total() > 0, Error::<T>::ZeroAmount);
+
+            let dapp_info =
+                IntegratedDApps::<T>::get(&smart_contract).ok_or(Error::<T>::ContractNotFound)?;
+
+            let protocol_state = ActiveProtocolState::<T>::get();
+            let current_era = protocol_state.era;
+            let period_number = protocol_state.period_info.number;
+            ensure!(
+                !protocol_state
+                    .period_info
+                    .is_next_period(current_era.saturating_add(1)),
+                Error::<T>::PeriodEndsInNextEra
+            );
+
+            let mut ledger = Ledger::<T>::get(&account);
+
+            // In case old stake rewards are unclaimed & have expired, clean them up.
+            let threshold_period = Self::oldest_claimable_period(protocol_state.period_number());
+            let _ignore = ledger.maybe_cleanup_expired(threshold_period);
+
+            // 1.
+            // Increase stake amount for the next era & current period in staker's ledger
+            ledger
+                .add_stake_amount(amount, current_era, protocol_state.period_info)
+                .map_err(|err| match err {
+                    AccountLedgerError::InvalidPeriod | AccountLedgerError::InvalidEra => {
+                        Error::<T>::UnclaimedRewards
+                    }
+                    AccountLedgerError::UnavailableStakeFunds => Error::<T>::UnavailableStakeFunds,
+                    // Defensive check, should never happen
+                    _ => Error::<T>::InternalStakeError,
+                })?;
+
+            // 2.
+            // Update `StakerInfo` storage with the new stake amount on the specified contract.
+            //
+            // There are three distinct scenarios:
+            // 1. Existing entry matches the current period number - just update it.
+            // 2. Existing entry is expired/orphaned - replace it.
+            // 3. Entry doesn't exist or it's for an older period - create a new one.
+            //
+            // This is ok since we only use this storage entry to keep track of how much each staker
+            // has staked on each contract in the current period. We only ever need the latest information.
+            // This is because `AccountLedger` is the one keeping information about how much was staked when.
+            let (mut new_staking_info, is_new_entry, replacing_old_entry) =
+                match StakerInfo::<T>::get(&account, &smart_contract) {
+                    // Entry with matching period exists
+                    Some(staking_info)
+                        if staking_info.period_number() == protocol_state.period_number() =>
+                    {
+                        (staking_info, false, false)
+                    }
+                    // Entry exists but period doesn't match. Bonus reward might still be claimable.
+                    Some(staking_info)
+                        if staking_info.period_number() >= threshold_period
+                            && staking_info.is_bonus_eligible() =>
+                    {
+                        return Err(Error::<T>::UnclaimedRewards.into());
+                    }
+                    // Entry exists but is expired/orphaned - we're replacing it, not adding new
+                    Some(_old_entry) => {
+                        // Remove the old orphaned entry explicitly
+                        StakerInfo::<T>::remove(&account, &smart_contract);
+                        (
+                            SingularStakingInfo::new(protocol_state.period_number(), bonus_status),
+                            true, // is_new_entry (for storage write)
+                            true, // replacing_old_entry (don't increment counter)
+                        )
+                    }
+                    // No entry exists at all - truly new
+                    None => (
+                        SingularStakingInfo::new(
+                            protocol_state.period_number(),
+                            // Important to account for the remaining bonus moves since it's possible to retain the bonus
+                            // after moving the stake to another contract.
+                            bonus_status,
+                        ),
+                        true,
+                        false,
+                    ),
+                };
+
+            new_staking_info.stake(amount, current_era, bonus_status);
+            ensure!(
+                new_staking_info.total_staked_amount() >= T::MinimumStakeAmount::get(),
+                Error::<T>::InsufficientStakeAmount
+            );
+
+            // Only increment if we're truly adding a new contract, not replacing an orphaned entry
+            if is_new_entry && !replacing_old_entry {
+                ledger.contract_stake_count.saturating_inc();
+                ensure!(
+                    ledger.contract_stake_count <= T::MaxNumberOfStakedContracts::get(),
+                    Error::<T>::TooManyStakedContracts
+                );
+            }
+
+            // 3.
+            // Update `ContractStake` storage with the new stake amount on the specified contract.
+            let mut contract_stake_info = ContractStake::<T>::get(&dapp_info.id);
+            contract_stake_info.stake(amount, current_era, period_number);
+
+            // 4.
+            // Update total staked amount for the next era.
+            CurrentEraInfo::<T>::mutate(|era_info| {
+                era_info.add_stake_amount(amount);
+            });
+
+            // 5.
+            // Update remaining storage entries
+            Self::update_ledger(&account, ledger)?;
+            StakerInfo::<T>::insert(&account, &smart_contract, new_staking_info);
+            ContractStake::<T>::insert(&dapp_info.id, contract_stake_info);
+
+            let observer_weight = T::Observers::on_stake(account, smart_contract, amount.total());
+
+            Ok(observer_weight)
+        }
+
+        /// Prepares the `StakeAmount` & `BonusStatus` for staking the specified amount in the current subperiod.
+        ///
+        /// User is only eligible for the bonus reward if their first time stake is in the `Voting` subperiod.
+        ///
+        /// If the user is staking for the first time in the `Voting` subperiod, they are eligible for the bonus reward, and the max number of bonus moves is set.
+        /// If the user is staking for the first time in the `Build&Earn` subperiod, they are not eligible for the bonus reward, and the bonus moves are set to 0.
+        pub(crate) fn current_subperiod_stake_amount(
+            protocol_state: &ProtocolState,
+            amount: Balance,
+        ) -> (StakeAmount, BonusStatus) {
+            match protocol_state.subperiod() {
+                Subperiod::Voting => (
+                    StakeAmount {
+                        voting: amount,
+                        build_and_earn: 0,
+                        era: protocol_state.era,
+                        period: protocol_state.period_number(),
+                    },
+                    *BonusStatusWrapperFor::<T>::default(),
+                ),
+                Subperiod::BuildAndEarn => (
+                    StakeAmount {
+                        voting: 0,
+                        build_and_earn: amount,
+                        era: protocol_state.era,
+                        period: protocol_state.period_number(),
+                    },
+                    0,
+                ),
+            }
+        }
+
+        /// Compounds the reward which was just paid out to the account, if the account opted-in for it.
+        ///
+        /// The reward is locked into dApp staking, and then restaked on the contracts the account
+        /// has staked on in the ongoing period, proportionally to the existing stake on each of them.
+        /// Since only existing stake entries are increased, the number of staked contracts and the minimum
+        /// stake amount per contract are respected by design. Bonus eligibility follows the same rules as
+        /// regular `stake` calls.
+        ///
+        /// Compounding is best-effort - if the reward cannot be locked it remains transferable,
+        /// and if it cannot be restaked (e.g. period ends in the next era, or there are unclaimed rewards) it remains locked.
+        ///
+        /// Returns the weight consumed by the operation.
+        pub(crate) fn maybe_compound_reward(account: &T::AccountId, reward: Balance) -> Weight {
+            if !AutoCompound::<T>::contains_key(account) || reward.is_zero() {
+                return T::DbWeight::get().reads(1);
+            }
+
+            // 1.
+            // Lock the reward.
+            let mut ledger = Ledger::<T>::get(account);
+            if ledger.is_empty() {
+                return T::WeightInfo::compound_reward(0);
+            }
+
+            let available_balance =
+                T::Currency::total_balance(account).saturating_sub(ledger.total_locked_amount());
+            let lock_amount = available_balance.min(reward);
+            ledger.add_lock_amount(lock_amount);
+
+            if lock_amount.is_zero()
+                || ledger.active_locked_amount() < T::MinimumLockedAmount::get()
+                || Self::update_ledger(account, ledger).is_err()
+            {
+                return T::WeightInfo::compound_reward(0);
+            }
+            CurrentEraInfo::<T>::mutate(|era_info| {
+                era_info.add_locked(lock_amount);
+            });
+
+            // Failing to mint the receipt mustn't prevent the reward from being compounded.
+            if LockReceiptsEnabled::<T>::get() {
+                let _ = with_storage_layer(|| Self::mint_lock_receipt(account, lock_amount));
+            }
+
+            Self::deposit_event(Event::<T>::Locked {
+                account: account.clone(),
+                amount: lock_amount,
+            });
+
+            // 2.
+            // Restake the locked reward on the ongoing period stake entries, proportionally.
+            let protocol_state = ActiveProtocolState::<T>::get();
+
+            // Bounded by max allowed number of stake entries per account.
+            let stake_entries: Vec<_> = StakerInfo::<T>::iter_prefix(account)
+                .filter(|(smart_contract, staking_info)| {
+                    staking_info.period_number() == protocol_state.period_number()
+                        && IntegratedDApps::<T>::contains_key(smart_contract)
+                })
+                .map(|(smart_contract, staking_info)| {
+                    (smart_contract, staking_info.total_staked_amount())
+                })
+                .collect();
+            let total_staked = stake_entries
+                .iter()
+                .fold(Balance::zero(), |sum, (_, staked)| {
+                    sum.saturating_add(*staked)
+                });
+            let number_of_entries: u32 = stake_entries.len().unique_saturated_into();
+
+            if total_staked.is_zero() {
+                return T::WeightInfo::compound_reward(number_of_entries);
+            }
+
+            let mut remaining = lock_amount;
+            let mut observer_weight = Weight::zero();
+            for (idx, (smart_contract, staked)) in stake_entries.into_iter().enumerate() {
+                // Last entry takes whatever remains, to avoid leaving rounding dust unstaked.
+                let amount = if idx.saturating_add(1) == number_of_entries as usize {
+                    remaining
+                } else {
+                    Perbill::from_rational(staked, total_staked) * lock_amount
+                };
+                if amount.is_zero() {
+                    continue;
+                }
+
+                let (stake_amount, bonus_status) =
+                    Self::current_subperiod_stake_amount(&protocol_state, amount);
+                let result = with_storage_layer::<_, DispatchError, _>(|| {
+                    Self::inner_stake(account, &smart_contract, stake_amount, bonus_status)
+                });
+
+                if let Ok(stake_observer_weight) = result {
+                    observer_weight.saturating_accrue(stake_observer_weight);
+                    remaining.saturating_reduce(amount);
+                    Self::deposit_event(Event::<T>::Stake {
+                        account: account.clone(),
+                        smart_contract,
+                        amount,
+                    });
+                }
+            }
+
+            T::WeightInfo::compound_reward(number_of_entries).saturating_add(observer_weight)
+        }
+
+        /// `true` if the account is a staker, `false` otherwise.
+        pub fn is_staker(account: &T::AccountId) -> bool {
+            Ledger::<T>::contains_key(account)
+        }
+
+        /// `Err` if pallet disabled for maintenance, `Ok` otherwise.
+        pub(crate) fn ensure_pallet_enabled() -> Result<(), Error<T>> {
+            if ActiveProtocolState::<T>::get().maintenance {
+                Err(Error::<T>::Disabled)
+            } else {
+                Ok(())
+            }
+        }
+
+        /// Total number of tier slots for a new period, as provided by `TierSlotsProvider`,
+        /// capped by the maximum number of tier slots.
+        pub(crate) fn new_number_of_tier_slots() -> u16 {
+            T::TierSlotsProvider::number_of_slots(IntegratedDApps::<T>::count())
+                .min(T::MaxNumberOfTierSlots::get().saturated_into())
+        }
+
+        /// `true` if the reward splits are valid, `false` otherwise.
+        ///
+        /// Valid splits have non-zero shares, unique beneficiaries, and don't exceed 100% in total.
+        pub(crate) fn reward_splits_valid(splits: &[(T::AccountId, Permill)]) -> bool {
+            let mut total: u32 = 0;
+            for (idx, (beneficiary, share)) in splits.iter().enumerate() {
+                if share.is_zero() || splits[..idx].iter().any(|(other, _)| other == beneficiary) {
+                    return false;
+                }
+                total.saturating_accrue(share.deconstruct());
+            }
+
+            total <= Permill::one().deconstruct()
+        }
+
+        /// Mints the lock receipt for the specified amount to the account.
+        pub(crate) fn mint_lock_receipt(account: &T::AccountId, amount: Balance) -> DispatchResult {
+            if amount.is_zero() {
+                return Ok(());
+            }
+
+            T::LockReceipt::mint(account, amount)?;
+            LockReceipts::<T>::mutate(account, |receipt| receipt.saturating_accrue(amount));
+
+            Self::deposit_event(Event::<T>::LockReceiptMinted {
+                account: account.clone(),
+                amount,
+            });
+
+            Ok(())
+        }
+
+        /// Burns the lock receipt for up to the specified amount from the account.
+        ///
+        /// The recorded receipt amount is always reduced, limited only by the amount of receipts minted to the account.
+        /// In case the account no longer holds all of its receipt tokens, the burn is done on a best-effort basis,
+        /// so the locked funds can't be held hostage by the missing receipts.
+        ///
+        /// Returns the actually burned amount of receipt tokens.
+        pub(crate) fn burn_lock_receipt(
+            account: &T::AccountId,
+            amount: Balance,
+        ) -> Result<Balance, DispatchError> {
+            let receipt = LockReceipts::<T>::get(account);
+            let burn_amount = receipt.min(amount);
+            if burn_amount.is_zero() {
+                return Ok(Balance::zero());
+            }
+
+            let burned = T::LockReceipt::burn(account, burn_amount)?;
+            let remaining = receipt.saturating_sub(burn_amount);
+            if remaining.is_zero() {
+                LockReceipts::<T>::remove(account);
+            } else {
+                LockReceipts::<T>::insert(account, remaining);
+            }
+
+            Self::deposit_event(Event::<T>::LockReceiptBurned {
+                account: account.clone(),
+                amount: burned,
+            });
+
+            Ok(burned)
+        }
+
+        /// Update the account ledger, and dApp staking balance freeze.
+        ///
+        /// In case account ledger is empty, entries from the DB are removed and freeze is thawed.
+        ///
+        /// This call can fail if the `freeze` or `thaw` operations fail. This should never happen since
+        /// runtime definition must ensure it supports necessary freezes.
+        pub(crate) fn update_ledger(
+            account: &T::AccountId,
+            ledger: AccountLedgerFor<T>,
+        ) -> Result<(), DispatchError> {
+            if ledger.is_empty() {
+                Ledger::<T>::remove(&account);
+                AutoCompound::<T>::remove(&account);
+                AutoClaim::<T>::remove(&account);
+                T::Currency::thaw(&FreezeReason::DAppStaking.into(), account)?;
+            } else {
+                T::Currency::set_freeze(
+                    &FreezeReason::DAppStaking.into(),
+                    account,
+                    ledger.total_locked_amount(),
+                )?;
+                Ledger::<T>::insert(account, ledger);
+            }
+
+            Ok(())
+        }
+
+        /// Returns the number of blocks per voting period.
+        pub(crate) fn blocks_per_voting_period() -> BlockNumber {
+            T::CycleConfiguration::blocks_per_era()
+                .saturating_mul(T::CycleConfiguration::eras_per_voting_subperiod().into())
+        }
+
+        /// Calculates the `EraRewardSpan` index for the specified era.
+        pub fn era_reward_span_index(era: EraNumber) -> EraNumber {
+            era.saturating_sub(era % T::EraRewardSpanLength::get())
+        }
+
+        /// Return the oldest period for which rewards can be claimed.
+        /// All rewards before that period are considered to be expired.
+        pub(crate) fn oldest_claimable_period(current_period: PeriodNumber) -> PeriodNumber {
+            current_period.saturating_sub(T::RewardRetentionInPeriods::get())
+        }
+
+        /// Unlocking period expressed in the number of blocks.
+        pub fn unlocking_period() -> BlockNumber {
+            T::CycleConfiguration::blocks_per_era().saturating_mul(T::UnlockingPeriod::get().into())
+        }
+
+        /// Returns the dApp tier assignment for the current era, based on the current stake amounts.
+        pub fn get_dapp_tier_assignment() -> BTreeMap<DAppId, RankedTier> {
+            let protocol_state = ActiveProtocolState::<T>::get();
+
+            let (dapp_tiers, _count) = Self::get_dapp_tier_assignment_and_rewards(
+                protocol_state.era,
+                protocol_state.period_number(),
+                Balance::zero(),
+            );
+
+            dapp_tiers.dapps.into_inner()
+        }
+
+        /// Previews the tier assignment for the ongoing era, as if the specified tier parameters were already in use.
+        ///
+        /// Tier configuration is derived from the parameters the same way as at the era change, based on the current
+        /// total issuance & total stake, and dApps are assigned into tiers based on their current stake.
+        /// None of the storage changes made during the simulation are persisted.
+        ///
+        /// Returns `None` if the parameters, or the tier configuration derived from them, are invalid.
+        pub fn preview_dapp_tier_assignment(
+            params: TierParameters<T::NumberOfTiers>,
+        ) -> Option<TierAssignmentPreview> {
+            if !params.is_valid() {
+                return None;
+            }
+
+            with_transaction(|| {
+                let protocol_state = ActiveProtocolState::<T>::get();
+                let total_value_staked = CurrentEraInfo::<T>::get().total_staked_amount();
+
+                let tier_config = TierConfig::<T>::get().calculate_new(
+                    &params,
+                    NumberOfTierSlots::<T>::get(),
+                    T::Currency::total_issuance(),
+                    total_value_staked,
+                );
+                if !tier_config.is_valid() {
+                    return TransactionOutcome::Rollback(Ok(None));
+                }
+
+                StaticTierParams::<T>::put(params);
+                TierConfig::<T>::put(tier_config.clone());
+
+                let (_, dapp_reward_pool) =
+                    T::StakingRewardHandler::staker_and_dapp_reward_pools(total_value_staked);
+                let (dapp_tiers, _count) = Self::get_dapp_tier_assignment_and_rewards(
+                    protocol_state.era,
+                    protocol_state.period_number(),
+                    dapp_reward_pool,
+                );
+
+                TransactionOutcome::Rollback(Ok::<_, DispatchError>(Some(TierAssignmentPreview {
+                    slots_per_tier: tier_config.slots_per_tier.into_inner(),
+                    tier_thresholds: tier_config.tier_thresholds.into_inner(),
+                    dapp_tiers: dapp_tiers.dapps.into_inner(),
+                    tier_rewards: dapp_tiers.rewards.into_inner(),
+                    rank_rewards: dapp_tiers.rank_rewards.into_inner(),
+                })))
+            })
+            .ok()
+            .flatten()
+        }
+
+        /// Returns all of the staker rewards the account can currently claim, per era.
+        ///
+        /// Simulates as many `claim_staker_rewards` calls as needed to claim everything.
+        pub fn get_claimable_staker_rewards(account: &T::AccountId) -> Vec<(EraNumber, Balance)> {
+            let mut ledger = Ledger::<T>::get(account);
+            let protocol_state = ActiveProtocolState::<T>::get();
+
+            let mut rewards = Vec::new();
+            while let Ok((chunk, _)) = Self::calculate_staker_rewards(&mut ledger, &protocol_state)
+            {
+                rewards.extend(chunk);
+            }
+
+            rewards
+        }
+
+        /// Returns all of the bonus rewards the account can currently claim, per smart contract.
+        pub fn get_claimable_bonus_rewards(
+            account: &T::AccountId,
+        ) -> Vec<(T::SmartContract, Balance)> {
+            let protocol_state = ActiveProtocolState::<T>::get();
+
+            // Bounded by max allowed number of stake entries per account.
+            StakerInfo::<T>::iter_prefix(account)
+                .filter_map(|(smart_contract, staker_info)| {
+                    Self::calculate_bonus_reward(&staker_info, &protocol_state)
+                        .ok()
+                        .map(|reward| (smart_contract, reward))
+                })
+                .collect()
+        }
+
+        /// Returns all of the unclaimed dApp rewards for the specified smart contract, per era.
+        pub fn get_unclaimed_dapp_rewards(
+            smart_contract: &T::SmartContract,
+        ) -> Vec<(EraNumber, Balance)> {
+            let dapp_info = match IntegratedDApps::<T>::get(smart_contract) {
+                Some(dapp_info) => dapp_info,
+                None => return Vec::new(),
+            };
+            let protocol_state = ActiveProtocolState::<T>::get();
+
+            // Bounded by the number of eras for which rewards are retained.
+            let mut rewards: Vec<_> = DAppTiers::<T>::iter_keys()
+                .filter_map(|era| {
+                    Self::consume_dapp_reward(dapp_info.id, era, &protocol_state)
+                        .ok()
+                        .map(|(_, amount, _)| (era, amount))
+                })
+                .collect();
+            rewards.sort_unstable_by_key(|(era, _)| *era);
+
+            rewards
+        }
+
+        /// Returns the tier assignment history of the specified dApp, from the oldest to the latest entry.
+        pub fn get_dapp_tier_history(dapp_id: DAppId) -> Vec<DAppTierHistoryEntry> {
+            DAppTierHistory::<T>::get(dapp_id).entries()
+        }
+
+        /// Append the tier assignment of the specified era to the history of each dApp which made it into a tier.
+        ///
+        /// Returns the number of updated dApp histories.
+        pub(crate) fn update_dapp_tier_history(
+            era: EraNumber,
+            dapp_tier_rewards: &DAppTierRewardsFor<T>,
+        ) -> u32 {
+            let period = dapp_tier_rewards.period;
+            for dapp_id in dapp_tier_rewards.dapps.keys() {
+                let Ok((reward, ranked_tier)) = dapp_tier_rewards.dapp_reward(*dapp_id) else {
+                    continue;
+                };
+                let stake = ContractStake::<T>::get(dapp_id)
+                    .get(era, period)
+                    .map_or(Balance::zero(), |stake_amount| stake_amount.total());
+
+                DAppTierHistory::<T>::mutate(dapp_id, |history| {
+                    history.push(DAppTierHistoryEntry {
+                        era,
+                        ranked_tier,
+                        stake,
+                        reward,
+                    })
+                });
+            }
+
+            dapp_tier_rewards.dapps.len() as u32
+        }
+
+        /// Assign eligible dApps into appropriate tiers, and calculate reward for each tier.
+        ///
+        /// ### Algorithm
+        ///
+        /// 1. Read in over all contract stake entries. In case staked amount is zero for the current era, ignore it.
+        ///    This information is used to calculate 'score' per dApp, which is used to determine the tier.
+        ///
+        /// 2. Sort the entries by the score, in descending order - the top score dApp comes first.
+        ///    Only as many entries as there are tier slots are kept, since the rest cannot be assigned to any tier.
+        ///
+        /// 3. Assign dApps to tiers based on stake thresholds, calculating ratio-based ranks within each tier.
+        ///
+        /// 4. Calculate reward components per tier using the **rank multiplier model**:
+        ///
+        ///    ```text
+        ///    Step 1: Calculate weights
+        ///        increment = (multiplier - 100%) ÷ MAX_RANK
+        ///        dApp_weight = 100% + rank × increment
+        ///
+        ///    Step 2: Find reward rate (with overflow cap)
+        ///        target_weight = max_slots × avg_weight       (calibrated for avg rank = 5)
+        ///        actual_weight = filled_slots × 100% + ranks_sum × increment
+        ///        reward_per_% = tier_allocation ÷ max(actual_weight, target_weight)
+        ///
+        ///    Step 3: Pre-compute claim values
+        ///        tier_reward = 100% × reward_per_%
+        ///        rank_reward = increment × reward_per_%
+        ///    ```
+        ///    (Sort the entries by dApp ID, in ascending order. This is so we can efficiently search for them using binary search.)
+        ///
+        /// All of the contract stake entries are read at once, in contrast to the paged tier assignment done
+        /// by the protocol before each era change.
+        ///
+        /// The returned object contains information about each dApp that made it into a tier.
+        /// Alongside tier assignment info, number of read DB contract stake entries is returned.
+        pub(crate) fn get_dapp_tier_assignment_and_rewards(
+            era: EraNumber,
+            period: PeriodNumber,
+            dapp_reward_pool: Balance,
+        ) -> (DAppTierRewardsFor<T>, DAppId) {
+            // 1. & 2.
+            let mut progress =
+                TierAssignmentProgressFor::<T>::new(era, Self::tier_candidates_limit());
+            let counter = Self::read_tier_candidates(&mut progress, period, u32::MAX);
+
+            (
+                Self::assign_dapps_to_tiers(&progress.candidates, period, dapp_reward_pool),
+                counter.saturated_into(),
+            )
+        }
+
+        /// Finalize the paged tier assignment for the specified era, at the era change.
+        ///
+        /// All of the contract stake entries which haven't been read in the previous blocks are read now.
+        /// Staked amounts of the candidates read in the previous blocks are refreshed, to account for any
+        /// unstaking or unregistration which happened in the meantime.
+        ///
+        /// In case some candidate's staked amount has decreased to, or below, the best staked amount among the
+        /// dropped dApps, one of the dropped dApps might be a better candidate now, and all of the contract stake
+        /// entries are read again. Such re-reads are normally done page by page, in the blocks before the era change,
+        /// so this only happens if the unstaking happened in the last of those blocks.
+        ///
+        /// Returns the tier assignment, alongside the number of read DB contract stake entries.
+        pub(crate) fn finalize_dapp_tier_assignment(
+            era: EraNumber,
+            period: PeriodNumber,
+            dapp_reward_pool: Balance,
+        ) -> (DAppTierRewardsFor<T>, u32) {
+            let mut counter = 0;
+            let mut progress = match TierAssignmentState::<T>::take() {
+                Some(mut progress) if progress.era == era => {
+                    counter = progress.candidates.len() as u32;
+                    if Self::refresh_tier_candidates(&mut progress, period) {
+                        TierAssignmentProgressFor::<T>::new(era, Self::tier_candidates_limit())
+                    } else {
+                        progress
+                    }
+                }
+                _ => TierAssignmentProgressFor::<T>::new(era, Self::tier_candidates_limit()),
+            };
+
+            if !progress.finished {
+                counter.saturating_accrue(Self::read_tier_candidates(
+                    &mut progress,
+                    period,
+                    u32::MAX,
+                ));
+            }
+
+            (
+                Self::assign_dapps_to_tiers(&progress.candidates, period, dapp_reward_pool),
+                counter,
+            )
+        }
+
+        /// Read the next page of contract stake entries for the paged tier assignment, if it's time for it.
+        ///
+        /// Tier assignment is only needed at the end of `Build&Earn` subperiod eras.
+        /// Reading starts just early enough so all of the contract stake entries can be read twice
+        /// before the era change, one page per block.
+        ///
+        /// Once all of the entries have been read, the candidates are refreshed in each block. In case some of the
+        /// dropped dApps might be better candidates now, all of the entries are read again, page by page.
+        pub(crate) fn tier_assignment_page_handler(
+            now: BlockNumber,
+            protocol_state: &ProtocolState,
+        ) -> Weight {
+            if protocol_state.subperiod() != Subperiod::BuildAndEarn {
+                return Weight::zero();
+            }
+
+            let era = protocol_state.era;
+            let page_size = T::TierAssignmentPageSize::get();
+
+            let mut progress = match TierAssignmentState::<T>::get() {
+                Some(progress) if progress.era == era => progress,
+                _ => {
+                    // Pages are reserved for one re-read, and one extra page in case new contracts get registered
+                    // in the meantime.
+                    let number_of_pages = IntegratedDApps::<T>::count()
+                        .div_ceil(page_size)
+                        .saturating_mul(2)
+                        .saturating_add(1);
+                    if protocol_state.next_era_start.saturating_sub(now) > number_of_pages {
+                        return T::DbWeight::get().reads(2);
+                    }
+
+                    TierAssignmentProgressFor::<T>::new(era, Self::tier_candidates_limit())
+                }
+            };
+
+            let counter = if progress.finished {
+                let counter = progress.candidates.len() as u32;
+                if Self::refresh_tier_candidates(&mut progress, protocol_state.period_number()) {
+                    progress =
+                        TierAssignmentProgressFor::<T>::new(era, Self::tier_candidates_limit());
+                }
+                counter
+            } else {
+                Self::read_tier_candidates(&mut progress, protocol_state.period_number(), page_size)
+            };
+            TierAssignmentState::<T>::put(progress);
+
+            T::WeightInfo::dapp_tier_assignment_page(counter)
+        }
+
+        /// Read up to `max_entries` contract stake entries, continuing after the last read entry,
+        /// and insert the dApps with non-zero stake into the sorted candidates list.
+        ///
+        /// Returns the number of read DB contract stake entries.
+        pub(crate) fn read_tier_candidates(
+            progress: &mut TierAssignmentProgressFor<T>,
+            period: PeriodNumber,
+            max_entries: u32,
+        ) -> u32 {
+            // Iteration order is stable, so it's safe to continue from the last read entry.
+            let mut stake_iter = match progress.cursor {
+                Some(dapp_id) => {
+                    ContractStake::<T>::iter_from(ContractStake::<T>::hashed_key_for(dapp_id))
+                }
+                None => ContractStake::<T>::iter(),
+            };
+
+            let mut counter = 0;
+            while counter < max_entries {
+                let Some((dapp_id, stake_amount)) = stake_iter.next() else {
+                    progress.finished = true;
+                    break;
+                };
+                counter.saturating_inc();
+                progress.cursor = Some(dapp_id);
+
+                // Skip dApps which don't have ANY amount staked
+                if let Some(stake_amount) = stake_amount.get(progress.era, period) {
+                    if !stake_amount.total().is_zero() {
+                        progress.insert_candidate(dapp_id, stake_amount.total());
+                    }
+                }
+            }
+
+            counter
+        }
+
+        /// Refresh the staked amounts of the already read candidates.
+        ///
+        /// Returns `true` if some of the dropped dApps might be better candidates now, `false` otherwise.
+        pub(crate) fn refresh_tier_candidates(
+            progress: &mut TierAssignmentProgressFor<T>,
+            period: PeriodNumber,
+        ) -> bool {
+            let era = progress.era;
+            progress.refresh_candidates(|dapp_id| {
+                ContractStake::<T>::get(&dapp_id)
+                    .get(era, period)
+                    .map_or(Balance::zero(), |stake_amount| stake_amount.total())
+            })
+        }
+
+        /// Maximum number of tier assignment candidates, based on the current tier configuration.
+        pub(crate) fn tier_candidates_limit() -> u32 {
+            TierConfig::<T>::get().total_number_of_slots().into()
+        }
+
+        /// Assign the sorted candidates into appropriate tiers, and calculate reward for each tier.
+        ///
+        /// Covers steps 3. to 5. of the algorithm described in [`Self::get_dapp_tier_assignment_and_rewards`].
+        pub(crate) fn assign_dapps_to_tiers(
+            dapp_stakes: &[(DAppId, Balance)],
+            period: PeriodNumber,
+            dapp_reward_pool: Balance,
+        ) -> DAppTierRewardsFor<T> {
+            let tier_config = TierConfig::<T>::get();
+            let tier_params = StaticTierParams::<T>::get();
+
+            // In case when tier has 1 more free slot, but two dApps with exactly same score satisfy the threshold,
+            // one of them will be assigned to the tier, and the other one will be assigned to the lower tier, if it exists.
+            //
+            // In the current implementation, the dApp with the lower dApp Id has the advantage.
+            // There is no guarantee this will persist in the future, so it's best for dApps to do their
+            // best to avoid getting themselves into such situations.
+
+            // 3.
+            // Iterate over configured tier and potential dApps.
+            // Each dApp will be assigned to the best possible tier if it satisfies the required condition,
+            // and tier capacity hasn't been filled yet.
+            let mut dapp_tiers = BTreeMap::new();
+            let mut tier_rewards = Vec::with_capacity(tier_config.slots_per_tier.len());
+            let mut rank_rewards = Vec::with_capacity(tier_config.slots_per_tier.len());
+
+            let mut upper_bound = Balance::zero();
+
+            for (tier_id, (tier_capacity, lower_bound)) in tier_config
+                .slots_per_tier
+                .iter()
+                .zip(tier_config.tier_thresholds.iter())
+                .enumerate()
+            {
+                let mut tier_slots = BTreeMap::new();
+
+                // Iterate over dApps until one of two conditions has been met:
+                // 1. Tier has no more capacity
+                // 2. dApp doesn't satisfy the tier threshold (since they're sorted, none of the following dApps will satisfy the condition either)
+                for (dapp_id, staked_amount) in dapp_stakes
+                    .iter()
+                    .skip(dapp_tiers.len())
+                    .take_while(|(_, amount)| amount.ge(lower_bound))
+                    .take(*tier_capacity as usize)
+                {
+                    let rank = if T::RankingEnabled::get() {
+                        RankedTier::find_rank(*lower_bound, upper_bound, *staked_amount)
+                    } else {
+                        0
+                    };
+                    tier_slots.insert(*dapp_id, RankedTier::new_saturated(tier_id as u8, rank));
+                }
+
+                // 4. Compute tier rewards using rank multiplier
+                let filled_slots = tier_slots.len() as u32;
+                // sum of all ranks for this tier
+                let ranks_sum = tier_slots
+                    .iter()
+                    .fold(0u32, |accum, (_, x)| accum.saturating_add(x.rank().into()));
+
+                let multiplier_bips = tier_params
+                    .tier_rank_multipliers
+                    .get(tier_id)
+                    .copied()
+                    .unwrap_or(10_000);
+
+                let tier_allocation = tier_config
+                    .reward_portion
+                    .get(tier_id)
+                    .copied()
+                    .unwrap_or(Permill::zero())
+                    * dapp_reward_pool;
+
+                let (tier_reward, rank_reward) = Self::compute_tier_rewards(
+                    tier_allocation,
+                    *tier_capacity,
+                    filled_slots,
+                    ranks_sum,
+                    multiplier_bips,
+                );
+
+                tier_rewards.push(tier_reward);
+                rank_rewards.push(rank_reward);
+                dapp_tiers.append(&mut tier_slots);
+                upper_bound = *lower_bound; // current threshold becomes upper bound for next tier
+            }
+
+            // 5.
+            // Prepare and return tier & rewards info.
+            // In case rewards creation fails, we just write the default value. This should never happen though.
+            DAppTierRewards::<T::MaxNumberOfTierSlots, T::NumberOfTiers>::new(
+                dapp_tiers,
+                tier_rewards,
+                period,
+                rank_rewards,
+            )
+            .unwrap_or_default()
+        }
+
+        /// Used to handle era & period transitions.
+        pub(crate) fn era_and_period_handler(
+            now: BlockNumber,
+            tier_assignment: TierAssignment,
+        ) -> Weight {
+            let mut protocol_state = ActiveProtocolState::<T>::get();
+
+            // `ActiveProtocolState` is whitelisted, so we need to account for its read.
+            let mut consumed_weight = T::DbWeight::get().reads(1);
+
+            // We should not modify pallet storage while in maintenance mode.
+            // This is a safety measure, since maintenance mode is expected to be
+            // enabled in case some misbehavior or corrupted storage is detected.
+            if protocol_state.maintenance {
+                return consumed_weight;
+            }
+
+            // Inform observers about the upcoming new era, if it's the case.
+            if protocol_state.next_era_start == now.saturating_add(1) {
+                consumed_weight
+                    .saturating_accrue(Self::notify_block_before_new_era(&protocol_state));
+            }
+
+            // Nothing to do if it's not new era, except preparing the tier assignment ahead of time.
+            if !protocol_state.is_new_era(now) {
+                if matches!(tier_assignment, TierAssignment::Real) {
+                    consumed_weight.saturating_accrue(Self::tier_assignment_page_handler(
+                        now,
+                        &protocol_state,
+                    ));
+                }
+                return consumed_weight;
+            }
+
+            // At this point it's clear that an era change will happen
+            let mut era_info = CurrentEraInfo::<T>::get();
+
+            let current_era = protocol_state.era;
+            let next_era = current_era.saturating_add(1);
+            let mut applied_tier_params_period = None;
+            let (maybe_period_event, era_reward) = match protocol_state.subperiod() {
+                // Voting subperiod only lasts for one 'prolonged' era
+                Subperiod::Voting => {
+                    // For the sake of consistency, we put zero reward into storage. There are no rewards for the voting subperiod.
+                    let era_reward = EraReward {
+                        staker_reward_pool: Balance::zero(),
+                        staked: era_info.total_staked_amount(),
+                        dapp_reward_pool: Balance::zero(),
+                    };
+
+                    let next_subperiod_start_era = next_era
+                        .saturating_add(T::CycleConfiguration::eras_per_build_and_earn_subperiod());
+                    let build_and_earn_start_block =
+                        now.saturating_add(T::CycleConfiguration::blocks_per_era());
+                    protocol_state.advance_to_next_subperiod(
+                        next_subperiod_start_era,
+                        build_and_earn_start_block,
+                    );
+
+                    era_info.migrate_to_next_era(Some(protocol_state.subperiod()));
+
+                    consumed_weight
+                        .saturating_accrue(T::WeightInfo::on_initialize_voting_to_build_and_earn());
+
+                    (
+                        Some(Event::<T>::NewSubperiod {
+                            subperiod: protocol_state.subperiod(),
+                            number: protocol_state.period_number(),
+                        }),
+                        era_reward,
+                    )
+                }
+                Subperiod::BuildAndEarn => {
+                    let staked = era_info.total_staked_amount();
+                    let (staker_reward_pool, dapp_reward_pool) =
+                        T::StakingRewardHandler::staker_and_dapp_reward_pools(staked);
+                    let era_reward = EraReward {
+                        staker_reward_pool,
+                        staked,
+                        dapp_reward_pool,
+                    };
+
+                    // Distribute dapps into tiers, write it into storage
+                    //
+                    // To help with benchmarking, it's possible to omit real tier calculation using the `Dummy` approach.
+                    // This must never be used in production code, obviously.
+                    let (dapp_tier_rewards, counter) = match tier_assignment {
+                        TierAssignment::Real => Self::finalize_dapp_tier_assignment(
+                            current_era,
+                            protocol_state.period_number(),
+                            dapp_reward_pool,
+                        ),
+                        #[cfg(feature = "runtime-benchmarks")]
+                        TierAssignment::Dummy => (DAppTierRewardsFor::<T>::default(), 0),
+                    };
+                    let tiered_dapps =
+                        Self::update_dapp_tier_history(current_era, &dapp_tier_rewards);
+                    DAppTiers::<T>::insert(&current_era, dapp_tier_rewards);
+
+                    consumed_weight.saturating_accrue(T::WeightInfo::dapp_tier_assignment(counter));
+                    consumed_weight
+                        .saturating_accrue(T::WeightInfo::update_dapp_tier_history(tiered_dapps));
+
+                    // Switch to `Voting` period if conditions are met.
+                    if protocol_state.period_info.is_next_period(next_era) {
+                        // Store info about period end
+                        let bonus_reward_pool = T::StakingRewardHandler::bonus_reward_pool();
+                        PeriodEnd::<T>::insert(
+                            &protocol_state.period_number(),
+                            PeriodEndInfo {
+                                bonus_reward_pool,
+                                total_vp_stake: era_info.staked_amount(Subperiod::Voting),
+                                final_era: current_era,
+                            },
+                        );
+
+                        // For the sake of consistency we treat the whole `Voting` period as a single era.
+                        // This means no special handling is required for this period, it only lasts potentially longer than a single standard era.
+                        let next_subperiod_start_era = next_era.saturating_add(1);
+                        let voting_period_length = Self::blocks_per_voting_period();
+                        let next_era_start_block = now.saturating_add(voting_period_length);
+
+                        protocol_state.advance_to_next_subperiod(
+                            next_subperiod_start_era,
+                            next_era_start_block,
+                        );
+
+                        era_info.migrate_to_next_era(Some(protocol_state.subperiod()));
+
+                        // Update historical cleanup marker.
+                        // Must be called with the new period number.
+                        Self::update_cleanup_marker(protocol_state.period_number());
+
+                        // Derive the number of tier slots for the new period.
+                        NumberOfTierSlots::<T>::put(Self::new_number_of_tier_slots());
+
+                        // Apply the tier parameters scheduled for the new period, if there are any.
+                        applied_tier_params_period =
+                            Self::apply_scheduled_tier_params(protocol_state.period_number());
+                        consumed_weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
+
+                        consumed_weight.saturating_accrue(T::Observers::on_new_period(
+                            protocol_state.period_number(),
+                        ));
+                        consumed_weight.saturating_accrue(
+                            T::WeightInfo::on_initialize_build_and_earn_to_voting(),
+                        );
+
+                        (
+                            Some(Event::<T>::NewSubperiod {
+                                subperiod: protocol_state.subperiod(),
+                                number: protocol_state.period_number(),
+                            }),
+                            era_reward,
+                        )
+                    } else {
+                        let next_era_start_block =
+                            now.saturating_add(T::CycleConfiguration::blocks_per_era());
+                        protocol_state.next_era_start = next_era_start_block;
+
+                        era_info.migrate_to_next_era(None);
+
+                        consumed_weight.saturating_accrue(
+                            T::WeightInfo::on_initialize_build_and_earn_to_build_and_earn(),
+                        );
+
+                        (None, era_reward)
+                    }
+                }
+            };
+
+            // Update storage items
+            protocol_state.era = next_era;
+            ActiveProtocolState::<T>::put(protocol_state);
+
+            let total_value_staked = era_info.total_staked_amount();
+            CurrentEraInfo::<T>::put(era_info);
+
+            let era_span_index = Self::era_reward_span_index(current_era);
+            let mut span = EraRewards::<T>::get(&era_span_index).unwrap_or(EraRewardSpan::new());
+            if let Err(_) = span.push(current_era, era_reward) {
+                // This must never happen but we log the error just in case.
+                log::error!(
+                    target: LOG_TARGET,
+                    "Failed to push era {} into the era reward span.",
+                    current_era
+                );
+            }
+            EraRewards::<T>::insert(&era_span_index, span);
+
+            // Re-calculate tier configuration for the upcoming new era
+            let tier_params = StaticTierParams::<T>::get();
+            let total_issuance = T::Currency::total_issuance();
+
+            let old_tier_config = TierConfig::<T>::get();
+            let new_tier_config = old_tier_config.calculate_new(
+                &tier_params,
+                NumberOfTierSlots::<T>::get(),
+                total_issuance,
+                total_value_staked,
+            );
+
+            // Validate new tier configuration
+            if new_tier_config.is_valid() {
+                TierConfig::<T>::put(new_tier_config);
+            } else {
+                log::warn!(
+                    target: LOG_TARGET,
+                    "New tier configuration is invalid for era {}, preserving old one.",
+                    next_era
+                );
+            }
+
+            Self::deposit_event(Event::<T>::NewEra { era: next_era });
+            if let Some(period_event) = maybe_period_event {
+                Self::deposit_event(period_event);
+            }
+            if let Some(period) = applied_tier_params_period {
+                Self::deposit_event(Event::<T>::ScheduledTierParametersApplied {
+                    period,
+                    old_tier_thresholds: old_tier_config.tier_thresholds,
+                    new_tier_thresholds: TierConfig::<T>::get().tier_thresholds,
+                });
+            }
+
+            consumed_weight
+        }
+
+        /// Maximum weight consumed by the observers during a single staker or bonus reward claim,
+        /// covering the reward payout and the stakes made while compounding the reward.
+        pub(crate) fn max_claim_observer_weight() -> Weight {
+            T::Observers::max_weight().saturating_mul(
+                T::MaxNumberOfStakedContracts::get()
+                    .saturating_add(1)
+                    .into(),
+            )
+        }
+
+        /// Maximum weight consumed by the observers during a single dApp reward claim,
+        /// covering the payouts to all split beneficiaries, and the reward beneficiary.
+        pub(crate) fn max_dapp_claim_observer_weight() -> Weight {
+            T::Observers::max_weight()
+                .saturating_mul(T::MaxNumberOfRewardSplits::get().saturating_add(1).into())
+        }
+
+        /// Used to notify observers about the upcoming new era in the next block.
+        fn notify_block_before_new_era(protocol_state: &ProtocolState) -> Weight {
+            let next_era = protocol_state.era.saturating_add(1);
+            T::Observers::block_before_new_era(next_era)
+        }
+
+        /// Replaces the static tier parameters with the ones scheduled for the specified period, if there are any.
         ///
-        /// 3. Assign dApps to tiers based on stake thresholds, calculating ratio-based ranks within each tier.
+        /// Returns the period if the scheduled parameters were applied, `None` otherwise.
+        fn apply_scheduled_tier_params(period: PeriodNumber) -> Option<PeriodNumber> {
+            match ScheduledTierParams::<T>::get() {
+                Some((scheduled_period, params)) if scheduled_period <= period => {
+                    ScheduledTierParams::<T>::kill();
+                    StaticTierParams::<T>::put(params);
+                    Some(scheduled_period)
+                }
+                _ => None,
+            }
+        }
+
+        /// Updates the cleanup marker with the new oldest valid era if possible.
         ///
-        /// 4. Calculate reward components per tier using the **rank multiplier model**:
+        /// It's possible that the call will be a no-op since we haven't advanced enough periods yet.
+        fn update_cleanup_marker(new_period_number: PeriodNumber) {
+            // 1. Find out the latest expired period; rewards can no longer be claimed for it or any older period.
+            let latest_expired_period = match new_period_number
+                .checked_sub(T::RewardRetentionInPeriods::get().saturating_add(1))
+            {
+                Some(period) if !period.is_zero() => period,
+                // Haven't advanced enough periods to have any expired entries.
+                _ => return,
+            };
+
+            // 2. Find the oldest valid era for which rewards can still be claimed.
+            //    Technically, this will be `Voting` subperiod era but it doesn't matter.
+            //
+            //    Also, remove the expired `PeriodEnd` entry since it's no longer needed.
+            let oldest_valid_era = match PeriodEnd::<T>::take(latest_expired_period) {
+                Some(period_end_info) => period_end_info.final_era.saturating_add(1),
+                None => {
+                    // Should never happen but nothing we can do if it does.
+                    log::error!(
+                        target: LOG_TARGET,
+                        "No `PeriodEnd` entry for the expired period: {}",
+                        latest_expired_period
+                    );
+                    return;
+                }
+            };
+
+            // 3. Update the cleanup marker with the new oldest valid era.
+            HistoryCleanupMarker::<T>::mutate(|marker| {
+                marker.oldest_valid_era = oldest_valid_era;
+            });
+        }
+
+        /// Attempt to cleanup some expired entries, if enough remaining weight & applicable entries exist.
         ///
-        ///    ```text
-        ///    Step 1: Calculate weights
-        ///        increment = (multiplier - 100%) ÷ MAX_RANK
-        ///        dApp_weight = 100% + rank × increment
+        /// Returns consumed weight.
+        fn expired_entry_cleanup(remaining_weight: &Weight) -> Weight {
+            // Need to be able to process one full pass
+            if remaining_weight.any_lt(T::WeightInfo::on_idle_cleanup()) {
+                return Weight::zero();
+            }
+
+            // Get the cleanup marker and ensure we have pending cleanups.
+            let mut cleanup_marker = HistoryCleanupMarker::<T>::get();
+            if !cleanup_marker.has_pending_cleanups() {
+                return T::DbWeight::get().reads(1);
+            }
+
+            // 1. Attempt to cleanup one expired `EraRewards` entry.
+            if cleanup_marker.era_reward_index < cleanup_marker.oldest_valid_era {
+                if let Some(era_reward) = EraRewards::<T>::get(cleanup_marker.era_reward_index) {
+                    // If oldest valid era comes AFTER this span, it's safe to delete it.
+                    if era_reward.last_era() < cleanup_marker.oldest_valid_era {
+                        EraRewards::<T>::remove(cleanup_marker.era_reward_index);
+                        cleanup_marker
+                            .era_reward_index
+                            .saturating_accrue(T::EraRewardSpanLength::get());
+                    }
+                } else {
+                    // Can happen if the entry is part of history before dApp staking v3
+                    log::warn!(
+                        target: LOG_TARGET,
+                        "Era rewards span for era {} is missing, but cleanup marker is set.",
+                        cleanup_marker.era_reward_index
+                    );
+                    cleanup_marker
+                        .era_reward_index
+                        .saturating_accrue(T::EraRewardSpanLength::get());
+                }
+            }
+
+            // 2. Attempt to cleanup one expired `DAppTiers` entry.
+            if cleanup_marker.dapp_tiers_index < cleanup_marker.oldest_valid_era {
+                DAppTiers::<T>::remove(cleanup_marker.dapp_tiers_index);
+                cleanup_marker.dapp_tiers_index.saturating_inc();
+            }
+
+            // Store the updated cleanup marker
+            HistoryCleanupMarker::<T>::put(cleanup_marker);
+
+            // We could try & cleanup more entries, but since it's not a critical operation and can happen whenever,
+            // we opt for the simpler solution where only 1 entry per block is cleaned up.
+            // It can be changed though.
+
+            // It could end up being less than this weight, but this won't occur often enough to be important.
+            T::WeightInfo::on_idle_cleanup()
+        }
+
+        /// Claims staker & bonus rewards which are about to expire, for the accounts registered for automatic claiming.
         ///
-        ///    Step 2: Find reward rate (with overflow cap)
-        ///        target_weight = max_slots × avg_weight       (calibrated for avg rank = 5)
-        ///        actual_weight = filled_slots × 100% + ranks_sum × increment
-        ///        reward_per_% = tier_allocation ÷ max(actual_weight, target_weight)
+        /// Up to `AutoClaimScanLimit` registered accounts are inspected, continuing after the last account
+        /// inspected in the previous call. Rewards are about to expire if they belong to the oldest claimable period.
+        /// Accounts with such rewards are processed in the order of their oldest unclaimed era,
+        /// for as long as there is enough weight remaining. Prioritization only applies within the inspected window
+        /// of up to `AutoClaimScanLimit` accounts, not across all of the registered accounts.
         ///
-        ///    Step 3: Pre-compute claim values
-        ///        tier_reward = 100% × reward_per_%
-        ///        rank_reward = increment × reward_per_%
-        ///    ```
-        ///    (Sort the entries by dApp ID, in ascending order. This is so we can efficiently search for them using binary search.)
+        /// In case there isn't enough weight to process all of the inspected accounts with expiring rewards,
+        /// the next call continues from the first such account which wasn't fully processed.
         ///
-        /// The returned object contains information about each dApp that made it into a tier.
-        /// Alongside tier assignment info, number of read DB contract stake entries is returned.
-        pub(crate) fn get_dapp_tier_assignment_and_rewards(
-            era: EraNumber,
-            period: PeriodNumber,
-            dapp_reward_pool: Balance,
-        ) -> (DAppTierRewardsFor<T>, DAppId) {
-            let mut dapp_stakes = Vec::with_capacity(T::MaxNumberOfContracts::get() as usize);
+        /// Returns the consumed weight.
+        pub(crate) fn auto_claim_rewards(remaining_weight: &Weight) -> Weight {
+            // Protocol state & cursor
+            let mut consumed_weight = T::DbWeight::get().reads(2);
+
+            // Account entry, ledger & all of the staker info entries
+            let scan_weight = T::DbWeight::get().reads(
+                T::MaxNumberOfStakedContracts::get()
+                    .saturating_add(2)
+                    .into(),
+            );
+            let staker_claim_weight = Self::max_past_period_staker_claim_weight();
+            let bonus_claim_weight = Self::max_bonus_claim_weight();
 
-            // 1.
-            // Iterate over all staked dApps.
-            // This is bounded by max amount of dApps we allow to be registered.
-            let mut counter = 0;
-            for (dapp_id, stake_amount) in ContractStake::<T>::iter() {
-                counter.saturating_inc();
+            // Need to be able to inspect & process at least one account.
+            if remaining_weight.any_lt(
+                consumed_weight
+                    .saturating_add(scan_weight)
+                    .saturating_add(staker_claim_weight.max(bonus_claim_weight)),
+            ) {
+                return Weight::zero();
+            }
 
-                // Skip dApps which don't have ANY amount staked
-                if let Some(stake_amount) = stake_amount.get(era, period) {
-                    if !stake_amount.total().is_zero() {
-                        dapp_stakes.push((dapp_id, stake_amount.total()));
-                    }
-                }
+            let protocol_state = ActiveProtocolState::<T>::get();
+            if protocol_state.maintenance {
+                return T::DbWeight::get().reads(1);
             }
+            let expiring_period = Self::oldest_claimable_period(protocol_state.period_number());
 
-            // 2.
-            // Sort by amount staked, in reverse - top dApp will end in the first place, 0th index.
-            dapp_stakes.sort_unstable_by(|(_, amount_1), (_, amount_2)| amount_2.cmp(amount_1));
+            // 1. Inspect the registered accounts, continuing from the cursor.
+            let cursor = AutoClaimCursor::<T>::get();
+            let mut accounts_iter = match &cursor {
+                Some(cursor) => {
+                    AutoClaim::<T>::iter_keys_from(AutoClaim::<T>::hashed_key_for(cursor))
+                }
+                None => AutoClaim::<T>::iter_keys(),
+            };
 
-            let tier_config = TierConfig::<T>::get();
-            let tier_params = StaticTierParams::<T>::get();
+            // Besides the expiring rewards, each candidate keeps its inspection order,
+            // and the cursor from which it would be inspected again.
+            let mut candidates = Vec::new();
+            let mut last_inspected = cursor;
+            let mut is_exhausted = false;
+            for _ in 0..T::AutoClaimScanLimit::get() {
+                if remaining_weight.any_lt(consumed_weight.saturating_add(scan_weight)) {
+                    break;
+                }
 
-            // In case when tier has 1 more free slot, but two dApps with exactly same score satisfy the threshold,
-            // one of them will be assigned to the tier, and the other one will be assigned to the lower tier, if it exists.
-            //
-            // In the current implementation, the dApp with the lower dApp Id has the advantage.
-            // There is no guarantee this will persist in the future, so it's best for dApps to do their
-            // best to avoid getting themselves into such situations.
+                let Some(account) = accounts_iter.next() else {
+                    is_exhausted = true;
+                    break;
+                };
+                consumed_weight.saturating_accrue(scan_weight);
+
+                let (expiring_era, expiring_bonus_contracts) =
+                    Self::expiring_rewards(&account, expiring_period);
+                if expiring_era.is_some() || !expiring_bonus_contracts.is_empty() {
+                    candidates.push((
+                        expiring_era,
+                        candidates.len(),
+                        last_inspected.clone(),
+                        account.clone(),
+                        expiring_bonus_contracts,
+                    ));
+                }
+                last_inspected = Some(account);
+            }
 
-            // 3.
-            // Iterate over configured tier and potential dApps.
-            // Each dApp will be assigned to the best possible tier if it satisfies the required condition,
-            // and tier capacity hasn't been filled yet.
-            let mut dapp_tiers = BTreeMap::new();
-            let mut tier_rewards = Vec::with_capacity(tier_config.slots_per_tier.len());
-            let mut rank_rewards = Vec::with_capacity(tier_config.slots_per_tier.len());
+            // Once all the accounts have been inspected, start from the beginning again.
+            let mut next_cursor = if is_exhausted { None } else { last_inspected };
+            consumed_weight.saturating_accrue(T::DbWeight::get().writes(1));
 
-            let mut upper_bound = Balance::zero();
+            // 2. Claim the expiring rewards, prioritizing the oldest unclaimed eras.
+            candidates.sort_by_key(|(era, _, _, _, _)| era.unwrap_or(EraNumber::MAX));
 
-            for (tier_id, (tier_capacity, lower_bound)) in tier_config
-                .slots_per_tier
-                .iter()
-                .zip(tier_config.tier_thresholds.iter())
-                .enumerate()
+            let mut unprocessed_from = None;
+            'candidates: for (index, (expiring_era, _, _, account, bonus_contracts)) in
+                candidates.iter().enumerate()
             {
-                let mut tier_slots = BTreeMap::new();
+                if expiring_era.is_some() {
+                    if remaining_weight.any_lt(consumed_weight.saturating_add(staker_claim_weight))
+                    {
+                        unprocessed_from = Some(index);
+                        break 'candidates;
+                    }
 
-                // Iterate over dApps until one of two conditions has been met:
-                // 1. Tier has no more capacity
-                // 2. dApp doesn't satisfy the tier threshold (since they're sorted, none of the following dApps will satisfy the condition either)
-                for (dapp_id, staked_amount) in dapp_stakes
+                    let result = with_storage_layer(|| {
+                        Self::internal_claim_staker_rewards_for(account.clone())
+                    });
+                    consumed_weight
+                        .saturating_accrue(Self::claim_weight(result, staker_claim_weight));
+                }
+
+                for smart_contract in bonus_contracts {
+                    if remaining_weight.any_lt(consumed_weight.saturating_add(bonus_claim_weight)) {
+                        unprocessed_from = Some(index);
+                        break 'candidates;
+                    }
+
+                    let result = with_storage_layer(|| {
+                        Self::internal_claim_bonus_reward_for(
+                            account.clone(),
+                            smart_contract.clone(),
+                        )
+                    });
+                    consumed_weight
+                        .saturating_accrue(Self::claim_weight(result, bonus_claim_weight));
+                }
+            }
+
+            // Candidates which weren't fully processed must be inspected again in the next call,
+            // so continue from the first one of them, in the inspection order.
+            if let Some(index) = unprocessed_from {
+                if let Some((_, _, cursor, _, _)) = candidates[index..]
                     .iter()
-                    .skip(dapp_tiers.len())
-                    .take_while(|(_, amount)| amount.ge(lower_bound))
-                    .take(*tier_capacity as usize)
+                    .min_by_key(|(_, inspection_index, _, _, _)| *inspection_index)
                 {
-                    let rank = if T::RankingEnabled::get() {
-                        RankedTier::find_rank(*lower_bound, upper_bound, *staked_amount)
-                    } else {
-                        0
-                    };
-                    tier_slots.insert(*dapp_id, RankedTier::new_saturated(tier_id as u8, rank));
+                    next_cursor = cursor.clone();
                 }
+            }
 
-                // 4. Compute tier rewards using rank multiplier
-                let filled_slots = tier_slots.len() as u32;
-                // sum of all ranks for this tier
-                let ranks_sum = tier_slots
-                    .iter()
-                    .fold(0u32, |accum, (_, x)| accum.saturating_add(x.rank().into()));
+            match next_cursor {
+                Some(account) => AutoClaimCursor::<T>::put(account),
+                None => AutoClaimCursor::<T>::kill(),
+            }
 
-                let multiplier_bips = tier_params
-                    .tier_rank_multipliers
-                    .get(tier_id)
-                    .copied()
-                    .unwrap_or(10_000);
+            consumed_weight
+        }
 
-                let tier_allocation = tier_config
-                    .reward_portion
-                    .get(tier_id)
-                    .copied()
-                    .unwrap_or(Permill::zero())
-                    * dapp_reward_pool;
+        /// Applies the carry-over plans of stakers during the `Voting` subperiod, staking the planned amounts on their behalf.
+        ///
+        /// Each plan is applied at most once per period. Plans are inspected continuing after the last account
+        /// inspected in the previous call, for as long as there is enough weight remaining.
+        ///
+        /// Returns the consumed weight.
+        fn apply_carry_over_plans(remaining_weight: &Weight) -> Weight {
+            // Protocol state & cursor
+            let mut consumed_weight = T::DbWeight::get().reads(2);
+
+            // Need to be able to apply at least one full plan, including the reward claims.
+            let max_plan_weight =
+                Self::carry_over_plan_weight(T::MaxNumberOfStakedContracts::get())
+                    .saturating_add(Self::max_past_period_claims_weight());
+            if remaining_weight.any_lt(consumed_weight.saturating_add(max_plan_weight)) {
+                return Weight::zero();
+            }
 
-                let (tier_reward, rank_reward) = Self::compute_tier_rewards(
-                    tier_allocation,
-                    *tier_capacity,
-                    filled_slots,
-                    ranks_sum,
-                    multiplier_bips,
-                );
+            let protocol_state = ActiveProtocolState::<T>::get();
+            if protocol_state.maintenance || protocol_state.subperiod() != Subperiod::Voting {
+                return T::DbWeight::get().reads(1);
+            }
+            let period = protocol_state.period_number();
+
+            let mut plans_iter = match CarryOverCursor::<T>::get() {
+                // All of the plans have already been inspected in this period.
+                Some((cursor_period, None)) if cursor_period == period => return consumed_weight,
+                Some((cursor_period, Some(cursor))) if cursor_period == period => {
+                    CarryOverPlans::<T>::iter_from(CarryOverPlans::<T>::hashed_key_for(&cursor))
+                }
+                _ => CarryOverPlans::<T>::iter(),
+            };
 
-                tier_rewards.push(tier_reward);
-                rank_rewards.push(rank_reward);
-                dapp_tiers.append(&mut tier_slots);
-                upper_bound = *lower_bound; // current threshold becomes upper bound for next tier
+            let mut last_inspected = None;
+            let mut is_exhausted = false;
+            loop {
+                if remaining_weight.any_lt(consumed_weight.saturating_add(max_plan_weight)) {
+                    break;
+                }
+
+                let Some((account, plan)) = plans_iter.next() else {
+                    is_exhausted = true;
+                    break;
+                };
+
+                // Plans set during the ongoing period only apply from the next one.
+                if plan.start_period <= period {
+                    consumed_weight
+                        .saturating_accrue(Self::apply_carry_over_plan(&account, plan, period));
+                } else {
+                    consumed_weight.saturating_accrue(T::DbWeight::get().reads(1));
+                }
+                last_inspected = Some(account);
             }
 
-            // 5.
-            // Prepare and return tier & rewards info.
-            // In case rewards creation fails, we just write the default value. This should never happen though.
-            (
-                DAppTierRewards::<T::MaxNumberOfContracts, T::NumberOfTiers>::new(
-                    dapp_tiers,
-                    tier_rewards,
-                    period,
-                    rank_rewards,
-                )
-                .unwrap_or_default(),
-                counter,
-            )
+            if is_exhausted {
+                CarryOverCursor::<T>::put((period, None::<T::AccountId>));
+            } else if last_inspected.is_some() {
+                CarryOverCursor::<T>::put((period, last_inspected));
+            }
+            consumed_weight.saturating_accrue(T::DbWeight::get().writes(1));
+
+            consumed_weight
         }
 
-        /// Used to handle era & period transitions.
-        pub(crate) fn era_and_period_handler(
-            now: BlockNumber,
-            tier_assignment: TierAssignment,
-        ) -> Weight {
-            let mut protocol_state = ActiveProtocolState::<T>::get();
+        /// Unstakes the stake left on unregistered smart contracts, once their grace period has passed.
+        ///
+        /// Stake entries are inspected in passes over all of the stakers, continuing after the last entry
+        /// inspected in the previous call, for as long as there is enough weight remaining.
+        /// A new pass only begins if stake on some unregistered contract can be swept.
+        /// Entries which are eligible for the bonus reward are left as they are, so the reward isn't forfeited.
+        ///
+        /// Returns the consumed weight.
+        fn sweep_unregistered_stake(remaining_weight: &Weight) -> Weight {
+            // Protocol state, cursor & sweeps counter
+            let mut consumed_weight = T::DbWeight::get().reads(3);
 
-            // `ActiveProtocolState` is whitelisted, so we need to account for its read.
-            let mut consumed_weight = T::DbWeight::get().reads(1);
+            // Stake entry & the corresponding sweep entry
+            let scan_weight = T::DbWeight::get().reads(2);
+            let sweep_weight = Self::max_sweep_weight();
 
-            // We should not modify pallet storage while in maintenance mode.
-            // This is a safety measure, since maintenance mode is expected to be
-            // enabled in case some misbehavior or corrupted storage is detected.
-            if protocol_state.maintenance {
-                return consumed_weight;
+            // Need to be able to inspect & sweep at least one stake entry.
+            if remaining_weight.any_lt(
+                consumed_weight
+                    .saturating_add(scan_weight)
+                    .saturating_add(sweep_weight),
+            ) {
+                return Weight::zero();
             }
 
-            // Inform observers about the upcoming new era, if it's the case.
-            if protocol_state.next_era_start == now.saturating_add(1) {
-                consumed_weight
-                    .saturating_accrue(Self::notify_block_before_new_era(&protocol_state));
+            let protocol_state = ActiveProtocolState::<T>::get();
+            if protocol_state.maintenance {
+                return T::DbWeight::get().reads(1);
             }
+            let current_period = protocol_state.period_number();
+            let current_era = protocol_state.era;
 
-            // Nothing to do if it's not new era
-            if !protocol_state.is_new_era(now) {
+            let number_of_sweeps = UnregisteredStakeSweeps::<T>::count();
+            if number_of_sweeps.is_zero() {
                 return consumed_weight;
             }
 
-            // At this point it's clear that an era change will happen
-            let mut era_info = CurrentEraInfo::<T>::get();
+            let (pass_era, mut stakers_iter) = match UnregisteredStakeSweepCursor::<T>::get() {
+                Some((pass_era, account, smart_contract)) => (
+                    pass_era,
+                    StakerInfo::<T>::iter_from(StakerInfo::<T>::hashed_key_for(
+                        &account,
+                        &smart_contract,
+                    )),
+                ),
+                None => {
+                    // Stake from the past periods is no longer counted in the ledger, so there's nothing to sweep.
+                    let mut stale = Vec::new();
+                    let mut is_due = false;
+                    for (smart_contract, (period, era)) in UnregisteredStakeSweeps::<T>::iter() {
+                        if period < current_period {
+                            stale.push(smart_contract);
+                        } else if era <= current_era {
+                            is_due = true;
+                        }
+                    }
+                    consumed_weight.saturating_accrue(T::DbWeight::get().reads_writes(
+                        number_of_sweeps.into(),
+                        stale.len().saturating_add(1) as u64,
+                    ));
 
-            let current_era = protocol_state.era;
-            let next_era = current_era.saturating_add(1);
-            let (maybe_period_event, era_reward) = match protocol_state.subperiod() {
-                // Voting subperiod only lasts for one 'prolonged' era
-                Subperiod::Voting => {
-                    // For the sake of consistency, we put zero reward into storage. There are no rewards for the voting subperiod.
-                    let era_reward = EraReward {
-                        staker_reward_pool: Balance::zero(),
-                        staked: era_info.total_staked_amount(),
-                        dapp_reward_pool: Balance::zero(),
-                    };
+                    for smart_contract in stale {
+                        UnregisteredStakeSweeps::<T>::remove(&smart_contract);
+                    }
 
-                    let next_subperiod_start_era = next_era
-                        .saturating_add(T::CycleConfiguration::eras_per_build_and_earn_subperiod());
-                    let build_and_earn_start_block =
-                        now.saturating_add(T::CycleConfiguration::blocks_per_era());
-                    protocol_state.advance_to_next_subperiod(
-                        next_subperiod_start_era,
-                        build_and_earn_start_block,
-                    );
+                    // Only begin a new pass if there's something to sweep.
+                    if !is_due {
+                        return consumed_weight;
+                    }
 
-                    era_info.migrate_to_next_era(Some(protocol_state.subperiod()));
+                    (current_era, StakerInfo::<T>::iter())
+                }
+            };
 
+            let mut last_inspected = None;
+            let mut is_exhausted = false;
+            loop {
+                if remaining_weight.any_lt(
                     consumed_weight
-                        .saturating_accrue(T::WeightInfo::on_initialize_voting_to_build_and_earn());
+                        .saturating_add(scan_weight)
+                        .saturating_add(sweep_weight),
+                ) {
+                    break;
+                }
 
-                    (
-                        Some(Event::<T>::NewSubperiod {
-                            subperiod: protocol_state.subperiod(),
-                            number: protocol_state.period_number(),
-                        }),
-                        era_reward,
-                    )
+                let Some((account, smart_contract, staking_info)) = stakers_iter.next() else {
+                    is_exhausted = true;
+                    break;
+                };
+                consumed_weight.saturating_accrue(scan_weight);
+
+                let is_due = UnregisteredStakeSweeps::<T>::get(&smart_contract)
+                    .is_some_and(|(period, era)| period == current_period && era <= current_era);
+                if is_due
+                    && staking_info.period_number() == current_period
+                    && !staking_info.is_bonus_eligible()
+                {
+                    let (result, weight) = Self::sweep_stake_entry(&account, &smart_contract);
+                    consumed_weight.saturating_accrue(weight);
+
+                    if let Ok(unstake_amount) = result {
+                        Self::deposit_event(Event::<T>::UnregisteredStakeSwept {
+                            account: account.clone(),
+                            smart_contract: smart_contract.clone(),
+                            amount: unstake_amount.total(),
+                        });
+                    }
                 }
-                Subperiod::BuildAndEarn => {
-                    let staked = era_info.total_staked_amount();
-                    let (staker_reward_pool, dapp_reward_pool) =
-                        T::StakingRewardHandler::staker_and_dapp_reward_pools(staked);
-                    let era_reward = EraReward {
-                        staker_reward_pool,
-                        staked,
-                        dapp_reward_pool,
-                    };
 
-                    // Distribute dapps into tiers, write it into storage
-                    //
-                    // To help with benchmarking, it's possible to omit real tier calculation using the `Dummy` approach.
-                    // This must never be used in production code, obviously.
-                    let (dapp_tier_rewards, counter) = match tier_assignment {
-                        TierAssignment::Real => Self::get_dapp_tier_assignment_and_rewards(
-                            current_era,
-                            protocol_state.period_number(),
-                            dapp_reward_pool,
-                        ),
-                        #[cfg(feature = "runtime-benchmarks")]
-                        TierAssignment::Dummy => (DAppTierRewardsFor::<T>::default(), 0),
-                    };
-                    DAppTiers::<T>::insert(&current_era, dapp_tier_rewards);
+                last_inspected = Some((account, smart_contract));
+            }
 
-                    consumed_weight
-                        .saturating_accrue(T::WeightInfo::dapp_tier_assignment(counter.into()));
+            if is_exhausted {
+                // All of the stake entries have been inspected since the pass began,
+                // so the sweeps which were already due back then are complete.
+                let completed: Vec<_> = UnregisteredStakeSweeps::<T>::iter()
+                    .filter(|(_, (period, era))| *period < current_period || *era <= pass_era)
+                    .map(|(smart_contract, _)| smart_contract)
+                    .collect();
+                consumed_weight.saturating_accrue(T::DbWeight::get().reads_writes(
+                    number_of_sweeps.into(),
+                    completed.len().saturating_add(1) as u64,
+                ));
+
+                for smart_contract in completed {
+                    UnregisteredStakeSweeps::<T>::remove(&smart_contract);
+                }
+                UnregisteredStakeSweepCursor::<T>::kill();
+            } else if let Some((account, smart_contract)) = last_inspected {
+                UnregisteredStakeSweepCursor::<T>::put((pass_era, account, smart_contract));
+            }
+            consumed_weight.saturating_accrue(T::DbWeight::get().writes(1));
 
-                    // Switch to `Voting` period if conditions are met.
-                    if protocol_state.period_info.is_next_period(next_era) {
-                        // Store info about period end
-                        let bonus_reward_pool = T::StakingRewardHandler::bonus_reward_pool();
-                        PeriodEnd::<T>::insert(
-                            &protocol_state.period_number(),
-                            PeriodEndInfo {
-                                bonus_reward_pool,
-                                total_vp_stake: era_info.staked_amount(Subperiod::Voting),
-                                final_era: current_era,
-                            },
-                        );
+            consumed_weight
+        }
 
-                        // For the sake of consistency we treat the whole `Voting` period as a single era.
-                        // This means no special handling is required for this period, it only lasts potentially longer than a single standard era.
-                        let next_subperiod_start_era = next_era.saturating_add(1);
-                        let voting_period_length = Self::blocks_per_voting_period();
-                        let next_era_start_block = now.saturating_add(voting_period_length);
+        /// Unstakes the stake left on the unregistered smart contract, claiming the staker rewards first if required.
+        ///
+        /// Nothing is changed in case of failure. Returns the result, and the consumed weight.
+        fn sweep_stake_entry(
+            account: &T::AccountId,
+            smart_contract: &T::SmartContract,
+        ) -> (Result<StakeAmount, DispatchError>, Weight) {
+            let claim_weight = Self::max_sweep_claim_weight();
+            let mut consumed_weight = Weight::zero();
+
+            let result = with_storage_layer(|| {
+                for _ in 0..=Self::max_sweep_claims() {
+                    match Self::inner_unstake_from_unregistered(account, smart_contract, None) {
+                        // Stake cannot be unstaked until all of the staker rewards have been claimed.
+                        Err(error) if error == Error::<T>::UnclaimedRewards.into() => {
+                            let result = Self::internal_claim_staker_rewards_for(account.clone());
+                            consumed_weight
+                                .saturating_accrue(Self::claim_weight(result, claim_weight));
+                            result.map_err(|error| error.error)?;
+                        }
+                        result => {
+                            return result.map(|(unstake_amount, _, observer_weight)| {
+                                consumed_weight.saturating_accrue(observer_weight);
+                                unstake_amount
+                            })
+                        }
+                    }
+                }
 
-                        protocol_state.advance_to_next_subperiod(
-                            next_subperiod_start_era,
-                            next_era_start_block,
-                        );
+                Err(Error::<T>::UnclaimedRewards.into())
+            });
+            consumed_weight.saturating_accrue(T::WeightInfo::unstake_from_unregistered());
 
-                        era_info.migrate_to_next_era(Some(protocol_state.subperiod()));
+            (result, consumed_weight)
+        }
 
-                        // Update historical cleanup marker.
-                        // Must be called with the new period number.
-                        Self::update_cleanup_marker(protocol_state.period_number());
+        /// Maximum number of staker reward claims required before the stake can be swept.
+        ///
+        /// Covers all of the eras of a single period.
+        fn max_sweep_claims() -> u32 {
+            T::CycleConfiguration::eras_per_period()
+                .div_ceil(T::EraRewardSpanLength::get())
+                .saturating_add(1)
+        }
 
-                        consumed_weight.saturating_accrue(
-                            T::WeightInfo::on_initialize_build_and_earn_to_voting(),
-                        );
+        /// Maximum weight of a single staker reward claim, done as part of the stake sweep.
+        fn max_sweep_claim_weight() -> Weight {
+            T::WeightInfo::claim_staker_rewards_ongoing_period(T::EraRewardSpanLength::get())
+                .saturating_add(T::WeightInfo::compound_reward(
+                    T::MaxNumberOfStakedContracts::get(),
+                ))
+                .saturating_add(Self::max_claim_observer_weight())
+        }
 
-                        (
-                            Some(Event::<T>::NewSubperiod {
-                                subperiod: protocol_state.subperiod(),
-                                number: protocol_state.period_number(),
-                            }),
-                            era_reward,
-                        )
-                    } else {
-                        let next_era_start_block =
-                            now.saturating_add(T::CycleConfiguration::blocks_per_era());
-                        protocol_state.next_era_start = next_era_start_block;
+        /// Maximum weight of sweeping a single stake entry, including the required staker reward claims.
+        fn max_sweep_weight() -> Weight {
+            Self::max_sweep_claim_weight()
+                .saturating_mul(Self::max_sweep_claims().into())
+                .saturating_add(T::WeightInfo::unstake_from_unregistered())
+                .saturating_add(T::Observers::max_weight())
+        }
 
-                        era_info.migrate_to_next_era(None);
+        /// Stakes the planned amounts on behalf of the account, reporting each stake which couldn't be applied.
+        ///
+        /// Since stake cannot be made while there are unclaimed rewards from the past periods,
+        /// these are claimed first.
+        ///
+        /// Returns the consumed weight.
+        fn apply_carry_over_plan(
+            account: &T::AccountId,
+            plan: CarryOverPlanFor<T>,
+            period: PeriodNumber,
+        ) -> Weight {
+            let number_of_entries = plan.entries.len() as u32;
+            let claim_weight = Self::claim_past_period_rewards(account, period);
 
-                        consumed_weight.saturating_accrue(
-                            T::WeightInfo::on_initialize_build_and_earn_to_build_and_earn(),
-                        );
+            for (smart_contract, amount) in plan.entries {
+                let result = with_storage_layer(|| {
+                    Self::internal_stake(account.clone(), smart_contract.clone(), amount)
+                });
 
-                        (None, era_reward)
-                    }
+                if let Err(error) = result {
+                    Self::deposit_event(Event::<T>::CarryOverStakeFailed {
+                        account: account.clone(),
+                        smart_contract,
+                        amount,
+                        error: error.error,
+                    });
                 }
-            };
+            }
 
-            // Update storage items
-            protocol_state.era = next_era;
-            ActiveProtocolState::<T>::put(protocol_state);
+            Self::deposit_event(Event::<T>::CarryOverPlanApplied {
+                account: account.clone(),
+                period,
+            });
 
-            CurrentEraInfo::<T>::put(era_info);
+            Self::carry_over_plan_weight(number_of_entries).saturating_add(claim_weight)
+        }
 
-            let era_span_index = Self::era_reward_span_index(current_era);
-            let mut span = EraRewards::<T>::get(&era_span_index).unwrap_or(EraRewardSpan::new());
-            if let Err(_) = span.push(current_era, era_reward) {
-                // This must never happen but we log the error just in case.
-                log::error!(
-                    target: LOG_TARGET,
-                    "Failed to push era {} into the era reward span.",
-                    current_era
-                );
-            }
-            EraRewards::<T>::insert(&era_span_index, span);
+        /// Weight of applying a carry-over plan with the specified number of entries, excluding the reward claims.
+        fn carry_over_plan_weight(number_of_entries: u32) -> Weight {
+            T::DbWeight::get().reads(1).saturating_add(
+                T::WeightInfo::stake()
+                    .saturating_add(T::Observers::max_weight())
+                    .saturating_mul(number_of_entries.into()),
+            )
+        }
 
-            // Re-calculate tier configuration for the upcoming new era
-            let tier_params = StaticTierParams::<T>::get();
-            let total_issuance = T::Currency::total_issuance();
+        /// Claims all of the account's staker & bonus rewards from the periods before the specified one.
+        ///
+        /// Failed claims are skipped, leaving it up to the planned stake to report the issue.
+        ///
+        /// Returns the consumed weight.
+        fn claim_past_period_rewards(account: &T::AccountId, period: PeriodNumber) -> Weight {
+            // Ledger & all of the staker info entries
+            let mut consumed_weight = T::DbWeight::get().reads(
+                T::MaxNumberOfStakedContracts::get()
+                    .saturating_add(1)
+                    .into(),
+            );
 
-            let new_tier_config =
-                TierConfig::<T>::get().calculate_new(&tier_params, total_issuance);
+            // 1.
+            // Claim the staker rewards, one era reward span at a time.
+            for _ in 0..Self::max_sweep_claims() {
+                let has_past_period_stake = Ledger::<T>::get(account)
+                    .staked_period()
+                    .is_some_and(|staked_period| staked_period < period);
+                if !has_past_period_stake {
+                    break;
+                }
 
-            // Validate new tier configuration
-            if new_tier_config.is_valid() {
-                TierConfig::<T>::put(new_tier_config);
-            } else {
-                log::warn!(
-                    target: LOG_TARGET,
-                    "New tier configuration is invalid for era {}, preserving old one.",
-                    next_era
-                );
+                let result =
+                    with_storage_layer(|| Self::internal_claim_staker_rewards_for(account.clone()));
+                let is_claimed = result.is_ok();
+                consumed_weight.saturating_accrue(Self::claim_weight(
+                    result,
+                    Self::max_past_period_staker_claim_weight(),
+                ));
+                if !is_claimed {
+                    break;
+                }
             }
 
-            Self::deposit_event(Event::<T>::NewEra { era: next_era });
-            if let Some(period_event) = maybe_period_event {
-                Self::deposit_event(period_event);
+            // 2.
+            // Claim the bonus rewards.
+            // Bounded by max allowed number of stake entries per account.
+            let bonus_contracts: Vec<_> = StakerInfo::<T>::iter_prefix(account)
+                .filter(|(_, staking_info)| {
+                    staking_info.period_number() < period && staking_info.is_bonus_eligible()
+                })
+                .map(|(smart_contract, _)| smart_contract)
+                .collect();
+            for smart_contract in bonus_contracts {
+                let result = with_storage_layer(|| {
+                    Self::internal_claim_bonus_reward_for(account.clone(), smart_contract)
+                });
+                consumed_weight
+                    .saturating_accrue(Self::claim_weight(result, Self::max_bonus_claim_weight()));
             }
 
             consumed_weight
         }
 
-        /// Used to notify observers about the upcoming new era in the next block.
-        fn notify_block_before_new_era(protocol_state: &ProtocolState) -> Weight {
-            let next_era = protocol_state.era.saturating_add(1);
-            T::Observers::block_before_new_era(next_era)
+        /// Maximum weight of claiming all of the account's staker & bonus rewards from the past periods.
+        fn max_past_period_claims_weight() -> Weight {
+            T::DbWeight::get()
+                .reads(
+                    T::MaxNumberOfStakedContracts::get()
+                        .saturating_add(1)
+                        .into(),
+                )
+                .saturating_add(
+                    Self::max_past_period_staker_claim_weight()
+                        .saturating_mul(Self::max_sweep_claims().into()),
+                )
+                .saturating_add(
+                    Self::max_bonus_claim_weight()
+                        .saturating_mul(T::MaxNumberOfStakedContracts::get().into()),
+                )
+        }
+
+        /// Maximum weight of a single past period staker reward claim, including the reward compounding.
+        pub(crate) fn max_past_period_staker_claim_weight() -> Weight {
+            T::WeightInfo::claim_staker_rewards_past_period(T::EraRewardSpanLength::get())
+                .saturating_add(T::WeightInfo::compound_reward(
+                    T::MaxNumberOfStakedContracts::get(),
+                ))
+                .saturating_add(Self::max_claim_observer_weight())
+        }
+
+        /// Maximum weight of a single bonus reward claim, including the reward compounding.
+        pub(crate) fn max_bonus_claim_weight() -> Weight {
+            T::WeightInfo::claim_bonus_reward()
+                .saturating_add(T::WeightInfo::compound_reward(
+                    T::MaxNumberOfStakedContracts::get(),
+                ))
+                .saturating_add(Self::max_claim_observer_weight())
+        }
+
+        /// Returns the oldest unclaimed era of the account's staker rewards, if they belong to the expiring period,
+        /// and the smart contracts for which the account's bonus rewards belong to the expiring period.
+        fn expiring_rewards(
+            account: &T::AccountId,
+            expiring_period: PeriodNumber,
+        ) -> (Option<EraNumber>, Vec<T::SmartContract>) {
+            let ledger = Ledger::<T>::get(account);
+            let expiring_era = if ledger.staked_period() == Some(expiring_period) {
+                ledger.earliest_staked_era()
+            } else {
+                None
+            };
+
+            let bonus_contracts = StakerInfo::<T>::iter_prefix(account)
+                .filter(|(_, staking_info)| {
+                    staking_info.period_number() == expiring_period
+                        && staking_info.is_bonus_eligible()
+                })
+                .map(|(smart_contract, _)| smart_contract)
+                .collect();
+
+            (expiring_era, bonus_contracts)
+        }
+
+        /// Account to which the staker & bonus rewards of the specified account are paid out.
+        pub(crate) fn staker_reward_destination(account: &T::AccountId) -> T::AccountId {
+            StakerRewardDestination::<T>::get(account).unwrap_or_else(|| account.clone())
+        }
+
+        /// Returns the weight consumed by the reward claim, based on its result.
+        ///
+        /// In case actual weight isn't known, the provided maximum weight is used.
+        fn claim_weight(result: DispatchResultWithPostInfo, max_weight: Weight) -> Weight {
+            let post_info = match result {
+                Ok(post_info) => post_info,
+                Err(error) => error.post_info,
+            };
+            post_info.actual_weight.unwrap_or(max_weight)
+        }
+
+        /// Ensure that `delegate` is permitted to perform `action` on behalf of `account`.
+        fn ensure_delegated(
+            account: &T::AccountId,
+            delegate: &T::AccountId,
+            action: DelegatedAction,
+        ) -> DispatchResult {
+            let is_permitted = StakeDelegates::<T>::get(account)
+                .get(delegate)
+                .is_some_and(|permissions| permissions.allows(action));
+            ensure!(is_permitted, Error::<T>::DelegatedActionNotPermitted);
+
+            Ok(())
+        }
+
+        /// Adds the weight of the delegation check to the actual weight of the delegated action.
+        fn with_delegation_check_weight(post_info: PostDispatchInfo) -> PostDispatchInfo {
+            post_info
+                .actual_weight
+                .map(|weight| weight.saturating_add(T::DbWeight::get().reads(1)))
+                .into()
         }
 
-        /// Updates the cleanup marker with the new oldest valid era if possible.
-        ///
-        /// It's possible that the call will be a no-op since we haven't advanced enough periods yet.
-        fn update_cleanup_marker(new_period_number: PeriodNumber) {
-            // 1. Find out the latest expired period; rewards can no longer be claimed for it or any older period.
-            let latest_expired_period = match new_period_number
-                .checked_sub(T::RewardRetentionInPeriods::get().saturating_add(1))
-            {
-                Some(period) if !period.is_zero() => period,
-                // Haven't advanced enough periods to have any expired entries.
-                _ => return,
-            };
+        /// Stake the specified amount of the account's locked funds on a smart contract.
+        fn internal_stake(
+            account: T::AccountId,
+            smart_contract: T::SmartContract,
+            amount: Balance,
+        ) -> DispatchResultWithPostInfo {
+            let protocol_state = ActiveProtocolState::<T>::get();
+            let (stake_amount, bonus_status) =
+                Self::current_subperiod_stake_amount(&protocol_state, amount);
 
-            // 2. Find the oldest valid era for which rewards can still be claimed.
-            //    Technically, this will be `Voting` subperiod era but it doesn't matter.
-            //
-            //    Also, remove the expired `PeriodEnd` entry since it's no longer needed.
-            let oldest_valid_era = match PeriodEnd::<T>::take(latest_expired_period) {
-                Some(period_end_info) => period_end_info.final_era.saturating_add(1),
-                None => {
-                    // Should never happen but nothing we can do if it does.
-                    log::error!(
-                        target: LOG_TARGET,
-                        "No `PeriodEnd` entry for the expired period: {}",
-                        latest_expired_period
-                    );
-                    return;
-                }
-            };
+            // The `inner_stake` function takes a `StakeAmount` struct allowing modification of both `voting` and `build_and_earn` amounts at the same time.
+            let observer_weight =
+                Self::inner_stake(&account, &smart_contract, stake_amount, bonus_status)?;
 
-            // 3. Update the cleanup marker with the new oldest valid era.
-            HistoryCleanupMarker::<T>::mutate(|marker| {
-                marker.oldest_valid_era = oldest_valid_era;
+            Self::deposit_event(Event::<T>::Stake {
+                account,
+                smart_contract,
+                amount,
             });
+
+            Ok(Some(T::WeightInfo::stake().saturating_add(observer_weight)).into())
         }
 
-        /// Attempt to cleanup some expired entries, if enough remaining weight & applicable entries exist.
-        ///
-        /// Returns consumed weight.
-        fn expired_entry_cleanup(remaining_weight: &Weight) -> Weight {
-            // Need to be able to process one full pass
-            if remaining_weight.any_lt(T::WeightInfo::on_idle_cleanup()) {
-                return Weight::zero();
-            }
+        /// Unstake the specified amount from a smart contract, for the account.
+        fn internal_unstake(
+            account: T::AccountId,
+            smart_contract: T::SmartContract,
+            amount: Balance,
+        ) -> DispatchResultWithPostInfo {
+            let (unstake_amount, _, observer_weight) =
+                Self::inner_unstake(&account, &smart_contract, amount)?;
 
-            // Get the cleanup marker and ensure we have pending cleanups.
-            let mut cleanup_marker = HistoryCleanupMarker::<T>::get();
-            if !cleanup_marker.has_pending_cleanups() {
-                return T::DbWeight::get().reads(1);
-            }
+            Self::deposit_event(Event::<T>::Unstake {
+                account,
+                smart_contract,
+                amount: unstake_amount.total(),
+            });
 
-            // 1. Attempt to cleanup one expired `EraRewards` entry.
-            if cleanup_marker.era_reward_index < cleanup_marker.oldest_valid_era {
-                if let Some(era_reward) = EraRewards::<T>::get(cleanup_marker.era_reward_index) {
-                    // If oldest valid era comes AFTER this span, it's safe to delete it.
-                    if era_reward.last_era() < cleanup_marker.oldest_valid_era {
-                        EraRewards::<T>::remove(cleanup_marker.era_reward_index);
-                        cleanup_marker
-                            .era_reward_index
-                            .saturating_accrue(T::EraRewardSpanLength::get());
-                    }
-                } else {
-                    // Can happen if the entry is part of history before dApp staking v3
-                    log::warn!(
-                        target: LOG_TARGET,
-                        "Era rewards span for era {} is missing, but cleanup marker is set.",
-                        cleanup_marker.era_reward_index
-                    );
-                    cleanup_marker
-                        .era_reward_index
-                        .saturating_accrue(T::EraRewardSpanLength::get());
-                }
-            }
+            Ok(Some(T::WeightInfo::unstake().saturating_add(observer_weight)).into())
+        }
 
-            // 2. Attempt to cleanup one expired `DAppTiers` entry.
-            if cleanup_marker.dapp_tiers_index < cleanup_marker.oldest_valid_era {
-                DAppTiers::<T>::remove(cleanup_marker.dapp_tiers_index);
-                cleanup_marker.dapp_tiers_index.saturating_inc();
+        /// Move the account's stake between two smart contracts, preserving the bonus status if eligible.
+        fn internal_move_stake(
+            account: T::AccountId,
+            source_contract: T::SmartContract,
+            destination_contract: T::SmartContract,
+            amount: Balance,
+        ) -> DispatchResultWithPostInfo {
+            ensure!(
+                !source_contract.eq(&destination_contract),
+                Error::<T>::SameContracts
+            );
+
+            ensure!(
+                IntegratedDApps::<T>::contains_key(&destination_contract),
+                Error::<T>::ContractNotFound
+            );
+
+            let maybe_source_dapp_info = IntegratedDApps::<T>::get(&source_contract);
+            let is_source_unregistered = maybe_source_dapp_info.is_none();
+
+            let (mut move_amount, bonus_status, unstake_observer_weight) = if is_source_unregistered
+            {
+                Self::inner_unstake_from_unregistered(&account, &source_contract, None)?
+            } else {
+                Self::inner_unstake(&account, &source_contract, amount)?
+            };
+
+            // When bonus is forfeited, voting stake must be merged into b&e stake
+            if bonus_status == 0 && move_amount.voting > 0 {
+                move_amount.convert_bonus_into_regular_stake();
             }
 
-            // Store the updated cleanup marker
-            HistoryCleanupMarker::<T>::put(cleanup_marker);
+            let stake_observer_weight =
+                Self::inner_stake(&account, &destination_contract, move_amount, bonus_status)?;
 
-            // We could try & cleanup more entries, but since it's not a critical operation and can happen whenever,
-            // we opt for the simpler solution where only 1 entry per block is cleaned up.
-            // It can be changed though.
+            Self::deposit_event(Event::<T>::StakeMoved {
+                account,
+                source_contract,
+                destination_contract,
+                amount: move_amount.total(),
+            });
 
-            // It could end up being less than this weight, but this won't occur often enough to be important.
-            T::WeightInfo::on_idle_cleanup()
+            Ok(Some(
+                if is_source_unregistered {
+                    T::WeightInfo::move_stake_unregistered_source()
+                } else {
+                    T::WeightInfo::move_stake_from_registered_source()
+                }
+                .saturating_add(unstake_observer_weight)
+                .saturating_add(stake_observer_weight),
+            )
+            .into())
         }
 
         /// Internal function that executes the `claim_unlocked` logic for the specified account.
@@ -2295,12 +4786,66 @@ pub mod pallet {
         /// Internal function that executes the `claim_staker_rewards_` logic for the specified account.
         fn internal_claim_staker_rewards_for(account: T::AccountId) -> DispatchResultWithPostInfo {
             let mut ledger = Ledger::<T>::get(&account);
+            let protocol_state = ActiveProtocolState::<T>::get();
+
+            let (rewards, period_end) =
+                Self::calculate_staker_rewards(&mut ledger, &protocol_state)?;
+
+            let reward_sum = rewards.iter().fold(Balance::zero(), |sum, (_, reward)| {
+                sum.saturating_add(*reward)
+            });
+            let rewards_len: u32 = rewards.len().unique_saturated_into();
+
+            let destination = Self::staker_reward_destination(&account);
+            T::StakingRewardHandler::payout_reward(&destination, reward_sum)
+                .map_err(|_| Error::<T>::RewardPayoutFailed)?;
+
+            Self::update_ledger(&account, ledger)?;
+
+            rewards.into_iter().for_each(|(era, reward)| {
+                Self::deposit_event(Event::<T>::Reward {
+                    account: account.clone(),
+                    era,
+                    amount: reward,
+                    destination: destination.clone(),
+                });
+            });
+
+            let observer_weight = T::Observers::on_reward_paid(&destination, reward_sum);
+
+            let compound_weight = if destination == account {
+                Self::maybe_compound_reward(&account, reward_sum)
+            } else {
+                Weight::zero()
+            };
+
+            Ok(Some(
+                if period_end.is_some() {
+                    T::WeightInfo::claim_staker_rewards_past_period(rewards_len)
+                } else {
+                    T::WeightInfo::claim_staker_rewards_ongoing_period(rewards_len)
+                }
+                .saturating_add(compound_weight)
+                .saturating_add(observer_weight),
+            )
+            .into())
+        }
+
+        /// Calculates staker rewards which can be claimed in a single claim call, for the provided ledger.
+        ///
+        /// At most one `EraRewardSpan` is covered, so multiple calls might be required to claim everything.
+        /// The ledger is updated to reflect the claimed state, but nothing is written to storage.
+        ///
+        /// Returns the `(era, reward)` pairs, and the period's final era if the staked period has ended.
+        pub(crate) fn calculate_staker_rewards(
+            ledger: &mut AccountLedgerFor<T>,
+            protocol_state: &ProtocolState,
+        ) -> Result<(Vec<(EraNumber, Balance)>, Option<EraNumber>), Error<T>> {
             let staked_period = ledger
                 .staked_period()
                 .ok_or(Error::<T>::NoClaimableRewards)?;
 
             // Check if the rewards have expired
-            let protocol_state = ActiveProtocolState::<T>::get();
             ensure!(
                 staked_period >= Self::oldest_claimable_period(protocol_state.period_number()),
                 Error::<T>::RewardExpired
@@ -2338,7 +4883,6 @@ pub mod pallet {
 
             // Calculate rewards
             let mut rewards: Vec<_> = Vec::new();
-            let mut reward_sum = Balance::zero();
             for (era, amount) in rewards_iter {
                 let era_reward = era_rewards
                     .get(era)
@@ -2352,40 +4896,61 @@ pub mod pallet {
                     * era_reward.staker_reward_pool;
 
                 rewards.push((era, staker_reward));
-                reward_sum.saturating_accrue(staker_reward);
             }
-            let rewards_len: u32 = rewards.len().unique_saturated_into();
-
-            T::StakingRewardHandler::payout_reward(&account, reward_sum)
-                .map_err(|_| Error::<T>::RewardPayoutFailed)?;
-
-            Self::update_ledger(&account, ledger)?;
-
-            rewards.into_iter().for_each(|(era, reward)| {
-                Self::deposit_event(Event::<T>::Reward {
-                    account: account.clone(),
-                    era,
-                    amount: reward,
-                });
-            });
 
-            Ok(Some(if period_end.is_some() {
-                T::WeightInfo::claim_staker_rewards_past_period(rewards_len)
-            } else {
-                T::WeightInfo::claim_staker_rewards_ongoing_period(rewards_len)
-            })
-            .into())
+            Ok((rewards, period_end))
         }
 
         /// Internal function that executes the `claim_bonus_reward` logic for the specified account & smart contract.
         fn internal_claim_bonus_reward_for(
             account: T::AccountId,
             smart_contract: T::SmartContract,
-        ) -> DispatchResult {
+        ) -> DispatchResultWithPostInfo {
             let staker_info = StakerInfo::<T>::get(&account, &smart_contract)
                 .ok_or(Error::<T>::NoClaimableRewards)?;
             let protocol_state = ActiveProtocolState::<T>::get();
 
+            let bonus_reward = Self::calculate_bonus_reward(&staker_info, &protocol_state)?;
+
+            let destination = Self::staker_reward_destination(&account);
+            T::StakingRewardHandler::payout_reward(&destination, bonus_reward)
+                .map_err(|_| Error::<T>::RewardPayoutFailed)?;
+
+            // Cleanup entry since the reward has been claimed
+            StakerInfo::<T>::remove(&account, &smart_contract);
+            Ledger::<T>::mutate(&account, |ledger| {
+                ledger.contract_stake_count.saturating_dec();
+            });
+
+            Self::deposit_event(Event::<T>::BonusReward {
+                account: account.clone(),
+                smart_contract,
+                period: staker_info.period_number(),
+                amount: bonus_reward,
+                destination: destination.clone(),
+            });
+
+            let observer_weight = T::Observers::on_reward_paid(&destination, bonus_reward);
+
+            let compound_weight = if destination == account {
+                Self::maybe_compound_reward(&account, bonus_reward)
+            } else {
+                Weight::zero()
+            };
+
+            Ok(Some(
+                T::WeightInfo::claim_bonus_reward()
+                    .saturating_add(compound_weight)
+                    .saturating_add(observer_weight),
+            )
+            .into())
+        }
+
+        /// Calculates the bonus reward for the provided staking info entry, if it's eligible for one.
+        pub(crate) fn calculate_bonus_reward(
+            staker_info: &SingularStakingInfo,
+            protocol_state: &ProtocolState,
+        ) -> Result<Balance, Error<T>> {
             // Ensure:
             // 1. Period for which rewards are being claimed has ended.
             // 2. Account has maintained an eligible bonus status.
@@ -2414,27 +4979,37 @@ pub mod pallet {
             );
 
             let eligible_amount = staker_info.staked_amount(Subperiod::Voting);
-            let bonus_reward =
+            Ok(
                 Perbill::from_rational(eligible_amount, period_end_info.total_vp_stake)
-                    * period_end_info.bonus_reward_pool;
+                    * period_end_info.bonus_reward_pool,
+            )
+        }
 
-            T::StakingRewardHandler::payout_reward(&account, bonus_reward)
-                .map_err(|_| Error::<T>::RewardPayoutFailed)?;
+        /// Attempts to 'consume' the dApp reward for the specified era.
+        ///
+        /// Returns the updated `DAppTiers` entry which must be written back to storage in order to prevent double claims,
+        /// the reward amount, and the ranked tier the dApp was assigned to.
+        pub(crate) fn consume_dapp_reward(
+            dapp_id: DAppId,
+            era: EraNumber,
+            protocol_state: &ProtocolState,
+        ) -> Result<(DAppTierRewardsFor<T>, Balance, RankedTier), Error<T>> {
+            // Make sure provided era has ended
+            ensure!(era < protocol_state.era, Error::<T>::InvalidClaimEra);
 
-            // Cleanup entry since the reward has been claimed
-            StakerInfo::<T>::remove(&account, &smart_contract);
-            Ledger::<T>::mutate(&account, |ledger| {
-                ledger.contract_stake_count.saturating_dec();
-            });
+            let mut dapp_tiers = DAppTiers::<T>::get(&era).ok_or(Error::<T>::NoDAppTierInfo)?;
+            ensure!(
+                dapp_tiers.period >= Self::oldest_claimable_period(protocol_state.period_number()),
+                Error::<T>::RewardExpired
+            );
 
-            Self::deposit_event(Event::<T>::BonusReward {
-                account: account.clone(),
-                smart_contract,
-                period: staked_period,
-                amount: bonus_reward,
-            });
+            let (amount, ranked_tier) =
+                dapp_tiers.try_claim(dapp_id).map_err(|error| match error {
+                    DAppTierError::NoDAppInTiers => Error::<T>::NoClaimableRewards,
+                    _ => Error::<T>::InternalClaimDAppError,
+                })?;
 
-            Ok(())
+            Ok((dapp_tiers, amount, ranked_tier))
         }
 
         /// Compute deterministic tier rewards params (base-0 reward and per-rank-step reward)
@@ -2525,6 +5100,7 @@ pub mod pallet {
             Self::try_state_contract_stake()?;
             Self::try_state_era_rewards()?;
             Self::try_state_era_info()?;
+            Self::try_state_lock_receipts()?;
 
             Ok(())
         }
@@ -2808,6 +5384,40 @@ pub mod pallet {
 
             Ok(())
         }
+
+        /// ### Invariants of lock receipts
+        ///
+        /// 1. Lock receipt amount of each account is never greater than the account's active locked amount.
+        /// 2. Lock receipt amount of each account is equal to the account's receipt token balance.
+        /// 3. Sum of all lock receipt amounts is equal to the total issuance of the receipt token.
+        #[cfg(any(feature = "try-runtime", test))]
+        pub fn try_state_lock_receipts() -> Result<(), sp_runtime::TryRuntimeError> {
+            let mut total_receipts = Balance::zero();
+
+            for (account, receipt) in LockReceipts::<T>::iter() {
+                // Invariant 1
+                ensure!(
+                    receipt <= Ledger::<T>::get(&account).active_locked_amount(),
+                    "Lock receipt amount exceeds the active locked amount."
+                );
+
+                // Invariant 2
+                ensure!(
+                    receipt == T::LockReceipt::balance(&account),
+                    "Lock receipt amount differs from the receipt token balance."
+                );
+
+                total_receipts.saturating_accrue(receipt);
+            }
+
+            // Invariant 3
+            ensure!(
+                total_receipts == T::LockReceipt::total_issuance(),
+                "Total lock receipt amount differs from the receipt token total issuance."
+            );
+
+            Ok(())
+        }
     }
 
     /// Implementation of the `SafeModeNotify` trait for the `DappStaking` pallet.
@@ -41,6 +41,16 @@ pub mod versioned_migrations {
         Pallet<T>,
         <T as frame_system::Config>::DbWeight,
     >;
+
+    /// Migration V12 to V13:
+    /// - Migrate `IntegratedDApps` entries to include empty `reward_splits`
+    pub type V12ToV13<T> = frame_support::migrations::VersionedMigration<
+        12,
+        13,
+        v13::VersionMigrateV12ToV13<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
 }
 
 mod v12 {
@@ -156,3 +166,76 @@ mod v12 {
         }
     }
 }
+
+mod v13 {
+    use super::*;
+
+    /// Old `DAppInfo` shape (without `reward_splits` field).
+    #[derive(Encode, Decode, Clone)]
+    pub struct OldDAppInfo<AccountId> {
+        pub owner: AccountId,
+        #[codec(compact)]
+        pub id: DAppId,
+        pub reward_beneficiary: Option<AccountId>,
+    }
+
+    pub struct VersionMigrateV12ToV13<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for VersionMigrateV12ToV13<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut translated: u64 = 0;
+
+            IntegratedDApps::<T>::translate::<OldDAppInfo<T::AccountId>, _>(
+                |_smart_contract, old_dapp_info| {
+                    translated.saturating_inc();
+                    Some(DAppInfo {
+                        owner: old_dapp_info.owner,
+                        id: old_dapp_info.id,
+                        reward_beneficiary: old_dapp_info.reward_beneficiary,
+                        reward_splits: Default::default(),
+                    })
+                },
+            );
+
+            log::info!(
+                target: LOG_TARGET,
+                "Migrated {} IntegratedDApps entries to v13",
+                translated
+            );
+
+            T::DbWeight::get().reads_writes(translated, translated)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+            let count = IntegratedDApps::<T>::count();
+            log::info!(
+                target: LOG_TARGET,
+                "V12ToV13 pre-upgrade: {} IntegratedDApps entries",
+                count
+            );
+            Ok(count.encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(data: Vec<u8>) -> Result<(), TryRuntimeError> {
+            // Verify storage version
+            ensure!(
+                Pallet::<T>::on_chain_storage_version() == StorageVersion::new(13),
+                "Storage version should be 13"
+            );
+
+            let old_count = u32::decode(&mut &data[..])
+                .map_err(|_| "Failed to decode IntegratedDApps count")?;
+
+            // Verify all entries can be decoded with new type
+            let new_count = IntegratedDApps::<T>::iter().count() as u32;
+            ensure!(
+                old_count == new_count && new_count == IntegratedDApps::<T>::count(),
+                "IntegratedDApps count mismatch after migration"
+            );
+
+            Ok(())
+        }
+    }
+}
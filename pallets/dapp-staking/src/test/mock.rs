@@ -26,15 +26,21 @@ use frame_support::{
     construct_runtime, derive_impl,
     migrations::MultiStepMigrator,
     ord_parameter_types, parameter_types,
-    traits::{fungible::Mutate as FunMutate, ConstBool, ConstU128, ConstU32, EitherOfDiverse},
+    traits::{
+        fungible::{Balanced as FunBalanced, Credit, Mutate as FunMutate},
+        ConstBool, ConstU128, ConstU32, EitherOfDiverse, OnUnbalanced,
+    },
     weights::Weight,
 };
 use sp_io::TestExternalities;
 use sp_runtime::{BuildStorage, Permill};
-use sp_std::cell::RefCell;
+use sp_std::{cell::RefCell, collections::btree_map::BTreeMap};
 
 use astar_primitives::{
-    dapp_staking::{Observer as DappStakingObserver, SmartContract},
+    dapp_staking::{
+        LockReceiptHandler, Observer as DappStakingObserver, SmartContract, TierSlotsProvider,
+        FIXED_NUMBER_OF_TIER_SLOTS,
+    },
     Balance, BlockNumber,
 };
 use frame_system::{EnsureRoot, EnsureSignedBy};
@@ -75,6 +81,7 @@ impl pallet_balances::Config for Test {
     type AccountStore = System;
     type FreezeIdentifier = RuntimeFreezeReason;
     type RuntimeFreezeReason = RuntimeFreezeReason;
+    type RuntimeHoldReason = RuntimeHoldReason;
     type MaxFreezes = ConstU32<1>;
 }
 
@@ -95,6 +102,10 @@ thread_local! {
     pub(crate) static DOES_PAYOUT_SUCCEED: RefCell<bool> = RefCell::new(false);
     pub(crate) static BLOCK_BEFORE_NEW_ERA: RefCell<EraNumber> = RefCell::new(0);
     pub(crate) static MAX_BONUS_SAFE_MOVES: RefCell<u8> = RefCell::new(0);
+    pub(crate) static NUMBER_OF_TIER_SLOTS: RefCell<u16> = RefCell::new(FIXED_NUMBER_OF_TIER_SLOTS);
+    pub(crate) static OBSERVER_CALLS: RefCell<Vec<ObserverCall>> = RefCell::new(Vec::new());
+    pub(crate) static IS_LOCK_RECEIPT_AVAILABLE: RefCell<bool> = RefCell::new(false);
+    pub(crate) static LOCK_RECEIPT_BALANCES: RefCell<BTreeMap<AccountId, Balance>> = RefCell::new(BTreeMap::new());
 }
 
 pub struct DummyStakingRewardHandler;
@@ -137,6 +148,10 @@ impl crate::BenchmarkHelper<MockSmartContract, AccountId>
         Balances::write_balance(account, amount)
             .expect("Must succeed in test/benchmark environment.");
     }
+
+    fn create_lock_receipt_asset() {
+        IS_LOCK_RECEIPT_AVAILABLE.with(|v| *v.borrow_mut() = true);
+    }
 }
 
 pub struct DummyCycleConfiguration;
@@ -158,12 +173,77 @@ impl CycleConfiguration for DummyCycleConfiguration {
     }
 }
 
+/// Observer callback, as recorded by the `DummyDappStakingObserver`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) enum ObserverCall {
+    NewPeriod(PeriodNumber),
+    Stake(AccountId, MockSmartContract, Balance),
+    Unstake(AccountId, MockSmartContract, Balance),
+    RewardPaid(AccountId, Balance),
+    DAppUnregistered(MockSmartContract),
+}
+
+/// Weight returned by each of the `DummyDappStakingObserver` callbacks, except `block_before_new_era`.
+pub(crate) const OBSERVER_CALL_WEIGHT: Weight = Weight::from_parts(3, 4);
+
 pub struct DummyDappStakingObserver;
-impl DappStakingObserver for DummyDappStakingObserver {
+impl DummyDappStakingObserver {
+    fn record(call: ObserverCall) -> Weight {
+        OBSERVER_CALLS.with(|v| v.borrow_mut().push(call));
+        OBSERVER_CALL_WEIGHT
+    }
+}
+
+impl DappStakingObserver<AccountId, MockSmartContract> for DummyDappStakingObserver {
+    fn max_weight() -> Weight {
+        OBSERVER_CALL_WEIGHT
+    }
+
     fn block_before_new_era(next_era: EraNumber) -> Weight {
         BLOCK_BEFORE_NEW_ERA.with(|v| *v.borrow_mut() = next_era);
         Weight::from_parts(1, 2)
     }
+
+    fn on_new_period(period: PeriodNumber) -> Weight {
+        Self::record(ObserverCall::NewPeriod(period))
+    }
+
+    fn on_stake(
+        account: &AccountId,
+        smart_contract: &MockSmartContract,
+        amount: Balance,
+    ) -> Weight {
+        Self::record(ObserverCall::Stake(
+            *account,
+            smart_contract.clone(),
+            amount,
+        ))
+    }
+
+    fn on_unstake(
+        account: &AccountId,
+        smart_contract: &MockSmartContract,
+        amount: Balance,
+    ) -> Weight {
+        Self::record(ObserverCall::Unstake(
+            *account,
+            smart_contract.clone(),
+            amount,
+        ))
+    }
+
+    fn on_reward_paid(beneficiary: &AccountId, amount: Balance) -> Weight {
+        Self::record(ObserverCall::RewardPaid(*beneficiary, amount))
+    }
+
+    fn on_dapp_unregistered(smart_contract: &MockSmartContract) -> Weight {
+        Self::record(ObserverCall::DAppUnregistered(smart_contract.clone()))
+    }
+}
+
+/// Returns all observer calls recorded so far, and clears the record.
+pub(crate) fn take_observer_calls() -> Vec<ObserverCall> {
+    OBSERVER_CALLS.with(|v| v.take())
 }
 
 pub(crate) const BLACKLISTED_ACCOUNT: AccountId = 789456123;
@@ -181,6 +261,13 @@ impl Get<u8> for DynamicMaxBonusSafeMovesPerPeriod {
     }
 }
 
+pub struct DummyTierSlotsProvider;
+impl TierSlotsProvider for DummyTierSlotsProvider {
+    fn number_of_slots(_number_of_integrated_dapps: u32) -> u16 {
+        NUMBER_OF_TIER_SLOTS.with(|v| *v.borrow())
+    }
+}
+
 ord_parameter_types! {
     pub const ContractRegisterAccount: AccountId = 1337;
     pub const ContractUnregisterAccount: AccountId = 1779;
@@ -190,6 +277,7 @@ ord_parameter_types! {
 impl pallet_dapp_staking::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type RuntimeFreezeReason = RuntimeFreezeReason;
+    type RuntimeHoldReason = RuntimeHoldReason;
     type Currency = Balances;
     type SmartContract = MockSmartContract;
     type ContractRegisterOrigin =
@@ -198,6 +286,10 @@ impl pallet_dapp_staking::Config for Test {
         EnsureRoot<AccountId>,
         EnsureSignedBy<ContractUnregisterAccount, AccountId>,
     >;
+    type DAppMetadataDepositPerByte = ConstU128<1>;
+    type MaxDAppMetadataLength = ConstU32<32>;
+    type InstantUnlockPenaltyHandler = DummyInstantUnlockPenaltyHandler;
+    type LockReceipt = DummyLockReceipt;
     type ManagerOrigin =
         EitherOfDiverse<EnsureRoot<AccountId>, EnsureSignedBy<ManagerAccount, AccountId>>;
     type StakingRewardHandler = DummyStakingRewardHandler;
@@ -206,15 +298,24 @@ impl pallet_dapp_staking::Config for Test {
     type AccountCheck = DummyAccountCheck;
     type EraRewardSpanLength = ConstU32<8>;
     type RewardRetentionInPeriods = ConstU32<2>;
-    type MaxNumberOfContracts = ConstU32<10>;
+    type MaxNumberOfContracts = ConstU32<100>;
+    type MaxNumberOfTierSlots = ConstU32<{ FIXED_NUMBER_OF_TIER_SLOTS as u32 }>;
+    type TierAssignmentPageSize = ConstU32<4>;
+    type TierHistoryLength = ConstU32<3>;
     type MaxUnlockingChunks = ConstU32<5>;
     type MinimumLockedAmount = ConstU128<MINIMUM_LOCK_AMOUNT>;
     type UnlockingPeriod = ConstU32<2>;
     type MaxNumberOfStakedContracts = ConstU32<5>;
+    type MaxNumberOfDelegates = ConstU32<3>;
     type MinimumStakeAmount = ConstU128<3>;
     type NumberOfTiers = ConstU32<4>;
+    type TierSlotsProvider = DummyTierSlotsProvider;
     type RankingEnabled = ConstBool<true>;
     type MaxBonusSafeMovesPerPeriod = DynamicMaxBonusSafeMovesPerPeriod;
+    type MaxNumberOfRewardSplits = ConstU32<3>;
+    type MaxNumberOfDAppRewardClaimEras = ConstU32<8>;
+    type AutoClaimScanLimit = ConstU32<4>;
+    type UnregisteredStakeGracePeriod = ConstU32<2>;
     type WeightInfo = weights::SubstrateWeight<Test>;
     #[cfg(feature = "runtime-benchmarks")]
     type BenchmarkHelper = BenchmarkHelper<MockSmartContract, AccountId>;
@@ -232,6 +333,10 @@ impl ExtBuilder {
     pub fn build(self) -> TestExternalities {
         // Normal behavior is for reward payout to succeed
         DOES_PAYOUT_SUCCEED.with(|v| *v.borrow_mut() = true);
+        NUMBER_OF_TIER_SLOTS.with(|v| *v.borrow_mut() = FIXED_NUMBER_OF_TIER_SLOTS);
+        OBSERVER_CALLS.with(|v| v.borrow_mut().clear());
+        IS_LOCK_RECEIPT_AVAILABLE.with(|v| *v.borrow_mut() = true);
+        LOCK_RECEIPT_BALANCES.with(|v| v.borrow_mut().clear());
 
         let mut storage = frame_system::GenesisConfig::<Test>::default()
             .build_storage()
@@ -324,7 +429,7 @@ impl ExtBuilder {
             let tier_thresholds = tier_params
                 .tier_thresholds
                 .iter()
-                .map(|t| t.threshold(total_issuance))
+                .map(|t| t.threshold(total_issuance, 0))
                 .collect::<Vec<Balance>>()
                 .try_into()
                 .expect("Invalid number of tier thresholds provided.");
@@ -335,7 +440,7 @@ impl ExtBuilder {
                 reward_portion: tier_params.reward_portion.clone(),
                 tier_thresholds,
             }
-            .calculate_new(&tier_params, total_issuance);
+            .calculate_new(&tier_params, FIXED_NUMBER_OF_TIER_SLOTS, total_issuance, 0);
 
             pallet_dapp_staking::StaticTierParams::<Test>::put(tier_params);
             pallet_dapp_staking::TierConfig::<Test>::put(init_tier_config.clone());
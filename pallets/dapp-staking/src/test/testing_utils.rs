@@ -20,16 +20,20 @@ use crate::test::mock::*;
 use crate::types::*;
 use crate::{
     pallet::Config, ActiveProtocolState, ContractStake, CurrentEraInfo, DAppId, DAppTiers,
-    EraRewards, Event, FreezeReason, HistoryCleanupMarker, IntegratedDApps, Ledger, NextDAppId,
-    PeriodEnd, PeriodEndInfo, StakerInfo,
+    EraRewards, Event, FreezeReason, HistoryCleanupMarker, HoldReason, InstantUnlockPenalty,
+    IntegratedDApps, Ledger, LockReceipts, NextDAppId, PeriodEnd, PeriodEndInfo, StakerInfo,
+    StakerRewardDestination,
 };
 
 use frame_support::{
     assert_ok,
-    traits::{fungible::InspectFreeze, Currency, Get, OnIdle},
+    traits::{
+        fungible::{InspectFreeze, InspectHold},
+        Currency, Get, OnIdle,
+    },
     weights::Weight,
 };
-use sp_runtime::{traits::Zero, Perbill};
+use sp_runtime::{traits::Zero, BoundedVec, Perbill, Permill};
 use std::collections::HashMap;
 
 use astar_primitives::{
@@ -44,10 +48,7 @@ pub(crate) struct MemorySnapshot {
     active_protocol_state: ProtocolState,
     next_dapp_id: DAppId,
     current_era_info: EraInfo,
-    integrated_dapps: HashMap<
-        <Test as Config>::SmartContract,
-        DAppInfo<<Test as frame_system::Config>::AccountId>,
-    >,
+    integrated_dapps: HashMap<<Test as Config>::SmartContract, DAppInfoFor<Test>>,
     ledger: HashMap<<Test as frame_system::Config>::AccountId, AccountLedgerFor<Test>>,
     staker_info: HashMap<
         (
@@ -151,6 +152,34 @@ pub(crate) fn assert_set_dapp_reward_beneficiary(
     );
 }
 
+/// Update dApp reward splits and assert success.
+pub(crate) fn assert_set_dapp_reward_splits(
+    owner: AccountId,
+    smart_contract: &MockSmartContract,
+    splits: Vec<(AccountId, Permill)>,
+) {
+    let splits = BoundedVec::try_from(splits).expect("Must not exceed the limit.");
+
+    // Change reward splits
+    assert_ok!(DappStaking::set_dapp_reward_splits(
+        RuntimeOrigin::signed(owner),
+        smart_contract.clone(),
+        splits.clone(),
+    ));
+    System::assert_last_event(RuntimeEvent::DappStaking(Event::DAppRewardSplitsUpdated {
+        smart_contract: smart_contract.clone(),
+        splits: splits.clone(),
+    }));
+
+    // Sanity check & reward splits update
+    assert_eq!(
+        IntegratedDApps::<Test>::get(&smart_contract)
+            .unwrap()
+            .reward_splits,
+        splits
+    );
+}
+
 /// Update dApp owner and assert success.
 /// if `caller` is `None`, `Root` origin is used, otherwise standard `Signed` origin is used.
 pub(crate) fn assert_set_dapp_owner(
@@ -178,6 +207,11 @@ pub(crate) fn assert_set_dapp_owner(
     );
 }
 
+/// Returns the amount held from the account as dApp metadata deposit.
+pub(crate) fn held_metadata_deposit(account: AccountId) -> Balance {
+    Balances::balance_on_hold(&HoldReason::DAppMetadata.into(), &account)
+}
+
 /// Update dApp status to unregistered and assert success.
 pub(crate) fn assert_unregister(smart_contract: &MockSmartContract) {
     let pre_snapshot = MemorySnapshot::new();
@@ -399,6 +433,116 @@ pub(crate) fn assert_claim_unlocked(account: AccountId) {
     }
 }
 
+/// Instantly unlock funds, paying the penalty, and assert success.
+pub(crate) fn assert_instant_unlock(account: AccountId, amount: Balance) {
+    let pre_snapshot = MemorySnapshot::new();
+    let pre_ledger = pre_snapshot
+        .ledger
+        .get(&account)
+        .cloned()
+        .unwrap_or_default();
+    let pre_total_balance = Balances::total_balance(&account);
+    let pre_pot_balance = Balances::total_balance(&INSTANT_UNLOCK_PENALTY_POT);
+    let penalty_ratio = InstantUnlockPenalty::<Test>::get().expect("Penalty must be set.");
+
+    // Calculate expected unlock amounts
+    let current_block = System::block_number();
+    let pending_unlocking_amount = pre_ledger
+        .unlocking
+        .iter()
+        .filter(|chunk| chunk.unlock_block > current_block)
+        .fold(0, |sum, chunk| sum + chunk.amount);
+    let expected_unlocking_amount = pending_unlocking_amount.min(amount);
+
+    let remaining_request = amount - expected_unlocking_amount;
+    let expected_locked_amount = if remaining_request.is_zero() {
+        0
+    } else {
+        let possible_unlock_amount = pre_ledger
+            .unlockable_amount(pre_snapshot.active_protocol_state.period_number())
+            .min(remaining_request);
+
+        // When unlocking would take account below the minimum lock threshold, unlock everything
+        let locked_amount = pre_ledger.active_locked_amount();
+        let min_locked_amount = <Test as Config>::MinimumLockedAmount::get();
+        if locked_amount.saturating_sub(possible_unlock_amount) < min_locked_amount {
+            locked_amount
+        } else {
+            possible_unlock_amount
+        }
+    };
+    let expected_amount = expected_unlocking_amount + expected_locked_amount;
+    let expected_penalty = penalty_ratio * expected_amount;
+
+    // Instantly unlock funds
+    assert_ok!(DappStaking::instant_unlock(
+        RuntimeOrigin::signed(account),
+        amount
+    ));
+    System::assert_last_event(RuntimeEvent::DappStaking(Event::InstantUnlock {
+        account,
+        amount: expected_amount,
+        penalty: expected_penalty,
+    }));
+
+    // Verify post-state
+    let post_snapshot = MemorySnapshot::new();
+    let post_ledger = post_snapshot
+        .ledger
+        .get(&account)
+        .cloned()
+        .unwrap_or_default();
+
+    assert_eq!(
+        pre_ledger.active_locked_amount(),
+        post_ledger.active_locked_amount() + expected_locked_amount,
+        "Active locked amount should be decreased by the amount unlocked from it."
+    );
+    assert_eq!(
+        pre_ledger.unlocking_amount(),
+        post_ledger.unlocking_amount() + expected_unlocking_amount,
+        "Unlocking amount should be decreased by the amount unlocked from the chunks."
+    );
+    assert_eq!(
+        Balances::balance_frozen(&FreezeReason::DAppStaking.into(), &account),
+        post_ledger.total_locked_amount(),
+        "Frozen balance must be reduced by the instantly unlocked amount."
+    );
+
+    // Verify era info post-state
+    let pre_era_info = &pre_snapshot.current_era_info;
+    let post_era_info = &post_snapshot.current_era_info;
+    assert_eq!(
+        pre_era_info.unlocking - expected_unlocking_amount,
+        post_era_info.unlocking
+    );
+    assert_eq!(
+        pre_era_info.total_locked - expected_locked_amount,
+        post_era_info.total_locked
+    );
+
+    // Verify penalty has been paid
+    assert_eq!(
+        Balances::total_balance(&account),
+        pre_total_balance - expected_penalty
+    );
+    assert_eq!(
+        Balances::total_balance(&INSTANT_UNLOCK_PENALTY_POT),
+        pre_pot_balance + expected_penalty
+    );
+
+    // In case of full withdrawal from the protocol
+    if post_ledger.is_empty() {
+        assert!(!Ledger::<Test>::contains_key(&account));
+        assert!(
+            StakerInfo::<Test>::iter_prefix_values(&account)
+                .count()
+                .is_zero(),
+            "All stake entries need to be cleaned up."
+        );
+    }
+}
+
 /// Claims the unlocked funds back into free balance of the user and assert success.
 pub(crate) fn assert_relock_unlocking(account: AccountId) {
     let pre_snapshot = MemorySnapshot::new();
@@ -439,6 +583,63 @@ pub(crate) fn assert_relock_unlocking(account: AccountId) {
     );
 }
 
+/// Transfer locked funds to another account, together with the lock receipts covering them, and assert success.
+pub(crate) fn assert_transfer_lock(from: AccountId, to: AccountId, amount: Balance) {
+    let pre_snapshot = MemorySnapshot::new();
+    let pre_from_locked = pre_snapshot.locked_balance(&from);
+    let pre_to_locked = pre_snapshot.locked_balance(&to);
+    let pre_from_balance = Balances::total_balance(&from);
+    let pre_to_balance = Balances::total_balance(&to);
+    let pre_from_receipt = LockReceipts::<Test>::get(&from);
+    let pre_to_receipt = LockReceipts::<Test>::get(&to);
+
+    let expected_receipt = pre_from_receipt.min(amount);
+
+    // Transfer the lock
+    assert_ok!(DappStaking::transfer_lock(
+        RuntimeOrigin::signed(from),
+        to,
+        amount
+    ));
+    System::assert_last_event(RuntimeEvent::DappStaking(Event::LockTransferred {
+        from,
+        to,
+        amount,
+        receipt: expected_receipt,
+    }));
+
+    // Verify post-state
+    let post_snapshot = MemorySnapshot::new();
+
+    assert_eq!(
+        post_snapshot.locked_balance(&from),
+        pre_from_locked - amount
+    );
+    assert_eq!(post_snapshot.locked_balance(&to), pre_to_locked + amount);
+    assert_eq!(Balances::total_balance(&from), pre_from_balance - amount);
+    assert_eq!(Balances::total_balance(&to), pre_to_balance + amount);
+    assert_eq!(
+        Balances::balance_frozen(&FreezeReason::DAppStaking.into(), &to),
+        post_snapshot.locked_balance(&to)
+    );
+
+    // Lock receipts
+    assert_eq!(
+        LockReceipts::<Test>::get(&from),
+        pre_from_receipt - expected_receipt
+    );
+    assert_eq!(
+        LockReceipts::<Test>::get(&to),
+        pre_to_receipt + expected_receipt
+    );
+
+    // Total locked amount remains the same
+    assert_eq!(
+        post_snapshot.current_era_info, pre_snapshot.current_era_info,
+        "Era info must remain unchanged."
+    );
+}
+
 /// Stake some funds on the specified smart contract.
 pub(crate) fn assert_stake(
     account: AccountId,
@@ -979,7 +1180,8 @@ pub(crate) fn assert_claim_staker_rewards(account: AccountId) {
     let pre_snapshot = MemorySnapshot::new();
     let pre_ledger = pre_snapshot.ledger.get(&account).unwrap();
     let pre_total_issuance = <Test as Config>::Currency::total_issuance();
-    let pre_free_balance = <Test as Config>::Currency::free_balance(&account);
+    let destination = StakerRewardDestination::<Test>::get(&account).unwrap_or(account);
+    let pre_free_balance = <Test as Config>::Currency::free_balance(&destination);
 
     // Get the first eligible era for claiming rewards
     let first_claim_era = pre_ledger
@@ -1068,6 +1270,7 @@ pub(crate) fn assert_claim_staker_rewards(account: AccountId) {
                 account,
                 era: *era,
                 amount: *reward,
+                destination,
             }
         );
     }
@@ -1081,11 +1284,11 @@ pub(crate) fn assert_claim_staker_rewards(account: AccountId) {
         "Total issuance must increase by the total reward amount."
     );
 
-    let post_free_balance = <Test as Config>::Currency::free_balance(&account);
+    let post_free_balance = <Test as Config>::Currency::free_balance(&destination);
     assert_eq!(
         post_free_balance,
         pre_free_balance + total_reward,
-        "Free balance must increase by the total reward amount."
+        "Free balance of the destination must increase by the total reward amount."
     );
 
     let post_snapshot = MemorySnapshot::new();
@@ -1108,7 +1311,8 @@ pub(crate) fn assert_claim_bonus_reward(account: AccountId, smart_contract: &Moc
         .get(&(account, *smart_contract))
         .unwrap();
     let pre_total_issuance = <Test as Config>::Currency::total_issuance();
-    let pre_free_balance = <Test as Config>::Currency::free_balance(&account);
+    let destination = StakerRewardDestination::<Test>::get(&account).unwrap_or(account);
+    let pre_free_balance = <Test as Config>::Currency::free_balance(&destination);
 
     let staked_period = pre_staker_info.period_number();
     let stake_amount = pre_staker_info.staked_amount(Subperiod::Voting);
@@ -1131,6 +1335,7 @@ pub(crate) fn assert_claim_bonus_reward(account: AccountId, smart_contract: &Moc
         smart_contract: *smart_contract,
         period: staked_period,
         amount: reward,
+        destination,
     }));
 
     // Verify post state
@@ -1142,11 +1347,11 @@ pub(crate) fn assert_claim_bonus_reward(account: AccountId, smart_contract: &Moc
         "Total issuance must increase by the reward amount."
     );
 
-    let post_free_balance = <Test as Config>::Currency::free_balance(&account);
+    let post_free_balance = <Test as Config>::Currency::free_balance(&destination);
     assert_eq!(
         post_free_balance,
         pre_free_balance + reward,
-        "Free balance must increase by the reward amount."
+        "Free balance of the destination must increase by the reward amount."
     );
 
     assert!(
@@ -1168,9 +1373,7 @@ pub(crate) fn assert_claim_dapp_reward(
 ) {
     let pre_snapshot = MemorySnapshot::new();
     let dapp_info = pre_snapshot.integrated_dapps.get(smart_contract).unwrap();
-    let beneficiary = dapp_info.reward_beneficiary();
     let pre_total_issuance = <Test as Config>::Currency::total_issuance();
-    let pre_free_balance = <Test as Config>::Currency::free_balance(beneficiary);
 
     let pre_reward_info = pre_snapshot
         .dapp_tiers
@@ -1181,21 +1384,34 @@ pub(crate) fn assert_claim_dapp_reward(
         let mut info = pre_reward_info.clone();
         info.try_claim(dapp_info.id).unwrap()
     };
+    let expected_payouts = dapp_info.reward_payouts(expected_reward);
+    let pre_free_balances: Vec<Balance> = expected_payouts
+        .iter()
+        .map(|(beneficiary, _)| <Test as Config>::Currency::free_balance(beneficiary))
+        .collect();
 
-    // Claim dApp reward & verify event
+    // Claim dApp reward & verify events, one for each beneficiary
     assert_ok!(DappStaking::claim_dapp_reward(
         RuntimeOrigin::signed(account),
         smart_contract.clone(),
         era,
     ));
-    System::assert_last_event(RuntimeEvent::DappStaking(Event::DAppReward {
-        beneficiary: beneficiary.clone(),
-        smart_contract: smart_contract.clone(),
-        tier_id: expected_ranked_tier.tier(),
-        rank: expected_ranked_tier.rank(),
-        era,
-        amount: expected_reward,
-    }));
+    let expected_events: Vec<_> = expected_payouts
+        .iter()
+        .map(|(beneficiary, amount)| Event::DAppReward {
+            beneficiary: *beneficiary,
+            smart_contract: smart_contract.clone(),
+            tier_id: expected_ranked_tier.tier(),
+            rank: expected_ranked_tier.rank(),
+            era,
+            amount: *amount,
+        })
+        .collect();
+    let events = dapp_staking_events();
+    assert_eq!(
+        events[events.len() - expected_events.len()..],
+        expected_events[..]
+    );
 
     // Verify post-state
 
@@ -1206,12 +1422,14 @@ pub(crate) fn assert_claim_dapp_reward(
         "Total issuance must increase by the reward amount."
     );
 
-    let post_free_balance = <Test as Config>::Currency::free_balance(beneficiary);
-    assert_eq!(
-        post_free_balance,
-        pre_free_balance + expected_reward,
-        "Free balance must increase by the reward amount."
-    );
+    for ((beneficiary, amount), pre_free_balance) in expected_payouts.iter().zip(pre_free_balances)
+    {
+        assert_eq!(
+            <Test as Config>::Currency::free_balance(beneficiary),
+            pre_free_balance + amount,
+            "Free balance must increase by the reward amount."
+        );
+    }
 
     let post_snapshot = MemorySnapshot::new();
     let mut post_reward_info = post_snapshot
@@ -1231,10 +1449,116 @@ pub(crate) fn assert_claim_dapp_reward(
     );
 }
 
-/// Unstake some funds from the specified unregistered smart contract.
+/// Claim dApp rewards for the specified era range, and assert success.
+/// Eras without a claimable reward are expected to be skipped.
+pub(crate) fn assert_claim_dapp_rewards(
+    account: AccountId,
+    smart_contract: &MockSmartContract,
+    first_era: EraNumber,
+    last_era: EraNumber,
+) {
+    let pre_snapshot = MemorySnapshot::new();
+    let dapp_info = pre_snapshot.integrated_dapps.get(smart_contract).unwrap();
+    let pre_total_issuance = <Test as Config>::Currency::total_issuance();
+
+    // Calculate expected per-era payouts & events, and aggregate payouts per beneficiary
+    let mut expected_events = Vec::new();
+    let mut expected_payouts: HashMap<AccountId, Balance> = HashMap::new();
+    let mut expected_total_reward = Balance::zero();
+    let mut claimed_eras = Vec::new();
+    for era in first_era..=last_era {
+        let Some(mut reward_info) = pre_snapshot.dapp_tiers.get(&era).cloned() else {
+            continue;
+        };
+        let Ok((reward, ranked_tier)) = reward_info.try_claim(dapp_info.id) else {
+            continue;
+        };
+
+        claimed_eras.push(era);
+        expected_total_reward += reward;
+        for (beneficiary, amount) in dapp_info.reward_payouts(reward) {
+            *expected_payouts.entry(beneficiary).or_default() += amount;
+            expected_events.push(Event::DAppReward {
+                beneficiary,
+                smart_contract: smart_contract.clone(),
+                tier_id: ranked_tier.tier(),
+                rank: ranked_tier.rank(),
+                era,
+                amount,
+            });
+        }
+    }
+    assert!(!claimed_eras.is_empty(), "Sanity check.");
+
+    let pre_free_balances: HashMap<AccountId, Balance> = expected_payouts
+        .keys()
+        .map(|beneficiary| {
+            (
+                *beneficiary,
+                <Test as Config>::Currency::free_balance(beneficiary),
+            )
+        })
+        .collect();
+
+    // Claim dApp rewards & verify events, one for each era & beneficiary
+    assert_ok!(DappStaking::claim_dapp_rewards(
+        RuntimeOrigin::signed(account),
+        smart_contract.clone(),
+        first_era,
+        last_era,
+    ));
+    let events = dapp_staking_events();
+    assert_eq!(
+        events[events.len() - expected_events.len()..],
+        expected_events[..]
+    );
+
+    // Verify post-state
+    assert_eq!(
+        <Test as Config>::Currency::total_issuance(),
+        pre_total_issuance + expected_total_reward,
+        "Total issuance must increase by the total reward amount."
+    );
+
+    for (beneficiary, amount) in expected_payouts.iter() {
+        assert_eq!(
+            <Test as Config>::Currency::free_balance(beneficiary),
+            pre_free_balances[beneficiary] + amount,
+            "Free balance must increase by the aggregated reward amount."
+        );
+    }
+
+    for era in claimed_eras {
+        let mut post_reward_info = DAppTiers::<Test>::get(&era).expect("Entry must exist.");
+        assert_eq!(
+            post_reward_info.try_claim(dapp_info.id),
+            Err(DAppTierError::NoDAppInTiers),
+            "It must not be possible to claim the same reward twice!.",
+        );
+    }
+}
+
+/// Unstake all funds from the specified unregistered smart contract.
 pub(crate) fn assert_unstake_from_unregistered(
     account: AccountId,
     smart_contract: &MockSmartContract,
+) {
+    assert_inner_unstake_from_unregistered(account, smart_contract, None);
+}
+
+/// Unstake some funds from the specified unregistered smart contract.
+pub(crate) fn assert_partial_unstake_from_unregistered(
+    account: AccountId,
+    smart_contract: &MockSmartContract,
+    amount: Balance,
+) {
+    assert_inner_unstake_from_unregistered(account, smart_contract, Some(amount));
+}
+
+fn assert_inner_unstake_from_unregistered(
+    account: AccountId,
+    smart_contract: &MockSmartContract,
+    maybe_amount: Option<Balance>,
 ) {
     let pre_snapshot = MemorySnapshot::new();
     let pre_ledger = pre_snapshot.ledger.get(&account).unwrap();
@@ -1244,13 +1568,27 @@ pub(crate) fn assert_unstake_from_unregistered(
         .expect("Entry must exist since 'unstake_from_unregistered' is being called.");
     let pre_era_info = pre_snapshot.current_era_info;
 
-    let amount = pre_staker_info.total_staked_amount();
+    let total_amount = pre_staker_info.total_staked_amount();
+    let amount = match maybe_amount {
+        Some(amount) if total_amount - amount >= <Test as Config>::MinimumStakeAmount::get() => {
+            amount
+        }
+        _ => total_amount,
+    };
+    let is_full_unstake = amount == total_amount;
 
     // Unstake from smart contract & verify event
-    assert_ok!(DappStaking::unstake_from_unregistered(
-        RuntimeOrigin::signed(account),
-        smart_contract.clone(),
-    ));
+    match maybe_amount {
+        Some(amount) => assert_ok!(DappStaking::partial_unstake_from_unregistered(
+            RuntimeOrigin::signed(account),
+            smart_contract.clone(),
+            amount,
+        )),
+        None => assert_ok!(DappStaking::unstake_from_unregistered(
+            RuntimeOrigin::signed(account),
+            smart_contract.clone(),
+        )),
+    }
     System::assert_last_event(RuntimeEvent::DappStaking(Event::UnstakeFromUnregistered {
         account,
         smart_contract: smart_contract.clone(),
@@ -1278,15 +1616,31 @@ pub(crate) fn assert_unstake_from_unregistered(
         "Stakeable amount must increase by the 'amount'"
     );
 
-    assert_ledger_contract_stake_count(pre_ledger, post_ledger, true, false);
+    assert_ledger_contract_stake_count(pre_ledger, post_ledger, is_full_unstake, false);
 
     // 2. verify staker info
     // =====================
     // =====================
-    assert!(
-        !StakerInfo::<Test>::contains_key(&account, smart_contract),
-        "Entry must be deleted since contract is unregistered."
-    );
+    if is_full_unstake {
+        assert!(
+            !StakerInfo::<Test>::contains_key(&account, smart_contract),
+            "Entry must be deleted since everything was unstaked."
+        );
+    } else {
+        let post_staker_info = post_snapshot
+            .staker_info
+            .get(&(account, smart_contract.clone()))
+            .expect("Entry must exist since only part of the stake was unstaked.");
+        assert_eq!(
+            post_staker_info.total_staked_amount(),
+            total_amount - amount,
+            "Total staked amount must decrease by the 'amount'"
+        );
+        assert_eq!(
+            post_staker_info.bonus_status, pre_staker_info.bonus_status,
+            "Bonus status must be preserved."
+        );
+    }
 
     // 3. verify era info
     // =========================
@@ -18,22 +18,29 @@
 
 use crate::test::{mock::*, testing_utils::*};
 use crate::{
-    pallet::Config, ActiveProtocolState, BonusStatusWrapperFor, ContractStake, CurrentEraInfo,
-    DAppId, DAppTierRewardsFor, DAppTiers, EraReward, EraRewards, Error, Event, ForcingType,
-    GenesisConfig, IntegratedDApps, Ledger, NextDAppId, Perbill, PeriodNumber, Permill, Safeguard,
-    SingularStakingInfo, StakeAmount, StakerInfo, StaticTierParams, Subperiod, TierConfig,
-    TierParameters, TierThreshold,
+    pallet::Config, weights::WeightInfo, ActiveProtocolState, AutoClaim, AutoClaimCursor,
+    AutoCompound, BonusStatusWrapperFor, BoundedDAppMetadata, Call, CarryOverPlans, ContractStake,
+    CurrentEraInfo, DAppId, DAppMetadataFor, DAppMetadataOf, DAppTierHistory, DAppTierRewardsFor,
+    DAppTiers, DelegatePermissions, EraReward, EraRewards, Error, Event, ForcingType,
+    GenesisConfig, InstantUnlockPenalty, IntegratedDApps, Ledger, LockReceipts,
+    LockReceiptsEnabled, NextDAppId, NumberOfTierSlots, Perbill, PeriodNumber, Permill, Safeguard,
+    ScheduledTierParams, SingularStakingInfo, StakeAmount, StakeDelegates, StakerInfo,
+    StakerRewardDestination, StaticTierParams, Subperiod, TierAssignmentState, TierConfig,
+    TierParameters, TierThreshold, UnregisteredStakeSweepCursor, UnregisteredStakeSweeps,
 };
 
 use frame_support::{
     assert_noop, assert_ok, assert_storage_noop,
+    dispatch::GetDispatchInfo,
     error::BadOrigin,
     traits::{
-        fungible::Unbalanced as FunUnbalanced, Currency, Get, OnFinalize, OnInitialize,
+        fungible::Unbalanced as FunUnbalanced, Currency, Get, OnFinalize, OnIdle, OnInitialize,
         ReservableCurrency, SafeModeNotify,
     },
+    weights::Weight,
     BoundedVec,
 };
+use parity_scale_codec::Encode;
 use sp_runtime::{
     traits::{ConstU32, Zero},
     BoundedBTreeMap,
@@ -41,8 +48,8 @@ use sp_runtime::{
 
 use astar_primitives::{
     dapp_staking::{
-        CycleConfiguration, EraNumber, RankedTier, SmartContractHandle, StakingRewardHandler,
-        FIXED_NUMBER_OF_TIER_SLOTS,
+        CycleConfiguration, DAppCategory, DAppTierHistoryEntry, EraNumber, LockReceiptHandler,
+        RankedTier, SmartContractHandle, StakingRewardHandler, FIXED_NUMBER_OF_TIER_SLOTS,
     },
     Balance, BlockNumber,
 };
@@ -121,6 +128,96 @@ fn maintenance_mode_call_filtering_works() {
             DappStaking::relock_unlocking(RuntimeOrigin::signed(1)),
             Error::<Test>::Disabled
         );
+        assert_noop!(
+            DappStaking::instant_unlock(RuntimeOrigin::signed(1), 100),
+            Error::<Test>::Disabled
+        );
+        assert_noop!(
+            DappStaking::set_staker_reward_destination(RuntimeOrigin::signed(1), Some(2)),
+            Error::<Test>::Disabled
+        );
+        assert_noop!(
+            DappStaking::set_delegate(RuntimeOrigin::signed(1), 2, DelegatePermissions::all()),
+            Error::<Test>::Disabled
+        );
+        assert_noop!(
+            DappStaking::revoke_delegate(RuntimeOrigin::signed(1), 2),
+            Error::<Test>::Disabled
+        );
+        assert_noop!(
+            DappStaking::set_carry_over_plan(
+                RuntimeOrigin::signed(1),
+                BoundedVec::try_from(vec![(MockSmartContract::wasm(1 as AccountId), 100)]).unwrap()
+            ),
+            Error::<Test>::Disabled
+        );
+        assert_noop!(
+            DappStaking::remove_carry_over_plan(RuntimeOrigin::signed(1)),
+            Error::<Test>::Disabled
+        );
+        assert_noop!(
+            DappStaking::set_dapp_metadata(
+                RuntimeOrigin::signed(1),
+                MockSmartContract::wasm(1 as AccountId),
+                Default::default()
+            ),
+            Error::<Test>::Disabled
+        );
+        assert_noop!(
+            DappStaking::clear_dapp_metadata(
+                RuntimeOrigin::signed(1),
+                MockSmartContract::wasm(1 as AccountId)
+            ),
+            Error::<Test>::Disabled
+        );
+        assert_noop!(
+            DappStaking::schedule_static_tier_params(
+                RuntimeOrigin::root(),
+                2,
+                StaticTierParams::<Test>::get()
+            ),
+            Error::<Test>::Disabled
+        );
+        assert_noop!(
+            DappStaking::cancel_scheduled_tier_params(RuntimeOrigin::root()),
+            Error::<Test>::Disabled
+        );
+        assert_noop!(
+            DappStaking::unstake_from_all_unregistered(RuntimeOrigin::signed(1)),
+            Error::<Test>::Disabled
+        );
+        assert_noop!(
+            DappStaking::transfer_lock(RuntimeOrigin::signed(1), 2, 100),
+            Error::<Test>::Disabled
+        );
+        assert_noop!(
+            DappStaking::stake_for(
+                RuntimeOrigin::signed(2),
+                1,
+                MockSmartContract::wasm(1 as AccountId),
+                100
+            ),
+            Error::<Test>::Disabled
+        );
+        assert_noop!(
+            DappStaking::unstake_for(
+                RuntimeOrigin::signed(2),
+                1,
+                MockSmartContract::wasm(1 as AccountId),
+                100
+            ),
+            Error::<Test>::Disabled
+        );
+        assert_noop!(
+            DappStaking::move_stake_for(
+                RuntimeOrigin::signed(2),
+                1,
+                MockSmartContract::wasm(1 as AccountId),
+                MockSmartContract::wasm(2 as AccountId),
+                100
+            ),
+            Error::<Test>::Disabled
+        );
         assert_noop!(
             DappStaking::stake(
                 RuntimeOrigin::signed(1),
@@ -163,6 +260,14 @@ fn maintenance_mode_call_filtering_works() {
             ),
             Error::<Test>::Disabled
         );
+        assert_noop!(
+            DappStaking::partial_unstake_from_unregistered(
+                RuntimeOrigin::signed(1),
+                MockSmartContract::wasm(1 as AccountId),
+                100
+            ),
+            Error::<Test>::Disabled
+        );
         assert_noop!(
             DappStaking::cleanup_expired_entries(RuntimeOrigin::signed(1)),
             Error::<Test>::Disabled
@@ -436,6 +541,99 @@ fn set_dapp_reward_beneficiary_fails() {
     })
 }
 
+#[test]
+fn set_dapp_reward_splits_is_ok() {
+    ExtBuilder::default().build_and_execute(|| {
+        // Prepare & register smart contract
+        let owner = 1;
+        let smart_contract = MockSmartContract::Wasm(3);
+        assert_register(owner, &smart_contract);
+
+        // Update reward splits
+        assert!(IntegratedDApps::<Test>::get(&smart_contract)
+            .unwrap()
+            .reward_splits
+            .is_empty());
+        assert_set_dapp_reward_splits(owner, &smart_contract, vec![(3, Permill::from_percent(50))]);
+        assert_set_dapp_reward_splits(
+            owner,
+            &smart_contract,
+            vec![
+                (3, Permill::from_percent(25)),
+                (5, Permill::from_percent(25)),
+                (7, Permill::from_percent(50)),
+            ],
+        );
+        assert_set_dapp_reward_splits(owner, &smart_contract, vec![]);
+    })
+}
+
+#[test]
+fn set_dapp_reward_splits_fails() {
+    ExtBuilder::default().build_and_execute(|| {
+        let owner = 1;
+        let smart_contract = MockSmartContract::Wasm(3);
+        let splits = |splits: Vec<(AccountId, Permill)>| BoundedVec::try_from(splits).unwrap();
+
+        // Contract doesn't exist yet
+        assert_noop!(
+            DappStaking::set_dapp_reward_splits(
+                RuntimeOrigin::signed(owner),
+                smart_contract,
+                splits(vec![(5, Permill::from_percent(10))])
+            ),
+            Error::<Test>::ContractNotFound
+        );
+
+        // Non-owner cannot change reward splits
+        assert_register(owner, &smart_contract);
+        assert_noop!(
+            DappStaking::set_dapp_reward_splits(
+                RuntimeOrigin::signed(owner + 1),
+                smart_contract,
+                splits(vec![(5, Permill::from_percent(10))])
+            ),
+            Error::<Test>::OriginNotOwner
+        );
+
+        // Zero share isn't allowed
+        assert_noop!(
+            DappStaking::set_dapp_reward_splits(
+                RuntimeOrigin::signed(owner),
+                smart_contract,
+                splits(vec![(5, Permill::zero())])
+            ),
+            Error::<Test>::InvalidRewardSplits
+        );
+
+        // Duplicate beneficiaries aren't allowed
+        assert_noop!(
+            DappStaking::set_dapp_reward_splits(
+                RuntimeOrigin::signed(owner),
+                smart_contract,
+                splits(vec![
+                    (5, Permill::from_percent(10)),
+                    (5, Permill::from_percent(20))
+                ])
+            ),
+            Error::<Test>::InvalidRewardSplits
+        );
+
+        // Sum of shares cannot exceed 100%
+        assert_noop!(
+            DappStaking::set_dapp_reward_splits(
+                RuntimeOrigin::signed(owner),
+                smart_contract,
+                splits(vec![
+                    (5, Permill::from_percent(60)),
+                    (7, Permill::from_percent(41))
+                ])
+            ),
+            Error::<Test>::InvalidRewardSplits
+        );
+    })
+}
+
 #[test]
 fn set_dapp_owner_is_ok() {
     ExtBuilder::default().build_and_execute(|| {
@@ -621,6 +819,153 @@ fn lock_with_incorrect_amount_fails() {
     })
 }
 
+#[test]
+fn set_instant_unlock_penalty_is_ok() {
+    ExtBuilder::default().build_and_execute(|| {
+        // Disabled by default
+        assert!(InstantUnlockPenalty::<Test>::get().is_none());
+
+        // Set the penalty & check the event
+        let penalty = Perbill::from_percent(10);
+        assert_ok!(DappStaking::set_instant_unlock_penalty(
+            RuntimeOrigin::root(),
+            Some(penalty)
+        ));
+        assert_eq!(InstantUnlockPenalty::<Test>::get(), Some(penalty));
+        System::assert_last_event(RuntimeEvent::DappStaking(Event::InstantUnlockPenaltySet {
+            penalty: Some(penalty),
+        }));
+
+        // Disable it again
+        assert_ok!(DappStaking::set_instant_unlock_penalty(
+            RuntimeOrigin::root(),
+            None
+        ));
+        assert!(InstantUnlockPenalty::<Test>::get().is_none());
+        System::assert_last_event(RuntimeEvent::DappStaking(Event::InstantUnlockPenaltySet {
+            penalty: None,
+        }));
+    })
+}
+
+#[test]
+fn set_instant_unlock_penalty_with_incorrect_origin_fails() {
+    ExtBuilder::default().build_and_execute(|| {
+        assert_noop!(
+            DappStaking::set_instant_unlock_penalty(
+                RuntimeOrigin::signed(1),
+                Some(Perbill::from_percent(10))
+            ),
+            BadOrigin
+        );
+    })
+}
+
+#[test]
+fn instant_unlock_basic_example_is_ok() {
+    ExtBuilder::default().build_and_execute(|| {
+        assert_ok!(DappStaking::set_instant_unlock_penalty(
+            RuntimeOrigin::root(),
+            Some(Perbill::from_percent(10))
+        ));
+
+        let account = 2;
+        let lock_amount = 1000;
+        assert_lock(account, lock_amount);
+
+        // Instantly unlock part of the locked amount
+        assert_instant_unlock(account, 100);
+
+        // Create an unlocking chunk, and then instantly unlock only part of it
+        assert_unlock(account, 300);
+        assert_instant_unlock(account, 200);
+
+        // Instantly unlock the remainder of the chunk, together with some locked amount
+        assert_instant_unlock(account, 150);
+        assert!(Ledger::<Test>::get(&account).unlocking.is_empty());
+
+        // Instantly unlock everything that's left
+        assert_instant_unlock(account, lock_amount);
+        assert!(!Ledger::<Test>::contains_key(&account));
+    })
+}
+
+#[test]
+fn instant_unlock_with_remaining_amount_below_threshold_is_ok() {
+    ExtBuilder::default().build_and_execute(|| {
+        assert_ok!(DappStaking::set_instant_unlock_penalty(
+            RuntimeOrigin::root(),
+            Some(Perbill::from_percent(5))
+        ));
+
+        let account = 3;
+        let minimum_locked_amount: Balance = <Test as Config>::MinimumLockedAmount::get();
+        assert_lock(account, minimum_locked_amount + 2);
+
+        // Remaining amount would fall below the threshold, so everything gets unlocked
+        assert_instant_unlock(account, 3);
+        assert!(!Ledger::<Test>::contains_key(&account));
+    })
+}
+
+#[test]
+fn instant_unlock_ignores_matured_unlocking_chunks() {
+    ExtBuilder::default().build_and_execute(|| {
+        assert_ok!(DappStaking::set_instant_unlock_penalty(
+            RuntimeOrigin::root(),
+            Some(Perbill::from_percent(10))
+        ));
+
+        let account = 2;
+        assert_lock(account, 1000);
+        assert_unlock(account, 100);
+
+        // Chunk can be claimed normally, so it shouldn't be consumed by instant unlock
+        run_for_blocks(DappStaking::unlocking_period());
+        assert_instant_unlock(account, 50);
+        assert_eq!(Ledger::<Test>::get(&account).unlocking_amount(), 100);
+        assert_claim_unlocked(account);
+    })
+}
+
+#[test]
+fn instant_unlock_fails() {
+    ExtBuilder::default().build_and_execute(|| {
+        let account = 2;
+        let lock_amount = 101;
+        assert_lock(account, lock_amount);
+
+        // Disabled by default
+        assert_noop!(
+            DappStaking::instant_unlock(RuntimeOrigin::signed(account), 10),
+            Error::<Test>::InstantUnlockDisabled,
+        );
+
+        assert_ok!(DappStaking::set_instant_unlock_penalty(
+            RuntimeOrigin::root(),
+            Some(Perbill::from_percent(10))
+        ));
+
+        // Zero amount fails
+        assert_noop!(
+            DappStaking::instant_unlock(RuntimeOrigin::signed(account), 0),
+            Error::<Test>::ZeroAmount,
+        );
+
+        // Stake so that a full unlock becomes impossible
+        advance_to_next_era();
+        let smart_contract = MockSmartContract::Wasm(1);
+        assert_register(1, &smart_contract);
+        let minimum_locked_amount: Balance = <Test as Config>::MinimumLockedAmount::get();
+        assert_stake(account, &smart_contract, minimum_locked_amount - 1);
+
+        assert_noop!(
+            DappStaking::instant_unlock(RuntimeOrigin::signed(account), lock_amount),
+            Error::<Test>::RemainingStakePreventsFullUnlock,
+        );
+    })
+}
+
 #[test]
 fn lock_with_blacklisted_account_fails() {
     ExtBuilder::default().build_and_execute(|| {
@@ -2067,6 +2412,59 @@ fn claim_dapp_reward_works() {
     })
 }
 
+#[test]
+fn claim_dapp_reward_with_splits_works() {
+    ExtBuilder::default().build_and_execute(|| {
+        // Register smart contract, lock&stake some amount
+        let dev_account = 1;
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(dev_account, &smart_contract);
+
+        let account = 2;
+        let amount = 300;
+        assert_lock(account, amount);
+        assert_stake(account, &smart_contract, amount);
+
+        // Split the reward between multiple beneficiaries, with remainder going to the owner
+        assert_set_dapp_reward_splits(
+            dev_account,
+            &smart_contract,
+            vec![
+                (17, Permill::from_percent(30)),
+                (19, Permill::from_percent(20)),
+            ],
+        );
+
+        // Advance 2 eras so we have an entry for reward claiming
+        advance_to_era(ActiveProtocolState::<Test>::get().era + 2);
+        let era = ActiveProtocolState::<Test>::get().era - 1;
+        let events_before = dapp_staking_events().len();
+        assert_claim_dapp_reward(account, &smart_contract, era);
+        assert_eq!(
+            dapp_staking_events().len() - events_before,
+            3,
+            "One reward event per beneficiary is expected."
+        );
+
+        // Advance to next era, and ensure rewards can be fully split with a custom beneficiary set
+        assert_set_dapp_reward_beneficiary(dev_account, &smart_contract, Some(23));
+        assert_set_dapp_reward_splits(
+            dev_account,
+            &smart_contract,
+            vec![
+                (17, Permill::from_percent(70)),
+                (19, Permill::from_percent(30)),
+            ],
+        );
+        advance_to_next_era();
+        assert_claim_dapp_reward(
+            account,
+            &smart_contract,
+            ActiveProtocolState::<Test>::get().era - 1,
+        );
+    })
+}
+
 #[test]
 fn claim_dapp_reward_from_non_existing_contract_fails() {
     ExtBuilder::default().build_and_execute(|| {
@@ -2240,19 +2638,126 @@ fn claim_dapp_reward_fails_due_to_payout_failure() {
 }
 
 #[test]
-fn unstake_from_unregistered_is_ok() {
+fn claim_dapp_rewards_works() {
     ExtBuilder::default().build_and_execute(|| {
         // Register smart contract, lock&stake some amount
+        let dev_account = 1;
         let smart_contract = MockSmartContract::wasm(1 as AccountId);
-        assert_register(1, &smart_contract);
+        assert_register(dev_account, &smart_contract);
 
         let account = 2;
         let amount = 300;
         assert_lock(account, amount);
         assert_stake(account, &smart_contract, amount);
 
-        // Unregister the smart contract, and unstake from it.
-        assert_unregister(&smart_contract);
+        // Split the reward, so multiple beneficiaries are paid out
+        assert_set_dapp_reward_splits(
+            dev_account,
+            &smart_contract,
+            vec![(17, Permill::from_percent(30))],
+        );
+
+        // Advance a few eras so we have multiple entries for reward claiming
+        advance_to_era(ActiveProtocolState::<Test>::get().era + 5);
+        let last_era = ActiveProtocolState::<Test>::get().era - 1;
+
+        // Claim a single era from the middle of the range
+        assert_claim_dapp_reward(account, &smart_contract, last_era - 2);
+
+        // Claim the entire range, including the voting era without tier info and the already claimed era.
+        let events_before = dapp_staking_events().len();
+        assert_claim_dapp_rewards(account, &smart_contract, 1, last_era);
+        assert_eq!(
+            dapp_staking_events().len() - events_before,
+            2 * 3,
+            "One reward event per beneficiary for each claimed era is expected."
+        );
+    })
+}
+
+#[test]
+fn claim_dapp_rewards_fails() {
+    ExtBuilder::default().build_and_execute(|| {
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_noop!(
+            DappStaking::claim_dapp_rewards(RuntimeOrigin::signed(1), smart_contract, 1, 2),
+            Error::<Test>::ContractNotFound,
+        );
+
+        // Register smart contract, lock&stake some amount
+        assert_register(1, &smart_contract);
+        let account = 2;
+        let amount = 300;
+        assert_lock(account, amount);
+        assert_stake(account, &smart_contract, amount);
+
+        let max_range: EraNumber = <Test as Config>::MaxNumberOfDAppRewardClaimEras::get();
+        advance_to_era(ActiveProtocolState::<Test>::get().era + max_range + 1);
+        let current_era = ActiveProtocolState::<Test>::get().era;
+
+        // Range must not be empty, or too long
+        assert_noop!(
+            DappStaking::claim_dapp_rewards(RuntimeOrigin::signed(account), smart_contract, 3, 2),
+            Error::<Test>::InvalidClaimEraRange,
+        );
+        assert_noop!(
+            DappStaking::claim_dapp_rewards(
+                RuntimeOrigin::signed(account),
+                smart_contract,
+                1,
+                1 + max_range
+            ),
+            Error::<Test>::InvalidClaimEraRange,
+        );
+
+        // Range must not include the ongoing era
+        assert_noop!(
+            DappStaking::claim_dapp_rewards(
+                RuntimeOrigin::signed(account),
+                smart_contract,
+                current_era - 1,
+                current_era
+            ),
+            Error::<Test>::InvalidClaimEra,
+        );
+
+        // At least one era must be claimable
+        assert_noop!(
+            DappStaking::claim_dapp_rewards(RuntimeOrigin::signed(account), smart_contract, 1, 1),
+            Error::<Test>::NoClaimableRewards,
+        );
+
+        // Payout failure must be propagated
+        DOES_PAYOUT_SUCCEED.with(|v| *v.borrow_mut() = false);
+        assert_noop!(
+            DappStaking::claim_dapp_rewards(RuntimeOrigin::signed(account), smart_contract, 1, 3),
+            Error::<Test>::RewardPayoutFailed,
+        );
+        DOES_PAYOUT_SUCCEED.with(|v| *v.borrow_mut() = true);
+
+        // Claimed eras cannot be claimed again
+        assert_claim_dapp_rewards(account, &smart_contract, 1, 3);
+        assert_noop!(
+            DappStaking::claim_dapp_rewards(RuntimeOrigin::signed(account), smart_contract, 2, 3),
+            Error::<Test>::NoClaimableRewards,
+        );
+    })
+}
+
+#[test]
+fn unstake_from_unregistered_is_ok() {
+    ExtBuilder::default().build_and_execute(|| {
+        // Register smart contract, lock&stake some amount
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(1, &smart_contract);
+
+        let account = 2;
+        let amount = 300;
+        assert_lock(account, amount);
+        assert_stake(account, &smart_contract, amount);
+
+        // Unregister the smart contract, and unstake from it.
+        assert_unregister(&smart_contract);
         assert_unstake_from_unregistered(account, &smart_contract);
     })
 }
@@ -2314,1134 +2819,916 @@ fn unstake_from_unregistered_fails_for_past_period() {
     })
 }
 
-// Tests a previous bug where extra stake was chipped from the current era info due to a next era total stake larger (likely after a stake)
 #[test]
-fn unstake_from_future_stake_does_not_chip_current_era_stake() {
+fn partial_unstake_from_unregistered_is_ok() {
     ExtBuilder::default().build_and_execute(|| {
-        // Register smart contract 1, lock&stake some amount on 1
-        let contract = MockSmartContract::wasm(1 as AccountId);
-        assert_register(1, &contract);
-
-        let account_1 = 2;
-        let account_2 = 3;
-        let amount = 100;
-        let partial_stake_account_1 = 50;
-        let partial_stake_account_2 = 30;
-        assert_lock(account_1, amount);
-        assert_stake(account_1, &contract, partial_stake_account_1);
-        assert_lock(account_2, amount);
-        assert_stake(account_2, &contract, partial_stake_account_2);
-
-        // Advance to B&E subperiod, stake again to check both stakes move and finally unregister source_contract
-        advance_to_next_subperiod();
-        assert_stake(account_1, &contract, amount - partial_stake_account_1);
+        // Register smart contract, lock&stake some amount
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(1, &smart_contract);
 
-        let unstake_era = ActiveProtocolState::<Test>::get().era;
-        assert_unstake(
-            account_1, &contract, amount, // full unstake
-        );
+        let account = 2;
+        let amount = 300;
+        assert_lock(account, amount);
+        assert_stake(account, &smart_contract, amount);
 
-        let current_era_info = CurrentEraInfo::<Test>::get();
-        assert_eq!(
-            current_era_info.total_staked_amount(), // previously this was fully chipped and set to 0 because partial_stake_account_1 > partial_stake_account_2
-            partial_stake_account_2
-        );
-        assert_eq!(
-            current_era_info.total_staked_amount_next_era(),
-            partial_stake_account_2
-        );
+        // Unregister the smart contract, and unstake part of the stake from it, in both subperiods.
+        assert_unregister(&smart_contract);
+        assert_partial_unstake_from_unregistered(account, &smart_contract, 50);
+        assert!(StakerInfo::<Test>::get(&account, &smart_contract)
+            .expect("Entry must exist.")
+            .is_bonus_eligible());
 
-        // Advance 1 era so we have claimable rewards for account_2.
-        advance_to_era(ActiveProtocolState::<Test>::get().era + 1);
-        for _ in 0..required_number_of_reward_claims(3) {
-            assert_claim_staker_rewards(3);
-        }
+        advance_to_next_subperiod();
+        assert_partial_unstake_from_unregistered(account, &smart_contract, 100);
+        assert!(StakerInfo::<Test>::get(&account, &smart_contract)
+            .expect("Entry must exist.")
+            .is_bonus_eligible());
 
-        let (_, mut reward_span) = EraRewards::<Test>::iter().next().unwrap();
-        if reward_span.len() == unstake_era as usize {
-            let era_reward: EraReward = reward_span.span.pop().unwrap();
-            assert_eq!(
-                era_reward.staked, // previously this was fully chipped and set to 0 because partial_stake_account_1 > partial_stake_account_2
-                partial_stake_account_2
-            );
-        }
+        // Unstake the rest
+        assert_partial_unstake_from_unregistered(account, &smart_contract, 150);
     })
 }
 
-// Tests bonus stake conversion into regular stake for just forfeited bonus
 #[test]
-fn unstake_forfeited_bonus_to_regular_stake_conversion() {
+fn partial_unstake_from_unregistered_below_threshold_unstakes_everything() {
     ExtBuilder::default().build_and_execute(|| {
-        // Sanity check - max_moves value must be 0
-        assert_eq!(
-            0,
-            <Test as Config>::MaxBonusSafeMovesPerPeriod::get(),
-            "Sanity check"
-        );
-
-        // Prep - Register smart contract, lock&stake some amounts
-        let contract = MockSmartContract::wasm(1 as AccountId);
-        assert_register(1, &contract);
+        // Register smart contract, lock&stake some amount
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(1, &smart_contract);
 
         let account = 2;
-        let amount = 100;
+        let min_stake_amount: Balance = <Test as Config>::MinimumStakeAmount::get();
+        let amount = min_stake_amount + 10;
         assert_lock(account, amount);
-        assert_stake(account, &contract, amount);
-
-        // Advance to B&E subperiod for bonus forfeiting
-        advance_to_next_subperiod();
-        let unstake_ammount = 10;
-        assert_unstake(account, &contract, unstake_ammount);
-
-        let expected_staking_info = SingularStakingInfo {
-            previous_staked: StakeAmount::default(),
-            staked: StakeAmount {
-                voting: amount - unstake_ammount,
-                build_and_earn: 0,
-                era: 2,
-                period: 1,
-            },
-            bonus_status: 0, // bonus has been forfeited
-        };
+        assert_stake(account, &smart_contract, amount);
 
-        let staking_info = StakerInfo::<Test>::get(&account, &contract)
-            .expect("Should exist after a successful move operation");
-        assert_eq!(staking_info, expected_staking_info);
+        // Unstaking would leave less than the minimum stake amount, so everything is unstaked.
+        assert_unregister(&smart_contract);
+        assert_partial_unstake_from_unregistered(account, &smart_contract, 11);
+        assert!(!StakerInfo::<Test>::contains_key(&account, &smart_contract));
     })
 }
 
 #[test]
-fn cleanup_expired_entries_is_ok() {
+fn partial_unstake_from_unregistered_fails() {
     ExtBuilder::default().build_and_execute(|| {
-        // Register smart contracts
-        let contracts: Vec<_> = (1..=5).map(|id| MockSmartContract::Wasm(id)).collect();
-        contracts.iter().for_each(|smart_contract| {
-            assert_register(1, smart_contract);
-        });
+        // Register smart contract, lock&stake some amount
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(1, &smart_contract);
+
         let account = 2;
-        assert_lock(account, 1000);
+        let amount = 300;
+        assert_lock(account, amount);
+        assert_stake(account, &smart_contract, amount);
 
-        // Scenario:
-        // - 1st contract will be staked in the period that expires due to exceeded reward retention
-        // - 2nd contract will be staked in the period on the edge of expiry, with loyalty flag
-        // - 3rd contract will be be staked in the period on the edge of expiry, without loyalty flag
-        // - 4th contract will be staked in the period right before the current one, with loyalty flag
-        // - 5th contract will be staked in the period right before the current one, without loyalty flag
-        //
-        // Expectation: 1, 3, 5 should be removed, 2 & 4 should remain
+        // Contract is still active
+        assert_noop!(
+            DappStaking::partial_unstake_from_unregistered(
+                RuntimeOrigin::signed(account),
+                smart_contract,
+                100
+            ),
+            Error::<Test>::ContractStillActive
+        );
 
-        // 1st
-        assert_stake(account, &contracts[0], 13);
+        assert_unregister(&smart_contract);
 
-        // 2nd & 3rd
-        advance_to_next_period();
-        for _ in 0..required_number_of_reward_claims(account) {
-            assert_claim_staker_rewards(account);
-        }
-        assert_stake(account, &contracts[1], 17);
-        advance_to_next_subperiod();
+        // Zero amount
+        assert_noop!(
+            DappStaking::partial_unstake_from_unregistered(
+                RuntimeOrigin::signed(account),
+                smart_contract,
+                0
+            ),
+            Error::<Test>::ZeroAmount
+        );
 
-        assert_stake(account, &contracts[2], 19);
+        // More than what's staked
+        assert_noop!(
+            DappStaking::partial_unstake_from_unregistered(
+                RuntimeOrigin::signed(account),
+                smart_contract,
+                amount + 1
+            ),
+            Error::<Test>::UnstakeAmountTooLarge
+        );
 
-        // 4th & 5th
-        let reward_retention_in_periods: PeriodNumber =
-            <Test as Config>::RewardRetentionInPeriods::get();
-        assert!(
-            reward_retention_in_periods >= 2,
-            "Sanity check, otherwise the test doesn't make sense."
+        // Nothing staked
+        assert_noop!(
+            DappStaking::partial_unstake_from_unregistered(
+                RuntimeOrigin::signed(account + 1),
+                smart_contract,
+                100
+            ),
+            Error::<Test>::NoStakingInfo
         );
-        advance_to_period(reward_retention_in_periods + 1);
-        for _ in 0..required_number_of_reward_claims(account) {
-            assert_claim_staker_rewards(account);
-        }
-        assert_stake(account, &contracts[3], 23);
-        advance_to_next_subperiod();
-        assert_stake(account, &contracts[4], 29);
 
-        // Finally do the test
+        // Past period
         advance_to_next_period();
-        assert_cleanup_expired_entries(account);
-
-        // Additional sanity check according to the described scenario
-        assert!(!StakerInfo::<Test>::contains_key(account, &contracts[0]));
-        assert!(!StakerInfo::<Test>::contains_key(account, &contracts[2]));
-        assert!(!StakerInfo::<Test>::contains_key(account, &contracts[4]));
-
-        assert!(StakerInfo::<Test>::contains_key(account, &contracts[1]));
-        assert!(StakerInfo::<Test>::contains_key(account, &contracts[3]));
+        assert_noop!(
+            DappStaking::partial_unstake_from_unregistered(
+                RuntimeOrigin::signed(account),
+                smart_contract,
+                100
+            ),
+            Error::<Test>::UnstakeFromPastPeriod
+        );
     })
 }
 
 #[test]
-fn cleanup_expired_entries_fails_with_no_entries() {
+fn unstake_from_all_unregistered_is_ok() {
     ExtBuilder::default().build_and_execute(|| {
-        // Register smart contracts
-        let (contract_1, contract_2) = (MockSmartContract::Wasm(1), MockSmartContract::Wasm(2));
-        assert_register(1, &contract_1);
-        assert_register(1, &contract_2);
-
+        // Register smart contracts, lock&stake some amount on each
+        let smart_contracts: Vec<_> = (1..=3)
+            .map(|x| MockSmartContract::wasm(x as AccountId))
+            .collect();
         let account = 2;
-        assert_lock(account, 1000);
-        assert_stake(account, &contract_1, 13);
-        assert_stake(account, &contract_2, 17);
+        let amount = 100;
+        assert_lock(account, amount * 3);
+        for smart_contract in &smart_contracts {
+            assert_register(1, smart_contract);
+            assert_stake(account, smart_contract, amount);
+        }
 
-        // Advance only one period, rewards should still be valid.
-        let reward_retention_in_periods: PeriodNumber =
-            <Test as Config>::RewardRetentionInPeriods::get();
-        assert!(
-            reward_retention_in_periods >= 1,
-            "Sanity check, otherwise the test doesn't make sense."
-        );
-        advance_to_next_period();
+        // Unregister two of the smart contracts, and unstake from both of them at once.
+        assert_unregister(&smart_contracts[0]);
+        assert_unregister(&smart_contracts[2]);
+        let pre_era_info = CurrentEraInfo::<Test>::get();
 
-        assert_noop!(
-            DappStaking::cleanup_expired_entries(RuntimeOrigin::signed(account)),
-            Error::<Test>::NoExpiredEntries
+        assert_ok!(DappStaking::unstake_from_all_unregistered(
+            RuntimeOrigin::signed(account)
+        ));
+        for smart_contract in [&smart_contracts[0], &smart_contracts[2]] {
+            System::assert_has_event(RuntimeEvent::DappStaking(Event::UnstakeFromUnregistered {
+                account,
+                smart_contract: smart_contract.clone(),
+                amount,
+            }));
+            assert!(StakerInfo::<Test>::get(&account, smart_contract).is_none());
+        }
+
+        // Stake on the active contract must remain untouched
+        let period = ActiveProtocolState::<Test>::get().period_number();
+        assert!(StakerInfo::<Test>::get(&account, &smart_contracts[1]).is_some());
+        let ledger = Ledger::<Test>::get(&account);
+        assert_eq!(ledger.staked_amount(period), amount);
+        assert_eq!(ledger.contract_stake_count, 1);
+        assert_eq!(
+            CurrentEraInfo::<Test>::get().total_staked_amount_next_era(),
+            pre_era_info.total_staked_amount_next_era() - amount * 2
         );
     })
 }
 
 #[test]
-fn force_era_works() {
+fn unstake_from_all_unregistered_fails() {
     ExtBuilder::default().build_and_execute(|| {
-        // 1. Force new era in the voting subperiod
-        let init_state = ActiveProtocolState::<Test>::get();
-        assert!(
-            init_state.next_era_start > System::block_number() + 1,
-            "Sanity check, new era cannot start in next block, otherwise the test doesn't guarantee it tests what's expected."
-        );
-        assert_eq!(
-            init_state.subperiod(),
-            Subperiod::Voting,
-            "Sanity check."
-        );
-        assert_ok!(DappStaking::force(RuntimeOrigin::root(), ForcingType::Era));
-        System::assert_last_event(RuntimeEvent::DappStaking(Event::Force {
-            forcing_type: ForcingType::Era,
-        }));
-
-        // Verify state change
-        assert_eq!(
-            ActiveProtocolState::<Test>::get().next_era_start,
-            System::block_number() + 1,
-        );
-        assert_eq!(
-            ActiveProtocolState::<Test>::get().next_subperiod_start_era(),
-            init_state.next_subperiod_start_era(),
-        );
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(1, &smart_contract);
 
-        // Go to the next block, and ensure new era is started
-        run_for_blocks(1);
-        assert_eq!(
-            ActiveProtocolState::<Test>::get().era,
-            init_state.era + 1,
-            "New era must be started."
-        );
-        assert_eq!(
-            ActiveProtocolState::<Test>::get().subperiod(),
-            Subperiod::BuildAndEarn,
-        );
+        let account = 2;
+        let amount = 300;
+        assert_lock(account, amount);
 
-        // 2. Force new era in the build&earn subperiod
-        let init_state = ActiveProtocolState::<Test>::get();
-        assert!(
-            init_state.next_era_start > System::block_number() + 1,
-            "Sanity check, new era cannot start in next block, otherwise the test doesn't guarantee it tests what's expected."
+        // No stake at all
+        assert_noop!(
+            DappStaking::unstake_from_all_unregistered(RuntimeOrigin::signed(account)),
+            Error::<Test>::NoUnregisteredStake
         );
-        assert!(init_state.next_subperiod_start_era() > init_state.era + 1, "Sanity check, otherwise the test doesn't guarantee it tests what's expected.");
-        assert_ok!(DappStaking::force(RuntimeOrigin::root(), ForcingType::Era));
-        System::assert_last_event(RuntimeEvent::DappStaking(Event::Force {
-            forcing_type: ForcingType::Era,
-        }));
 
-        // Verify state change
-        assert_eq!(
-            ActiveProtocolState::<Test>::get().next_era_start,
-            System::block_number() + 1,
-        );
-        assert_eq!(
-            ActiveProtocolState::<Test>::get().next_subperiod_start_era(),
-            init_state.next_subperiod_start_era(),
-            "Only era is bumped, but we don't expect to switch over to the next subperiod."
+        // Stake only on the active contract
+        assert_stake(account, &smart_contract, amount);
+        assert_noop!(
+            DappStaking::unstake_from_all_unregistered(RuntimeOrigin::signed(account)),
+            Error::<Test>::NoUnregisteredStake
         );
 
-        run_for_blocks(1);
-        assert_eq!(
-            ActiveProtocolState::<Test>::get().era,
-            init_state.era + 1,
-            "New era must be started."
-        );
-        assert_eq!(
-            ActiveProtocolState::<Test>::get().subperiod(),
-            Subperiod::BuildAndEarn,
-            "We're expected to remain in the same subperiod."
+        // Stake from the past period cannot be unstaked
+        assert_unregister(&smart_contract);
+        advance_to_next_period();
+        assert_noop!(
+            DappStaking::unstake_from_all_unregistered(RuntimeOrigin::signed(account)),
+            Error::<Test>::NoUnregisteredStake
         );
     })
 }
 
 #[test]
-fn force_subperiod_works() {
+fn unregistered_stake_is_swept_after_grace_period() {
     ExtBuilder::default().build_and_execute(|| {
-        // 1. Force new subperiod in the voting subperiod
-        let init_state = ActiveProtocolState::<Test>::get();
-        assert!(
-            init_state.next_era_start > System::block_number() + 1,
-            "Sanity check, new era cannot start in next block, otherwise the test doesn't guarantee it tests what's expected."
-        );
-        assert_eq!(
-            init_state.subperiod(),
-            Subperiod::Voting,
-            "Sanity check."
-        );
-        assert_ok!(DappStaking::force(RuntimeOrigin::root(), ForcingType::Subperiod));
-        System::assert_last_event(RuntimeEvent::DappStaking(Event::Force {
-            forcing_type: ForcingType::Subperiod,
-        }));
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(1, &smart_contract);
 
-        // Verify state change
-        assert_eq!(
-            ActiveProtocolState::<Test>::get().next_era_start,
-            System::block_number() + 1,
-        );
-        assert_eq!(
-            ActiveProtocolState::<Test>::get().next_subperiod_start_era(),
-            init_state.era + 1,
-            "The switch to the next subperiod must happen in the next era."
-        );
+        // Bonus eligible stake is made during the voting subperiod
+        let (bonus_staker, bonus_amount) = (3, 200);
+        assert_lock(bonus_staker, bonus_amount);
+        assert_stake(bonus_staker, &smart_contract, bonus_amount);
 
-        // Go to the next block, and ensure new era is started
+        // Stake without a bonus status is made during the build&earn subperiod
+        advance_to_next_subperiod();
+        let (staker, amount) = (2, 100);
+        assert_lock(staker, amount);
+        assert_stake(staker, &smart_contract, amount);
+        advance_to_next_era();
+
+        assert_unregister(&smart_contract);
+        let grace_period: EraNumber = <Test as Config>::UnregisteredStakeGracePeriod::get();
+        let sweep_era = ActiveProtocolState::<Test>::get().era + grace_period;
+        assert!(UnregisteredStakeSweeps::<Test>::contains_key(
+            &smart_contract
+        ));
+
+        // Nothing is swept during the grace period
+        advance_to_era(sweep_era - 1);
         run_for_blocks(1);
-        assert_eq!(
-            ActiveProtocolState::<Test>::get().era,
-            init_state.era + 1,
-            "New era must be started."
-        );
-        assert_eq!(
-            ActiveProtocolState::<Test>::get().subperiod(),
-            Subperiod::BuildAndEarn,
-            "New subperiod must be started."
-        );
-        assert_eq!(ActiveProtocolState::<Test>::get().period_number(), init_state.period_number(), "Period must remain the same.");
+        assert!(StakerInfo::<Test>::get(&staker, &smart_contract).is_some());
 
-        // 2. Force new era in the build&earn subperiod
-        let init_state = ActiveProtocolState::<Test>::get();
-        assert!(
-            init_state.next_era_start > System::block_number() + 1,
-            "Sanity check, new era cannot start in next block, otherwise the test doesn't guarantee it tests what's expected."
-        );
-        assert!(init_state.next_subperiod_start_era() > init_state.era + 1, "Sanity check, otherwise the test doesn't guarantee it tests what's expected.");
-        assert_ok!(DappStaking::force(RuntimeOrigin::root(), ForcingType::Subperiod));
-        System::assert_last_event(RuntimeEvent::DappStaking(Event::Force {
-            forcing_type: ForcingType::Subperiod,
+        // Once it passes, stake without a bonus status is swept, claiming the rewards first
+        advance_to_era(sweep_era);
+        run_for_blocks(1);
+        System::assert_has_event(RuntimeEvent::DappStaking(Event::UnregisteredStakeSwept {
+            account: staker,
+            smart_contract: smart_contract.clone(),
+            amount,
         }));
-
-        // Verify state change
-        assert_eq!(
-            ActiveProtocolState::<Test>::get().next_era_start,
-            System::block_number() + 1,
-        );
+        assert!(StakerInfo::<Test>::get(&staker, &smart_contract).is_none());
+        let period = ActiveProtocolState::<Test>::get().period_number();
+        let ledger = Ledger::<Test>::get(&staker);
+        assert!(ledger.staked_amount(period).is_zero());
+        assert!(ledger.earliest_staked_era().is_none());
+
+        // Bonus eligible stake is left as it is
+        assert!(StakerInfo::<Test>::get(&bonus_staker, &smart_contract).is_some());
         assert_eq!(
-            ActiveProtocolState::<Test>::get().next_subperiod_start_era(),
-            init_state.era + 1,
-            "The switch to the next subperiod must happen in the next era."
+            Ledger::<Test>::get(&bonus_staker).staked_amount(period),
+            bonus_amount
         );
 
+        // Sweep is complete
+        assert!(!UnregisteredStakeSweeps::<Test>::contains_key(
+            &smart_contract
+        ));
+        assert!(UnregisteredStakeSweepCursor::<Test>::get().is_none());
+    })
+}
+
+#[test]
+fn unregistered_stake_sweep_expires_with_period() {
+    ExtBuilder::default().build_and_execute(|| {
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(1, &smart_contract);
+
+        // Unregister the contract in the last era of the period, before the grace period passes
+        advance_to_next_subperiod();
+        let last_era = ActiveProtocolState::<Test>::get()
+            .period_info
+            .next_subperiod_start_era
+            - 1;
+        advance_to_era(last_era);
+        assert_unregister(&smart_contract);
+        assert!(UnregisteredStakeSweeps::<Test>::contains_key(
+            &smart_contract
+        ));
+
+        // Stake from the past period isn't counted in the ledger, so there's nothing to sweep
+        advance_to_next_period();
         run_for_blocks(1);
-        assert_eq!(
-            ActiveProtocolState::<Test>::get().era,
-            init_state.era + 1,
-            "New era must be started."
-        );
-        assert_eq!(
-            ActiveProtocolState::<Test>::get().subperiod(),
-            Subperiod::Voting,
-            "New subperiod must be started."
-        );
-        assert_eq!(ActiveProtocolState::<Test>::get().period_number(), init_state.period_number() + 1, "New period must be started.");
+        assert!(!UnregisteredStakeSweeps::<Test>::contains_key(
+            &smart_contract
+        ));
     })
 }
 
 #[test]
-fn force_with_incorrect_origin_fails() {
+fn set_lock_receipts_enabled_is_ok() {
     ExtBuilder::default().build_and_execute(|| {
-        assert_noop!(
-            DappStaking::force(RuntimeOrigin::signed(1), ForcingType::Era),
-            BadOrigin
-        );
+        // Disabled by default
+        assert!(!LockReceiptsEnabled::<Test>::get());
+
+        assert_ok!(DappStaking::set_lock_receipts_enabled(
+            RuntimeOrigin::root(),
+            true
+        ));
+        assert!(LockReceiptsEnabled::<Test>::get());
+        System::assert_last_event(RuntimeEvent::DappStaking(Event::LockReceiptsToggled {
+            enabled: true,
+        }));
+
+        // Disabling works even if the receipt asset is unavailable
+        IS_LOCK_RECEIPT_AVAILABLE.with(|v| *v.borrow_mut() = false);
+        assert_ok!(DappStaking::set_lock_receipts_enabled(
+            RuntimeOrigin::root(),
+            false
+        ));
+        assert!(!LockReceiptsEnabled::<Test>::get());
+        System::assert_last_event(RuntimeEvent::DappStaking(Event::LockReceiptsToggled {
+            enabled: false,
+        }));
     })
 }
 
 #[test]
-fn force_with_safeguard_on_fails() {
+fn set_lock_receipts_enabled_fails() {
     ExtBuilder::default().build_and_execute(|| {
-        Safeguard::<Test>::put(true);
         assert_noop!(
-            DappStaking::force(RuntimeOrigin::root(), ForcingType::Era),
-            Error::<Test>::ForceNotAllowed
+            DappStaking::set_lock_receipts_enabled(RuntimeOrigin::signed(1), true),
+            BadOrigin
+        );
+
+        IS_LOCK_RECEIPT_AVAILABLE.with(|v| *v.borrow_mut() = false);
+        assert_noop!(
+            DappStaking::set_lock_receipts_enabled(RuntimeOrigin::root(), true),
+            Error::<Test>::LockReceiptUnavailable
         );
     })
 }
 
 #[test]
-fn get_dapp_tier_assignment_and_rewards_basic_example_works() {
+fn lock_receipts_are_minted_and_burned() {
     ExtBuilder::default().build_and_execute(|| {
-        // Tier config is specially adapted for this test.
-        TierConfig::<Test>::mutate(|config| {
-            config.slots_per_tier = BoundedVec::try_from(vec![0, 6, 10, 0]).unwrap();
-        });
-
-        // Scenario:
-        // - 1st tier is empty, with 3 dApps satisfying the threshold but not making it due to lack of tier capacity
-        // - 2nd tier has 5 dApps - 3 that could make it into the 1st tier (expected to be rank 10) and 2 that are supposed to be in the 2nd tier
-        // - 3rd tier has no dApps
-        // - 4th tier has no dApps due to lack of tier capacity
-        // - 1 dApp doesn't make it into any tier
+        let account = 2;
 
-        // Register smart contracts
-        let tier_params = StaticTierParams::<Test>::get();
-        let tier_config = TierConfig::<Test>::get();
-        let number_of_smart_contracts = 0 + 5 + 0 + 2 + 1;
-        let smart_contracts: Vec<_> = (1..=number_of_smart_contracts)
-            .map(|x| {
-                let smart_contract = MockSmartContract::Wasm(x.into());
-                assert_register(x.into(), &smart_contract);
-                smart_contract
-            })
-            .collect();
-        let mut dapp_index: usize = 0;
+        // Funds locked before receipts are enabled aren't covered by receipts
+        assert_lock(account, 100);
+        assert!(!LockReceipts::<Test>::contains_key(&account));
 
-        fn lock_and_stake(account: usize, smart_contract: &MockSmartContract, amount: Balance) {
-            let account = account.try_into().unwrap();
-            Balances::make_free_balance_be(&account, amount);
-            assert_lock(account, amount);
-            assert_stake(account, smart_contract, amount);
-        }
+        assert_ok!(DappStaking::set_lock_receipts_enabled(
+            RuntimeOrigin::root(),
+            true
+        ));
 
-        // 2nd tier - 3 that won't make it into the 1st tier due to lack of tier capacity.
-        lock_and_stake(
-            dapp_index,
-            &smart_contracts[dapp_index],
-            tier_config.tier_thresholds[0] + 2,
-        );
-        dapp_index += 1;
-        lock_and_stake(
-            dapp_index,
-            &smart_contracts[dapp_index],
-            tier_config.tier_thresholds[0] + 1,
-        );
-        dapp_index += 1;
-        lock_and_stake(
-            dapp_index,
-            &smart_contracts[dapp_index],
-            tier_config.tier_thresholds[0],
-        );
-        dapp_index += 1;
-
-        // 2nd tier - 2 dedicated dApp
-        lock_and_stake(
-            dapp_index,
-            &smart_contracts[dapp_index],
-            tier_config.tier_thresholds[0] - 1,
-        );
-        dapp_index += 1;
-        lock_and_stake(
-            dapp_index,
-            &smart_contracts[dapp_index],
-            tier_config.tier_thresholds[0] - 2,
-        );
-        dapp_index += 1;
-
-        // 3rd tier is empty
-        // 4th tier has 2 dApps
-        for x in 0..2 {
-            lock_and_stake(
-                dapp_index,
-                &smart_contracts[dapp_index],
-                tier_config.tier_thresholds[3] + x,
-            );
-            dapp_index += 1;
-        }
-
-        // One dApp doesn't make it into any tier
-        lock_and_stake(
-            dapp_index,
-            &smart_contracts[dapp_index],
-            tier_config.tier_thresholds[3] - 1,
-        );
+        // Lock some more, receipt is minted for it
+        assert_lock(account, 300);
+        System::assert_has_event(RuntimeEvent::DappStaking(Event::LockReceiptMinted {
+            account,
+            amount: 300,
+        }));
+        assert_eq!(LockReceipts::<Test>::get(&account), 300);
+        assert_eq!(DummyLockReceipt::balance(&account), 300);
 
-        // Finally, the actual test
-        let protocol_state = ActiveProtocolState::<Test>::get();
-        let dapp_reward_pool: Balance = 1_000_000;
-        let (tier_assignment, counter) = DappStaking::get_dapp_tier_assignment_and_rewards(
-            protocol_state.era + 1,
-            protocol_state.period_number(),
-            dapp_reward_pool,
-        );
+        // Unlock, receipt is burned for the unlocked amount
+        assert_unlock(account, 50);
+        System::assert_has_event(RuntimeEvent::DappStaking(Event::LockReceiptBurned {
+            account,
+            amount: 50,
+        }));
+        assert_eq!(LockReceipts::<Test>::get(&account), 250);
 
-        assert_eq!(
-            tier_assignment.rank_rewards,
-            BoundedVec::<Balance, ConstU32<4>>::try_from(vec![0, 3583, 0, 0]).unwrap()
-        );
+        // Relock, receipt is minted again
+        assert_relock_unlocking(account);
+        assert_eq!(LockReceipts::<Test>::get(&account), 300);
 
-        // Basic checks
-        let number_of_tiers: u32 = <Test as Config>::NumberOfTiers::get();
-        assert_eq!(tier_assignment.period, protocol_state.period_number());
-        assert_eq!(tier_assignment.rewards.len(), number_of_tiers as usize);
-        assert_eq!(
-            tier_assignment.dapps.len(),
-            number_of_smart_contracts as usize - 3,
-            "Three contract doesn't make it into any tier."
-        );
-        assert_eq!(counter, number_of_smart_contracts);
+        // Receipts are burned even after they are disabled, but no longer minted
+        assert_ok!(DappStaking::set_lock_receipts_enabled(
+            RuntimeOrigin::root(),
+            false
+        ));
+        assert_unlock(account, 100);
+        assert_eq!(LockReceipts::<Test>::get(&account), 200);
+        assert_relock_unlocking(account);
+        assert_eq!(LockReceipts::<Test>::get(&account), 200);
 
-        // 2nd tier checks
-        let (dapp_1_tier, dapp_2_tier, dapp_3_tier, dapp_4_tier, dapp_5_tier) = (
-            tier_assignment.dapps[&0],
-            tier_assignment.dapps[&1],
-            tier_assignment.dapps[&2],
-            tier_assignment.dapps[&3],
-            tier_assignment.dapps[&4],
-        );
-        assert_eq!(dapp_1_tier, RankedTier::new_saturated(1, 10));
-        assert_eq!(dapp_2_tier, RankedTier::new_saturated(1, 10));
-        assert_eq!(dapp_3_tier, RankedTier::new_saturated(1, 10));
-        assert_eq!(dapp_4_tier, RankedTier::new_saturated(1, 9));
-        assert_eq!(dapp_5_tier, RankedTier::new_saturated(1, 9));
+        // Instantly unlock everything, all of the receipts are burned
+        assert_ok!(DappStaking::set_instant_unlock_penalty(
+            RuntimeOrigin::root(),
+            Some(Perbill::from_percent(10))
+        ));
+        assert_instant_unlock(account, 400);
+        assert!(!LockReceipts::<Test>::contains_key(&account));
+        assert!(DummyLockReceipt::total_issuance().is_zero());
+    })
+}
 
-        // === Verify reward calculations ===
-        // Tier 0: 40% of 1M = 400,000 ASTR
-        //   multiplier = 0% → compute_tier_rewards returns (0, 0)
-        assert_eq!(tier_assignment.rewards[0], 0);
-        assert_eq!(tier_assignment.rank_rewards[0], 0);
+#[test]
+fn unlock_with_missing_lock_receipts_is_ok() {
+    ExtBuilder::default().build_and_execute(|| {
+        assert_ok!(DappStaking::set_lock_receipts_enabled(
+            RuntimeOrigin::root(),
+            true
+        ));
 
-        // Tier 1: 30% of 1M = 300,000 ASTR
-        let tier_1_allocation = Permill::from_percent(30) * dapp_reward_pool;
-        let tier_1_slots = tier_config.slots_per_tier[1];
-        let tier_1_filled = 5u32;
-        let tier_1_ranks_sum = 10u32 + 10 + 10 + 9 + 9; // 48
-        let tier_1_multiplier = tier_params.tier_rank_multipliers[1];
-        let (expected_reward_1, expected_rank_reward_1) = DappStaking::compute_tier_rewards(
-            tier_1_allocation,
-            tier_1_slots,
-            tier_1_filled,
-            tier_1_ranks_sum,
-            tier_1_multiplier,
-        );
-        assert_eq!(tier_assignment.rewards[1], expected_reward_1);
-        assert_eq!(tier_assignment.rank_rewards[1], expected_rank_reward_1);
+        let account = 2;
+        assert_lock(account, 300);
 
-        // Tier 2: 20% of 1M = 200,000 ASTR
-        // empty tier (unminted rewards)
-        assert_eq!(tier_assignment.rewards[2], 0);
-        assert_eq!(tier_assignment.rank_rewards[2], 0);
+        // Some of the receipt tokens are no longer held by the account
+        LOCK_RECEIPT_BALANCES.with(|v| *v.borrow_mut().entry(account).or_default() -= 120);
 
-        // Tier 3: 10% of 1M = 100,000 ASTR
-        let tier_3_allocation = Permill::from_percent(10) * dapp_reward_pool;
-        let tier_3_slots = tier_config.slots_per_tier[3];
-        let tier_3_filled = 2u32;
-        let tier_3_ranks_sum = 0u32;
-        let tier_3_multiplier = tier_params.tier_rank_multipliers[3];
-        let (expected_reward_3, expected_rank_reward_3) = DappStaking::compute_tier_rewards(
-            tier_3_allocation,
-            tier_3_slots,
-            tier_3_filled,
-            tier_3_ranks_sum,
-            tier_3_multiplier,
-        );
-        assert_eq!(tier_assignment.rewards[3], expected_reward_3);
-        assert_eq!(tier_assignment.rank_rewards[3], expected_rank_reward_3);
+        // Unlock is still possible, only the remaining receipt tokens are burned
+        assert_unlock(account, 100);
+        System::assert_has_event(RuntimeEvent::DappStaking(Event::LockReceiptBurned {
+            account,
+            amount: 100,
+        }));
+        assert_eq!(LockReceipts::<Test>::get(&account), 200);
 
-        // === Verify claim formula produces capped total rewards ===
-        let dapp_0_reward = tier_assignment.rewards[1] + 10 * tier_assignment.rank_rewards[1];
-        let dapp_1_reward = tier_assignment.rewards[1] + 10 * tier_assignment.rank_rewards[1];
-        let dapp_2_reward = tier_assignment.rewards[1] + 10 * tier_assignment.rank_rewards[1];
-        let dapp_3_reward = tier_assignment.rewards[1] + 9 * tier_assignment.rank_rewards[1];
-        let dapp_4_reward = tier_assignment.rewards[1] + 9 * tier_assignment.rank_rewards[1];
-        let tier_1_total_disbursed =
-            dapp_0_reward + dapp_1_reward + dapp_2_reward + dapp_3_reward + dapp_4_reward;
-        assert!(tier_1_total_disbursed <= tier_1_allocation);
+        assert_unlock(account, 200);
+        System::assert_has_event(RuntimeEvent::DappStaking(Event::LockReceiptBurned {
+            account,
+            amount: 80,
+        }));
+        assert!(!LockReceipts::<Test>::contains_key(&account));
+        assert!(DummyLockReceipt::balance(&account).is_zero());
     })
 }
 
 #[test]
-fn get_dapp_tier_assignment_and_rewards_zero_slots_per_tier_works() {
+fn transfer_lock_is_ok() {
     ExtBuilder::default().build_and_execute(|| {
-        // This test will rely on the configuration inside the mock file.
-        // If that changes, this test might have to be updated as well.
+        assert_ok!(DappStaking::set_lock_receipts_enabled(
+            RuntimeOrigin::root(),
+            true
+        ));
 
-        // Ensure that first tier has 0 slots.
-        TierConfig::<Test>::mutate(|config| {
-            config.slots_per_tier[0] = 0;
-        });
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(1, &smart_contract);
 
-        // Calculate tier assignment (we don't need dApps for this test)
-        let protocol_state = ActiveProtocolState::<Test>::get();
-        let dapp_reward_pool = 1000000;
-        let (tier_assignment, counter) = DappStaking::get_dapp_tier_assignment_and_rewards(
-            protocol_state.era,
-            protocol_state.period_number(),
-            dapp_reward_pool,
-        );
+        let (from, to) = (2, 3);
+        assert_lock(from, 500);
+        assert_stake(from, &smart_contract, 200);
 
-        // Basic checks
-        let number_of_tiers: u32 = <Test as Config>::NumberOfTiers::get();
-        assert_eq!(tier_assignment.period, protocol_state.period_number());
-        assert_eq!(tier_assignment.rewards.len(), number_of_tiers as usize);
-        assert!(tier_assignment.dapps.is_empty());
-        assert!(counter.is_zero());
+        // Transfer part of the unstaked lock to a new account
+        assert_transfer_lock(from, to, 100);
+        assert_eq!(DummyLockReceipt::balance(&to), 100);
 
-        assert!(
-            tier_assignment.rewards[0].is_zero(),
-            "1st tier has no slots so no rewards should be assigned to it."
-        );
-        assert!(
-            tier_assignment.rank_rewards[0].is_zero(),
-            "1st tier has no slots so no rank rewards should be assigned."
-        );
+        // Transfer to an account which already has some lock
+        assert_transfer_lock(to, from, 50);
 
-        // Regardless of that, other tiers shouldn't benefit from this
-        assert!(tier_assignment.rewards.iter().sum::<Balance>() < dapp_reward_pool);
+        // Transfer everything that isn't staked
+        let period = ActiveProtocolState::<Test>::get().period_number();
+        let unlockable = Ledger::<Test>::get(&from).unlockable_amount(period);
+        assert_transfer_lock(from, to, unlockable);
+        assert_eq!(Ledger::<Test>::get(&from).staked_amount(period), 200);
     })
 }
 
 #[test]
-fn advance_for_some_periods_works() {
+fn transfer_lock_with_partial_receipt_is_ok() {
     ExtBuilder::default().build_and_execute(|| {
-        advance_to_period(10);
+        let (from, to) = (2, 3);
+        assert_lock(from, 300);
+
+        // Only part of the locked amount is covered by receipts
+        assert_ok!(DappStaking::set_lock_receipts_enabled(
+            RuntimeOrigin::root(),
+            true
+        ));
+        assert_lock(from, 100);
+
+        // Entire lock is transferred, but only the existing receipts are moved
+        assert_transfer_lock(from, to, 400);
+        assert!(!Ledger::<Test>::contains_key(&from));
+        assert!(!LockReceipts::<Test>::contains_key(&from));
+        assert_eq!(LockReceipts::<Test>::get(&to), 100);
     })
 }
 
 #[test]
-fn unlock_after_staked_period_ends_is_ok() {
+fn transfer_lock_fails() {
     ExtBuilder::default().build_and_execute(|| {
-        // Register smart contract, lock&stake some amount
         let smart_contract = MockSmartContract::wasm(1 as AccountId);
         assert_register(1, &smart_contract);
 
-        let account = 2;
-        let amount = 101;
-        assert_lock(account, amount);
-        assert_stake(account, &smart_contract, amount);
-
-        // Advance to the next period, and ensure stake is reset and can be fully unlocked
-        advance_to_next_period();
-        assert!(Ledger::<Test>::get(&account)
-            .staked_amount(ActiveProtocolState::<Test>::get().period_number())
-            .is_zero());
-        assert_unlock(account, amount);
-        assert_eq!(Ledger::<Test>::get(&account).unlocking_amount(), amount);
-    })
-}
-
-#[test]
-fn unstake_from_a_contract_staked_in_past_period_fails() {
-    ExtBuilder::default().build_and_execute(|| {
-        // Register smart contract & lock some amount
-        let smart_contract_1 = MockSmartContract::Wasm(1);
-        let smart_contract_2 = MockSmartContract::Wasm(2);
-        assert_register(1, &smart_contract_1);
-        assert_register(1, &smart_contract_2);
-        let account = 2;
-        assert_lock(account, 300);
+        let (from, to) = (2, 3);
+        let minimum_locked_amount: Balance = <Test as Config>::MinimumLockedAmount::get();
+        assert_lock(from, 300);
+        assert_stake(from, &smart_contract, 200);
 
-        // Stake some amount on the 2nd contract.
-        let stake_amount = 100;
-        assert_stake(account, &smart_contract_2, stake_amount);
+        assert_noop!(
+            DappStaking::transfer_lock(RuntimeOrigin::signed(from), from, 50),
+            Error::<Test>::LockTransferToSelf
+        );
+        assert_noop!(
+            DappStaking::transfer_lock(RuntimeOrigin::signed(from), to, 0),
+            Error::<Test>::ZeroAmount
+        );
 
-        // Advance to the next period, and stake on the 1st contract.
-        advance_to_next_period();
-        for _ in 0..required_number_of_reward_claims(account) {
-            assert_claim_staker_rewards(account);
-        }
+        // Staked amount cannot be transferred
+        assert_noop!(
+            DappStaking::transfer_lock(RuntimeOrigin::signed(from), to, 101),
+            Error::<Test>::InsufficientTransferableLock
+        );
 
-        // Try to unstake from the 2nd contract, which is no longer staked on due to period change.
+        // Sender cannot be left with less than the minimum locked amount
+        assert_unstake(from, &smart_contract, 200);
         assert_noop!(
-            DappStaking::unstake(RuntimeOrigin::signed(account), smart_contract_2, 1,),
-            Error::<Test>::UnstakeFromPastPeriod
+            DappStaking::transfer_lock(
+                RuntimeOrigin::signed(from),
+                to,
+                300 - minimum_locked_amount + 1
+            ),
+            Error::<Test>::InsufficientTransferableLock
         );
 
-        // Staking on the 1st contract should succeed since we haven't staked on it before so there are no bonus rewards to claim
-        assert_stake(account, &smart_contract_1, stake_amount);
+        // Destination must end up with at least the minimum locked amount
+        assert_noop!(
+            DappStaking::transfer_lock(RuntimeOrigin::signed(from), to, minimum_locked_amount - 1),
+            Error::<Test>::LockedAmountBelowThreshold
+        );
 
-        // Even with active stake on the 1st contract, unstake from 2nd should still fail since period change reset its stake.
+        // Destination must be allowed to participate in dApp staking
         assert_noop!(
-            DappStaking::unstake(RuntimeOrigin::signed(account), smart_contract_2, 1,),
-            Error::<Test>::UnstakeFromPastPeriod
+            DappStaking::transfer_lock(RuntimeOrigin::signed(from), BLACKLISTED_ACCOUNT, 100),
+            Error::<Test>::AccountNotAvailableForDappStaking
         );
     })
 }
 
+// Tests a previous bug where extra stake was chipped from the current era info due to a next era total stake larger (likely after a stake)
 #[test]
-fn stake_and_unstake_after_reward_claim_is_ok() {
-    ExtBuilder::default().build_and_execute(|| {
-        // Register smart contract, lock&stake some amount
-        let dev_account = 1;
-        let smart_contract = MockSmartContract::wasm(1 as AccountId);
-        assert_register(dev_account, &smart_contract);
-
-        let account = 2;
-        let amount = 400;
-        assert_lock(account, amount);
-        assert_stake(account, &smart_contract, amount - 100);
-
-        // Advance 2 eras so we have claimable rewards. Both stake & unstake should fail.
-        advance_to_era(ActiveProtocolState::<Test>::get().era + 2);
-        assert_noop!(
-            DappStaking::stake(RuntimeOrigin::signed(account), smart_contract, 1),
-            Error::<Test>::UnclaimedRewards
-        );
-        assert_noop!(
-            DappStaking::unstake(RuntimeOrigin::signed(account), smart_contract, 1),
-            Error::<Test>::UnclaimedRewards
-        );
-
-        // Claim rewards, unstake should work now.
-        for _ in 0..required_number_of_reward_claims(account) {
-            assert_claim_staker_rewards(account);
-        }
-        assert_stake(account, &smart_contract, 1);
-        assert_unstake(account, &smart_contract, 1);
-    })
-}
-
-#[test]
-fn stake_and_unstake_correctly_updates_staked_amounts() {
+fn unstake_from_future_stake_does_not_chip_current_era_stake() {
     ExtBuilder::default().build_and_execute(|| {
-        // Register smart contract
-        let dev_account = 1;
-        let smart_contract = MockSmartContract::wasm(1 as AccountId);
-        assert_register(dev_account, &smart_contract);
-        let smart_contract_id = IntegratedDApps::<Test>::get(&smart_contract).unwrap().id;
+        // Register smart contract 1, lock&stake some amount on 1
+        let contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(1, &contract);
 
-        // Lock & stake some amount by the first staker, and lock some amount by the second staker
         let account_1 = 2;
-        let amount_1 = 50;
-        assert_lock(account_1, amount_1);
-        assert_stake(account_1, &smart_contract, amount_1);
-
         let account_2 = 3;
-        let amount_2 = 10;
-        assert_lock(account_2, amount_2);
-
-        // 1st scenario: repeated stake & unstake in the `Voting` subperiod
-        let contract_stake_snapshot = ContractStake::<Test>::get(&smart_contract_id);
+        let amount = 100;
+        let partial_stake_account_1 = 50;
+        let partial_stake_account_2 = 30;
+        assert_lock(account_1, amount);
+        assert_stake(account_1, &contract, partial_stake_account_1);
+        assert_lock(account_2, amount);
+        assert_stake(account_2, &contract, partial_stake_account_2);
 
-        for _ in 0..20 {
-            assert_stake(account_2, &smart_contract, amount_2);
-            assert_unstake(account_2, &smart_contract, amount_2);
-        }
+        // Advance to B&E subperiod, stake again to check both stakes move and finally unregister source_contract
+        advance_to_next_subperiod();
+        assert_stake(account_1, &contract, amount - partial_stake_account_1);
 
-        // Check that the staked amount for the upcoming era is same as before
-        let current_era = ActiveProtocolState::<Test>::get().era;
-        let period_number = ActiveProtocolState::<Test>::get().period_number();
-        assert_eq!(
-            contract_stake_snapshot
-                .get(current_era + 1, period_number)
-                .expect("Entry must exist."),
-            ContractStake::<Test>::get(&smart_contract_id)
-                .get(current_era + 1, period_number)
-                .expect("Entry must exist."),
-            "Ongoing era staked amount must not change."
+        let unstake_era = ActiveProtocolState::<Test>::get().era;
+        assert_unstake(
+            account_1, &contract, amount, // full unstake
         );
 
-        // 2nd scenario: repeated stake & unstake in the first era of the `Build&Earn` subperiod
-        advance_to_next_era();
-        let contract_stake_snapshot = ContractStake::<Test>::get(&smart_contract_id);
-
-        for _ in 0..20 {
-            assert_stake(account_2, &smart_contract, amount_2);
-            assert_unstake(account_2, &smart_contract, amount_2);
-        }
-
-        // Check that the contract stake snapshot staked amount is the same as before
-        let current_era = ActiveProtocolState::<Test>::get().era;
+        let current_era_info = CurrentEraInfo::<Test>::get();
         assert_eq!(
-            contract_stake_snapshot
-                .get(current_era, period_number)
-                .expect("Entry must exist."),
-            ContractStake::<Test>::get(&smart_contract_id)
-                .get(current_era, period_number)
-                .expect("Entry must exist."),
-            "Ongoing era staked amount must not change."
+            current_era_info.total_staked_amount(), // previously this was fully chipped and set to 0 because partial_stake_account_1 > partial_stake_account_2
+            partial_stake_account_2
         );
-
         assert_eq!(
-            contract_stake_snapshot
-                .get(current_era, period_number)
-                .expect("Entry must exist.")
-                .total(),
-            ContractStake::<Test>::get(&smart_contract_id)
-                .get(current_era + 1, period_number)
-                .expect("Entry must exist.")
-                .total(),
-            "Ongoing era staked amount must be equal to the upcoming era stake."
+            current_era_info.total_staked_amount_next_era(),
+            partial_stake_account_2
         );
 
-        // 3rd scenario: repeated stake & unstake in the second era of the `Build&Earn` subperiod
-        assert_stake(account_2, &smart_contract, amount_2);
-        assert_lock(account_2, amount_2);
-        advance_to_next_era();
-
-        let contract_stake_snapshot = ContractStake::<Test>::get(&smart_contract_id);
+        // Advance 1 era so we have claimable rewards for account_2.
+        advance_to_era(ActiveProtocolState::<Test>::get().era + 1);
+        for _ in 0..required_number_of_reward_claims(3) {
+            assert_claim_staker_rewards(3);
+        }
 
-        for _ in 0..20 {
-            assert_stake(account_2, &smart_contract, amount_2);
-            assert_unstake(account_2, &smart_contract, amount_2);
+        let (_, mut reward_span) = EraRewards::<Test>::iter().next().unwrap();
+        if reward_span.len() == unstake_era as usize {
+            let era_reward: EraReward = reward_span.span.pop().unwrap();
+            assert_eq!(
+                era_reward.staked, // previously this was fully chipped and set to 0 because partial_stake_account_1 > partial_stake_account_2
+                partial_stake_account_2
+            );
         }
+    })
+}
 
-        // Check that the contract stake snapshot staked amount is the same as before
-        let current_era = ActiveProtocolState::<Test>::get().era;
+// Tests bonus stake conversion into regular stake for just forfeited bonus
+#[test]
+fn unstake_forfeited_bonus_to_regular_stake_conversion() {
+    ExtBuilder::default().build_and_execute(|| {
+        // Sanity check - max_moves value must be 0
         assert_eq!(
-            contract_stake_snapshot
-                .get(current_era, period_number)
-                .expect("Entry must exist."),
-            ContractStake::<Test>::get(&smart_contract_id)
-                .get(current_era, period_number)
-                .expect("Entry must exist."),
-            "Ongoing era staked amount must not change."
+            0,
+            <Test as Config>::MaxBonusSafeMovesPerPeriod::get(),
+            "Sanity check"
         );
 
-        // 4th scenario: Unstake with more than was staked for the next era
-        let delta = 5;
-        let amount_3 = amount_2 + delta;
-        assert_stake(account_2, &smart_contract, amount_2);
+        // Prep - Register smart contract, lock&stake some amounts
+        let contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(1, &contract);
 
-        let contract_stake_snapshot = ContractStake::<Test>::get(&smart_contract_id);
-        for _ in 0..20 {
-            assert_unstake(account_2, &smart_contract, amount_3);
-            assert_stake(account_2, &smart_contract, amount_3);
-        }
+        let account = 2;
+        let amount = 100;
+        assert_lock(account, amount);
+        assert_stake(account, &contract, amount);
 
-        // Check that the contract stake snapshot staked amount is the same as before
-        let current_era = ActiveProtocolState::<Test>::get().era;
-        assert_eq!(
-            contract_stake_snapshot
-                .get(current_era, period_number)
-                .expect("Entry must exist.")
-                .total(),
-            ContractStake::<Test>::get(&smart_contract_id)
-                .get(current_era, period_number)
-                .expect("Entry must exist.")
-                .total()
-                + delta,
-            "Ongoing era stake must be reduced by the `delta` amount."
-        );
+        // Advance to B&E subperiod for bonus forfeiting
+        advance_to_next_subperiod();
+        let unstake_ammount = 10;
+        assert_unstake(account, &contract, unstake_ammount);
+
+        let expected_staking_info = SingularStakingInfo {
+            previous_staked: StakeAmount::default(),
+            staked: StakeAmount {
+                voting: amount - unstake_ammount,
+                build_and_earn: 0,
+                era: 2,
+                period: 1,
+            },
+            bonus_status: 0, // bonus has been forfeited
+        };
+
+        let staking_info = StakerInfo::<Test>::get(&account, &contract)
+            .expect("Should exist after a successful move operation");
+        assert_eq!(staking_info, expected_staking_info);
     })
 }
 
 #[test]
-fn stake_after_period_ends_with_max_staked_contracts() {
+fn cleanup_expired_entries_is_ok() {
     ExtBuilder::default().build_and_execute(|| {
-        let max_number_of_contracts: u32 = <Test as Config>::MaxNumberOfStakedContracts::get();
+        // Register smart contracts
+        let contracts: Vec<_> = (1..=5).map(|id| MockSmartContract::Wasm(id)).collect();
+        contracts.iter().for_each(|smart_contract| {
+            assert_register(1, smart_contract);
+        });
+        let account = 2;
+        assert_lock(account, 1000);
 
-        // Lock amount by staker
-        let account = 1;
-        assert_lock(account, 100 as Balance * max_number_of_contracts as Balance);
+        // Scenario:
+        // - 1st contract will be staked in the period that expires due to exceeded reward retention
+        // - 2nd contract will be staked in the period on the edge of expiry, with loyalty flag
+        // - 3rd contract will be be staked in the period on the edge of expiry, without loyalty flag
+        // - 4th contract will be staked in the period right before the current one, with loyalty flag
+        // - 5th contract will be staked in the period right before the current one, without loyalty flag
+        //
+        // Expectation: 1, 3, 5 should be removed, 2 & 4 should remain
 
-        // Register smart contracts up to the max allowed number
-        for id in 1..=max_number_of_contracts {
-            let smart_contract = MockSmartContract::Wasm(id.into());
-            assert_register(2, &smart_contract);
-            assert_stake(account, &smart_contract, 10);
-        }
+        // 1st
+        assert_stake(account, &contracts[0], 13);
 
-        // Advance to the next period, and claim ALL rewards
+        // 2nd & 3rd
         advance_to_next_period();
         for _ in 0..required_number_of_reward_claims(account) {
             assert_claim_staker_rewards(account);
         }
-        for id in 1..=max_number_of_contracts {
-            let smart_contract = MockSmartContract::Wasm(id.into());
-            assert_claim_bonus_reward(account, &smart_contract);
-        }
-
-        // Make sure it's possible to stake again
-        for id in 1..=max_number_of_contracts {
-            let smart_contract = MockSmartContract::Wasm(id.into());
-            assert_stake(account, &smart_contract, 10);
-        }
-    })
-}
+        assert_stake(account, &contracts[1], 17);
+        advance_to_next_subperiod();
 
-#[test]
-fn stake_after_period_ends_reset_bonus_status_is_ok() {
-    ExtBuilder::default().build_and_execute(|| {
-        let default_bonus_status = *BonusStatusWrapperFor::<Test>::default();
+        assert_stake(account, &contracts[2], 19);
 
-        // Phase 1: Register smart contract, lock&stake some amount
-        let dev_account = 1;
-        let smart_contract = MockSmartContract::wasm(1 as AccountId);
-        assert_register(dev_account, &smart_contract);
-
-        let account = 2;
-        let amount = 400;
-        let partial_unstake_amount = 100;
-        assert_lock(account, amount);
-        assert_stake(account, &smart_contract, amount - partial_unstake_amount);
-
-        // Phase 2: Advance to B&E subperiod, we ensure 'bonus status' is decreased with a partial unstake (overflowing 'voting' stake)
-        advance_to_next_subperiod();
-        assert_unstake(account, &smart_contract, partial_unstake_amount);
-
-        let staking_info = StakerInfo::<Test>::get(account, &smart_contract)
-            .expect("Should exist since partial unstake operation was successful.");
-        assert_eq!(staking_info.bonus_status, default_bonus_status - 1);
-
-        // Phase 3: Advance to the next period, claim rewards
-        advance_to_next_period();
+        // 4th & 5th
+        let reward_retention_in_periods: PeriodNumber =
+            <Test as Config>::RewardRetentionInPeriods::get();
+        assert!(
+            reward_retention_in_periods >= 2,
+            "Sanity check, otherwise the test doesn't make sense."
+        );
+        advance_to_period(reward_retention_in_periods + 1);
         for _ in 0..required_number_of_reward_claims(account) {
             assert_claim_staker_rewards(account);
         }
+        assert_stake(account, &contracts[3], 23);
+        advance_to_next_subperiod();
+        assert_stake(account, &contracts[4], 29);
 
-        if default_bonus_status > 1 {
-            assert_claim_bonus_reward(account, &smart_contract);
-        }
+        // Finally do the test
+        advance_to_next_period();
+        assert_cleanup_expired_entries(account);
 
-        // Phase 4: Restake and verify BonusStatus reset
-        assert_stake(account, &smart_contract, partial_unstake_amount);
-        assert_default_bonus_status_after_voting_stake(account, &smart_contract);
+        // Additional sanity check according to the described scenario
+        assert!(!StakerInfo::<Test>::contains_key(account, &contracts[0]));
+        assert!(!StakerInfo::<Test>::contains_key(account, &contracts[2]));
+        assert!(!StakerInfo::<Test>::contains_key(account, &contracts[4]));
+
+        assert!(StakerInfo::<Test>::contains_key(account, &contracts[1]));
+        assert!(StakerInfo::<Test>::contains_key(account, &contracts[3]));
     })
 }
 
 #[test]
-fn post_unlock_balance_cannot_be_transferred() {
+fn cleanup_expired_entries_fails_with_no_entries() {
     ExtBuilder::default().build_and_execute(|| {
-        let staker = 2;
-
-        // Lock some of the free balance
-        let init_free_balance = Balances::free_balance(&staker);
-        let lock_amount = init_free_balance / 3;
-        assert_lock(staker, lock_amount);
-
-        // Make sure second account is empty
-        let other_account = 42;
-        assert_ok!(Balances::write_balance(&other_account, 0));
-
-        // 1. Ensure we can only transfer what is not locked/frozen.
-        assert_ok!(Balances::transfer_all(
-            RuntimeOrigin::signed(staker),
-            other_account,
-            true
-        ));
-        assert_eq!(
-            Balances::free_balance(&other_account),
-            init_free_balance - lock_amount,
-            "Only what is locked can be transferred."
-        );
+        // Register smart contracts
+        let (contract_1, contract_2) = (MockSmartContract::Wasm(1), MockSmartContract::Wasm(2));
+        assert_register(1, &contract_1);
+        assert_register(1, &contract_2);
 
-        // 2. Start the 'unlocking process' for the locked amount, but ensure it still cannot be transferred.
-        assert_unlock(staker, lock_amount);
+        let account = 2;
+        assert_lock(account, 1000);
+        assert_stake(account, &contract_1, 13);
+        assert_stake(account, &contract_2, 17);
 
-        assert_ok!(Balances::write_balance(&other_account, 0));
-        assert_ok!(Balances::transfer_all(
-            RuntimeOrigin::signed(staker),
-            other_account,
-            true
-        ));
+        // Advance only one period, rewards should still be valid.
+        let reward_retention_in_periods: PeriodNumber =
+            <Test as Config>::RewardRetentionInPeriods::get();
         assert!(
-            Balances::free_balance(&other_account).is_zero(),
-            "Nothing could have been transferred since it's still locked/frozen."
+            reward_retention_in_periods >= 1,
+            "Sanity check, otherwise the test doesn't make sense."
         );
+        advance_to_next_period();
 
-        // 3. Claim the unlocked chunk, and ensure it can be transferred afterwards.
-        run_to_block(Ledger::<Test>::get(&staker).unlocking[0].unlock_block);
-        assert_claim_unlocked(staker);
-
-        assert_ok!(Balances::write_balance(&other_account, 0));
-        assert_ok!(Balances::transfer_all(
-            RuntimeOrigin::signed(staker),
-            other_account,
-            false
-        ));
-        assert_eq!(
-            Balances::free_balance(&other_account),
-            lock_amount,
-            "Everything should have been transferred."
+        assert_noop!(
+            DappStaking::cleanup_expired_entries(RuntimeOrigin::signed(account)),
+            Error::<Test>::NoExpiredEntries
         );
-        assert!(Balances::free_balance(&staker).is_zero());
     })
 }
 
 #[test]
-fn observer_pre_new_era_block_works() {
+fn force_era_works() {
     ExtBuilder::default().build_and_execute(|| {
-        fn assert_observer_value(expected: EraNumber) {
-            BLOCK_BEFORE_NEW_ERA.with(|v| assert_eq!(expected, *v.borrow()));
-        }
-
-        // 1. Sanity check
-        assert_observer_value(0);
+        // 1. Force new era in the voting subperiod
+        let init_state = ActiveProtocolState::<Test>::get();
+        assert!(
+            init_state.next_era_start > System::block_number() + 1,
+            "Sanity check, new era cannot start in next block, otherwise the test doesn't guarantee it tests what's expected."
+        );
+        assert_eq!(
+            init_state.subperiod(),
+            Subperiod::Voting,
+            "Sanity check."
+        );
+        assert_ok!(DappStaking::force(RuntimeOrigin::root(), ForcingType::Era));
+        System::assert_last_event(RuntimeEvent::DappStaking(Event::Force {
+            forcing_type: ForcingType::Era,
+        }));
 
-        // 2. Advance to the block right before the observer value should be set.
-        //    No modifications should happen.
-        BLOCK_BEFORE_NEW_ERA.with(|v| {
-            let _lock = v.borrow();
-            run_to_block(ActiveProtocolState::<Test>::get().next_era_start - 2);
-        });
+        // Verify state change
+        assert_eq!(
+            ActiveProtocolState::<Test>::get().next_era_start,
+            System::block_number() + 1,
+        );
+        assert_eq!(
+            ActiveProtocolState::<Test>::get().next_subperiod_start_era(),
+            init_state.next_subperiod_start_era(),
+        );
 
-        // 3. Advance to the next block, when observer value is expected to be set to the next era.
+        // Go to the next block, and ensure new era is started
         run_for_blocks(1);
-        assert_observer_value(2);
-
-        // 4. Advance again, until the same similar scenario
-        BLOCK_BEFORE_NEW_ERA.with(|v| {
-            let _lock = v.borrow();
-            run_for_blocks(1);
-            assert_eq!(
-                ActiveProtocolState::<Test>::get().subperiod(),
-                Subperiod::BuildAndEarn,
-                "Sanity check."
-            );
+        assert_eq!(
+            ActiveProtocolState::<Test>::get().era,
+            init_state.era + 1,
+            "New era must be started."
+        );
+        assert_eq!(
+            ActiveProtocolState::<Test>::get().subperiod(),
+            Subperiod::BuildAndEarn,
+        );
 
-            run_to_block(ActiveProtocolState::<Test>::get().next_era_start - 2);
-            assert_eq!(ActiveProtocolState::<Test>::get().era, 2, "Sanity check.");
-            assert_observer_value(2);
-        });
+        // 2. Force new era in the build&earn subperiod
+        let init_state = ActiveProtocolState::<Test>::get();
+        assert!(
+            init_state.next_era_start > System::block_number() + 1,
+            "Sanity check, new era cannot start in next block, otherwise the test doesn't guarantee it tests what's expected."
+        );
+        assert!(init_state.next_subperiod_start_era() > init_state.era + 1, "Sanity check, otherwise the test doesn't guarantee it tests what's expected.");
+        assert_ok!(DappStaking::force(RuntimeOrigin::root(), ForcingType::Era));
+        System::assert_last_event(RuntimeEvent::DappStaking(Event::Force {
+            forcing_type: ForcingType::Era,
+        }));
 
-        // 5. Again, check that value is set to the expected one.
-        run_for_blocks(1);
-        assert_observer_value(3);
+        // Verify state change
+        assert_eq!(
+            ActiveProtocolState::<Test>::get().next_era_start,
+            System::block_number() + 1,
+        );
+        assert_eq!(
+            ActiveProtocolState::<Test>::get().next_subperiod_start_era(),
+            init_state.next_subperiod_start_era(),
+            "Only era is bumped, but we don't expect to switch over to the next subperiod."
+        );
 
-        // 6. Force new era, and ensure observer value is set to the next one.
         run_for_blocks(1);
-        assert_eq!(ActiveProtocolState::<Test>::get().era, 3, "Sanity check.");
-        assert_ok!(DappStaking::force(RuntimeOrigin::root(), ForcingType::Era));
-        assert_observer_value(4);
+        assert_eq!(
+            ActiveProtocolState::<Test>::get().era,
+            init_state.era + 1,
+            "New era must be started."
+        );
+        assert_eq!(
+            ActiveProtocolState::<Test>::get().subperiod(),
+            Subperiod::BuildAndEarn,
+            "We're expected to remain in the same subperiod."
+        );
     })
 }
 
 #[test]
-fn unregister_after_max_number_of_contracts_allows_register_again() {
+fn force_subperiod_works() {
     ExtBuilder::default().build_and_execute(|| {
-        let max_number_of_contracts = <Test as Config>::MaxNumberOfContracts::get();
-        let developer = 2;
-
-        // Reach max number of contracts
-        for id in 0..max_number_of_contracts {
-            assert_register(developer, &MockSmartContract::Wasm(id.into()));
-        }
+        // 1. Force new subperiod in the voting subperiod
+        let init_state = ActiveProtocolState::<Test>::get();
+        assert!(
+            init_state.next_era_start > System::block_number() + 1,
+            "Sanity check, new era cannot start in next block, otherwise the test doesn't guarantee it tests what's expected."
+        );
+        assert_eq!(
+            init_state.subperiod(),
+            Subperiod::Voting,
+            "Sanity check."
+        );
+        assert_ok!(DappStaking::force(RuntimeOrigin::root(), ForcingType::Subperiod));
+        System::assert_last_event(RuntimeEvent::DappStaking(Event::Force {
+            forcing_type: ForcingType::Subperiod,
+        }));
 
-        // Ensure we cannot register more contracts
-        assert_noop!(
-            DappStaking::register(
-                RuntimeOrigin::root(),
-                developer,
-                MockSmartContract::Wasm((max_number_of_contracts).into())
-            ),
-            Error::<Test>::ExceededMaxNumberOfContracts
+        // Verify state change
+        assert_eq!(
+            ActiveProtocolState::<Test>::get().next_era_start,
+            System::block_number() + 1,
+        );
+        assert_eq!(
+            ActiveProtocolState::<Test>::get().next_subperiod_start_era(),
+            init_state.era + 1,
+            "The switch to the next subperiod must happen in the next era."
         );
 
-        // Unregister one contract, and ensure register works again
-        let smart_contract = MockSmartContract::Wasm(0);
-        assert_unregister(&smart_contract);
-        assert_register(developer, &smart_contract);
-    })
-}
-
-#[test]
-fn safeguard_on_by_default() {
-    use sp_runtime::BuildStorage;
-    let storage = frame_system::GenesisConfig::<Test>::default()
-        .build_storage()
-        .unwrap();
-
-    let mut ext = sp_io::TestExternalities::from(storage);
-    ext.execute_with(|| {
-        assert!(Safeguard::<Test>::get());
-    });
-}
-
-#[test]
-fn safeguard_configurable_by_genesis_config() {
-    use sp_runtime::BuildStorage;
-    let mut genesis_config = GenesisConfig::<Test> {
-        reward_portion: vec![
-            Permill::from_percent(40),
-            Permill::from_percent(30),
-            Permill::from_percent(20),
-            Permill::from_percent(10),
-        ],
-        slot_distribution: vec![
-            Permill::from_percent(10),
-            Permill::from_percent(20),
-            Permill::from_percent(30),
-            Permill::from_percent(40),
-        ],
-        slots_per_tier: vec![10, 20, 30, 40],
-        ..Default::default()
-    };
-
-    // Test case 1: Safeguard enabled via Genesis Config
-    genesis_config.safeguard = Some(true);
-    let storage = genesis_config.build_storage().unwrap();
-    let mut ext = sp_io::TestExternalities::from(storage);
-    ext.execute_with(|| {
-        assert!(Safeguard::<Test>::get());
-    });
-
-    // Test case 2: Safeguard disabled via Genesis Config
-    genesis_config.safeguard = Some(false);
-    let storage = genesis_config.build_storage().unwrap();
-    let mut ext = sp_io::TestExternalities::from(storage);
-    ext.execute_with(|| {
-        assert!(!Safeguard::<Test>::get());
-    });
-
-    // Test case 3: Safeguard not set via Genesis Config
-    genesis_config.safeguard = None;
-    let storage = genesis_config.build_storage().unwrap();
-    let mut ext = sp_io::TestExternalities::from(storage);
-    ext.execute_with(|| {
-        assert!(Safeguard::<Test>::get());
-    });
-}
-
-#[test]
-fn base_number_of_slots_is_respected() {
-    ExtBuilder::default().build_and_execute(|| {
-        assert_ok!(DappStaking::force(RuntimeOrigin::root(), ForcingType::Era));
+        // Go to the next block, and ensure new era is started
         run_for_blocks(1);
+        assert_eq!(
+            ActiveProtocolState::<Test>::get().era,
+            init_state.era + 1,
+            "New era must be started."
+        );
+        assert_eq!(
+            ActiveProtocolState::<Test>::get().subperiod(),
+            Subperiod::BuildAndEarn,
+            "New subperiod must be started."
+        );
+        assert_eq!(ActiveProtocolState::<Test>::get().period_number(), init_state.period_number(), "Period must remain the same.");
 
-        let config_before = TierConfig::<Test>::get();
+        // 2. Force new era in the build&earn subperiod
+        let init_state = ActiveProtocolState::<Test>::get();
+        assert!(
+            init_state.next_era_start > System::block_number() + 1,
+            "Sanity check, new era cannot start in next block, otherwise the test doesn't guarantee it tests what's expected."
+        );
+        assert!(init_state.next_subperiod_start_era() > init_state.era + 1, "Sanity check, otherwise the test doesn't guarantee it tests what's expected.");
+        assert_ok!(DappStaking::force(RuntimeOrigin::root(), ForcingType::Subperiod));
+        System::assert_last_event(RuntimeEvent::DappStaking(Event::Force {
+            forcing_type: ForcingType::Subperiod,
+        }));
+
+        // Verify state change
         assert_eq!(
-            config_before.total_number_of_slots(),
-            FIXED_NUMBER_OF_TIER_SLOTS,
-            "Number of slots must respect the fixed slot limit."
+            ActiveProtocolState::<Test>::get().next_era_start,
+            System::block_number() + 1,
+        );
+        assert_eq!(
+            ActiveProtocolState::<Test>::get().next_subperiod_start_era(),
+            init_state.era + 1,
+            "The switch to the next subperiod must happen in the next era."
         );
 
-        // Force another era and verify config stays the same.
-        assert_ok!(DappStaking::force(RuntimeOrigin::root(), ForcingType::Era));
         run_for_blocks(1);
-
-        let config_after = TierConfig::<Test>::get();
         assert_eq!(
-            config_after.total_number_of_slots(),
-            FIXED_NUMBER_OF_TIER_SLOTS,
-            "Number of slots must remain fixed."
+            ActiveProtocolState::<Test>::get().era,
+            init_state.era + 1,
+            "New era must be started."
         );
         assert_eq!(
-            config_after, config_before,
-            "Tier configuration must remain unchanged."
+            ActiveProtocolState::<Test>::get().subperiod(),
+            Subperiod::Voting,
+            "New subperiod must be started."
         );
+        assert_eq!(ActiveProtocolState::<Test>::get().period_number(), init_state.period_number() + 1, "New period must be started.");
     })
 }
 
 #[test]
-fn ranking_will_calc_reward_correctly() {
+fn force_with_incorrect_origin_fails() {
+    ExtBuilder::default().build_and_execute(|| {
+        assert_noop!(
+            DappStaking::force(RuntimeOrigin::signed(1), ForcingType::Era),
+            BadOrigin
+        );
+    })
+}
+
+#[test]
+fn force_with_safeguard_on_fails() {
+    ExtBuilder::default().build_and_execute(|| {
+        Safeguard::<Test>::put(true);
+        assert_noop!(
+            DappStaking::force(RuntimeOrigin::root(), ForcingType::Era),
+            Error::<Test>::ForceNotAllowed
+        );
+    })
+}
+
+#[test]
+fn get_dapp_tier_assignment_and_rewards_basic_example_works() {
     ExtBuilder::default().build_and_execute(|| {
         // Tier config is specially adapted for this test.
         TierConfig::<Test>::mutate(|config| {
-            config.slots_per_tier = BoundedVec::try_from(vec![2, 3, 2, 20]).unwrap();
+            config.slots_per_tier = BoundedVec::try_from(vec![0, 6, 10, 0]).unwrap();
         });
 
+        // Scenario:
+        // - 1st tier is empty, with 3 dApps satisfying the threshold but not making it due to lack of tier capacity
+        // - 2nd tier has 5 dApps - 3 that could make it into the 1st tier (expected to be rank 10) and 2 that are supposed to be in the 2nd tier
+        // - 3rd tier has no dApps
+        // - 4th tier has no dApps due to lack of tier capacity
+        // - 1 dApp doesn't make it into any tier
+
         // Register smart contracts
-        let smart_contracts: Vec<_> = (1..=8u32)
+        let tier_params = StaticTierParams::<Test>::get();
+        let tier_config = TierConfig::<Test>::get();
+        let number_of_smart_contracts = 0 + 5 + 0 + 2 + 1;
+        let smart_contracts: Vec<_> = (1..=number_of_smart_contracts)
             .map(|x| {
                 let smart_contract = MockSmartContract::Wasm(x.into());
                 assert_register(x.into(), &smart_contract);
                 smart_contract
             })
             .collect();
+        let mut dapp_index: usize = 0;
 
         fn lock_and_stake(account: usize, smart_contract: &MockSmartContract, amount: Balance) {
             let account = account.try_into().unwrap();
@@ -3450,1067 +3737,3701 @@ fn ranking_will_calc_reward_correctly() {
             assert_stake(account, smart_contract, amount);
         }
 
-        for (idx, amount) in [101, 102, 100, 99, 15, 49, 35, 14].into_iter().enumerate() {
-            lock_and_stake(idx, &smart_contracts[idx], amount)
+        // 2nd tier - 3 that won't make it into the 1st tier due to lack of tier capacity.
+        lock_and_stake(
+            dapp_index,
+            &smart_contracts[dapp_index],
+            tier_config.tier_thresholds[0] + 2,
+        );
+        dapp_index += 1;
+        lock_and_stake(
+            dapp_index,
+            &smart_contracts[dapp_index],
+            tier_config.tier_thresholds[0] + 1,
+        );
+        dapp_index += 1;
+        lock_and_stake(
+            dapp_index,
+            &smart_contracts[dapp_index],
+            tier_config.tier_thresholds[0],
+        );
+        dapp_index += 1;
+
+        // 2nd tier - 2 dedicated dApp
+        lock_and_stake(
+            dapp_index,
+            &smart_contracts[dapp_index],
+            tier_config.tier_thresholds[0] - 1,
+        );
+        dapp_index += 1;
+        lock_and_stake(
+            dapp_index,
+            &smart_contracts[dapp_index],
+            tier_config.tier_thresholds[0] - 2,
+        );
+        dapp_index += 1;
+
+        // 3rd tier is empty
+        // 4th tier has 2 dApps
+        for x in 0..2 {
+            lock_and_stake(
+                dapp_index,
+                &smart_contracts[dapp_index],
+                tier_config.tier_thresholds[3] + x,
+            );
+            dapp_index += 1;
         }
 
+        // One dApp doesn't make it into any tier
+        lock_and_stake(
+            dapp_index,
+            &smart_contracts[dapp_index],
+            tier_config.tier_thresholds[3] - 1,
+        );
+
         // Finally, the actual test
         let protocol_state = ActiveProtocolState::<Test>::get();
+        let dapp_reward_pool: Balance = 1_000_000;
         let (tier_assignment, counter) = DappStaking::get_dapp_tier_assignment_and_rewards(
             protocol_state.era + 1,
             protocol_state.period_number(),
-            1_000_000,
+            dapp_reward_pool,
         );
 
         assert_eq!(
-            tier_assignment,
-            DAppTierRewardsFor::<Test> {
-                dapps: BoundedBTreeMap::try_from(BTreeMap::from([
-                    (0, RankedTier::new_saturated(0, 0)),
-                    (1, RankedTier::new_saturated(0, 0)),
-                    (2, RankedTier::new_saturated(1, 10)),
-                    (3, RankedTier::new_saturated(1, 9)),
-                    (5, RankedTier::new_saturated(2, 9)),
-                    (6, RankedTier::new_saturated(2, 5)),
-                    (4, RankedTier::new_saturated(3, 0)),
-                ]))
-                .unwrap(),
-                rewards: BoundedVec::try_from(vec![200_000, 58_823, 28_019, 5_000]).unwrap(),
-                period: 1,
-                // Tier 0 has no ranking multiplier therefore no rank reward.
-                // Tier 3 has no ranking multiplier therefore no rank reward.
-                rank_rewards: BoundedVec::try_from(vec![0, 8_235, 10_282, 0]).unwrap()
-            }
+            tier_assignment.rank_rewards,
+            BoundedVec::<Balance, ConstU32<4>>::try_from(vec![0, 3583, 0, 0]).unwrap()
         );
 
-        // one didn't make it
-        assert_eq!(counter, 8);
-    })
-}
-
-#[test]
-fn claim_dapp_reward_with_rank() {
-    ExtBuilder::default().build_and_execute(|| {
-        // Register smart contract
-        let smart_contract = MockSmartContract::wasm(1 as AccountId);
-        assert_register(1, &smart_contract);
+        // Basic checks
+        let number_of_tiers: u32 = <Test as Config>::NumberOfTiers::get();
+        assert_eq!(tier_assignment.period, protocol_state.period_number());
+        assert_eq!(tier_assignment.rewards.len(), number_of_tiers as usize);
+        assert_eq!(
+            tier_assignment.dapps.len(),
+            number_of_smart_contracts as usize - 3,
+            "Three contract doesn't make it into any tier."
+        );
+        assert_eq!(counter, number_of_smart_contracts);
 
-        let alice = 2;
-        // Stake amount that puts dApp in Tier 1 with a specific rank
-        // Assuming tier_0=100, tier_1=50
-        // Stake 75 → rank = (75-50)/5 = 5
-        let amount = 75;
-        assert_lock(alice, amount);
-        assert_stake(alice, &smart_contract, amount);
-
-        // Advance 2 eras for reward claiming
-        advance_to_era(ActiveProtocolState::<Test>::get().era + 2);
-
-        let era = ActiveProtocolState::<Test>::get().era - 1;
-        let tiers = DAppTiers::<Test>::get(era).unwrap();
-
-        // Verify tier assignment
-        let ranked_tier = tiers.dapps.get(&0).unwrap();
-        assert_eq!(ranked_tier.tier(), 1, "Should be in tier 1");
+        // 2nd tier checks
+        let (dapp_1_tier, dapp_2_tier, dapp_3_tier, dapp_4_tier, dapp_5_tier) = (
+            tier_assignment.dapps[&0],
+            tier_assignment.dapps[&1],
+            tier_assignment.dapps[&2],
+            tier_assignment.dapps[&3],
+            tier_assignment.dapps[&4],
+        );
+        assert_eq!(dapp_1_tier, RankedTier::new_saturated(1, 10));
+        assert_eq!(dapp_2_tier, RankedTier::new_saturated(1, 10));
+        assert_eq!(dapp_3_tier, RankedTier::new_saturated(1, 10));
+        assert_eq!(dapp_4_tier, RankedTier::new_saturated(1, 9));
+        assert_eq!(dapp_5_tier, RankedTier::new_saturated(1, 9));
 
-        let expected_rank = 5; // (75-50)/(50/10) = 25/5 = 5
-        assert_eq!(ranked_tier.rank(), expected_rank);
+        // === Verify reward calculations ===
+        // Tier 0: 40% of 1M = 400,000 ASTR
+        //   multiplier = 0% → compute_tier_rewards returns (0, 0)
+        assert_eq!(tier_assignment.rewards[0], 0);
+        assert_eq!(tier_assignment.rank_rewards[0], 0);
 
-        let base_reward = tiers.rewards[1];
-        let rank_reward = tiers.rank_rewards[1];
+        // Tier 1: 30% of 1M = 300,000 ASTR
+        let tier_1_allocation = Permill::from_percent(30) * dapp_reward_pool;
+        let tier_1_slots = tier_config.slots_per_tier[1];
+        let tier_1_filled = 5u32;
+        let tier_1_ranks_sum = 10u32 + 10 + 10 + 9 + 9; // 48
+        let tier_1_multiplier = tier_params.tier_rank_multipliers[1];
+        let (expected_reward_1, expected_rank_reward_1) = DappStaking::compute_tier_rewards(
+            tier_1_allocation,
+            tier_1_slots,
+            tier_1_filled,
+            tier_1_ranks_sum,
+            tier_1_multiplier,
+        );
+        assert_eq!(tier_assignment.rewards[1], expected_reward_1);
+        assert_eq!(tier_assignment.rank_rewards[1], expected_rank_reward_1);
 
-        // Calculate expected total reward
-        let expected_total_reward = base_reward + expected_rank as Balance * rank_reward;
+        // Tier 2: 20% of 1M = 200,000 ASTR
+        // empty tier (unminted rewards)
+        assert_eq!(tier_assignment.rewards[2], 0);
+        assert_eq!(tier_assignment.rank_rewards[2], 0);
 
-        // Claim dApp reward
-        assert_ok!(DappStaking::claim_dapp_reward(
-            RuntimeOrigin::signed(alice),
-            smart_contract.clone(),
-            era,
-        ));
+        // Tier 3: 10% of 1M = 100,000 ASTR
+        let tier_3_allocation = Permill::from_percent(10) * dapp_reward_pool;
+        let tier_3_slots = tier_config.slots_per_tier[3];
+        let tier_3_filled = 2u32;
+        let tier_3_ranks_sum = 0u32;
+        let tier_3_multiplier = tier_params.tier_rank_multipliers[3];
+        let (expected_reward_3, expected_rank_reward_3) = DappStaking::compute_tier_rewards(
+            tier_3_allocation,
+            tier_3_slots,
+            tier_3_filled,
+            tier_3_ranks_sum,
+            tier_3_multiplier,
+        );
+        assert_eq!(tier_assignment.rewards[3], expected_reward_3);
+        assert_eq!(tier_assignment.rank_rewards[3], expected_rank_reward_3);
 
-        System::assert_last_event(RuntimeEvent::DappStaking(Event::DAppReward {
-            beneficiary: 1,
-            smart_contract: smart_contract.clone(),
-            tier_id: 1,
-            rank: expected_rank,
-            era,
-            amount: expected_total_reward,
-        }));
+        // === Verify claim formula produces capped total rewards ===
+        let dapp_0_reward = tier_assignment.rewards[1] + 10 * tier_assignment.rank_rewards[1];
+        let dapp_1_reward = tier_assignment.rewards[1] + 10 * tier_assignment.rank_rewards[1];
+        let dapp_2_reward = tier_assignment.rewards[1] + 10 * tier_assignment.rank_rewards[1];
+        let dapp_3_reward = tier_assignment.rewards[1] + 9 * tier_assignment.rank_rewards[1];
+        let dapp_4_reward = tier_assignment.rewards[1] + 9 * tier_assignment.rank_rewards[1];
+        let tier_1_total_disbursed =
+            dapp_0_reward + dapp_1_reward + dapp_2_reward + dapp_3_reward + dapp_4_reward;
+        assert!(tier_1_total_disbursed <= tier_1_allocation);
     })
 }
 
 #[test]
-fn unstake_correctly_reduces_future_contract_stake() {
+fn get_dapp_tier_assignment_and_rewards_zero_slots_per_tier_works() {
     ExtBuilder::default().build_and_execute(|| {
-        // 0. Register smart contract, lock&stake some amount with staker 1 during the voting subperiod
-        let smart_contract = MockSmartContract::wasm(1 as AccountId);
-        assert_register(1, &smart_contract);
-
-        let (staker_1, amount_1) = (1, 29);
-        assert_lock(staker_1, amount_1);
-        assert_stake(staker_1, &smart_contract, amount_1);
+        // This test will rely on the configuration inside the mock file.
+        // If that changes, this test might have to be updated as well.
 
-        // 1. Advance to the build&earn subperiod, stake some amount with staker 2
-        advance_to_next_era();
-        let (staker_2, amount_2) = (2, 11);
-        assert_lock(staker_2, amount_2);
-        assert_stake(staker_2, &smart_contract, amount_2);
+        // Ensure that first tier has 0 slots.
+        TierConfig::<Test>::mutate(|config| {
+            config.slots_per_tier[0] = 0;
+        });
 
-        // 2. Advance a few eras, creating a gap but remaining within the same period.
-        //    Claim all rewards for staker 1.
-        //    Lock & stake some amount with staker 3.
-        advance_to_era(ActiveProtocolState::<Test>::get().era + 3);
-        assert_eq!(
-            ActiveProtocolState::<Test>::get().period_number(),
-            1,
-            "Sanity check."
+        // Calculate tier assignment (we don't need dApps for this test)
+        let protocol_state = ActiveProtocolState::<Test>::get();
+        let dapp_reward_pool = 1000000;
+        let (tier_assignment, counter) = DappStaking::get_dapp_tier_assignment_and_rewards(
+            protocol_state.era,
+            protocol_state.period_number(),
+            dapp_reward_pool,
         );
-        for _ in 0..required_number_of_reward_claims(staker_1) {
-            assert_claim_staker_rewards(staker_1);
-        }
 
-        // This ensures contract stake entry is aligned to the current era, and future entry refers to the era after this one.
-        //
-        // This is important to reproduce an issue where the (era, amount) pairs returned by the `unstake` function don't correctly
-        // cover the next era.
-        let (staker_3, amount_3) = (3, 13);
-        assert_lock(staker_3, amount_3);
-        assert_stake(staker_3, &smart_contract, amount_3);
+        // Basic checks
+        let number_of_tiers: u32 = <Test as Config>::NumberOfTiers::get();
+        assert_eq!(tier_assignment.period, protocol_state.period_number());
+        assert_eq!(tier_assignment.rewards.len(), number_of_tiers as usize);
+        assert!(tier_assignment.dapps.is_empty());
+        assert!(counter.is_zero());
 
-        // 3. Unstake from staker 1, and ensure the future stake is reduced.
-        //    Unstake amount should be slightly higher than the 2nd stake amount to ensure whole b&e stake amount is removed.
-        assert_unstake(staker_1, &smart_contract, amount_2 + 3);
+        assert!(
+            tier_assignment.rewards[0].is_zero(),
+            "1st tier has no slots so no rewards should be assigned to it."
+        );
+        assert!(
+            tier_assignment.rank_rewards[0].is_zero(),
+            "1st tier has no slots so no rank rewards should be assigned."
+        );
+
+        // Regardless of that, other tiers shouldn't benefit from this
+        assert!(tier_assignment.rewards.iter().sum::<Balance>() < dapp_reward_pool);
     })
 }
 
 #[test]
-fn lock_correctly_considers_unlocking_amount() {
+fn advance_for_some_periods_works() {
     ExtBuilder::default().build_and_execute(|| {
-        // Lock the entire amount & immediately start the unlocking process
-        let (staker, unlock_amount) = (1, 13);
-        let total_balance = Balances::total_balance(&staker);
-        assert_lock(staker, total_balance);
-        assert_unlock(staker, unlock_amount);
-
-        assert_noop!(
-            DappStaking::lock(RuntimeOrigin::signed(staker), 1),
-            Error::<Test>::ZeroAmount
-        );
+        advance_to_period(10);
     })
 }
 
 #[test]
-fn claim_staker_rewards_for_basic_example_is_ok() {
+fn unlock_after_staked_period_ends_is_ok() {
     ExtBuilder::default().build_and_execute(|| {
         // Register smart contract, lock&stake some amount
-        let dev_account = 1;
         let smart_contract = MockSmartContract::wasm(1 as AccountId);
-        assert_register(dev_account, &smart_contract);
+        assert_register(1, &smart_contract);
 
-        let staker_account = 2;
-        let lock_amount = 300;
-        assert_lock(staker_account, lock_amount);
-        let stake_amount = 93;
-        assert_stake(staker_account, &smart_contract, stake_amount);
+        let account = 2;
+        let amount = 101;
+        assert_lock(account, amount);
+        assert_stake(account, &smart_contract, amount);
 
-        // Advance into Build&Earn period, and allow one era to pass. Claim reward for 1 era.
-        advance_to_era(ActiveProtocolState::<Test>::get().era + 2);
+        // Advance to the next period, and ensure stake is reset and can be fully unlocked
+        advance_to_next_period();
+        assert!(Ledger::<Test>::get(&account)
+            .staked_amount(ActiveProtocolState::<Test>::get().period_number())
+            .is_zero());
+        assert_unlock(account, amount);
+        assert_eq!(Ledger::<Test>::get(&account).unlocking_amount(), amount);
+    })
+}
 
-        // Basic checks, since the entire claim logic is already covered by other tests
-        let claimer_account = 3;
-        let (init_staker_balance, init_claimer_balance) = (
-            Balances::free_balance(&staker_account),
-            Balances::free_balance(&claimer_account),
-        );
-        assert_ok!(DappStaking::claim_staker_rewards_for(
-            RuntimeOrigin::signed(claimer_account),
-            staker_account
-        ));
-        System::assert_last_event(RuntimeEvent::DappStaking(Event::Reward {
-            account: staker_account,
-            era: ActiveProtocolState::<Test>::get().era - 1,
-            // for this simple test, entire staker reward pool goes to the staker
-            amount: <Test as Config>::StakingRewardHandler::staker_and_dapp_reward_pools(0).0,
-        }));
+#[test]
+fn unstake_from_a_contract_staked_in_past_period_fails() {
+    ExtBuilder::default().build_and_execute(|| {
+        // Register smart contract & lock some amount
+        let smart_contract_1 = MockSmartContract::Wasm(1);
+        let smart_contract_2 = MockSmartContract::Wasm(2);
+        assert_register(1, &smart_contract_1);
+        assert_register(1, &smart_contract_2);
+        let account = 2;
+        assert_lock(account, 300);
 
-        assert!(
-            Balances::free_balance(&staker_account) > init_staker_balance,
-            "Balance must have increased due to the reward payout."
+        // Stake some amount on the 2nd contract.
+        let stake_amount = 100;
+        assert_stake(account, &smart_contract_2, stake_amount);
+
+        // Advance to the next period, and stake on the 1st contract.
+        advance_to_next_period();
+        for _ in 0..required_number_of_reward_claims(account) {
+            assert_claim_staker_rewards(account);
+        }
+
+        // Try to unstake from the 2nd contract, which is no longer staked on due to period change.
+        assert_noop!(
+            DappStaking::unstake(RuntimeOrigin::signed(account), smart_contract_2, 1,),
+            Error::<Test>::UnstakeFromPastPeriod
         );
-        assert_eq!(
-            init_claimer_balance,
-            Balances::free_balance(&claimer_account),
-            "Claimer balance must not change since reward is deposited to the staker."
+
+        // Staking on the 1st contract should succeed since we haven't staked on it before so there are no bonus rewards to claim
+        assert_stake(account, &smart_contract_1, stake_amount);
+
+        // Even with active stake on the 1st contract, unstake from 2nd should still fail since period change reset its stake.
+        assert_noop!(
+            DappStaking::unstake(RuntimeOrigin::signed(account), smart_contract_2, 1,),
+            Error::<Test>::UnstakeFromPastPeriod
         );
     })
 }
 
 #[test]
-fn claim_bonus_reward_for_works() {
+fn stake_and_unstake_after_reward_claim_is_ok() {
     ExtBuilder::default().build_and_execute(|| {
         // Register smart contract, lock&stake some amount
         let dev_account = 1;
         let smart_contract = MockSmartContract::wasm(1 as AccountId);
         assert_register(dev_account, &smart_contract);
 
-        let staker_account = 2;
-        let lock_amount = 300;
-        assert_lock(staker_account, lock_amount);
-        let stake_amount = 93;
-        assert_stake(staker_account, &smart_contract, stake_amount);
+        let account = 2;
+        let amount = 400;
+        assert_lock(account, amount);
+        assert_stake(account, &smart_contract, amount - 100);
 
-        // Advance to the next period, and claim the bonus
-        advance_to_next_period();
-        let claimer_account = 3;
-        let (init_staker_balance, init_claimer_balance) = (
-            Balances::free_balance(&staker_account),
-            Balances::free_balance(&claimer_account),
+        // Advance 2 eras so we have claimable rewards. Both stake & unstake should fail.
+        advance_to_era(ActiveProtocolState::<Test>::get().era + 2);
+        assert_noop!(
+            DappStaking::stake(RuntimeOrigin::signed(account), smart_contract, 1),
+            Error::<Test>::UnclaimedRewards
+        );
+        assert_noop!(
+            DappStaking::unstake(RuntimeOrigin::signed(account), smart_contract, 1),
+            Error::<Test>::UnclaimedRewards
         );
 
-        assert_ok!(DappStaking::claim_bonus_reward_for(
-            RuntimeOrigin::signed(claimer_account),
-            staker_account,
-            smart_contract.clone()
-        ));
-        System::assert_last_event(RuntimeEvent::DappStaking(Event::BonusReward {
-            account: staker_account,
-            period: ActiveProtocolState::<Test>::get().period_number() - 1,
-            smart_contract,
-            // for this simple test, entire bonus reward pool goes to the staker
-            amount: <Test as Config>::StakingRewardHandler::bonus_reward_pool(),
-        }));
+        // Claim rewards, unstake should work now.
+        for _ in 0..required_number_of_reward_claims(account) {
+            assert_claim_staker_rewards(account);
+        }
+        assert_stake(account, &smart_contract, 1);
+        assert_unstake(account, &smart_contract, 1);
+    })
+}
 
-        assert!(
-            Balances::free_balance(&staker_account) > init_staker_balance,
-            "Balance must have increased due to the reward payout."
+#[test]
+fn stake_and_unstake_correctly_updates_staked_amounts() {
+    ExtBuilder::default().build_and_execute(|| {
+        // Register smart contract
+        let dev_account = 1;
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(dev_account, &smart_contract);
+        let smart_contract_id = IntegratedDApps::<Test>::get(&smart_contract).unwrap().id;
+
+        // Lock & stake some amount by the first staker, and lock some amount by the second staker
+        let account_1 = 2;
+        let amount_1 = 50;
+        assert_lock(account_1, amount_1);
+        assert_stake(account_1, &smart_contract, amount_1);
+
+        let account_2 = 3;
+        let amount_2 = 10;
+        assert_lock(account_2, amount_2);
+
+        // 1st scenario: repeated stake & unstake in the `Voting` subperiod
+        let contract_stake_snapshot = ContractStake::<Test>::get(&smart_contract_id);
+
+        for _ in 0..20 {
+            assert_stake(account_2, &smart_contract, amount_2);
+            assert_unstake(account_2, &smart_contract, amount_2);
+        }
+
+        // Check that the staked amount for the upcoming era is same as before
+        let current_era = ActiveProtocolState::<Test>::get().era;
+        let period_number = ActiveProtocolState::<Test>::get().period_number();
+        assert_eq!(
+            contract_stake_snapshot
+                .get(current_era + 1, period_number)
+                .expect("Entry must exist."),
+            ContractStake::<Test>::get(&smart_contract_id)
+                .get(current_era + 1, period_number)
+                .expect("Entry must exist."),
+            "Ongoing era staked amount must not change."
+        );
+
+        // 2nd scenario: repeated stake & unstake in the first era of the `Build&Earn` subperiod
+        advance_to_next_era();
+        let contract_stake_snapshot = ContractStake::<Test>::get(&smart_contract_id);
+
+        for _ in 0..20 {
+            assert_stake(account_2, &smart_contract, amount_2);
+            assert_unstake(account_2, &smart_contract, amount_2);
+        }
+
+        // Check that the contract stake snapshot staked amount is the same as before
+        let current_era = ActiveProtocolState::<Test>::get().era;
+        assert_eq!(
+            contract_stake_snapshot
+                .get(current_era, period_number)
+                .expect("Entry must exist."),
+            ContractStake::<Test>::get(&smart_contract_id)
+                .get(current_era, period_number)
+                .expect("Entry must exist."),
+            "Ongoing era staked amount must not change."
         );
+
         assert_eq!(
-            init_claimer_balance,
-            Balances::free_balance(&claimer_account),
-            "Claimer balance must not change since reward is deposited to the staker."
+            contract_stake_snapshot
+                .get(current_era, period_number)
+                .expect("Entry must exist.")
+                .total(),
+            ContractStake::<Test>::get(&smart_contract_id)
+                .get(current_era + 1, period_number)
+                .expect("Entry must exist.")
+                .total(),
+            "Ongoing era staked amount must be equal to the upcoming era stake."
+        );
+
+        // 3rd scenario: repeated stake & unstake in the second era of the `Build&Earn` subperiod
+        assert_stake(account_2, &smart_contract, amount_2);
+        assert_lock(account_2, amount_2);
+        advance_to_next_era();
+
+        let contract_stake_snapshot = ContractStake::<Test>::get(&smart_contract_id);
+
+        for _ in 0..20 {
+            assert_stake(account_2, &smart_contract, amount_2);
+            assert_unstake(account_2, &smart_contract, amount_2);
+        }
+
+        // Check that the contract stake snapshot staked amount is the same as before
+        let current_era = ActiveProtocolState::<Test>::get().era;
+        assert_eq!(
+            contract_stake_snapshot
+                .get(current_era, period_number)
+                .expect("Entry must exist."),
+            ContractStake::<Test>::get(&smart_contract_id)
+                .get(current_era, period_number)
+                .expect("Entry must exist."),
+            "Ongoing era staked amount must not change."
+        );
+
+        // 4th scenario: Unstake with more than was staked for the next era
+        let delta = 5;
+        let amount_3 = amount_2 + delta;
+        assert_stake(account_2, &smart_contract, amount_2);
+
+        let contract_stake_snapshot = ContractStake::<Test>::get(&smart_contract_id);
+        for _ in 0..20 {
+            assert_unstake(account_2, &smart_contract, amount_3);
+            assert_stake(account_2, &smart_contract, amount_3);
+        }
+
+        // Check that the contract stake snapshot staked amount is the same as before
+        let current_era = ActiveProtocolState::<Test>::get().era;
+        assert_eq!(
+            contract_stake_snapshot
+                .get(current_era, period_number)
+                .expect("Entry must exist.")
+                .total(),
+            ContractStake::<Test>::get(&smart_contract_id)
+                .get(current_era, period_number)
+                .expect("Entry must exist.")
+                .total()
+                + delta,
+            "Ongoing era stake must be reduced by the `delta` amount."
         );
     })
 }
 
 #[test]
-fn set_static_tier_params_incorrect_origin_fails() {
+fn stake_after_period_ends_with_max_staked_contracts() {
     ExtBuilder::default().build_and_execute(|| {
-        let tier_params = StaticTierParams::<Test>::get();
-        assert_noop!(
-            DappStaking::set_static_tier_params(RuntimeOrigin::signed(1), tier_params),
-            BadOrigin
-        );
+        let max_number_of_contracts: u32 = <Test as Config>::MaxNumberOfStakedContracts::get();
+
+        // Lock amount by staker
+        let account = 1;
+        assert_lock(account, 100 as Balance * max_number_of_contracts as Balance);
+
+        // Register smart contracts up to the max allowed number
+        for id in 1..=max_number_of_contracts {
+            let smart_contract = MockSmartContract::Wasm(id.into());
+            assert_register(2, &smart_contract);
+            assert_stake(account, &smart_contract, 10);
+        }
+
+        // Advance to the next period, and claim ALL rewards
+        advance_to_next_period();
+        for _ in 0..required_number_of_reward_claims(account) {
+            assert_claim_staker_rewards(account);
+        }
+        for id in 1..=max_number_of_contracts {
+            let smart_contract = MockSmartContract::Wasm(id.into());
+            assert_claim_bonus_reward(account, &smart_contract);
+        }
+
+        // Make sure it's possible to stake again
+        for id in 1..=max_number_of_contracts {
+            let smart_contract = MockSmartContract::Wasm(id.into());
+            assert_stake(account, &smart_contract, 10);
+        }
     })
 }
 
-// Tests moving stakes from unregistered contracts to another contract while verifying that:
-// - All staked funds are moved from the unregistered contracts.
-// - The bonus_status is preserved during the move from an unregistered contract.
-// - Destination stake is successfully created if entry does not exist yet.
 #[test]
-fn move_stake_from_unregistered_contract_is_ok() {
+fn stake_after_period_ends_reset_bonus_status_is_ok() {
     ExtBuilder::default().build_and_execute(|| {
-        // Register smart contracts 1 & 2, lock&stake some amount on 1, unregister the smart contract 1
-        let source_contract = MockSmartContract::wasm(1 as AccountId);
-        let dest_contract = MockSmartContract::wasm(2 as AccountId);
-        assert_register(1, &source_contract);
-        assert_register(1, &dest_contract);
+        let default_bonus_status = *BonusStatusWrapperFor::<Test>::default();
+
+        // Phase 1: Register smart contract, lock&stake some amount
+        let dev_account = 1;
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(dev_account, &smart_contract);
 
         let account = 2;
-        let amount = 300;
-        let partial_stake_1 = 200;
-        let partial_stake_2 = 100;
+        let amount = 400;
+        let partial_unstake_amount = 100;
         assert_lock(account, amount);
-        assert_stake(account, &source_contract, partial_stake_1);
+        assert_stake(account, &smart_contract, amount - partial_unstake_amount);
+
+        // Phase 2: Advance to B&E subperiod, we ensure 'bonus status' is decreased with a partial unstake (overflowing 'voting' stake)
+        advance_to_next_subperiod();
+        assert_unstake(account, &smart_contract, partial_unstake_amount);
+
+        let staking_info = StakerInfo::<Test>::get(account, &smart_contract)
+            .expect("Should exist since partial unstake operation was successful.");
+        assert_eq!(staking_info.bonus_status, default_bonus_status - 1);
+
+        // Phase 3: Advance to the next period, claim rewards
+        advance_to_next_period();
+        for _ in 0..required_number_of_reward_claims(account) {
+            assert_claim_staker_rewards(account);
+        }
+
+        if default_bonus_status > 1 {
+            assert_claim_bonus_reward(account, &smart_contract);
+        }
+
+        // Phase 4: Restake and verify BonusStatus reset
+        assert_stake(account, &smart_contract, partial_unstake_amount);
+        assert_default_bonus_status_after_voting_stake(account, &smart_contract);
+    })
+}
+
+#[test]
+fn post_unlock_balance_cannot_be_transferred() {
+    ExtBuilder::default().build_and_execute(|| {
+        let staker = 2;
+
+        // Lock some of the free balance
+        let init_free_balance = Balances::free_balance(&staker);
+        let lock_amount = init_free_balance / 3;
+        assert_lock(staker, lock_amount);
+
+        // Make sure second account is empty
+        let other_account = 42;
+        assert_ok!(Balances::write_balance(&other_account, 0));
+
+        // 1. Ensure we can only transfer what is not locked/frozen.
+        assert_ok!(Balances::transfer_all(
+            RuntimeOrigin::signed(staker),
+            other_account,
+            true
+        ));
+        assert_eq!(
+            Balances::free_balance(&other_account),
+            init_free_balance - lock_amount,
+            "Only what is locked can be transferred."
+        );
+
+        // 2. Start the 'unlocking process' for the locked amount, but ensure it still cannot be transferred.
+        assert_unlock(staker, lock_amount);
+
+        assert_ok!(Balances::write_balance(&other_account, 0));
+        assert_ok!(Balances::transfer_all(
+            RuntimeOrigin::signed(staker),
+            other_account,
+            true
+        ));
+        assert!(
+            Balances::free_balance(&other_account).is_zero(),
+            "Nothing could have been transferred since it's still locked/frozen."
+        );
+
+        // 3. Claim the unlocked chunk, and ensure it can be transferred afterwards.
+        run_to_block(Ledger::<Test>::get(&staker).unlocking[0].unlock_block);
+        assert_claim_unlocked(staker);
+
+        assert_ok!(Balances::write_balance(&other_account, 0));
+        assert_ok!(Balances::transfer_all(
+            RuntimeOrigin::signed(staker),
+            other_account,
+            false
+        ));
+        assert_eq!(
+            Balances::free_balance(&other_account),
+            lock_amount,
+            "Everything should have been transferred."
+        );
+        assert!(Balances::free_balance(&staker).is_zero());
+    })
+}
+
+#[test]
+fn observer_pre_new_era_block_works() {
+    ExtBuilder::default().build_and_execute(|| {
+        fn assert_observer_value(expected: EraNumber) {
+            BLOCK_BEFORE_NEW_ERA.with(|v| assert_eq!(expected, *v.borrow()));
+        }
+
+        // 1. Sanity check
+        assert_observer_value(0);
+
+        // 2. Advance to the block right before the observer value should be set.
+        //    No modifications should happen.
+        BLOCK_BEFORE_NEW_ERA.with(|v| {
+            let _lock = v.borrow();
+            run_to_block(ActiveProtocolState::<Test>::get().next_era_start - 2);
+        });
+
+        // 3. Advance to the next block, when observer value is expected to be set to the next era.
+        run_for_blocks(1);
+        assert_observer_value(2);
+
+        // 4. Advance again, until the same similar scenario
+        BLOCK_BEFORE_NEW_ERA.with(|v| {
+            let _lock = v.borrow();
+            run_for_blocks(1);
+            assert_eq!(
+                ActiveProtocolState::<Test>::get().subperiod(),
+                Subperiod::BuildAndEarn,
+                "Sanity check."
+            );
+
+            run_to_block(ActiveProtocolState::<Test>::get().next_era_start - 2);
+            assert_eq!(ActiveProtocolState::<Test>::get().era, 2, "Sanity check.");
+            assert_observer_value(2);
+        });
+
+        // 5. Again, check that value is set to the expected one.
+        run_for_blocks(1);
+        assert_observer_value(3);
+
+        // 6. Force new era, and ensure observer value is set to the next one.
+        run_for_blocks(1);
+        assert_eq!(ActiveProtocolState::<Test>::get().era, 3, "Sanity check.");
+        assert_ok!(DappStaking::force(RuntimeOrigin::root(), ForcingType::Era));
+        assert_observer_value(4);
+    })
+}
+
+#[test]
+fn observer_staking_and_reward_hooks_work() {
+    ExtBuilder::default().build_and_execute(|| {
+        let _ = take_observer_calls();
+
+        // Register smart contract, lock & stake some amount
+        let dev_account = 1;
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(dev_account, &smart_contract);
+
+        let account = 2;
+        assert_lock(account, 300);
+
+        // 1. Stake & unstake, and verify observers are notified, with their weight being accounted for
+        let stake_weight = <Test as Config>::WeightInfo::stake();
+        assert_eq!(
+            Call::<Test>::stake {
+                smart_contract: smart_contract.clone(),
+                amount: 200,
+            }
+            .get_dispatch_info()
+            .call_weight,
+            stake_weight.saturating_add(OBSERVER_CALL_WEIGHT)
+        );
+        let post_info = DappStaking::stake(
+            RuntimeOrigin::signed(account),
+            smart_contract.clone(),
+            200,
+        )
+        .expect("Stake must succeed.");
+        assert_eq!(
+            post_info.actual_weight,
+            Some(stake_weight.saturating_add(OBSERVER_CALL_WEIGHT))
+        );
+        assert_unstake(account, &smart_contract, 50);
+        assert_eq!(
+            take_observer_calls(),
+            vec![
+                ObserverCall::Stake(account, smart_contract.clone(), 200),
+                ObserverCall::Unstake(account, smart_contract.clone(), 50),
+            ]
+        );
+
+        // 2. Claim staker & dApp rewards, and verify observers are notified about the payouts
+        advance_to_era(ActiveProtocolState::<Test>::get().era + 2);
+        let _ = take_observer_calls();
+
+        assert_claim_staker_rewards(account);
+        assert_claim_dapp_reward(
+            account,
+            &smart_contract,
+            ActiveProtocolState::<Test>::get().era - 1,
+        );
+        let calls = take_observer_calls();
+        assert_eq!(calls.len(), 2);
+        assert!(matches!(calls[0], ObserverCall::RewardPaid(beneficiary, _) if beneficiary == account));
+        assert!(matches!(calls[1], ObserverCall::RewardPaid(beneficiary, _) if beneficiary == dev_account));
+
+        // 3. Unregister the dApp & unstake from it, and verify observers are notified
+        assert_unregister(&smart_contract);
+        assert_unstake_from_unregistered(account, &smart_contract);
+        assert_eq!(
+            take_observer_calls(),
+            vec![
+                ObserverCall::DAppUnregistered(smart_contract.clone()),
+                ObserverCall::Unstake(account, smart_contract.clone(), 150),
+            ]
+        );
+    })
+}
+
+#[test]
+fn observer_new_period_hook_works() {
+    ExtBuilder::default().build_and_execute(|| {
+        let _ = take_observer_calls();
+
+        // Observers shouldn't be notified about new subperiods or eras, only about new periods
+        advance_to_next_subperiod();
+        advance_to_next_era();
+        assert!(take_observer_calls().is_empty());
+
+        let period = ActiveProtocolState::<Test>::get().period_number();
+        advance_to_next_period();
+        assert_eq!(
+            take_observer_calls(),
+            vec![ObserverCall::NewPeriod(period + 1)]
+        );
+    })
+}
+
+#[test]
+fn unregister_after_max_number_of_contracts_allows_register_again() {
+    ExtBuilder::default().build_and_execute(|| {
+        let max_number_of_contracts = <Test as Config>::MaxNumberOfContracts::get();
+        let developer = 2;
+
+        // Reach max number of contracts
+        for id in 0..max_number_of_contracts {
+            assert_register(developer, &MockSmartContract::Wasm(id.into()));
+        }
+
+        // Ensure we cannot register more contracts
+        assert_noop!(
+            DappStaking::register(
+                RuntimeOrigin::root(),
+                developer,
+                MockSmartContract::Wasm((max_number_of_contracts).into())
+            ),
+            Error::<Test>::ExceededMaxNumberOfContracts
+        );
+
+        // Unregister one contract, and ensure register works again
+        let smart_contract = MockSmartContract::Wasm(0);
+        assert_unregister(&smart_contract);
+        assert_register(developer, &smart_contract);
+    })
+}
+
+#[test]
+fn safeguard_on_by_default() {
+    use sp_runtime::BuildStorage;
+    let storage = frame_system::GenesisConfig::<Test>::default()
+        .build_storage()
+        .unwrap();
+
+    let mut ext = sp_io::TestExternalities::from(storage);
+    ext.execute_with(|| {
+        assert!(Safeguard::<Test>::get());
+    });
+}
+
+#[test]
+fn safeguard_configurable_by_genesis_config() {
+    use sp_runtime::BuildStorage;
+    let mut genesis_config = GenesisConfig::<Test> {
+        reward_portion: vec![
+            Permill::from_percent(40),
+            Permill::from_percent(30),
+            Permill::from_percent(20),
+            Permill::from_percent(10),
+        ],
+        slot_distribution: vec![
+            Permill::from_percent(10),
+            Permill::from_percent(20),
+            Permill::from_percent(30),
+            Permill::from_percent(40),
+        ],
+        slots_per_tier: vec![10, 20, 30, 40],
+        ..Default::default()
+    };
+
+    // Test case 1: Safeguard enabled via Genesis Config
+    genesis_config.safeguard = Some(true);
+    let storage = genesis_config.build_storage().unwrap();
+    let mut ext = sp_io::TestExternalities::from(storage);
+    ext.execute_with(|| {
+        assert!(Safeguard::<Test>::get());
+    });
+
+    // Test case 2: Safeguard disabled via Genesis Config
+    genesis_config.safeguard = Some(false);
+    let storage = genesis_config.build_storage().unwrap();
+    let mut ext = sp_io::TestExternalities::from(storage);
+    ext.execute_with(|| {
+        assert!(!Safeguard::<Test>::get());
+    });
+
+    // Test case 3: Safeguard not set via Genesis Config
+    genesis_config.safeguard = None;
+    let storage = genesis_config.build_storage().unwrap();
+    let mut ext = sp_io::TestExternalities::from(storage);
+    ext.execute_with(|| {
+        assert!(Safeguard::<Test>::get());
+    });
+}
+
+#[test]
+fn number_of_tier_slots_derived_at_new_period() {
+    ExtBuilder::default().build_and_execute(|| {
+        let slots_for = |number_of_slots: u16| -> Vec<u16> {
+            StaticTierParams::<Test>::get()
+                .slot_distribution
+                .iter()
+                .map(|percent| (*percent * number_of_slots as u128) as u16)
+                .collect()
+        };
+
+        // Provider value changes, but it's only applied once the new period starts
+        NUMBER_OF_TIER_SLOTS.with(|v| *v.borrow_mut() = 8);
+        advance_to_next_era();
+        assert_eq!(NumberOfTierSlots::<Test>::get(), FIXED_NUMBER_OF_TIER_SLOTS);
+
+        advance_to_next_period();
+        assert_eq!(NumberOfTierSlots::<Test>::get(), 8);
+        assert_eq!(
+            TierConfig::<Test>::get()
+                .slots_per_tier()
+                .clone()
+                .into_inner(),
+            slots_for(8)
+        );
+
+        // Slot count persists across eras of the same period
+        advance_to_next_era();
+        assert_eq!(
+            TierConfig::<Test>::get()
+                .slots_per_tier()
+                .clone()
+                .into_inner(),
+            slots_for(8)
+        );
+
+        // Provided value is capped by the max number of tier slots
+        NUMBER_OF_TIER_SLOTS.with(|v| *v.borrow_mut() = u16::MAX);
+        advance_to_next_period();
+        let max_number_of_tier_slots = <Test as Config>::MaxNumberOfTierSlots::get() as u16;
+        assert_eq!(NumberOfTierSlots::<Test>::get(), max_number_of_tier_slots);
+        assert_eq!(
+            TierConfig::<Test>::get()
+                .slots_per_tier()
+                .clone()
+                .into_inner(),
+            slots_for(max_number_of_tier_slots)
+        );
+    })
+}
+
+#[test]
+fn base_number_of_slots_is_respected() {
+    ExtBuilder::default().build_and_execute(|| {
+        assert_ok!(DappStaking::force(RuntimeOrigin::root(), ForcingType::Era));
+        run_for_blocks(1);
+
+        let config_before = TierConfig::<Test>::get();
+        assert_eq!(
+            config_before.total_number_of_slots(),
+            FIXED_NUMBER_OF_TIER_SLOTS,
+            "Number of slots must respect the fixed slot limit."
+        );
+
+        // Force another era and verify config stays the same.
+        assert_ok!(DappStaking::force(RuntimeOrigin::root(), ForcingType::Era));
+        run_for_blocks(1);
+
+        let config_after = TierConfig::<Test>::get();
+        assert_eq!(
+            config_after.total_number_of_slots(),
+            FIXED_NUMBER_OF_TIER_SLOTS,
+            "Number of slots must remain fixed."
+        );
+        assert_eq!(
+            config_after, config_before,
+            "Tier configuration must remain unchanged."
+        );
+    })
+}
+
+#[test]
+fn ranking_will_calc_reward_correctly() {
+    ExtBuilder::default().build_and_execute(|| {
+        // Tier config is specially adapted for this test.
+        TierConfig::<Test>::mutate(|config| {
+            config.slots_per_tier = BoundedVec::try_from(vec![2, 3, 2, 20]).unwrap();
+        });
+
+        // Register smart contracts
+        let smart_contracts: Vec<_> = (1..=8u32)
+            .map(|x| {
+                let smart_contract = MockSmartContract::Wasm(x.into());
+                assert_register(x.into(), &smart_contract);
+                smart_contract
+            })
+            .collect();
+
+        fn lock_and_stake(account: usize, smart_contract: &MockSmartContract, amount: Balance) {
+            let account = account.try_into().unwrap();
+            Balances::make_free_balance_be(&account, amount);
+            assert_lock(account, amount);
+            assert_stake(account, smart_contract, amount);
+        }
+
+        for (idx, amount) in [101, 102, 100, 99, 15, 49, 35, 14].into_iter().enumerate() {
+            lock_and_stake(idx, &smart_contracts[idx], amount)
+        }
+
+        // Finally, the actual test
+        let protocol_state = ActiveProtocolState::<Test>::get();
+        let (tier_assignment, counter) = DappStaking::get_dapp_tier_assignment_and_rewards(
+            protocol_state.era + 1,
+            protocol_state.period_number(),
+            1_000_000,
+        );
+
+        assert_eq!(
+            tier_assignment,
+            DAppTierRewardsFor::<Test> {
+                dapps: BoundedBTreeMap::try_from(BTreeMap::from([
+                    (0, RankedTier::new_saturated(0, 0)),
+                    (1, RankedTier::new_saturated(0, 0)),
+                    (2, RankedTier::new_saturated(1, 10)),
+                    (3, RankedTier::new_saturated(1, 9)),
+                    (5, RankedTier::new_saturated(2, 9)),
+                    (6, RankedTier::new_saturated(2, 5)),
+                    (4, RankedTier::new_saturated(3, 0)),
+                ]))
+                .unwrap(),
+                rewards: BoundedVec::try_from(vec![200_000, 58_823, 28_019, 5_000]).unwrap(),
+                period: 1,
+                // Tier 0 has no ranking multiplier therefore no rank reward.
+                // Tier 3 has no ranking multiplier therefore no rank reward.
+                rank_rewards: BoundedVec::try_from(vec![0, 8_235, 10_282, 0]).unwrap()
+            }
+        );
+
+        // one didn't make it
+        assert_eq!(counter, 8);
+    })
+}
+
+#[test]
+fn claim_dapp_reward_with_rank() {
+    ExtBuilder::default().build_and_execute(|| {
+        // Register smart contract
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(1, &smart_contract);
+
+        let alice = 2;
+        // Stake amount that puts dApp in Tier 1 with a specific rank
+        // Assuming tier_0=100, tier_1=50
+        // Stake 75 → rank = (75-50)/5 = 5
+        let amount = 75;
+        assert_lock(alice, amount);
+        assert_stake(alice, &smart_contract, amount);
+
+        // Advance 2 eras for reward claiming
+        advance_to_era(ActiveProtocolState::<Test>::get().era + 2);
+
+        let era = ActiveProtocolState::<Test>::get().era - 1;
+        let tiers = DAppTiers::<Test>::get(era).unwrap();
+
+        // Verify tier assignment
+        let ranked_tier = tiers.dapps.get(&0).unwrap();
+        assert_eq!(ranked_tier.tier(), 1, "Should be in tier 1");
+
+        let expected_rank = 5; // (75-50)/(50/10) = 25/5 = 5
+        assert_eq!(ranked_tier.rank(), expected_rank);
+
+        let base_reward = tiers.rewards[1];
+        let rank_reward = tiers.rank_rewards[1];
+
+        // Calculate expected total reward
+        let expected_total_reward = base_reward + expected_rank as Balance * rank_reward;
+
+        // Claim dApp reward
+        assert_ok!(DappStaking::claim_dapp_reward(
+            RuntimeOrigin::signed(alice),
+            smart_contract.clone(),
+            era,
+        ));
+
+        System::assert_last_event(RuntimeEvent::DappStaking(Event::DAppReward {
+            beneficiary: 1,
+            smart_contract: smart_contract.clone(),
+            tier_id: 1,
+            rank: expected_rank,
+            era,
+            amount: expected_total_reward,
+        }));
+    })
+}
+
+#[test]
+fn unstake_correctly_reduces_future_contract_stake() {
+    ExtBuilder::default().build_and_execute(|| {
+        // 0. Register smart contract, lock&stake some amount with staker 1 during the voting subperiod
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(1, &smart_contract);
+
+        let (staker_1, amount_1) = (1, 29);
+        assert_lock(staker_1, amount_1);
+        assert_stake(staker_1, &smart_contract, amount_1);
+
+        // 1. Advance to the build&earn subperiod, stake some amount with staker 2
+        advance_to_next_era();
+        let (staker_2, amount_2) = (2, 11);
+        assert_lock(staker_2, amount_2);
+        assert_stake(staker_2, &smart_contract, amount_2);
+
+        // 2. Advance a few eras, creating a gap but remaining within the same period.
+        //    Claim all rewards for staker 1.
+        //    Lock & stake some amount with staker 3.
+        advance_to_era(ActiveProtocolState::<Test>::get().era + 3);
+        assert_eq!(
+            ActiveProtocolState::<Test>::get().period_number(),
+            1,
+            "Sanity check."
+        );
+        for _ in 0..required_number_of_reward_claims(staker_1) {
+            assert_claim_staker_rewards(staker_1);
+        }
+
+        // This ensures contract stake entry is aligned to the current era, and future entry refers to the era after this one.
+        //
+        // This is important to reproduce an issue where the (era, amount) pairs returned by the `unstake` function don't correctly
+        // cover the next era.
+        let (staker_3, amount_3) = (3, 13);
+        assert_lock(staker_3, amount_3);
+        assert_stake(staker_3, &smart_contract, amount_3);
+
+        // 3. Unstake from staker 1, and ensure the future stake is reduced.
+        //    Unstake amount should be slightly higher than the 2nd stake amount to ensure whole b&e stake amount is removed.
+        assert_unstake(staker_1, &smart_contract, amount_2 + 3);
+    })
+}
+
+#[test]
+fn lock_correctly_considers_unlocking_amount() {
+    ExtBuilder::default().build_and_execute(|| {
+        // Lock the entire amount & immediately start the unlocking process
+        let (staker, unlock_amount) = (1, 13);
+        let total_balance = Balances::total_balance(&staker);
+        assert_lock(staker, total_balance);
+        assert_unlock(staker, unlock_amount);
+
+        assert_noop!(
+            DappStaking::lock(RuntimeOrigin::signed(staker), 1),
+            Error::<Test>::ZeroAmount
+        );
+    })
+}
+
+#[test]
+fn claim_staker_rewards_for_basic_example_is_ok() {
+    ExtBuilder::default().build_and_execute(|| {
+        // Register smart contract, lock&stake some amount
+        let dev_account = 1;
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(dev_account, &smart_contract);
+
+        let staker_account = 2;
+        let lock_amount = 300;
+        assert_lock(staker_account, lock_amount);
+        let stake_amount = 93;
+        assert_stake(staker_account, &smart_contract, stake_amount);
+
+        // Advance into Build&Earn period, and allow one era to pass. Claim reward for 1 era.
+        advance_to_era(ActiveProtocolState::<Test>::get().era + 2);
+
+        // Basic checks, since the entire claim logic is already covered by other tests
+        let claimer_account = 3;
+        let (init_staker_balance, init_claimer_balance) = (
+            Balances::free_balance(&staker_account),
+            Balances::free_balance(&claimer_account),
+        );
+        assert_ok!(DappStaking::claim_staker_rewards_for(
+            RuntimeOrigin::signed(claimer_account),
+            staker_account
+        ));
+        System::assert_last_event(RuntimeEvent::DappStaking(Event::Reward {
+            account: staker_account,
+            era: ActiveProtocolState::<Test>::get().era - 1,
+            // for this simple test, entire staker reward pool goes to the staker
+            amount: <Test as Config>::StakingRewardHandler::staker_and_dapp_reward_pools(0).0,
+            destination: staker_account,
+        }));
+
+        assert!(
+            Balances::free_balance(&staker_account) > init_staker_balance,
+            "Balance must have increased due to the reward payout."
+        );
+        assert_eq!(
+            init_claimer_balance,
+            Balances::free_balance(&claimer_account),
+            "Claimer balance must not change since reward is deposited to the staker."
+        );
+    })
+}
+
+#[test]
+fn claim_bonus_reward_for_works() {
+    ExtBuilder::default().build_and_execute(|| {
+        // Register smart contract, lock&stake some amount
+        let dev_account = 1;
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(dev_account, &smart_contract);
+
+        let staker_account = 2;
+        let lock_amount = 300;
+        assert_lock(staker_account, lock_amount);
+        let stake_amount = 93;
+        assert_stake(staker_account, &smart_contract, stake_amount);
+
+        // Advance to the next period, and claim the bonus
+        advance_to_next_period();
+        let claimer_account = 3;
+        let (init_staker_balance, init_claimer_balance) = (
+            Balances::free_balance(&staker_account),
+            Balances::free_balance(&claimer_account),
+        );
+
+        assert_ok!(DappStaking::claim_bonus_reward_for(
+            RuntimeOrigin::signed(claimer_account),
+            staker_account,
+            smart_contract.clone()
+        ));
+        System::assert_last_event(RuntimeEvent::DappStaking(Event::BonusReward {
+            account: staker_account,
+            period: ActiveProtocolState::<Test>::get().period_number() - 1,
+            smart_contract,
+            // for this simple test, entire bonus reward pool goes to the staker
+            amount: <Test as Config>::StakingRewardHandler::bonus_reward_pool(),
+            destination: staker_account,
+        }));
+
+        assert!(
+            Balances::free_balance(&staker_account) > init_staker_balance,
+            "Balance must have increased due to the reward payout."
+        );
+        assert_eq!(
+            init_claimer_balance,
+            Balances::free_balance(&claimer_account),
+            "Claimer balance must not change since reward is deposited to the staker."
+        );
+    })
+}
+
+#[test]
+fn get_claimable_staker_rewards_matches_claimed_rewards() {
+    ExtBuilder::default().build_and_execute(|| {
+        // Register smart contract, lock&stake some amount
+        let dev_account = 1;
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(dev_account, &smart_contract);
+
+        let account = 2;
+        let lock_amount = 300;
+        assert_lock(account, lock_amount);
+        let stake_amount = 93;
+        assert_stake(account, &smart_contract, stake_amount);
+
+        // Nothing to claim while the stake is still fresh
+        assert!(DappStaking::get_claimable_staker_rewards(&account).is_empty());
+
+        // Advance into the next period, which requires multiple claim calls
+        advance_to_next_period();
+        let claimable = DappStaking::get_claimable_staker_rewards(&account);
+        assert!(!claimable.is_empty());
+
+        System::reset_events();
+        for _ in 0..required_number_of_reward_claims(account) {
+            assert_claim_staker_rewards(account);
+        }
+        let claimed: Vec<_> = dapp_staking_events()
+            .into_iter()
+            .filter_map(|e| match e {
+                Event::Reward {
+                    account: a,
+                    era,
+                    amount,
+                    ..
+                } if a == account => Some((era, amount)),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(claimable, claimed);
+
+        // Everything has been claimed
+        assert!(DappStaking::get_claimable_staker_rewards(&account).is_empty());
+    })
+}
+
+#[test]
+fn get_claimable_bonus_rewards_is_ok() {
+    ExtBuilder::default().build_and_execute(|| {
+        // Register smart contract, lock&stake some amount
+        let dev_account = 1;
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(dev_account, &smart_contract);
+
+        let account = 2;
+        let lock_amount = 300;
+        assert_lock(account, lock_amount);
+        let stake_amount = 93;
+        assert_stake(account, &smart_contract, stake_amount);
+
+        // Bonus reward can only be claimed once the period ends
+        assert!(DappStaking::get_claimable_bonus_rewards(&account).is_empty());
+
+        advance_to_next_period();
+        assert_eq!(
+            DappStaking::get_claimable_bonus_rewards(&account),
+            // for this simple test, entire bonus reward pool goes to the staker
+            vec![(
+                smart_contract,
+                <Test as Config>::StakingRewardHandler::bonus_reward_pool()
+            )]
+        );
+
+        assert_claim_bonus_reward(account, &smart_contract);
+        assert!(DappStaking::get_claimable_bonus_rewards(&account).is_empty());
+    })
+}
+
+#[test]
+fn get_unclaimed_dapp_rewards_matches_claimed_rewards() {
+    ExtBuilder::default().build_and_execute(|| {
+        // Register smart contract, lock&stake some amount
+        let dev_account = 1;
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(dev_account, &smart_contract);
+
+        let account = 2;
+        let amount = 300;
+        assert_lock(account, amount);
+        assert_stake(account, &smart_contract, amount);
+
+        // Unknown contract has nothing to claim
+        let unknown_contract = MockSmartContract::wasm(2 as AccountId);
+        assert!(DappStaking::get_unclaimed_dapp_rewards(&unknown_contract).is_empty());
+
+        // Advance a few eras so we have multiple entries for reward claiming
+        advance_to_era(ActiveProtocolState::<Test>::get().era + 4);
+        let unclaimed = DappStaking::get_unclaimed_dapp_rewards(&smart_contract);
+        assert!(unclaimed.len() > 1);
+        assert!(unclaimed.windows(2).all(|w| w[0].0 < w[1].0));
+
+        System::reset_events();
+        for (era, _) in unclaimed.iter() {
+            assert_claim_dapp_reward(account, &smart_contract, *era);
+        }
+        let claimed: Vec<_> = dapp_staking_events()
+            .into_iter()
+            .filter_map(|e| match e {
+                Event::DAppReward { era, amount, .. } => Some((era, amount)),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(unclaimed, claimed);
+
+        // Everything has been claimed
+        assert!(DappStaking::get_unclaimed_dapp_rewards(&smart_contract).is_empty());
+    })
+}
+
+#[test]
+fn set_auto_compound_works() {
+    ExtBuilder::default().build_and_execute(|| {
+        let account = 2;
+        assert!(!AutoCompound::<Test>::contains_key(&account));
+
+        // Enable auto-compounding
+        assert_ok!(DappStaking::set_auto_compound(
+            RuntimeOrigin::signed(account),
+            true
+        ));
+        assert!(AutoCompound::<Test>::contains_key(&account));
+        System::assert_last_event(RuntimeEvent::DappStaking(Event::AutoCompoundSet {
+            account,
+            enabled: true,
+        }));
+
+        // Disable auto-compounding
+        assert_ok!(DappStaking::set_auto_compound(
+            RuntimeOrigin::signed(account),
+            false
+        ));
+        assert!(!AutoCompound::<Test>::contains_key(&account));
+        System::assert_last_event(RuntimeEvent::DappStaking(Event::AutoCompoundSet {
+            account,
+            enabled: false,
+        }));
+    })
+}
+
+#[test]
+fn claim_staker_rewards_with_auto_compound_restakes_proportionally() {
+    ExtBuilder::default().build_and_execute(|| {
+        // Register smart contracts, lock&stake some amount on both of them
+        let smart_contract_1 = MockSmartContract::wasm(1 as AccountId);
+        let smart_contract_2 = MockSmartContract::wasm(2 as AccountId);
+        assert_register(1, &smart_contract_1);
+        assert_register(1, &smart_contract_2);
+
+        let account = 2;
+        let (stake_amount_1, stake_amount_2) = (100, 300);
+        assert_lock(account, stake_amount_1 + stake_amount_2);
+        assert_stake(account, &smart_contract_1, stake_amount_1);
+        assert_stake(account, &smart_contract_2, stake_amount_2);
+
+        // Advance into Build&Earn period, and allow one era to pass.
+        advance_to_era(ActiveProtocolState::<Test>::get().era + 2);
+        assert_ok!(DappStaking::set_auto_compound(
+            RuntimeOrigin::signed(account),
+            true
+        ));
+
+        let pre_ledger = Ledger::<Test>::get(&account);
+        let pre_era_info = CurrentEraInfo::<Test>::get();
+        let reward: Balance = DappStaking::get_claimable_staker_rewards(&account)
+            .iter()
+            .map(|(_, amount)| amount)
+            .sum();
+        assert!(reward > 0, "Sanity check.");
+
+        // Claim on behalf of the staker, compounding must happen regardless.
+        assert_ok!(DappStaking::claim_staker_rewards_for(
+            RuntimeOrigin::signed(3),
+            account
+        ));
+
+        // Entire reward must be locked & staked
+        let post_ledger = Ledger::<Test>::get(&account);
+        assert_eq!(
+            post_ledger.active_locked_amount(),
+            pre_ledger.active_locked_amount() + reward
+        );
+        assert_eq!(
+            post_ledger.stakeable_amount(ActiveProtocolState::<Test>::get().period_number()),
+            0
+        );
+        assert_eq!(
+            CurrentEraInfo::<Test>::get().total_locked,
+            pre_era_info.total_locked + reward
+        );
+
+        // Stake must be distributed proportionally, and bonus eligibility must remain intact
+        let staker_info_1 = StakerInfo::<Test>::get(&account, &smart_contract_1).unwrap();
+        let staker_info_2 = StakerInfo::<Test>::get(&account, &smart_contract_2).unwrap();
+        assert_eq!(
+            staker_info_1.total_staked_amount(),
+            stake_amount_1 + reward / 4
+        );
+        assert_eq!(
+            staker_info_2.total_staked_amount(),
+            stake_amount_2 + reward * 3 / 4
+        );
+        assert!(staker_info_1.is_bonus_eligible());
+        assert!(staker_info_2.is_bonus_eligible());
+
+        let events = dapp_staking_events();
+        assert!(events.contains(&Event::Locked {
+            account,
+            amount: reward
+        }));
+        assert!(events.contains(&Event::Stake {
+            account,
+            smart_contract: smart_contract_1,
+            amount: reward / 4,
+        }));
+    })
+}
+
+#[test]
+fn claim_staker_rewards_without_auto_compound_does_not_lock() {
+    ExtBuilder::default().build_and_execute(|| {
+        // Register smart contract, lock&stake some amount
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(1, &smart_contract);
+
+        let account = 2;
+        assert_lock(account, 300);
+        assert_stake(account, &smart_contract, 100);
+
+        advance_to_era(ActiveProtocolState::<Test>::get().era + 2);
+        assert_claim_staker_rewards(account);
+
+        assert_eq!(Ledger::<Test>::get(&account).active_locked_amount(), 300);
+        assert_eq!(
+            StakerInfo::<Test>::get(&account, &smart_contract)
+                .unwrap()
+                .total_staked_amount(),
+            100
+        );
+    })
+}
+
+#[test]
+fn claim_bonus_reward_with_auto_compound_only_locks_without_ongoing_stake() {
+    ExtBuilder::default().build_and_execute(|| {
+        // Register smart contract, lock&stake some amount
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(1, &smart_contract);
+
+        let account = 2;
+        let lock_amount = 300;
+        assert_lock(account, lock_amount);
+        assert_stake(account, &smart_contract, 100);
+
+        // Advance to the next period, and claim all staker rewards
+        advance_to_next_period();
+        for _ in 0..required_number_of_reward_claims(account) {
+            assert_claim_staker_rewards(account);
+        }
+
+        // No stake exists in the ongoing period, so the bonus reward is only locked.
+        assert_ok!(DappStaking::set_auto_compound(
+            RuntimeOrigin::signed(account),
+            true
+        ));
+        System::reset_events();
+        assert_ok!(DappStaking::claim_bonus_reward(
+            RuntimeOrigin::signed(account),
+            smart_contract
+        ));
+
+        let bonus_reward = <Test as Config>::StakingRewardHandler::bonus_reward_pool();
+        assert_eq!(
+            Ledger::<Test>::get(&account).active_locked_amount(),
+            lock_amount + bonus_reward
+        );
+        let events = dapp_staking_events();
+        assert_eq!(
+            events.last(),
+            Some(&Event::Locked {
+                account,
+                amount: bonus_reward
+            })
+        );
+        assert!(!events.iter().any(|e| matches!(e, Event::Stake { .. })));
+    })
+}
+
+#[test]
+fn set_staker_reward_destination_works() {
+    ExtBuilder::default().build_and_execute(|| {
+        let (account, destination) = (2, 4);
+        assert!(!StakerRewardDestination::<Test>::contains_key(&account));
+
+        // Set the destination
+        assert_ok!(DappStaking::set_staker_reward_destination(
+            RuntimeOrigin::signed(account),
+            Some(destination)
+        ));
+        assert_eq!(
+            StakerRewardDestination::<Test>::get(&account),
+            Some(destination)
+        );
+        System::assert_last_event(RuntimeEvent::DappStaking(
+            Event::StakerRewardDestinationSet {
+                account,
+                destination: Some(destination),
+            },
+        ));
+
+        // Setting the staker itself as the destination is the same as removing it
+        assert_ok!(DappStaking::set_staker_reward_destination(
+            RuntimeOrigin::signed(account),
+            Some(account)
+        ));
+        assert!(!StakerRewardDestination::<Test>::contains_key(&account));
+        System::assert_last_event(RuntimeEvent::DappStaking(
+            Event::StakerRewardDestinationSet {
+                account,
+                destination: None,
+            },
+        ));
+
+        // Set & remove the destination
+        assert_ok!(DappStaking::set_staker_reward_destination(
+            RuntimeOrigin::signed(account),
+            Some(destination)
+        ));
+        assert_ok!(DappStaking::set_staker_reward_destination(
+            RuntimeOrigin::signed(account),
+            None
+        ));
+        assert!(!StakerRewardDestination::<Test>::contains_key(&account));
+    })
+}
+
+#[test]
+fn claim_rewards_with_reward_destination_pays_destination() {
+    ExtBuilder::default().build_and_execute(|| {
+        // Register smart contract, lock&stake some amount
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(1, &smart_contract);
+
+        let (account, destination) = (2, 4);
+        assert_lock(account, 300);
+        assert_stake(account, &smart_contract, 100);
+        assert_ok!(DappStaking::set_staker_reward_destination(
+            RuntimeOrigin::signed(account),
+            Some(destination)
+        ));
+
+        // Staker & bonus rewards are paid out to the destination
+        advance_to_next_period();
+        let pre_staker_balance = Balances::free_balance(&account);
+        for _ in 0..required_number_of_reward_claims(account) {
+            assert_claim_staker_rewards(account);
+        }
+        assert_claim_bonus_reward(account, &smart_contract);
+        assert_eq!(Balances::free_balance(&account), pre_staker_balance);
+    })
+}
+
+#[test]
+fn claim_rewards_with_reward_destination_does_not_compound() {
+    ExtBuilder::default().build_and_execute(|| {
+        // Register smart contract, lock&stake some amount
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(1, &smart_contract);
+
+        let (account, destination) = (2, 4);
+        assert_lock(account, 300);
+        assert_stake(account, &smart_contract, 100);
+
+        advance_to_era(ActiveProtocolState::<Test>::get().era + 2);
+        assert_ok!(DappStaking::set_auto_compound(
+            RuntimeOrigin::signed(account),
+            true
+        ));
+        assert_ok!(DappStaking::set_staker_reward_destination(
+            RuntimeOrigin::signed(account),
+            Some(destination)
+        ));
+
+        // Reward is paid out to the destination, nothing is locked or staked
+        assert_claim_staker_rewards(account);
+        assert_eq!(Ledger::<Test>::get(&account).active_locked_amount(), 300);
+        assert_eq!(
+            StakerInfo::<Test>::get(&account, &smart_contract)
+                .unwrap()
+                .total_staked_amount(),
+            100
+        );
+    })
+}
+
+#[test]
+fn observer_is_notified_about_reward_destination() {
+    ExtBuilder::default().build_and_execute(|| {
+        // Register smart contract, lock&stake some amount
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(1, &smart_contract);
+
+        let (account, destination) = (2, 4);
+        assert_lock(account, 300);
+        assert_stake(account, &smart_contract, 100);
+        assert_ok!(DappStaking::set_staker_reward_destination(
+            RuntimeOrigin::signed(account),
+            Some(destination)
+        ));
+
+        // Claim staker & bonus rewards, observer must see the account which received them
+        advance_to_next_period();
+        let _ = take_observer_calls();
+        for _ in 0..required_number_of_reward_claims(account) {
+            assert_claim_staker_rewards(account);
+        }
+        assert_claim_bonus_reward(account, &smart_contract);
+
+        let calls = take_observer_calls();
+        assert!(!calls.is_empty());
+        assert!(calls.iter().all(
+            |call| matches!(call, ObserverCall::RewardPaid(beneficiary, _) if *beneficiary == destination)
+        ));
+    })
+}
+
+#[test]
+fn set_static_tier_params_incorrect_origin_fails() {
+    ExtBuilder::default().build_and_execute(|| {
+        let tier_params = StaticTierParams::<Test>::get();
+        assert_noop!(
+            DappStaking::set_static_tier_params(RuntimeOrigin::signed(1), tier_params),
+            BadOrigin
+        );
+    })
+}
+
+// Tests moving stakes from unregistered contracts to another contract while verifying that:
+// - All staked funds are moved from the unregistered contracts.
+// - The bonus_status is preserved during the move from an unregistered contract.
+// - Destination stake is successfully created if entry does not exist yet.
+#[test]
+fn move_stake_from_unregistered_contract_is_ok() {
+    ExtBuilder::default().build_and_execute(|| {
+        // Register smart contracts 1 & 2, lock&stake some amount on 1, unregister the smart contract 1
+        let source_contract = MockSmartContract::wasm(1 as AccountId);
+        let dest_contract = MockSmartContract::wasm(2 as AccountId);
+        assert_register(1, &source_contract);
+        assert_register(1, &dest_contract);
+
+        let account = 2;
+        let amount = 300;
+        let partial_stake_1 = 200;
+        let partial_stake_2 = 100;
+        assert_lock(account, amount);
+        assert_stake(account, &source_contract, partial_stake_1);
+
+        // Advance to B&E subperiod, stake again to check both stakes move and finally unregister source_contract
+        advance_to_next_subperiod();
+        assert_stake(account, &source_contract, partial_stake_2);
+        assert_unregister(&source_contract);
+
+        assert_move_stake(
+            account,
+            &source_contract,
+            &dest_contract,
+            1, // the amount is not important for an unregistered contract, everything is moved
+        );
+
+        let default_bonus_status = *BonusStatusWrapperFor::<Test>::default();
+        assert!(StakerInfo::<Test>::get(&account, &source_contract).is_none());
+        let expected_dest_staking_info = SingularStakingInfo {
+            previous_staked: StakeAmount::default(),
+            staked: StakeAmount {
+                voting: partial_stake_1,
+                build_and_earn: partial_stake_2,
+                era: 3,
+                period: 1,
+            },
+            bonus_status: default_bonus_status,
+        };
+        let dest_staking_info = StakerInfo::<Test>::get(&account, &dest_contract)
+            .expect("Should exist after a successful move operation");
+        assert_eq!(dest_staking_info, expected_dest_staking_info);
+    })
+}
+
+// Tests multiple moves for registered contracts
+// Checks proper stake amounts transfer for preserved bonus and conversion for forfeited bonus
+#[test]
+fn move_stake_bonus_preserved_with_transfer_conversion_is_ok() {
+    ExtBuilder::default()
+        .with_max_bonus_safe_moves(1)
+        .build_and_execute(|| {
+            // Sanity check
+            let default_bonus_status = *BonusStatusWrapperFor::<Test>::default();
+            assert_eq!(
+                default_bonus_status, 2,
+                "max_moves value must be 1 to cover both scenarios"
+            );
+
+            // Prep - Register source/stopover/dest smart contracts, lock&stake some amounts
+            let source_contract = MockSmartContract::wasm(1 as AccountId);
+            let stopover_contract = MockSmartContract::wasm(2 as AccountId);
+            let final_contract = MockSmartContract::wasm(3 as AccountId);
+            assert_register(1, &source_contract);
+            assert_register(1, &stopover_contract);
+            assert_register(1, &final_contract);
+
+            let min_stake_amount: Balance = <Test as Config>::MinimumStakeAmount::get();
+
+            let account = 2;
+            let amount = 700;
+            assert_lock(account, amount);
+            assert_stake(account, &source_contract, 100);
+            assert_stake(account, &final_contract, 200);
+
+            // Advance to B&E subperiod, stake again to check both stakes move
+            advance_to_next_subperiod();
+            assert_stake(account, &source_contract, 100);
+            assert_stake(account, &stopover_contract, 100);
+            assert_stake(account, &final_contract, 200);
+
+            // Move 1 - source -> stopover - VOTING TRANSFER
+            let move_amount = 200 - min_stake_amount + 1; // for full move
+            assert_move_stake(account, &source_contract, &stopover_contract, move_amount);
+
+            assert!(StakerInfo::<Test>::get(&account, &source_contract).is_none());
+            let default_bonus_status = *BonusStatusWrapperFor::<Test>::default();
+            let expected_bonus_status = default_bonus_status - 1; // B&E subperiod + full move
+            let expected_stopover_staking_info = SingularStakingInfo {
+                previous_staked: StakeAmount::default(),
+                staked: StakeAmount {
+                    voting: 100,
+                    build_and_earn: 200,
+                    era: 3,
+                    period: 1,
+                },
+                bonus_status: expected_bonus_status,
+            };
+            let stopover_staking_info = StakerInfo::<Test>::get(&account, &stopover_contract)
+                .expect("Should exist after a successful stake/move operations");
+            assert_eq!(stopover_staking_info, expected_stopover_staking_info);
+
+            // Move 2 - stopover -> final - VOTING CONVERSION
+
+            // Advance one era (era 3) so we can check that move_2 amount will be effective the era after (era 4) (even if it was already effective)
+            advance_to_era(ActiveProtocolState::<Test>::get().era + 1);
+
+            // Prep - claim rewards to be able to move
+            for _ in 0..required_number_of_reward_claims(account) {
+                assert_claim_staker_rewards(account);
+            }
+
+            let move_2_amount = 300; // for full move again
+            assert_move_stake(account, &stopover_contract, &final_contract, move_2_amount);
+
+            assert!(StakerInfo::<Test>::get(&account, &stopover_contract).is_none());
+            let expected_final_staking_info = SingularStakingInfo {
+                previous_staked: StakeAmount {
+                    voting: 200,
+                    build_and_earn: 200,
+                    era: 3,
+                    period: 1,
+                },
+                staked: StakeAmount {
+                    voting: 200,
+                    build_and_earn: 500,
+                    era: 4,
+                    period: 1,
+                },
+                bonus_status: default_bonus_status,
+            };
+            let final_staking_info = StakerInfo::<Test>::get(&account, &final_contract)
+                .expect("Should exist after a successful move operation");
+            assert_eq!(final_staking_info, expected_final_staking_info);
+        })
+}
+
+#[test]
+fn move_stake_multiple_conversions_are_ok() {
+    ExtBuilder::default().build_and_execute(|| {
+        // Sanity check - max_moves value must be 0
+        assert_eq!(
+            0,
+            <Test as Config>::MaxBonusSafeMovesPerPeriod::get(),
+            "Sanity check"
+        );
+
+        // Prep - Register source/dest smart contracts, lock&stake some amounts
+        let source_contract = MockSmartContract::wasm(1 as AccountId);
+        let dest_contract = MockSmartContract::wasm(2 as AccountId);
+        assert_register(1, &source_contract);
+        assert_register(1, &dest_contract);
+
+        let account = 2;
+        let amount = 300;
+        assert_lock(account, amount);
+        assert_stake(account, &source_contract, 100);
+        assert_stake(account, &dest_contract, 100);
+
+        // Advance to B&E subperiod, stake again to check both stakes move
+        advance_to_next_subperiod();
+        assert_stake(account, &source_contract, 100);
+
+        // Move 1 - source -> dest - VOTING CONVERSION 1
+        let move_amount = 150; // reduce bonus status
+        assert_move_stake(account, &source_contract, &dest_contract, move_amount);
+
+        let expected_source_staking_info = SingularStakingInfo {
+            previous_staked: StakeAmount {
+                voting: 50,
+                build_and_earn: 0,
+                era: 2,
+                period: 1,
+            },
+            staked: StakeAmount {
+                voting: 50,
+                build_and_earn: 0,
+                era: 3,
+                period: 1,
+            },
+            bonus_status: 0, // bonus has been forfeited
+        };
+
+        let source_staking_info = StakerInfo::<Test>::get(&account, &source_contract)
+            .expect("Should exist after a successful move operation");
+        assert_eq!(source_staking_info, expected_source_staking_info);
+
+        let expected_dest_staking_info = SingularStakingInfo {
+            previous_staked: StakeAmount {
+                voting: 100,
+                build_and_earn: 0,
+                era: 2,
+                period: 1,
+            },
+            staked: StakeAmount {
+                voting: 100,
+                build_and_earn: 150,
+                era: 3,
+                period: 1,
+            },
+            bonus_status: 1, // bonus is preserved
+        };
+        let dest_staking_info = StakerInfo::<Test>::get(&account, &dest_contract)
+            .expect("Should exist after a successful move operation");
+        assert_eq!(dest_staking_info, expected_dest_staking_info);
+
+        // Move 2 - source -> dest - VOTING CONVERSION 2
+        let move_amount = 50; // full move
+        assert_move_stake(account, &source_contract, &dest_contract, move_amount);
+
+        assert!(StakerInfo::<Test>::get(&account, &source_contract).is_none());
+
+        let expected_dest_staking_info = SingularStakingInfo {
+            previous_staked: StakeAmount {
+                voting: 100,
+                build_and_earn: 0,
+                era: 2,
+                period: 1,
+            },
+            staked: StakeAmount {
+                voting: 100,
+                build_and_earn: 200,
+                era: 3,
+                period: 1,
+            },
+            bonus_status: 1, // bonus is still preserved
+        };
+        let dest_staking_info = StakerInfo::<Test>::get(&account, &dest_contract)
+            .expect("Should exist after a successful move operation");
+        assert_eq!(dest_staking_info, expected_dest_staking_info);
+    })
+}
+
+#[test]
+// Tests moving stake with bonus preservation to an already lost bonus contract but that has some voting stake amount leftover. Verify that:
+// - total staked amount is preserved (the voting leftover stake should have been moved to bep stake),
+// - bonus status is updated (decreased but bonus is transferred and preserved)
+fn move_stake_in_bep_to_contract_with_voting_leftover_and_no_bonus_is_ok() {
+    ExtBuilder::default()
+        .with_max_bonus_safe_moves(1)
+        .build_and_execute(|| {
+            // Sanity check - max_moves minimal value must be 1
+            let default_bonus_status = *BonusStatusWrapperFor::<Test>::default();
+            assert!(default_bonus_status > 1);
+
+            // Register smart contracts 1 & 2, lock&stake some amount on 1 & 2
+            let source_contract = MockSmartContract::wasm(1 as AccountId);
+            let dest_contract = MockSmartContract::wasm(2 as AccountId);
+            assert_register(1, &source_contract);
+            assert_register(1, &dest_contract);
+
+            let account = 2;
+            let amount = 300;
+            assert_lock(account, amount);
+
+            let voting_stake_1 = 100;
+            assert_stake(account, &source_contract, voting_stake_1);
+            assert_stake(account, &dest_contract, voting_stake_1);
+
+            advance_to_next_subperiod();
+
+            // We want to forfeit destination contract stake bonus, this is done with 2 unstake.
+            // We also want to have some bep stake amount to check both stake transfers.
+            // All is done with the following stake/unstake operations, for the desired state:
+            // source staker info { voting: 100, build_and_earn: 20, bonus_status: 2 }
+            // dest staker info { voting: 50, build_and_earn: 30, bonus_status: 0 }
+            assert_stake(account, &source_contract, 20);
+            assert_unstake(account, &dest_contract, 20);
+            assert_unstake(account, &dest_contract, 30);
+            assert_stake(account, &dest_contract, 30);
+
+            assert_move_stake(
+                account,
+                &source_contract,
+                &dest_contract,
+                120, // full move
+            );
+
+            let staking_info = StakerInfo::<Test>::get(account, &dest_contract)
+                .expect("Should exist since move operation was successful.");
+            assert_eq!(staking_info.bonus_status, default_bonus_status - 1);
+        })
+}
+
+// Tests bonus status merging when source and destination stake infos have both non zero statuses.
+// The expected result is to take the middle.
+#[test]
+fn move_stake_merge_bonus_status() {
+    ExtBuilder::default()
+        .with_max_bonus_safe_moves(2)
+        .build_and_execute(|| {
+            // Register smart contracts 1 & 2, lock&stake some amount on 1 & 2
+            let source_contract = MockSmartContract::wasm(1 as AccountId);
+            let dest_contract = MockSmartContract::wasm(2 as AccountId);
+            assert_register(1, &source_contract);
+            assert_register(1, &dest_contract);
+
+            let account = 2;
+            let amount = 300;
+            assert_lock(account, amount);
+
+            let stake = 100;
+            assert_stake(account, &source_contract, stake);
+            assert_stake(account, &dest_contract, stake);
+
+            advance_to_next_subperiod();
+
+            // This is done to reduce the bonus status and increase the gap for future merging in move.
+            let unstake = 20;
+            assert_unstake(account, &source_contract, unstake);
+
+            assert_move_stake(
+                account,
+                &source_contract,
+                &dest_contract,
+                stake - unstake, // full move
+            );
+
+            let staking_info = StakerInfo::<Test>::get(account, &dest_contract)
+                .expect("Should exist since move operation was successful.");
+            let incoming_bonus_status_from_source = 1; // default (3) - 2 move actions
+            let bonus_status_for_dest = 3; // default (3)
+            let expected_merged_bonus_status =
+                (incoming_bonus_status_from_source + bonus_status_for_dest) / 2;
+            assert_eq!(staking_info.bonus_status, expected_merged_bonus_status);
+        })
+}
+
+#[test]
+fn move_for_same_contract_fails() {
+    ExtBuilder::default().build_and_execute(|| {
+        let account = 2;
+        let contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(1, &contract);
+
+        assert_noop!(
+            DappStaking::move_stake(RuntimeOrigin::signed(account), contract, contract, 1),
+            Error::<Test>::SameContracts
+        );
+    })
+}
+
+// Destination contract is not found in IntegratedDApps.
+#[test]
+fn move_to_invalid_dapp_fails() {
+    ExtBuilder::default().build_and_execute(|| {
+        let source_contract = MockSmartContract::wasm(1 as AccountId);
+        let destination_contract = MockSmartContract::wasm(2 as AccountId);
+        assert_register(1, &source_contract);
+
+        let account = 2;
+        assert_lock(account, 300);
+
+        // Try to move to non-existing destination contract
+        assert_noop!(
+            DappStaking::move_stake(
+                RuntimeOrigin::signed(account),
+                source_contract,
+                destination_contract,
+                1
+            ),
+            Error::<Test>::ContractNotFound
+        );
+    })
+}
+
+#[test]
+fn move_voting_stake_merge_into_bep_for_bonus_forfeited_works() {
+    ExtBuilder::default().build_and_execute(|| {
+        let source_contract = MockSmartContract::wasm(1 as AccountId);
+        let dest_contract = MockSmartContract::wasm(2 as AccountId);
+        assert_register(1, &source_contract);
+        assert_register(1, &dest_contract);
+
+        let account = 2;
+        assert_lock(account, 300);
+        let stake = 100;
+        assert_stake(account, &source_contract, stake);
+
+        advance_to_next_subperiod();
+
+        // Unstake to forfeit bonus but preserve voting stake
+        let unstake = 20;
+        assert_unstake(account, &source_contract, unstake);
+
+        let staking_info = StakerInfo::<Test>::get(account, &source_contract)
+            .expect("Should exist since stake/unstake operations were successful.");
+        assert_eq!(
+            staking_info.staked_amount(Subperiod::Voting),
+            stake - unstake
+        );
+        assert_eq!(staking_info.staked_amount(Subperiod::BuildAndEarn), 0);
+        assert!(!staking_info.is_bonus_eligible(), "Sanity check");
+
+        // Move to merge voting stake into bep stake
+        assert_move_stake(account, &source_contract, &dest_contract, stake - unstake); // full move
+        let staking_info = StakerInfo::<Test>::get(account, &dest_contract)
+            .expect("Should exist since move operations is successful.");
+        assert_eq!(staking_info.staked_amount(Subperiod::Voting), 0);
+        assert_eq!(
+            staking_info.staked_amount(Subperiod::BuildAndEarn),
+            stake - unstake
+        );
+    })
+}
+
+#[test]
+fn set_static_tier_params_invalid_params_fails() {
+    ExtBuilder::default().build_and_execute(|| {
+        // Base value is assumed to be correct
+        let tier_params = StaticTierParams::<Test>::get();
+        assert!(tier_params.is_valid(), "Sanity check");
+        type NumberOfTiers = <Test as Config>::NumberOfTiers;
+
+        let invalid_tier_params = TierParameters::<NumberOfTiers> {
+            reward_portion: tier_params.reward_portion[1..].to_vec().try_into().unwrap(),
+            ..tier_params.clone()
+        };
+        assert!(!invalid_tier_params.is_valid(), "Sanity check");
+
+        assert_noop!(
+            DappStaking::set_static_tier_params(RuntimeOrigin::root(), invalid_tier_params),
+            Error::<Test>::InvalidTierParams
+        );
+
+        // invalid tier rank multiplier (tier 0 exceeds 10_000)
+        let mut bad_params = tier_params.clone();
+        bad_params.tier_rank_multipliers[0] = 20_000;
+        assert!(!bad_params.is_valid(), "Invalid tier rank multiplier");
+
+        assert_noop!(
+            DappStaking::set_static_tier_params(RuntimeOrigin::root(), bad_params),
+            Error::<Test>::InvalidTierParams
+        );
+    })
+}
+
+#[test]
+fn set_static_tier_params_works() {
+    ExtBuilder::default().build_and_execute(|| {
+        let mut tier_params = StaticTierParams::<Test>::get();
+
+        // An example of complete invalidation of the first tier - still valid params.
+        tier_params.reward_portion[0] = Permill::zero();
+        tier_params.slot_distribution[0] = Permill::zero();
+        tier_params.tier_thresholds[0] = TierThreshold::FixedPercentage {
+            required_percentage: Perbill::one(),
+        };
+
+        assert_ok!(DappStaking::set_static_tier_params(
+            RuntimeOrigin::root(),
+            tier_params.clone()
+        ));
+
+        assert_eq!(StaticTierParams::<Test>::get(), tier_params);
+        System::assert_last_event(RuntimeEvent::DappStaking(Event::NewTierParameters {
+            params: tier_params,
+        }));
+    })
+}
+
+#[test]
+fn tier_config_recalculated_with_staked_thresholds() {
+    ExtBuilder::default().build_and_execute(|| {
+        let mut tier_params = StaticTierParams::<Test>::get();
+        tier_params.tier_thresholds[0] = TierThreshold::StakedPercentage {
+            required_percentage: Perbill::from_percent(50),
+        };
+        tier_params.tier_thresholds[1] = TierThreshold::DynamicPercentage {
+            percentage: Perbill::from_percent(30),
+            minimum_required_percentage: Perbill::zero(),
+            maximum_possible_percentage: Perbill::one(),
+        };
+        assert_ok!(DappStaking::set_static_tier_params(
+            RuntimeOrigin::root(),
+            tier_params.clone()
+        ));
+
+        // Register smart contract, lock&stake some amount
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(1, &smart_contract);
+        let account = 2;
+        let amount = 400;
+        assert_lock(account, amount);
+        assert_stake(account, &smart_contract, amount);
+
+        // Thresholds must follow the total value staked in the new era
+        advance_to_next_era();
+        let total_value_staked = CurrentEraInfo::<Test>::get().total_staked_amount();
+        assert_eq!(total_value_staked, amount, "Sanity check");
+
+        let tier_config = TierConfig::<Test>::get();
+        assert_eq!(
+            tier_config.tier_thresholds()[0],
+            Perbill::from_percent(50) * total_value_staked
+        );
+        assert_eq!(
+            tier_config.tier_thresholds()[1],
+            Perbill::from_percent(30) * total_value_staked
+        );
+    })
+}
+
+// Tests a previous bug where previous_stake was storing future stake amounts (amounts that should be eligible in the next era)
+#[test]
+fn previous_stake_unchanged_for_future_era_staking() {
+    ExtBuilder::default()
+        .with_max_bonus_safe_moves(2)
+        .build_and_execute(|| {
+            // Register smart contracts 1 & 2, lock&stake some amount on 1 & 2
+            let smart_contract = MockSmartContract::wasm(1 as AccountId);
+            assert_register(1, &smart_contract);
+
+            let account = 2;
+            let amount = 100;
+            assert_lock(account, amount);
+
+            let stake_1 = 10;
+            let stake_2 = 20;
+            let stake_era_origin = ActiveProtocolState::<Test>::get().era;
+            assert_stake(account, &smart_contract, stake_1);
+            assert_stake(account, &smart_contract, stake_2);
+
+            let expected_bonus_status = *BonusStatusWrapperFor::<Test>::default();
+            let expected_staker_info = SingularStakingInfo {
+                previous_staked: Default::default(),
+                staked: StakeAmount {
+                    voting: stake_1 + stake_2,
+                    build_and_earn: 0,
+                    era: stake_era_origin + 1,
+                    period: 1,
+                },
+                bonus_status: expected_bonus_status,
+            };
+            assert_eq!(
+                StakerInfo::<Test>::get(&account, &smart_contract),
+                Some(expected_staker_info)
+            );
+
+            advance_to_next_era();
+            let stake_era_next = ActiveProtocolState::<Test>::get().era;
+            let stake_3 = 20;
+            assert_stake(account, &smart_contract, stake_3);
+
+            let expected_bonus_status = *BonusStatusWrapperFor::<Test>::default();
+            let expected_staker_info = SingularStakingInfo {
+                previous_staked: StakeAmount {
+                    voting: stake_1 + stake_2,
+                    build_and_earn: 0,
+                    era: stake_era_origin + 1,
+                    period: 1,
+                },
+                staked: StakeAmount {
+                    voting: stake_1 + stake_2,
+                    build_and_earn: stake_3,
+                    era: stake_era_next + 1,
+                    period: 1,
+                },
+                bonus_status: expected_bonus_status,
+            };
+            assert_eq!(
+                StakerInfo::<Test>::get(&account, &smart_contract),
+                Some(expected_staker_info)
+            );
+        })
+}
+
+#[test]
+fn unstake_from_unregistered_matching_next_era_total_stake() {
+    ExtBuilder::default().build_and_execute(|| {
+        let smart_contract_1 = MockSmartContract::wasm(1 as AccountId);
+        assert_register(1, &smart_contract_1);
+        let smart_contract_2 = MockSmartContract::wasm(2 as AccountId);
+        assert_register(1, &smart_contract_2);
+
+        let amount = 100;
+        let account_2 = 3;
+        assert_lock(account_2, amount);
+        let account_3 = 4;
+        assert_lock(account_3, amount);
+
+        let current_period_number = ActiveProtocolState::<Test>::get().period_number();
+
+        assert_stake(account_2, &smart_contract_1, 50);
+        assert_stake(account_2, &smart_contract_2, 50);
+        assert_stake(account_3, &smart_contract_1, amount);
+
+        advance_to_next_era();
+        assert_unregister(&smart_contract_2);
+
+        advance_to_next_era();
+        assert_claim_staker_rewards(account_2);
+        assert_unstake_from_unregistered(account_2, &smart_contract_2);
+        assert_stake(account_2, &smart_contract_1, 50);
+
+        let mut ledger_total_stake = Balance::zero();
+        for (_, ledger) in Ledger::<Test>::iter() {
+            let account_stake = ledger.staked_amount(current_period_number);
+            ledger_total_stake += account_stake;
+        }
+
+        let current_era_info = CurrentEraInfo::<Test>::get();
+        let next_era_total_stake = current_era_info.total_staked_amount_next_era();
+
+        assert_eq!(
+            ledger_total_stake, next_era_total_stake,
+            "Total stake amount across all ledger has to be equal to the next era total stake"
+        )
+    })
+}
+
+// Tests a previous bug where voting stake move/unstake was subtracted from next BuildAndEarn stake in era info
+#[test]
+fn era_info_stakes_remain_synced() {
+    ExtBuilder::default()
+        .with_max_bonus_safe_moves(1)
+        .build_and_execute(|| {
+            let smart_contract_1 = MockSmartContract::wasm(1 as AccountId);
+            assert_register(1, &smart_contract_1);
+            let smart_contract_2 = MockSmartContract::wasm(2 as AccountId);
+            assert_register(1, &smart_contract_2);
+
+            let (staker_1, amount_1) = (1, 50);
+            assert_lock(staker_1, amount_1);
+            assert_stake(staker_1, &smart_contract_1, amount_1 - 10);
+
+            advance_to_next_subperiod(); // b&e
+
+            assert_stake(staker_1, &smart_contract_1, 10);
+
+            advance_to_next_era();
+            assert_claim_staker_rewards(staker_1);
+
+            let (staker_2, amount_2) = (2, 60);
+            assert_lock(staker_2, amount_2);
+            assert_stake(staker_2, &smart_contract_1, amount_2);
+
+            let unstake_amount = 15;
+            assert_unstake(staker_1, &smart_contract_1, unstake_amount);
+
+            let current_era_info = CurrentEraInfo::<Test>::get();
+            assert_eq!(current_era_info.staked_amount(Subperiod::Voting), 35);
+            assert_eq!(current_era_info.staked_amount(Subperiod::BuildAndEarn), 0);
+            assert_eq!(
+                current_era_info.staked_amount_next_era(Subperiod::Voting),
+                35
+            );
+            assert_eq!(
+                current_era_info.staked_amount_next_era(Subperiod::BuildAndEarn),
+                60,
+            );
+
+            // Move to forfeit bonus
+            let move_amount = 35;
+            assert_move_stake(staker_1, &smart_contract_1, &smart_contract_2, move_amount);
+
+            let current_era_info = CurrentEraInfo::<Test>::get();
+            assert_eq!(current_era_info.staked_amount(Subperiod::Voting), 0);
+            assert_eq!(current_era_info.staked_amount(Subperiod::BuildAndEarn), 0);
+            assert_eq!(
+                current_era_info.staked_amount_next_era(Subperiod::Voting),
+                0
+            );
+            assert_eq!(
+                current_era_info.staked_amount_next_era(Subperiod::BuildAndEarn),
+                95,
+            );
+        })
+}
+
+// Tests a previous bug where greater previous stake amount total than current one was tried to be unstake from unregistered,
+// resulting in a `InternalUnstakeError` error masking a `AccountLedgerError::UnstakeAmountLargerThanStake` error.
+#[test]
+fn unstake_from_unregistered_use_correct_stake_amount() {
+    ExtBuilder::default().build_and_execute(|| {
+        let smart_contract_1 = MockSmartContract::wasm(1 as AccountId);
+        assert_register(1, &smart_contract_1);
+
+        let (staker_1, amount_1) = (1, 280);
+        assert_lock(staker_1, amount_1);
+        assert_stake(staker_1, &smart_contract_1, 280);
+
+        advance_to_next_subperiod(); // b&e
+        advance_to_next_era();
+
+        assert_claim_staker_rewards(staker_1);
+        assert_unstake(staker_1, &smart_contract_1, 30);
+
+        advance_to_next_era();
+        assert_unregister(&smart_contract_1);
+
+        advance_to_next_era();
+        assert_claim_staker_rewards(staker_1);
+        assert_unstake_from_unregistered(staker_1, &smart_contract_1);
+    })
+}
+
+// Tests a previous bug where the contract_stake_count kept increasing for the same contract entry
+// when a staker continued staking across periods.
+// Now restaking on the same contract after a period transition
+// replaces the old StakerInfo entry without inflating contract_stake_count.
+#[test]
+fn restake_replaces_old_entry_without_inflating_counter() {
+    ExtBuilder::default().build_and_execute(|| {
+        let staker = 2;
+        let contract = MockSmartContract::wasm(1 as AccountId);
+
+        assert_register(1, &contract);
+        assert_lock(staker, 1_000);
+
+        // Period 1: initial stake
+        advance_to_next_subperiod();
+        assert_stake(staker, &contract, 100);
+
+        let ledger_after_first_stake = Ledger::<Test>::get(&staker);
+        assert_eq!(ledger_after_first_stake.contract_stake_count, 1);
+        assert_eq!(StakerInfo::<Test>::iter_prefix(&staker).count(), 1);
+
+        let original_entry = StakerInfo::<Test>::get(&staker, &contract).expect("entry must exist");
+        let original_period = original_entry.period_number();
+
+        // Move to next period and claim rewards (ledger cleared, StakerInfo entry remains)
+        advance_to_next_period();
+        for _ in 0..required_number_of_reward_claims(staker) {
+            assert_claim_staker_rewards(staker);
+        }
+
+        // Entry still exists but is now orphaned
+        assert_eq!(
+            StakerInfo::<Test>::iter_prefix(&staker).count(),
+            1,
+            "Old StakerInfo entry should still exist after claim"
+        );
+
+        // Period 2: restake same contract
+        advance_to_next_subperiod();
+        assert_stake(staker, &contract, 100);
+
+        let final_ledger = Ledger::<Test>::get(&staker);
+        assert_eq!(
+            final_ledger.contract_stake_count, 1,
+            "contract_stake_count must not inflate when replacing old entry"
+        );
+
+        let entries: Vec<_> = StakerInfo::<Test>::iter_prefix(&staker).collect();
+        assert_eq!(entries.len(), 1, "Should still have exactly one entry");
+
+        let (_, new_entry) = &entries[0];
+        assert_eq!(
+            new_entry.period_number(),
+            original_period + 1,
+            "Old entry should have been replaced with new period entry"
+        );
+    });
+}
+
+// Tests cleanup partitioning and ledger counter alignment with one real stake and injected historical entries.
+#[test]
+fn cleanup_expired_entries_correctly_with_ledger_counter_reconciliation() {
+    ExtBuilder::default().build_and_execute(|| {
+        let account: AccountId = 1;
+
+        // setup
+        let retained_periods: u32 = <Test as Config>::RewardRetentionInPeriods::get();
+        assert_eq!(retained_periods, 2, "sanity check");
+
+        // Advance protocol to period 3
+        advance_to_next_period();
+        advance_to_next_period();
+
+        let protocol_state = ActiveProtocolState::<Test>::get();
+        let current_period = protocol_state.period_number();
+        let threshold_period = DappStaking::oldest_claimable_period(current_period);
+
+        // Sanity checks for the test assumptions
+        assert_eq!(current_period, 3);
+        assert_eq!(threshold_period, 1);
+
+        // Valid current-period stake (keep after cleanup)
+        let keep_current = MockSmartContract::wasm(10 as AccountId);
+        assert_register(1, &keep_current);
+        assert_lock(account, 1_000);
+
+        advance_to_next_subperiod(); // no bonus
+        assert_stake(account, &keep_current, 10);
+
+        // Injected contracts (impossible via extrinsics)
+        let keep_bonus = MockSmartContract::wasm(11 as AccountId);
+        let delete_no_bonus = MockSmartContract::wasm(12 as AccountId);
+        let delete_too_old = MockSmartContract::wasm(13 as AccountId);
+
+        // 1. Past but claimable + bonus eligible (keep after cleanup)
+        StakerInfo::<Test>::insert(
+            &account,
+            &keep_bonus,
+            SingularStakingInfo::new(current_period - 1, 1),
+        );
+
+        // 2. Past but claimable + NOT bonus eligible (delete after cleanup)
+        StakerInfo::<Test>::insert(
+            &account,
+            &delete_no_bonus,
+            SingularStakingInfo::new(current_period - 1, 0),
+        );
+
+        // 3. Older than oldest claimable period → (delete after cleanup)
+        StakerInfo::<Test>::insert(
+            &account,
+            &delete_too_old,
+            SingularStakingInfo::new(threshold_period - 1, 1),
+        );
+
+        // Inflate the ledger counter
+        Ledger::<Test>::mutate(&account, |ledger| {
+            ledger.contract_stake_count = 10;
+        });
+
+        assert_ok!(DappStaking::cleanup_expired_entries(RuntimeOrigin::signed(
+            account
+        )));
+
+        // ---- assert remaining entries ----
+        assert!(StakerInfo::<Test>::contains_key(&account, &keep_current));
+        assert!(StakerInfo::<Test>::contains_key(&account, &keep_bonus));
+
+        // ---- assert deleted entries ----
+        assert!(!StakerInfo::<Test>::contains_key(
+            &account,
+            &delete_no_bonus
+        ));
+        assert!(!StakerInfo::<Test>::contains_key(&account, &delete_too_old));
 
-        // Advance to B&E subperiod, stake again to check both stakes move and finally unregister source_contract
-        advance_to_next_subperiod();
-        assert_stake(account, &source_contract, partial_stake_2);
-        assert_unregister(&source_contract);
+        // ---- assert counter reconciliation ----
+        let ledger = Ledger::<Test>::get(&account);
+        let actual_entries = StakerInfo::<Test>::iter_prefix(&account).count() as u32;
 
-        assert_move_stake(
-            account,
-            &source_contract,
-            &dest_contract,
-            1, // the amount is not important for an unregistered contract, everything is moved
+        assert_eq!(
+            ledger.contract_stake_count, actual_entries,
+            "contract_stake_count must be equal to remaining entries"
         );
 
-        let default_bonus_status = *BonusStatusWrapperFor::<Test>::default();
-        assert!(StakerInfo::<Test>::get(&account, &source_contract).is_none());
-        let expected_dest_staking_info = SingularStakingInfo {
-            previous_staked: StakeAmount::default(),
-            staked: StakeAmount {
-                voting: partial_stake_1,
-                build_and_earn: partial_stake_2,
-                era: 3,
-                period: 1,
-            },
-            bonus_status: default_bonus_status,
-        };
-        let dest_staking_info = StakerInfo::<Test>::get(&account, &dest_contract)
-            .expect("Should exist after a successful move operation");
-        assert_eq!(dest_staking_info, expected_dest_staking_info);
-    })
+        assert_eq!(
+            actual_entries, 2,
+            "only current-period and bonus-eligible past entries should remain"
+        );
+    });
 }
 
-// Tests multiple moves for registered contracts
-// Checks proper stake amounts transfer for preserved bonus and conversion for forfeited bonus
 #[test]
-fn move_stake_bonus_preserved_with_transfer_conversion_is_ok() {
-    ExtBuilder::default()
-        .with_max_bonus_safe_moves(1)
-        .build_and_execute(|| {
-            // Sanity check
-            let default_bonus_status = *BonusStatusWrapperFor::<Test>::default();
-            assert_eq!(
-                default_bonus_status, 2,
-                "max_moves value must be 1 to cover both scenarios"
-            );
-
-            // Prep - Register source/stopover/dest smart contracts, lock&stake some amounts
-            let source_contract = MockSmartContract::wasm(1 as AccountId);
-            let stopover_contract = MockSmartContract::wasm(2 as AccountId);
-            let final_contract = MockSmartContract::wasm(3 as AccountId);
-            assert_register(1, &source_contract);
-            assert_register(1, &stopover_contract);
-            assert_register(1, &final_contract);
-
-            let min_stake_amount: Balance = <Test as Config>::MinimumStakeAmount::get();
-
-            let account = 2;
-            let amount = 700;
-            assert_lock(account, amount);
-            assert_stake(account, &source_contract, 100);
-            assert_stake(account, &final_contract, 200);
+fn set_auto_claim_works() {
+    ExtBuilder::default().build_and_execute(|| {
+        let account = 2;
+        assert!(!AutoClaim::<Test>::contains_key(&account));
 
-            // Advance to B&E subperiod, stake again to check both stakes move
-            advance_to_next_subperiod();
-            assert_stake(account, &source_contract, 100);
-            assert_stake(account, &stopover_contract, 100);
-            assert_stake(account, &final_contract, 200);
+        // Enable automatic claiming
+        assert_ok!(DappStaking::set_auto_claim(
+            RuntimeOrigin::signed(account),
+            true
+        ));
+        assert!(AutoClaim::<Test>::contains_key(&account));
+        System::assert_last_event(RuntimeEvent::DappStaking(Event::AutoClaimSet {
+            account,
+            enabled: true,
+        }));
 
-            // Move 1 - source -> stopover - VOTING TRANSFER
-            let move_amount = 200 - min_stake_amount + 1; // for full move
-            assert_move_stake(account, &source_contract, &stopover_contract, move_amount);
+        // Disable automatic claiming
+        assert_ok!(DappStaking::set_auto_claim(
+            RuntimeOrigin::signed(account),
+            false
+        ));
+        assert!(!AutoClaim::<Test>::contains_key(&account));
+        System::assert_last_event(RuntimeEvent::DappStaking(Event::AutoClaimSet {
+            account,
+            enabled: false,
+        }));
+    })
+}
 
-            assert!(StakerInfo::<Test>::get(&account, &source_contract).is_none());
-            let default_bonus_status = *BonusStatusWrapperFor::<Test>::default();
-            let expected_bonus_status = default_bonus_status - 1; // B&E subperiod + full move
-            let expected_stopover_staking_info = SingularStakingInfo {
-                previous_staked: StakeAmount::default(),
-                staked: StakeAmount {
-                    voting: 100,
-                    build_and_earn: 200,
-                    era: 3,
-                    period: 1,
-                },
-                bonus_status: expected_bonus_status,
-            };
-            let stopover_staking_info = StakerInfo::<Test>::get(&account, &stopover_contract)
-                .expect("Should exist after a successful stake/move operations");
-            assert_eq!(stopover_staking_info, expected_stopover_staking_info);
+#[test]
+fn auto_claim_claims_expiring_rewards() {
+    ExtBuilder::default().build_and_execute(|| {
+        // Register smart contract, lock & stake some amount in the voting subperiod
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(1, &smart_contract);
 
-            // Move 2 - stopover -> final - VOTING CONVERSION
+        let (auto_claimer, other_staker) = (2, 3);
+        for account in [auto_claimer, other_staker] {
+            assert_lock(account, 300);
+            assert_stake(account, &smart_contract, 100);
+        }
+        assert_ok!(DappStaking::set_auto_claim(
+            RuntimeOrigin::signed(auto_claimer),
+            true
+        ));
+        let staked_period = ActiveProtocolState::<Test>::get().period_number();
 
-            // Advance one era (era 3) so we can check that move_2 amount will be effective the era after (era 4) (even if it was already effective)
-            advance_to_era(ActiveProtocolState::<Test>::get().era + 1);
+        // Rewards aren't claimed automatically until they are about to expire
+        let retention_periods: PeriodNumber = <Test as Config>::RewardRetentionInPeriods::get();
+        advance_to_period(staked_period + retention_periods - 1);
+        run_for_blocks(5);
+        assert_eq!(
+            Ledger::<Test>::get(&auto_claimer).staked_period(),
+            Some(staked_period)
+        );
+        assert!(StakerInfo::<Test>::contains_key(
+            &auto_claimer,
+            &smart_contract
+        ));
 
-            // Prep - claim rewards to be able to move
-            for _ in 0..required_number_of_reward_claims(account) {
-                assert_claim_staker_rewards(account);
-            }
+        // Once staked period becomes the oldest claimable one, rewards are claimed automatically.
+        // Multiple blocks are needed since each claim covers one era reward span at most.
+        let pre_free_balance = Balances::free_balance(&auto_claimer);
+        advance_to_period(staked_period + retention_periods);
+        run_for_blocks(5);
 
-            let move_2_amount = 300; // for full move again
-            assert_move_stake(account, &stopover_contract, &final_contract, move_2_amount);
+        assert!(Ledger::<Test>::get(&auto_claimer).staked_period().is_none());
+        assert!(!StakerInfo::<Test>::contains_key(
+            &auto_claimer,
+            &smart_contract
+        ));
+        assert!(Balances::free_balance(&auto_claimer) > pre_free_balance);
 
-            assert!(StakerInfo::<Test>::get(&account, &stopover_contract).is_none());
-            let expected_final_staking_info = SingularStakingInfo {
-                previous_staked: StakeAmount {
-                    voting: 200,
-                    build_and_earn: 200,
-                    era: 3,
-                    period: 1,
-                },
-                staked: StakeAmount {
-                    voting: 200,
-                    build_and_earn: 500,
-                    era: 4,
-                    period: 1,
-                },
-                bonus_status: default_bonus_status,
-            };
-            let final_staking_info = StakerInfo::<Test>::get(&account, &final_contract)
-                .expect("Should exist after a successful move operation");
-            assert_eq!(final_staking_info, expected_final_staking_info);
-        })
+        // Accounts which aren't registered must claim rewards themselves
+        assert_eq!(
+            Ledger::<Test>::get(&other_staker).staked_period(),
+            Some(staked_period)
+        );
+        assert!(StakerInfo::<Test>::contains_key(
+            &other_staker,
+            &smart_contract
+        ));
+    })
 }
 
 #[test]
-fn move_stake_multiple_conversions_are_ok() {
+fn auto_claim_cursor_continues_from_last_inspected_account() {
     ExtBuilder::default().build_and_execute(|| {
-        // Sanity check - max_moves value must be 0
+        let scan_limit: u32 = <Test as Config>::AutoClaimScanLimit::get();
+        for account in 1..=(scan_limit + 1) as AccountId {
+            assert_ok!(DappStaking::set_auto_claim(
+                RuntimeOrigin::signed(account),
+                true
+            ));
+        }
+        AutoClaimCursor::<Test>::kill();
+
+        // First pass inspects the max number of accounts, and stores the cursor
+        DappStaking::on_idle(System::block_number(), Weight::MAX);
+        let cursor = AutoClaimCursor::<Test>::get().expect("Must be set since limit was reached.");
         assert_eq!(
-            0,
-            <Test as Config>::MaxBonusSafeMovesPerPeriod::get(),
-            "Sanity check"
+            AutoClaim::<Test>::iter_keys().nth(scan_limit as usize - 1),
+            Some(cursor)
         );
 
-        // Prep - Register source/dest smart contracts, lock&stake some amounts
-        let source_contract = MockSmartContract::wasm(1 as AccountId);
-        let dest_contract = MockSmartContract::wasm(2 as AccountId);
-        assert_register(1, &source_contract);
-        assert_register(1, &dest_contract);
-
-        let account = 2;
-        let amount = 300;
-        assert_lock(account, amount);
-        assert_stake(account, &source_contract, 100);
-        assert_stake(account, &dest_contract, 100);
+        // Second pass inspects the remaining account, and resets the cursor
+        DappStaking::on_idle(System::block_number(), Weight::MAX);
+        assert!(AutoClaimCursor::<Test>::get().is_none());
+    })
+}
 
-        // Advance to B&E subperiod, stake again to check both stakes move
+#[test]
+fn auto_claim_continues_from_first_unprocessed_account() {
+    ExtBuilder::default().build_and_execute(|| {
+        // Register smart contract, lock & stake some amount in the build&earn subperiod, so there are no bonus rewards
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(1, &smart_contract);
         advance_to_next_subperiod();
-        assert_stake(account, &source_contract, 100);
-
-        // Move 1 - source -> dest - VOTING CONVERSION 1
-        let move_amount = 150; // reduce bonus status
-        assert_move_stake(account, &source_contract, &dest_contract, move_amount);
-
-        let expected_source_staking_info = SingularStakingInfo {
-            previous_staked: StakeAmount {
-                voting: 50,
-                build_and_earn: 0,
-                era: 2,
-                period: 1,
-            },
-            staked: StakeAmount {
-                voting: 50,
-                build_and_earn: 0,
-                era: 3,
-                period: 1,
-            },
-            bonus_status: 0, // bonus has been forfeited
-        };
-
-        let source_staking_info = StakerInfo::<Test>::get(&account, &source_contract)
-            .expect("Should exist after a successful move operation");
-        assert_eq!(source_staking_info, expected_source_staking_info);
+        for account in [2, 3] {
+            assert_lock(account, 300);
+            assert_stake(account, &smart_contract, 100);
+        }
+        let staked_period = ActiveProtocolState::<Test>::get().period_number();
 
-        let expected_dest_staking_info = SingularStakingInfo {
-            previous_staked: StakeAmount {
-                voting: 100,
-                build_and_earn: 0,
-                era: 2,
-                period: 1,
-            },
-            staked: StakeAmount {
-                voting: 100,
-                build_and_earn: 150,
-                era: 3,
-                period: 1,
-            },
-            bonus_status: 1, // bonus is preserved
-        };
-        let dest_staking_info = StakerInfo::<Test>::get(&account, &dest_contract)
-            .expect("Should exist after a successful move operation");
-        assert_eq!(dest_staking_info, expected_dest_staking_info);
+        // Advance until the staker rewards are about to expire, and only then register for automatic claiming
+        let retention_periods: PeriodNumber = <Test as Config>::RewardRetentionInPeriods::get();
+        advance_to_period(staked_period + retention_periods);
+        for account in [2, 3] {
+            assert_ok!(DappStaking::set_auto_claim(
+                RuntimeOrigin::signed(account),
+                true
+            ));
+        }
+        AutoClaimCursor::<Test>::kill();
+        let accounts: Vec<AccountId> = AutoClaim::<Test>::iter_keys().collect();
+        let pre_ledgers: Vec<_> = accounts
+            .iter()
+            .map(|account| Ledger::<Test>::get(account))
+            .collect();
 
-        // Move 2 - source -> dest - VOTING CONVERSION 2
-        let move_amount = 50; // full move
-        assert_move_stake(account, &source_contract, &dest_contract, move_amount);
+        // Enough weight to inspect both accounts, but to claim the rewards of only one of them
+        let db_weight = <Test as frame_system::Config>::DbWeight::get();
+        let scan_weight =
+            db_weight.reads((<Test as Config>::MaxNumberOfStakedContracts::get() + 2).into());
+        let weight = db_weight
+            .reads_writes(2, 1)
+            .saturating_add(scan_weight.saturating_mul(2))
+            .saturating_add(
+                DappStaking::max_past_period_staker_claim_weight()
+                    .max(DappStaking::max_bonus_claim_weight()),
+            );
+        DappStaking::auto_claim_rewards(&weight);
+        assert_ne!(Ledger::<Test>::get(&accounts[0]), pre_ledgers[0]);
+        assert_eq!(Ledger::<Test>::get(&accounts[1]), pre_ledgers[1]);
 
-        assert!(StakerInfo::<Test>::get(&account, &source_contract).is_none());
+        // Cursor must not skip the unprocessed account
+        assert_eq!(AutoClaimCursor::<Test>::get(), Some(accounts[0]));
 
-        let expected_dest_staking_info = SingularStakingInfo {
-            previous_staked: StakeAmount {
-                voting: 100,
-                build_and_earn: 0,
-                era: 2,
-                period: 1,
-            },
-            staked: StakeAmount {
-                voting: 100,
-                build_and_earn: 200,
-                era: 3,
-                period: 1,
-            },
-            bonus_status: 1, // bonus is still preserved
-        };
-        let dest_staking_info = StakerInfo::<Test>::get(&account, &dest_contract)
-            .expect("Should exist after a successful move operation");
-        assert_eq!(dest_staking_info, expected_dest_staking_info);
+        DappStaking::auto_claim_rewards(&weight);
+        assert_ne!(Ledger::<Test>::get(&accounts[1]), pre_ledgers[1]);
     })
 }
 
-#[test]
-// Tests moving stake with bonus preservation to an already lost bonus contract but that has some voting stake amount leftover. Verify that:
-// - total staked amount is preserved (the voting leftover stake should have been moved to bep stake),
-// - bonus status is updated (decreased but bonus is transferred and preserved)
-fn move_stake_in_bep_to_contract_with_voting_leftover_and_no_bonus_is_ok() {
-    ExtBuilder::default()
-        .with_max_bonus_safe_moves(1)
-        .build_and_execute(|| {
-            // Sanity check - max_moves minimal value must be 1
-            let default_bonus_status = *BonusStatusWrapperFor::<Test>::default();
-            assert!(default_bonus_status > 1);
+#[test]
+fn auto_claim_does_nothing_without_enough_weight() {
+    ExtBuilder::default().build_and_execute(|| {
+        for account in [1, 2] {
+            assert_ok!(DappStaking::set_auto_claim(
+                RuntimeOrigin::signed(account),
+                true
+            ));
+        }
+        let cursor = AutoClaim::<Test>::iter_keys().next().unwrap();
+        AutoClaimCursor::<Test>::put(cursor);
 
-            // Register smart contracts 1 & 2, lock&stake some amount on 1 & 2
-            let source_contract = MockSmartContract::wasm(1 as AccountId);
-            let dest_contract = MockSmartContract::wasm(2 as AccountId);
-            assert_register(1, &source_contract);
-            assert_register(1, &dest_contract);
+        // Not enough weight to claim rewards, so no account must be inspected
+        let weight = DappStaking::on_idle(
+            System::block_number(),
+            <Test as Config>::WeightInfo::on_idle_cleanup(),
+        );
+        assert!(weight.all_lte(<Test as Config>::WeightInfo::on_idle_cleanup()));
+        assert_eq!(AutoClaimCursor::<Test>::get(), Some(cursor));
 
-            let account = 2;
-            let amount = 300;
-            assert_lock(account, amount);
+        // With enough weight, the remaining account is inspected, and cursor is reset
+        DappStaking::on_idle(System::block_number(), Weight::MAX);
+        assert!(AutoClaimCursor::<Test>::get().is_none());
+    })
+}
 
-            let voting_stake_1 = 100;
-            assert_stake(account, &source_contract, voting_stake_1);
-            assert_stake(account, &dest_contract, voting_stake_1);
+#[test]
+fn paged_tier_assignment_is_prepared_before_era_change() {
+    ExtBuilder::default().build_and_execute(|| {
+        // Register & stake on enough dApps to require multiple pages
+        let page_size = <Test as Config>::TierAssignmentPageSize::get();
+        let number_of_dapps = page_size * 2 + 1;
+        let threshold = TierConfig::<Test>::get().tier_thresholds()[1];
+        for x in 1..=number_of_dapps {
+            let account = x as AccountId;
+            let smart_contract = MockSmartContract::wasm(account);
+            assert_register(account, &smart_contract);
+
+            let amount = threshold + x as Balance;
+            Balances::make_free_balance_be(&account, amount);
+            assert_lock(account, amount);
+            assert_stake(account, &smart_contract, amount);
+        }
+        advance_to_next_subperiod();
 
-            advance_to_next_subperiod();
+        // Tier assignment preparation starts just early enough to read all pages before the era change
+        let protocol_state = ActiveProtocolState::<Test>::get();
+        let era = protocol_state.era;
+        let number_of_pages = number_of_dapps.div_ceil(page_size) * 2 + 1;
+        run_to_block(protocol_state.next_era_start - number_of_pages - 1);
+        assert!(TierAssignmentState::<Test>::get().is_none());
 
-            // We want to forfeit destination contract stake bonus, this is done with 2 unstake.
-            // We also want to have some bep stake amount to check both stake transfers.
-            // All is done with the following stake/unstake operations, for the desired state:
-            // source staker info { voting: 100, build_and_earn: 20, bonus_status: 2 }
-            // dest staker info { voting: 50, build_and_earn: 30, bonus_status: 0 }
-            assert_stake(account, &source_contract, 20);
-            assert_unstake(account, &dest_contract, 20);
-            assert_unstake(account, &dest_contract, 30);
-            assert_stake(account, &dest_contract, 30);
+        run_for_blocks(1);
+        let progress = TierAssignmentState::<Test>::get().expect("First page must be read.");
+        assert_eq!(progress.era, era);
+        assert_eq!(progress.candidates.len(), page_size as usize);
+        assert!(!progress.finished);
 
-            assert_move_stake(
-                account,
-                &source_contract,
-                &dest_contract,
-                120, // full move
-            );
+        // All pages are read before the era change
+        run_to_block(protocol_state.next_era_start - 1);
+        let progress = TierAssignmentState::<Test>::get().expect("Progress must exist.");
+        assert_eq!(progress.candidates.len(), number_of_dapps as usize);
+        assert!(progress.finished);
+        let expected_dapps = DappStaking::get_dapp_tier_assignment();
+        assert!(!expected_dapps.is_empty());
 
-            let staking_info = StakerInfo::<Test>::get(account, &dest_contract)
-                .expect("Should exist since move operation was successful.");
-            assert_eq!(staking_info.bonus_status, default_bonus_status - 1);
-        })
+        // Tier assignment is finalized at the era change
+        run_for_blocks(1);
+        assert_eq!(ActiveProtocolState::<Test>::get().era, era + 1);
+        assert!(TierAssignmentState::<Test>::get().is_none());
+        assert_eq!(
+            DAppTiers::<Test>::get(era)
+                .expect("Must exist.")
+                .dapps
+                .into_inner(),
+            expected_dapps
+        );
+    })
 }
 
-// Tests bonus status merging when source and destination stake infos have both non zero statuses.
-// The expected result is to take the middle.
 #[test]
-fn move_stake_merge_bonus_status() {
-    ExtBuilder::default()
-        .with_max_bonus_safe_moves(2)
-        .build_and_execute(|| {
-            // Register smart contracts 1 & 2, lock&stake some amount on 1 & 2
-            let source_contract = MockSmartContract::wasm(1 as AccountId);
-            let dest_contract = MockSmartContract::wasm(2 as AccountId);
-            assert_register(1, &source_contract);
-            assert_register(1, &dest_contract);
-
-            let account = 2;
-            let amount = 300;
+fn paged_tier_assignment_accounts_for_changes_after_page_is_read() {
+    ExtBuilder::default().build_and_execute(|| {
+        // Register & stake on enough dApps to require multiple pages
+        let page_size = <Test as Config>::TierAssignmentPageSize::get();
+        let number_of_dapps = page_size * 2;
+        let threshold = TierConfig::<Test>::get().tier_thresholds()[1];
+        for x in 1..=number_of_dapps {
+            let account = x as AccountId;
+            let smart_contract = MockSmartContract::wasm(account);
+            assert_register(account, &smart_contract);
+
+            let amount = threshold + x as Balance;
+            Balances::make_free_balance_be(&account, amount);
             assert_lock(account, amount);
+            assert_stake(account, &smart_contract, amount);
+        }
+        advance_to_next_subperiod();
 
-            let stake = 100;
-            assert_stake(account, &source_contract, stake);
-            assert_stake(account, &dest_contract, stake);
+        // Read the first page
+        let protocol_state = ActiveProtocolState::<Test>::get();
+        let era = protocol_state.era;
+        let number_of_pages = number_of_dapps.div_ceil(page_size) * 2 + 1;
+        run_to_block(protocol_state.next_era_start - number_of_pages);
+        let progress = TierAssignmentState::<Test>::get().expect("First page must be read.");
+        assert_eq!(progress.candidates.len(), page_size as usize);
+
+        // Unregister one of the already read dApps, and fully unstake from another one.
+        // dApp Ids are assigned sequentially, and each dApp is staked on by its owner.
+        let (unregistered_dapp_id, _) = progress.candidates[0];
+        let unregistered_owner = unregistered_dapp_id as AccountId + 1;
+        assert_unregister(&MockSmartContract::wasm(unregistered_owner));
+
+        let (unstaked_dapp_id, unstaked_amount) = progress.candidates[1];
+        let unstaked_owner = unstaked_dapp_id as AccountId + 1;
+        assert_unstake(
+            unstaked_owner,
+            &MockSmartContract::wasm(unstaked_owner),
+            unstaked_amount,
+        );
 
-            advance_to_next_subperiod();
+        // Tier assignment must reflect the changes
+        run_to_block(protocol_state.next_era_start - 1);
+        let expected_dapps = DappStaking::get_dapp_tier_assignment();
+        assert_eq!(expected_dapps.len(), number_of_dapps as usize - 2);
 
-            // This is done to reduce the bonus status and increase the gap for future merging in move.
-            let unstake = 20;
-            assert_unstake(account, &source_contract, unstake);
+        run_for_blocks(1);
+        let dapps = DAppTiers::<Test>::get(era)
+            .expect("Must exist.")
+            .dapps
+            .into_inner();
+        assert!(!dapps.contains_key(&unregistered_dapp_id));
+        assert!(!dapps.contains_key(&unstaked_dapp_id));
+        assert_eq!(dapps, expected_dapps);
+    })
+}
 
-            assert_move_stake(
+#[test]
+fn paged_tier_assignment_reconsiders_dropped_candidates() {
+    ExtBuilder::default().build_and_execute(|| {
+        // Register more dApps than there are tier slots, so some of them are dropped from the candidates list.
+        let page_size = <Test as Config>::TierAssignmentPageSize::get();
+        let number_of_dapps = page_size * 2 + 2;
+        for x in 1..=number_of_dapps {
+            assert_register(x as AccountId, &MockSmartContract::wasm(x as AccountId));
+        }
+
+        // Contract stake entries are read in the storage iteration order.
+        // dApps which are read first get the highest stake, so the ones read afterwards are dropped.
+        // Stake is large enough for all of the dApps to satisfy the highest tier threshold.
+        // dApp Ids are assigned sequentially, and each dApp is staked on by its owner.
+        let mut read_order: Vec<DAppId> = (0..number_of_dapps as DAppId).collect();
+        read_order.sort_by_key(|dapp_id| ContractStake::<Test>::hashed_key_for(dapp_id));
+        let stake_of =
+            |position: usize| 1000 + 10 * (number_of_dapps as usize - position) as Balance;
+        for (position, dapp_id) in read_order.iter().enumerate() {
+            let account = *dapp_id as AccountId + 1;
+            Balances::make_free_balance_be(&account, stake_of(position));
+            assert_lock(account, stake_of(position));
+            assert_stake(
                 account,
-                &source_contract,
-                &dest_contract,
-                stake - unstake, // full move
+                &MockSmartContract::wasm(account),
+                stake_of(position),
             );
+        }
+        advance_to_next_subperiod();
+        TierConfig::<Test>::mutate(|config| {
+            config.slots_per_tier = BoundedVec::try_from(vec![1, 1, 1, 1]).unwrap();
+        });
+        let limit = DappStaking::tier_candidates_limit() as usize;
+        assert!(limit < number_of_dapps as usize);
 
-            let staking_info = StakerInfo::<Test>::get(account, &dest_contract)
-                .expect("Should exist since move operation was successful.");
-            let incoming_bonus_status_from_source = 1; // default (3) - 2 move actions
-            let bonus_status_for_dest = 3; // default (3)
-            let expected_merged_bonus_status =
-                (incoming_bonus_status_from_source + bonus_status_for_dest) / 2;
-            assert_eq!(staking_info.bonus_status, expected_merged_bonus_status);
-        })
-}
+        // All pages are read, and the best dropped stake is tracked
+        let protocol_state = ActiveProtocolState::<Test>::get();
+        let era = protocol_state.era;
+        let number_of_pages = number_of_dapps.div_ceil(page_size);
+        run_to_block(protocol_state.next_era_start - number_of_pages - 2);
+        let progress = TierAssignmentState::<Test>::get().expect("Progress must exist.");
+        assert!(progress.finished);
+        assert_eq!(progress.candidates.len(), limit);
+        assert_eq!(progress.dropped_max_amount, stake_of(limit));
+
+        // Unregister one of the candidates, so one of the dropped dApps could take its place.
+        let (unregistered_dapp_id, _) = progress.candidates[0];
+        let unregistered_owner = unregistered_dapp_id as AccountId + 1;
+        assert_unregister(&MockSmartContract::wasm(unregistered_owner));
+
+        // All of the entries are read again, page by page
+        run_for_blocks(1);
+        let progress = TierAssignmentState::<Test>::get().expect("Progress must exist.");
+        assert!(progress.candidates.is_empty());
+        assert!(!progress.finished);
 
-#[test]
-fn move_for_same_contract_fails() {
-    ExtBuilder::default().build_and_execute(|| {
-        let account = 2;
-        let contract = MockSmartContract::wasm(1 as AccountId);
-        assert_register(1, &contract);
+        run_to_block(protocol_state.next_era_start - 1);
+        let progress = TierAssignmentState::<Test>::get().expect("Progress must exist.");
+        assert!(progress.finished);
+        assert!(progress
+            .candidates
+            .iter()
+            .any(|(dapp_id, _)| *dapp_id == read_order[limit]));
+
+        // Fully unstake from one of the candidates right before the era change.
+        // Dropped dApps must still be reconsidered.
+        let (unstaked_dapp_id, unstaked_amount) = progress.candidates[0];
+        let unstaked_owner = unstaked_dapp_id as AccountId + 1;
+        assert_unstake(
+            unstaked_owner,
+            &MockSmartContract::wasm(unstaked_owner),
+            unstaked_amount,
+        );
 
-        assert_noop!(
-            DappStaking::move_stake(RuntimeOrigin::signed(account), contract, contract, 1),
-            Error::<Test>::SameContracts
+        let expected_dapps = DappStaking::get_dapp_tier_assignment();
+        assert_eq!(expected_dapps.len(), limit);
+        assert!(expected_dapps.contains_key(&read_order[limit + 1]));
+
+        run_for_blocks(1);
+        assert_eq!(ActiveProtocolState::<Test>::get().era, era + 1);
+        assert_eq!(
+            DAppTiers::<Test>::get(era)
+                .expect("Must exist.")
+                .dapps
+                .into_inner(),
+            expected_dapps
         );
     })
 }
 
-// Destination contract is not found in IntegratedDApps.
 #[test]
-fn move_to_invalid_dapp_fails() {
+fn dapp_tier_history_is_updated_at_era_change() {
     ExtBuilder::default().build_and_execute(|| {
-        let source_contract = MockSmartContract::wasm(1 as AccountId);
-        let destination_contract = MockSmartContract::wasm(2 as AccountId);
-        assert_register(1, &source_contract);
+        // Register & stake on the dApp, so it gets assigned to a tier
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(1, &smart_contract);
+        let dapp_id = IntegratedDApps::<Test>::get(&smart_contract)
+            .expect("Must exist.")
+            .id;
 
         let account = 2;
-        assert_lock(account, 300);
+        let amount = TierConfig::<Test>::get().tier_thresholds()[1];
+        Balances::make_free_balance_be(&account, amount);
+        assert_lock(account, amount);
+        assert_stake(account, &smart_contract, amount);
+        advance_to_next_subperiod();
+        assert!(DappStaking::get_dapp_tier_history(dapp_id).is_empty());
 
-        // Try to move to non-existing destination contract
-        assert_noop!(
-            DappStaking::move_stake(
-                RuntimeOrigin::signed(account),
-                source_contract,
-                destination_contract,
-                1
-            ),
-            Error::<Test>::ContractNotFound
+        // Each era change appends a new entry, overwriting the oldest one once the history is full
+        let history_length = <Test as Config>::TierHistoryLength::get();
+        let mut expected_entries = Vec::new();
+        for _ in 0..history_length + 1 {
+            let era = ActiveProtocolState::<Test>::get().era;
+            advance_to_next_era();
+
+            let (reward, ranked_tier) = DAppTiers::<Test>::get(era)
+                .expect("Must exist.")
+                .try_claim(dapp_id)
+                .expect("dApp must be in a tier.");
+            expected_entries.push(DAppTierHistoryEntry {
+                era,
+                ranked_tier,
+                stake: amount,
+                reward,
+            });
+        }
+
+        let history = DAppTierHistory::<Test>::get(dapp_id);
+        assert_eq!(history.len(), history_length);
+        assert_eq!(history.entries(), expected_entries[1..].to_vec());
+        assert_eq!(
+            DappStaking::get_dapp_tier_history(dapp_id),
+            expected_entries[1..].to_vec()
         );
     })
 }
 
 #[test]
-fn move_voting_stake_merge_into_bep_for_bonus_forfeited_works() {
+fn dapp_tier_history_is_not_updated_for_dapps_without_tier() {
     ExtBuilder::default().build_and_execute(|| {
-        let source_contract = MockSmartContract::wasm(1 as AccountId);
-        let dest_contract = MockSmartContract::wasm(2 as AccountId);
-        assert_register(1, &source_contract);
-        assert_register(1, &dest_contract);
+        // Stake below the lowest tier threshold
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(1, &smart_contract);
+        let dapp_id = IntegratedDApps::<Test>::get(&smart_contract)
+            .expect("Must exist.")
+            .id;
 
         let account = 2;
-        assert_lock(account, 300);
-        let stake = 100;
-        assert_stake(account, &source_contract, stake);
-
+        let amount = *TierConfig::<Test>::get()
+            .tier_thresholds()
+            .last()
+            .expect("Must exist.")
+            - 1;
+        Balances::make_free_balance_be(&account, amount);
+        assert_lock(account, amount);
+        assert_stake(account, &smart_contract, amount);
         advance_to_next_subperiod();
 
-        // Unstake to forfeit bonus but preserve voting stake
-        let unstake = 20;
-        assert_unstake(account, &source_contract, unstake);
+        advance_to_next_era();
+        assert!(!DAppTierHistory::<Test>::contains_key(dapp_id));
+    })
+}
 
-        let staking_info = StakerInfo::<Test>::get(account, &source_contract)
-            .expect("Should exist since stake/unstake operations were successful.");
+#[test]
+fn set_and_revoke_delegate_is_ok() {
+    ExtBuilder::default().build_and_execute(|| {
+        let (account, delegate) = (1, 2);
+        let permissions = DelegatePermissions {
+            stake: true,
+            unstake: false,
+            move_stake: true,
+        };
+
+        // Set the delegate
+        assert_ok!(DappStaking::set_delegate(
+            RuntimeOrigin::signed(account),
+            delegate,
+            permissions
+        ));
         assert_eq!(
-            staking_info.staked_amount(Subperiod::Voting),
-            stake - unstake
+            StakeDelegates::<Test>::get(&account).get(&delegate),
+            Some(&permissions)
         );
-        assert_eq!(staking_info.staked_amount(Subperiod::BuildAndEarn), 0);
-        assert!(!staking_info.is_bonus_eligible(), "Sanity check");
+        System::assert_last_event(RuntimeEvent::DappStaking(Event::DelegateSet {
+            account,
+            delegate,
+            permissions,
+        }));
 
-        // Move to merge voting stake into bep stake
-        assert_move_stake(account, &source_contract, &dest_contract, stake - unstake); // full move
-        let staking_info = StakerInfo::<Test>::get(account, &dest_contract)
-            .expect("Should exist since move operations is successful.");
-        assert_eq!(staking_info.staked_amount(Subperiod::Voting), 0);
+        // Update the delegate permissions
+        assert_ok!(DappStaking::set_delegate(
+            RuntimeOrigin::signed(account),
+            delegate,
+            DelegatePermissions::all()
+        ));
+        assert_eq!(StakeDelegates::<Test>::get(&account).len(), 1);
         assert_eq!(
-            staking_info.staked_amount(Subperiod::BuildAndEarn),
-            stake - unstake
+            StakeDelegates::<Test>::get(&account).get(&delegate),
+            Some(&DelegatePermissions::all())
         );
+
+        // Revoke the delegate, storage entry is removed once there are no more delegates
+        assert_ok!(DappStaking::revoke_delegate(
+            RuntimeOrigin::signed(account),
+            delegate
+        ));
+        assert!(!StakeDelegates::<Test>::contains_key(&account));
+        System::assert_last_event(RuntimeEvent::DappStaking(Event::DelegateRevoked {
+            account,
+            delegate,
+        }));
     })
 }
 
 #[test]
-fn set_static_tier_params_invalid_params_fails() {
+fn set_and_revoke_delegate_fails() {
     ExtBuilder::default().build_and_execute(|| {
-        // Base value is assumed to be correct
-        let tier_params = StaticTierParams::<Test>::get();
-        assert!(tier_params.is_valid(), "Sanity check");
-        type NumberOfTiers = <Test as Config>::NumberOfTiers;
+        let account = 1;
 
-        let invalid_tier_params = TierParameters::<NumberOfTiers> {
-            reward_portion: tier_params.reward_portion[1..].to_vec().try_into().unwrap(),
-            ..tier_params.clone()
-        };
-        assert!(!invalid_tier_params.is_valid(), "Sanity check");
+        // Account cannot delegate to itself
+        assert_noop!(
+            DappStaking::set_delegate(
+                RuntimeOrigin::signed(account),
+                account,
+                DelegatePermissions::all()
+            ),
+            Error::<Test>::SelfDelegation
+        );
 
+        // At least one action must be permitted
         assert_noop!(
-            DappStaking::set_static_tier_params(RuntimeOrigin::root(), invalid_tier_params),
-            Error::<Test>::InvalidTierParams
+            DappStaking::set_delegate(
+                RuntimeOrigin::signed(account),
+                2,
+                DelegatePermissions::default()
+            ),
+            Error::<Test>::NoDelegatePermissions
         );
 
-        // invalid tier rank multiplier (tier 0 exceeds 10_000)
-        let mut bad_params = tier_params.clone();
-        bad_params.tier_rank_multipliers[0] = 20_000;
-        assert!(!bad_params.is_valid(), "Invalid tier rank multiplier");
+        // Number of delegates is limited
+        let max_delegates: u32 = <Test as Config>::MaxNumberOfDelegates::get();
+        for delegate in 0..max_delegates {
+            assert_ok!(DappStaking::set_delegate(
+                RuntimeOrigin::signed(account),
+                delegate as AccountId + 2,
+                DelegatePermissions::all()
+            ));
+        }
+        assert_noop!(
+            DappStaking::set_delegate(
+                RuntimeOrigin::signed(account),
+                max_delegates as AccountId + 2,
+                DelegatePermissions::all()
+            ),
+            Error::<Test>::TooManyDelegates
+        );
 
+        // Cannot revoke a non-existing delegate
         assert_noop!(
-            DappStaking::set_static_tier_params(RuntimeOrigin::root(), bad_params),
-            Error::<Test>::InvalidTierParams
+            DappStaking::revoke_delegate(
+                RuntimeOrigin::signed(account),
+                max_delegates as AccountId + 2
+            ),
+            Error::<Test>::DelegateNotFound
+        );
+        assert_noop!(
+            DappStaking::revoke_delegate(RuntimeOrigin::signed(2), account),
+            Error::<Test>::DelegateNotFound
         );
     })
 }
 
 #[test]
-fn set_static_tier_params_works() {
+fn delegated_stake_unstake_and_move_stake_is_ok() {
     ExtBuilder::default().build_and_execute(|| {
-        let mut tier_params = StaticTierParams::<Test>::get();
+        // Register smart contracts, lock some amount
+        let source_contract = MockSmartContract::wasm(1 as AccountId);
+        let destination_contract = MockSmartContract::wasm(2 as AccountId);
+        assert_register(1, &source_contract);
+        assert_register(1, &destination_contract);
+
+        let (account, delegate) = (2, 3);
+        assert_lock(account, 300);
+        assert_ok!(DappStaking::set_delegate(
+            RuntimeOrigin::signed(account),
+            delegate,
+            DelegatePermissions::all()
+        ));
+        let delegate_balance = Balances::free_balance(&delegate);
+        let period = ActiveProtocolState::<Test>::get().period_number();
+
+        // Stake on behalf of the account
+        assert_ok!(DappStaking::stake_for(
+            RuntimeOrigin::signed(delegate),
+            account,
+            source_contract.clone(),
+            100
+        ));
+        System::assert_last_event(RuntimeEvent::DappStaking(Event::Stake {
+            account,
+            smart_contract: source_contract.clone(),
+            amount: 100,
+        }));
+
+        // Move part of the stake on behalf of the account
+        assert_ok!(DappStaking::move_stake_for(
+            RuntimeOrigin::signed(delegate),
+            account,
+            source_contract.clone(),
+            destination_contract.clone(),
+            40
+        ));
+        System::assert_last_event(RuntimeEvent::DappStaking(Event::StakeMoved {
+            account,
+            source_contract: source_contract.clone(),
+            destination_contract: destination_contract.clone(),
+            amount: 40,
+        }));
+
+        // Unstake on behalf of the account
+        assert_ok!(DappStaking::unstake_for(
+            RuntimeOrigin::signed(delegate),
+            account,
+            destination_contract.clone(),
+            10
+        ));
+        System::assert_last_event(RuntimeEvent::DappStaking(Event::Unstake {
+            account,
+            smart_contract: destination_contract.clone(),
+            amount: 10,
+        }));
 
-        // An example of complete invalidation of the first tier - still valid params.
-        tier_params.reward_portion[0] = Permill::zero();
-        tier_params.slot_distribution[0] = Permill::zero();
-        tier_params.tier_thresholds[0] = TierThreshold::FixedPercentage {
-            required_percentage: Perbill::one(),
+        // Only the account's stake was changed, locked funds remain untouched
+        let staked_amount = |smart_contract: &MockSmartContract| {
+            StakerInfo::<Test>::get(&account, smart_contract)
+                .expect("Must exist.")
+                .total_staked_amount()
         };
+        assert_eq!(staked_amount(&source_contract), 60);
+        assert_eq!(staked_amount(&destination_contract), 30);
 
-        assert_ok!(DappStaking::set_static_tier_params(
-            RuntimeOrigin::root(),
-            tier_params.clone()
-        ));
+        let ledger = Ledger::<Test>::get(&account);
+        assert_eq!(ledger.active_locked_amount(), 300);
+        assert_eq!(ledger.staked_amount(period), 90);
 
-        assert_eq!(StaticTierParams::<Test>::get(), tier_params);
-        System::assert_last_event(RuntimeEvent::DappStaking(Event::NewTierParameters {
-            params: tier_params,
-        }));
+        // Delegate has no stake of its own, and its funds aren't affected
+        assert!(!Ledger::<Test>::contains_key(&delegate));
+        assert_eq!(Balances::free_balance(&delegate), delegate_balance);
     })
 }
 
-// Tests a previous bug where previous_stake was storing future stake amounts (amounts that should be eligible in the next era)
 #[test]
-fn previous_stake_unchanged_for_future_era_staking() {
-    ExtBuilder::default()
-        .with_max_bonus_safe_moves(2)
-        .build_and_execute(|| {
-            // Register smart contracts 1 & 2, lock&stake some amount on 1 & 2
-            let smart_contract = MockSmartContract::wasm(1 as AccountId);
-            assert_register(1, &smart_contract);
+fn delegated_actions_require_permissions() {
+    ExtBuilder::default().build_and_execute(|| {
+        // Register smart contracts, lock & stake some amount
+        let source_contract = MockSmartContract::wasm(1 as AccountId);
+        let destination_contract = MockSmartContract::wasm(2 as AccountId);
+        assert_register(1, &source_contract);
+        assert_register(1, &destination_contract);
 
-            let account = 2;
-            let amount = 100;
-            assert_lock(account, amount);
+        let (account, delegate) = (2, 3);
+        assert_lock(account, 300);
+        assert_stake(account, &source_contract, 100);
 
-            let stake_1 = 10;
-            let stake_2 = 20;
-            let stake_era_origin = ActiveProtocolState::<Test>::get().era;
-            assert_stake(account, &smart_contract, stake_1);
-            assert_stake(account, &smart_contract, stake_2);
+        // Account which isn't a delegate cannot perform any action
+        assert_noop!(
+            DappStaking::stake_for(
+                RuntimeOrigin::signed(delegate),
+                account,
+                source_contract.clone(),
+                100
+            ),
+            Error::<Test>::DelegatedActionNotPermitted
+        );
 
-            let expected_bonus_status = *BonusStatusWrapperFor::<Test>::default();
-            let expected_staker_info = SingularStakingInfo {
-                previous_staked: Default::default(),
-                staked: StakeAmount {
-                    voting: stake_1 + stake_2,
-                    build_and_earn: 0,
-                    era: stake_era_origin + 1,
-                    period: 1,
-                },
-                bonus_status: expected_bonus_status,
-            };
-            assert_eq!(
-                StakerInfo::<Test>::get(&account, &smart_contract),
-                Some(expected_staker_info)
-            );
+        // Delegate can only perform the permitted actions
+        assert_ok!(DappStaking::set_delegate(
+            RuntimeOrigin::signed(account),
+            delegate,
+            DelegatePermissions {
+                stake: true,
+                unstake: false,
+                move_stake: false,
+            }
+        ));
+        assert_ok!(DappStaking::stake_for(
+            RuntimeOrigin::signed(delegate),
+            account,
+            source_contract.clone(),
+            50
+        ));
+        assert_noop!(
+            DappStaking::unstake_for(
+                RuntimeOrigin::signed(delegate),
+                account,
+                source_contract.clone(),
+                50
+            ),
+            Error::<Test>::DelegatedActionNotPermitted
+        );
+        assert_noop!(
+            DappStaking::move_stake_for(
+                RuntimeOrigin::signed(delegate),
+                account,
+                source_contract.clone(),
+                destination_contract.clone(),
+                50
+            ),
+            Error::<Test>::DelegatedActionNotPermitted
+        );
 
-            advance_to_next_era();
-            let stake_era_next = ActiveProtocolState::<Test>::get().era;
-            let stake_3 = 20;
-            assert_stake(account, &smart_contract, stake_3);
+        // Delegation is scoped to the account which granted it
+        assert_noop!(
+            DappStaking::stake_for(
+                RuntimeOrigin::signed(account),
+                delegate,
+                source_contract.clone(),
+                50
+            ),
+            Error::<Test>::DelegatedActionNotPermitted
+        );
 
-            let expected_bonus_status = *BonusStatusWrapperFor::<Test>::default();
-            let expected_staker_info = SingularStakingInfo {
-                previous_staked: StakeAmount {
-                    voting: stake_1 + stake_2,
-                    build_and_earn: 0,
-                    era: stake_era_origin + 1,
-                    period: 1,
-                },
-                staked: StakeAmount {
-                    voting: stake_1 + stake_2,
-                    build_and_earn: stake_3,
-                    era: stake_era_next + 1,
-                    period: 1,
-                },
-                bonus_status: expected_bonus_status,
-            };
-            assert_eq!(
-                StakerInfo::<Test>::get(&account, &smart_contract),
-                Some(expected_staker_info)
-            );
-        })
+        // Once revoked, delegate can no longer perform any action
+        assert_ok!(DappStaking::revoke_delegate(
+            RuntimeOrigin::signed(account),
+            delegate
+        ));
+        assert_noop!(
+            DappStaking::stake_for(
+                RuntimeOrigin::signed(delegate),
+                account,
+                source_contract.clone(),
+                50
+            ),
+            Error::<Test>::DelegatedActionNotPermitted
+        );
+    })
 }
 
 #[test]
-fn unstake_from_unregistered_matching_next_era_total_stake() {
+fn set_and_remove_carry_over_plan_is_ok() {
     ExtBuilder::default().build_and_execute(|| {
         let smart_contract_1 = MockSmartContract::wasm(1 as AccountId);
-        assert_register(1, &smart_contract_1);
         let smart_contract_2 = MockSmartContract::wasm(2 as AccountId);
+        assert_register(1, &smart_contract_1);
         assert_register(1, &smart_contract_2);
 
-        let amount = 100;
-        let account_2 = 3;
-        assert_lock(account_2, amount);
-        let account_3 = 4;
-        assert_lock(account_3, amount);
-
-        let current_period_number = ActiveProtocolState::<Test>::get().period_number();
-
-        assert_stake(account_2, &smart_contract_1, 50);
-        assert_stake(account_2, &smart_contract_2, 50);
-        assert_stake(account_3, &smart_contract_1, amount);
-
-        advance_to_next_era();
-        assert_unregister(&smart_contract_2);
-
-        advance_to_next_era();
-        assert_claim_staker_rewards(account_2);
-        assert_unstake_from_unregistered(account_2, &smart_contract_2);
-        assert_stake(account_2, &smart_contract_1, 50);
-
-        let mut ledger_total_stake = Balance::zero();
-        for (_, ledger) in Ledger::<Test>::iter() {
-            let account_stake = ledger.staked_amount(current_period_number);
-            ledger_total_stake += account_stake;
-        }
+        // Set the plan, it applies from the next period onwards
+        let account = 2;
+        let entries: BoundedVec<_, _> = vec![
+            (smart_contract_1.clone(), 100),
+            (smart_contract_2.clone(), 50),
+        ]
+        .try_into()
+        .unwrap();
+        let start_period = ActiveProtocolState::<Test>::get().period_number() + 1;
+        assert_ok!(DappStaking::set_carry_over_plan(
+            RuntimeOrigin::signed(account),
+            entries.clone()
+        ));
 
-        let current_era_info = CurrentEraInfo::<Test>::get();
-        let next_era_total_stake = current_era_info.total_staked_amount_next_era();
+        let plan = CarryOverPlans::<Test>::get(&account).expect("Must exist.");
+        assert_eq!(plan.entries(), entries.as_slice());
+        assert_eq!(plan.start_period(), start_period);
+        System::assert_last_event(RuntimeEvent::DappStaking(Event::CarryOverPlanSet {
+            account,
+            entries,
+            start_period,
+        }));
 
+        // Replace the plan
+        let entries: BoundedVec<_, _> = vec![(smart_contract_2.clone(), 70)].try_into().unwrap();
+        assert_ok!(DappStaking::set_carry_over_plan(
+            RuntimeOrigin::signed(account),
+            entries.clone()
+        ));
         assert_eq!(
-            ledger_total_stake, next_era_total_stake,
-            "Total stake amount across all ledger has to be equal to the next era total stake"
-        )
+            CarryOverPlans::<Test>::get(&account)
+                .expect("Must exist.")
+                .entries(),
+            entries.as_slice()
+        );
+
+        // Remove the plan
+        assert_ok!(DappStaking::remove_carry_over_plan(RuntimeOrigin::signed(
+            account
+        )));
+        assert!(!CarryOverPlans::<Test>::contains_key(&account));
+        System::assert_last_event(RuntimeEvent::DappStaking(Event::CarryOverPlanRemoved {
+            account,
+        }));
     })
 }
 
-// Tests a previous bug where voting stake move/unstake was subtracted from next BuildAndEarn stake in era info
 #[test]
-fn era_info_stakes_remain_synced() {
-    ExtBuilder::default()
-        .with_max_bonus_safe_moves(1)
-        .build_and_execute(|| {
-            let smart_contract_1 = MockSmartContract::wasm(1 as AccountId);
-            assert_register(1, &smart_contract_1);
-            let smart_contract_2 = MockSmartContract::wasm(2 as AccountId);
-            assert_register(1, &smart_contract_2);
-
-            let (staker_1, amount_1) = (1, 50);
-            assert_lock(staker_1, amount_1);
-            assert_stake(staker_1, &smart_contract_1, amount_1 - 10);
-
-            advance_to_next_subperiod(); // b&e
-
-            assert_stake(staker_1, &smart_contract_1, 10);
-
-            advance_to_next_era();
-            assert_claim_staker_rewards(staker_1);
+fn set_and_remove_carry_over_plan_fails() {
+    ExtBuilder::default().build_and_execute(|| {
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(1, &smart_contract);
+        let account = 2;
 
-            let (staker_2, amount_2) = (2, 60);
-            assert_lock(staker_2, amount_2);
-            assert_stake(staker_2, &smart_contract_1, amount_2);
+        // Plan must not be empty
+        assert_noop!(
+            DappStaking::set_carry_over_plan(RuntimeOrigin::signed(account), BoundedVec::new()),
+            Error::<Test>::EmptyCarryOverPlan
+        );
 
-            let unstake_amount = 15;
-            assert_unstake(staker_1, &smart_contract_1, unstake_amount);
+        // Planned amounts must not be zero
+        assert_noop!(
+            DappStaking::set_carry_over_plan(
+                RuntimeOrigin::signed(account),
+                vec![(smart_contract.clone(), 0)].try_into().unwrap()
+            ),
+            Error::<Test>::ZeroAmount
+        );
 
-            let current_era_info = CurrentEraInfo::<Test>::get();
-            assert_eq!(current_era_info.staked_amount(Subperiod::Voting), 35);
-            assert_eq!(current_era_info.staked_amount(Subperiod::BuildAndEarn), 0);
-            assert_eq!(
-                current_era_info.staked_amount_next_era(Subperiod::Voting),
-                35
-            );
-            assert_eq!(
-                current_era_info.staked_amount_next_era(Subperiod::BuildAndEarn),
-                60,
-            );
+        // Each contract can only be specified once
+        assert_noop!(
+            DappStaking::set_carry_over_plan(
+                RuntimeOrigin::signed(account),
+                vec![(smart_contract.clone(), 100), (smart_contract.clone(), 50)]
+                    .try_into()
+                    .unwrap()
+            ),
+            Error::<Test>::DuplicateCarryOverContract
+        );
 
-            // Move to forfeit bonus
-            let move_amount = 35;
-            assert_move_stake(staker_1, &smart_contract_1, &smart_contract_2, move_amount);
+        // Contracts must be registered
+        assert_noop!(
+            DappStaking::set_carry_over_plan(
+                RuntimeOrigin::signed(account),
+                vec![(MockSmartContract::wasm(2 as AccountId), 100)]
+                    .try_into()
+                    .unwrap()
+            ),
+            Error::<Test>::ContractNotFound
+        );
 
-            let current_era_info = CurrentEraInfo::<Test>::get();
-            assert_eq!(current_era_info.staked_amount(Subperiod::Voting), 0);
-            assert_eq!(current_era_info.staked_amount(Subperiod::BuildAndEarn), 0);
-            assert_eq!(
-                current_era_info.staked_amount_next_era(Subperiod::Voting),
-                0
-            );
-            assert_eq!(
-                current_era_info.staked_amount_next_era(Subperiod::BuildAndEarn),
-                95,
-            );
-        })
+        // Cannot remove a non-existing plan
+        assert_noop!(
+            DappStaking::remove_carry_over_plan(RuntimeOrigin::signed(account)),
+            Error::<Test>::NoCarryOverPlan
+        );
+    })
 }
 
-// Tests a previous bug where greater previous stake amount total than current one was tried to be unstake from unregistered,
-// resulting in a `InternalUnstakeError` error masking a `AccountLedgerError::UnstakeAmountLargerThanStake` error.
 #[test]
-fn unstake_from_unregistered_use_correct_stake_amount() {
+fn carry_over_plan_is_applied_in_voting_subperiod() {
     ExtBuilder::default().build_and_execute(|| {
         let smart_contract_1 = MockSmartContract::wasm(1 as AccountId);
+        let smart_contract_2 = MockSmartContract::wasm(2 as AccountId);
         assert_register(1, &smart_contract_1);
+        assert_register(1, &smart_contract_2);
 
-        let (staker_1, amount_1) = (1, 280);
-        assert_lock(staker_1, amount_1);
-        assert_stake(staker_1, &smart_contract_1, 280);
+        let account = 2;
+        assert_lock(account, 300);
+        assert_ok!(DappStaking::set_carry_over_plan(
+            RuntimeOrigin::signed(account),
+            vec![
+                (smart_contract_1.clone(), 100),
+                (smart_contract_2.clone(), 50)
+            ]
+            .try_into()
+            .unwrap()
+        ));
 
-        advance_to_next_subperiod(); // b&e
-        advance_to_next_era();
+        // Plan isn't applied in the period in which it was set
+        run_for_blocks(2);
+        assert!(!StakerInfo::<Test>::contains_key(
+            &account,
+            &smart_contract_1
+        ));
 
-        assert_claim_staker_rewards(staker_1);
-        assert_unstake(staker_1, &smart_contract_1, 30);
+        // Plan is applied at the start of the next period, as voting stake
+        advance_to_next_period();
+        run_for_blocks(1);
+        let period = ActiveProtocolState::<Test>::get().period_number();
+        for (smart_contract, amount) in [(&smart_contract_1, 100), (&smart_contract_2, 50)] {
+            let staking_info =
+                StakerInfo::<Test>::get(&account, smart_contract).expect("Must exist.");
+            assert_eq!(staking_info.period_number(), period);
+            assert_eq!(staking_info.staked_amount(Subperiod::Voting), amount);
+            assert_eq!(staking_info.staked_amount(Subperiod::BuildAndEarn), 0);
+        }
+        assert!(dapp_staking_events().contains(&Event::CarryOverPlanApplied { account, period }));
 
-        advance_to_next_era();
-        assert_unregister(&smart_contract_1);
+        // Plan is applied only once per period
+        run_for_blocks(2);
+        assert_eq!(
+            StakerInfo::<Test>::get(&account, &smart_contract_1)
+                .expect("Must exist.")
+                .total_staked_amount(),
+            100
+        );
 
-        advance_to_next_era();
-        assert_claim_staker_rewards(staker_1);
-        assert_unstake_from_unregistered(staker_1, &smart_contract_1);
+        // Rewards which weren't claimed before the period ended are claimed first, so the plan is applied again.
+        // Planned stake which cannot be applied is reported.
+        assert_unregister(&smart_contract_2);
+        advance_to_next_period();
+        run_for_blocks(1);
+        let events = dapp_staking_events();
+        assert!(events.iter().any(
+            |event| matches!(event, Event::Reward { account: staker, .. } if *staker == account)
+        ));
+        assert!(events.iter().any(
+            |event| matches!(event, Event::BonusReward { account: staker, .. } if *staker == account)
+        ));
+        assert_eq!(
+            Ledger::<Test>::get(&account).staked_period(),
+            Some(period + 1)
+        );
+
+        let staking_info =
+            StakerInfo::<Test>::get(&account, &smart_contract_1).expect("Must exist.");
+        assert_eq!(staking_info.period_number(), period + 1);
+        assert_eq!(staking_info.staked_amount(Subperiod::Voting), 100);
+        assert!(events.contains(&Event::CarryOverStakeFailed {
+            account,
+            smart_contract: smart_contract_2,
+            amount: 50,
+            error: Error::<Test>::ContractNotFound.into(),
+        }));
+        assert!(events.contains(&Event::CarryOverPlanApplied {
+            account,
+            period: period + 1,
+        }));
     })
 }
 
-// Tests a previous bug where the contract_stake_count kept increasing for the same contract entry
-// when a staker continued staking across periods.
-// Now restaking on the same contract after a period transition
-// replaces the old StakerInfo entry without inflating contract_stake_count.
+/// Builds dApp metadata with the given name, used in the metadata tests.
+fn mock_dapp_metadata(name: &[u8]) -> DAppMetadataFor<Test> {
+    BoundedDAppMetadata {
+        name: name.to_vec().try_into().unwrap(),
+        url: b"https://astar.network".to_vec().try_into().unwrap(),
+        logo_cid: b"bafybeigdyrzt5sfp7udm7hu".to_vec().try_into().unwrap(),
+        category: DAppCategory::DeFi,
+    }
+}
+
 #[test]
-fn restake_replaces_old_entry_without_inflating_counter() {
+fn set_and_clear_dapp_metadata_is_ok() {
     ExtBuilder::default().build_and_execute(|| {
-        let staker = 2;
-        let contract = MockSmartContract::wasm(1 as AccountId);
+        let owner = 1;
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(owner, &smart_contract);
 
-        assert_register(1, &contract);
-        assert_lock(staker, 1_000);
+        // Set the metadata, deposit is held for each encoded byte
+        let metadata = mock_dapp_metadata(b"Astar dApp");
+        let deposit = metadata.encoded_size() as Balance;
+        assert_ok!(DappStaking::set_dapp_metadata(
+            RuntimeOrigin::signed(owner),
+            smart_contract.clone(),
+            metadata.clone()
+        ));
+        System::assert_last_event(RuntimeEvent::DappStaking(Event::DAppMetadataSet {
+            smart_contract: smart_contract.clone(),
+            metadata: metadata.clone(),
+            depositor: owner,
+            deposit,
+        }));
+        assert_eq!(held_metadata_deposit(owner), deposit);
+        assert_eq!(
+            DappStaking::get_dapp_metadata(&smart_contract),
+            Some(metadata.into_dapp_metadata(deposit))
+        );
 
-        // Period 1: initial stake
-        advance_to_next_subperiod();
-        assert_stake(staker, &contract, 100);
+        // Replace the metadata, deposit is adjusted accordingly
+        let metadata = mock_dapp_metadata(b"dApp");
+        let deposit = metadata.encoded_size() as Balance;
+        assert_ok!(DappStaking::set_dapp_metadata(
+            RuntimeOrigin::signed(owner),
+            smart_contract.clone(),
+            metadata.clone()
+        ));
+        assert_eq!(held_metadata_deposit(owner), deposit);
+        assert_eq!(
+            DAppMetadataOf::<Test>::get(&smart_contract),
+            Some((metadata.clone(), owner, deposit))
+        );
 
-        let ledger_after_first_stake = Ledger::<Test>::get(&staker);
-        assert_eq!(ledger_after_first_stake.contract_stake_count, 1);
-        assert_eq!(StakerInfo::<Test>::iter_prefix(&staker).count(), 1);
+        // Clear the metadata as the owner
+        assert_ok!(DappStaking::clear_dapp_metadata(
+            RuntimeOrigin::signed(owner),
+            smart_contract.clone()
+        ));
+        System::assert_last_event(RuntimeEvent::DappStaking(Event::DAppMetadataCleared {
+            smart_contract: smart_contract.clone(),
+            depositor: owner,
+            deposit,
+        }));
+        assert!(!DAppMetadataOf::<Test>::contains_key(&smart_contract));
+        assert_eq!(held_metadata_deposit(owner), 0);
 
-        let original_entry = StakerInfo::<Test>::get(&staker, &contract).expect("entry must exist");
-        let original_period = original_entry.period_number();
+        // Clear the metadata using the contract unregister origin
+        assert_ok!(DappStaking::set_dapp_metadata(
+            RuntimeOrigin::signed(owner),
+            smart_contract.clone(),
+            metadata
+        ));
+        assert_ok!(DappStaking::clear_dapp_metadata(
+            RuntimeOrigin::signed(ContractUnregisterAccount::get()),
+            smart_contract.clone()
+        ));
+        assert!(!DAppMetadataOf::<Test>::contains_key(&smart_contract));
+        assert_eq!(held_metadata_deposit(owner), 0);
+    })
+}
 
-        // Move to next period and claim rewards (ledger cleared, StakerInfo entry remains)
-        advance_to_next_period();
-        for _ in 0..required_number_of_reward_claims(staker) {
-            assert_claim_staker_rewards(staker);
-        }
+#[test]
+fn set_and_clear_dapp_metadata_fails() {
+    ExtBuilder::default().build_and_execute(|| {
+        let owner = 1;
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
 
-        // Entry still exists but is now orphaned
-        assert_eq!(
-            StakerInfo::<Test>::iter_prefix(&staker).count(),
-            1,
-            "Old StakerInfo entry should still exist after claim"
+        // Contract must be registered
+        assert_noop!(
+            DappStaking::set_dapp_metadata(
+                RuntimeOrigin::signed(owner),
+                smart_contract.clone(),
+                mock_dapp_metadata(b"Astar dApp")
+            ),
+            Error::<Test>::ContractNotFound
         );
+        assert_noop!(
+            DappStaking::clear_dapp_metadata(RuntimeOrigin::signed(owner), smart_contract.clone()),
+            Error::<Test>::ContractNotFound
+        );
+        assert_register(owner, &smart_contract);
 
-        // Period 2: restake same contract
-        advance_to_next_subperiod();
-        assert_stake(staker, &contract, 100);
+        // Only the owner can set or clear the metadata
+        assert_noop!(
+            DappStaking::set_dapp_metadata(
+                RuntimeOrigin::signed(2),
+                smart_contract.clone(),
+                mock_dapp_metadata(b"Astar dApp")
+            ),
+            Error::<Test>::OriginNotOwner
+        );
+        assert_noop!(
+            DappStaking::clear_dapp_metadata(RuntimeOrigin::signed(2), smart_contract.clone()),
+            Error::<Test>::OriginNotOwner
+        );
 
-        let final_ledger = Ledger::<Test>::get(&staker);
-        assert_eq!(
-            final_ledger.contract_stake_count, 1,
-            "contract_stake_count must not inflate when replacing old entry"
+        // Name must not be empty
+        assert_noop!(
+            DappStaking::set_dapp_metadata(
+                RuntimeOrigin::signed(owner),
+                smart_contract.clone(),
+                mock_dapp_metadata(b"")
+            ),
+            Error::<Test>::EmptyDAppName
         );
 
-        let entries: Vec<_> = StakerInfo::<Test>::iter_prefix(&staker).collect();
-        assert_eq!(entries.len(), 1, "Should still have exactly one entry");
+        // Cannot clear non-existing metadata
+        assert_noop!(
+            DappStaking::clear_dapp_metadata(RuntimeOrigin::signed(owner), smart_contract.clone()),
+            Error::<Test>::NoDAppMetadata
+        );
 
-        let (_, new_entry) = &entries[0];
-        assert_eq!(
-            new_entry.period_number(),
-            original_period + 1,
-            "Old entry should have been replaced with new period entry"
+        // Deposit cannot be covered by funds locked in dApp staking
+        assert_lock(owner, Balances::free_balance(owner));
+        assert_noop!(
+            DappStaking::set_dapp_metadata(
+                RuntimeOrigin::signed(owner),
+                smart_contract,
+                mock_dapp_metadata(b"Astar dApp")
+            ),
+            Error::<Test>::InsufficientBalanceForDeposit
         );
-    });
+    })
 }
 
-// Tests cleanup partitioning and ledger counter alignment with one real stake and injected historical entries.
 #[test]
-fn cleanup_expired_entries_correctly_with_ledger_counter_reconciliation() {
+fn unregister_clears_dapp_metadata() {
     ExtBuilder::default().build_and_execute(|| {
-        let account: AccountId = 1;
+        let owner = 1;
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(owner, &smart_contract);
 
-        // setup
-        let retained_periods: u32 = <Test as Config>::RewardRetentionInPeriods::get();
-        assert_eq!(retained_periods, 2, "sanity check");
+        let metadata = mock_dapp_metadata(b"Astar dApp");
+        let deposit = metadata.encoded_size() as Balance;
+        assert_ok!(DappStaking::set_dapp_metadata(
+            RuntimeOrigin::signed(owner),
+            smart_contract.clone(),
+            metadata
+        ));
 
-        // Advance protocol to period 3
-        advance_to_next_period();
-        advance_to_next_period();
+        // Metadata is cleared & deposit released once the dApp is unregistered
+        assert_ok!(DappStaking::unregister(
+            RuntimeOrigin::root(),
+            smart_contract.clone()
+        ));
+        System::assert_has_event(RuntimeEvent::DappStaking(Event::DAppMetadataCleared {
+            smart_contract: smart_contract.clone(),
+            depositor: owner,
+            deposit,
+        }));
+        assert!(!DAppMetadataOf::<Test>::contains_key(&smart_contract));
+        assert_eq!(held_metadata_deposit(owner), 0);
+    })
+}
 
-        let protocol_state = ActiveProtocolState::<Test>::get();
-        let current_period = protocol_state.period_number();
-        let threshold_period = DappStaking::oldest_claimable_period(current_period);
+#[test]
+fn schedule_and_cancel_static_tier_params_is_ok() {
+    ExtBuilder::default().build_and_execute(|| {
+        let mut tier_params = StaticTierParams::<Test>::get();
+        tier_params.reward_portion[0] = Permill::zero();
+        let period = ActiveProtocolState::<Test>::get().period_number() + 2;
 
-        // Sanity checks for the test assumptions
-        assert_eq!(current_period, 3);
-        assert_eq!(threshold_period, 1);
+        assert_ok!(DappStaking::schedule_static_tier_params(
+            RuntimeOrigin::root(),
+            period,
+            tier_params.clone()
+        ));
+        System::assert_last_event(RuntimeEvent::DappStaking(Event::TierParametersScheduled {
+            period,
+            params: tier_params.clone(),
+        }));
+        assert_eq!(
+            ScheduledTierParams::<Test>::get(),
+            Some((period, tier_params.clone()))
+        );
 
-        // Valid current-period stake (keep after cleanup)
-        let keep_current = MockSmartContract::wasm(10 as AccountId);
-        assert_register(1, &keep_current);
-        assert_lock(account, 1_000);
+        // Scheduling again replaces the previous schedule
+        assert_ok!(DappStaking::schedule_static_tier_params(
+            RuntimeOrigin::root(),
+            period - 1,
+            tier_params.clone()
+        ));
+        assert_eq!(
+            ScheduledTierParams::<Test>::get(),
+            Some((period - 1, tier_params))
+        );
 
-        advance_to_next_subperiod(); // no bonus
-        assert_stake(account, &keep_current, 10);
+        assert_ok!(DappStaking::cancel_scheduled_tier_params(
+            RuntimeOrigin::root()
+        ));
+        System::assert_last_event(RuntimeEvent::DappStaking(
+            Event::ScheduledTierParametersCancelled { period: period - 1 },
+        ));
+        assert!(ScheduledTierParams::<Test>::get().is_none());
+    })
+}
 
-        // Injected contracts (impossible via extrinsics)
-        let keep_bonus = MockSmartContract::wasm(11 as AccountId);
-        let delete_no_bonus = MockSmartContract::wasm(12 as AccountId);
-        let delete_too_old = MockSmartContract::wasm(13 as AccountId);
+#[test]
+fn schedule_and_cancel_static_tier_params_fails() {
+    ExtBuilder::default().build_and_execute(|| {
+        let tier_params = StaticTierParams::<Test>::get();
+        let current_period = ActiveProtocolState::<Test>::get().period_number();
 
-        // 1. Past but claimable + bonus eligible (keep after cleanup)
-        StakerInfo::<Test>::insert(
-            &account,
-            &keep_bonus,
-            SingularStakingInfo::new(current_period - 1, 1),
+        // Only root can schedule or cancel
+        assert_noop!(
+            DappStaking::schedule_static_tier_params(
+                RuntimeOrigin::signed(1),
+                current_period + 1,
+                tier_params.clone()
+            ),
+            BadOrigin
+        );
+        assert_noop!(
+            DappStaking::cancel_scheduled_tier_params(RuntimeOrigin::signed(1)),
+            BadOrigin
         );
 
-        // 2. Past but claimable + NOT bonus eligible (delete after cleanup)
-        StakerInfo::<Test>::insert(
-            &account,
-            &delete_no_bonus,
-            SingularStakingInfo::new(current_period - 1, 0),
+        // Parameters must be valid
+        let mut invalid_tier_params = tier_params.clone();
+        invalid_tier_params.tier_rank_multipliers[0] = 20_000;
+        assert_noop!(
+            DappStaking::schedule_static_tier_params(
+                RuntimeOrigin::root(),
+                current_period + 1,
+                invalid_tier_params
+            ),
+            Error::<Test>::InvalidTierParams
         );
 
-        // 3. Older than oldest claimable period → (delete after cleanup)
-        StakerInfo::<Test>::insert(
-            &account,
-            &delete_too_old,
-            SingularStakingInfo::new(threshold_period - 1, 1),
+        // Period must be in the future
+        assert_noop!(
+            DappStaking::schedule_static_tier_params(
+                RuntimeOrigin::root(),
+                current_period,
+                tier_params
+            ),
+            Error::<Test>::InvalidTierParamsPeriod
         );
 
-        // Inflate the ledger counter
-        Ledger::<Test>::mutate(&account, |ledger| {
-            ledger.contract_stake_count = 10;
-        });
+        // Nothing to cancel
+        assert_noop!(
+            DappStaking::cancel_scheduled_tier_params(RuntimeOrigin::root()),
+            Error::<Test>::NoScheduledTierParams
+        );
+    })
+}
 
-        assert_ok!(DappStaking::cleanup_expired_entries(RuntimeOrigin::signed(
-            account
-        )));
+#[test]
+fn scheduled_static_tier_params_are_applied_at_period_start() {
+    ExtBuilder::default().build_and_execute(|| {
+        // Raise the entry threshold of the first tier
+        let old_tier_params = StaticTierParams::<Test>::get();
+        let mut tier_params = old_tier_params.clone();
+        tier_params.tier_thresholds[0] = TierThreshold::FixedPercentage {
+            required_percentage: Perbill::from_percent(5),
+        };
+        let period = ActiveProtocolState::<Test>::get().period_number() + 2;
+        assert_ok!(DappStaking::schedule_static_tier_params(
+            RuntimeOrigin::root(),
+            period,
+            tier_params.clone()
+        ));
 
-        // ---- assert remaining entries ----
-        assert!(StakerInfo::<Test>::contains_key(&account, &keep_current));
-        assert!(StakerInfo::<Test>::contains_key(&account, &keep_bonus));
+        // Parameters aren't applied before the scheduled period
+        advance_to_next_period();
+        assert_eq!(StaticTierParams::<Test>::get(), old_tier_params);
+        assert!(ScheduledTierParams::<Test>::get().is_some());
+        let old_tier_thresholds = TierConfig::<Test>::get().tier_thresholds().clone();
 
-        // ---- assert deleted entries ----
-        assert!(!StakerInfo::<Test>::contains_key(
-            &account,
-            &delete_no_bonus
+        // Parameters are applied at the start of the scheduled period
+        advance_to_next_period();
+        assert_eq!(ActiveProtocolState::<Test>::get().period_number(), period);
+        assert_eq!(StaticTierParams::<Test>::get(), tier_params);
+        assert!(ScheduledTierParams::<Test>::get().is_none());
+
+        let new_tier_thresholds = TierConfig::<Test>::get().tier_thresholds().clone();
+        assert!(new_tier_thresholds[0] > old_tier_thresholds[0]);
+        System::assert_has_event(RuntimeEvent::DappStaking(
+            Event::ScheduledTierParametersApplied {
+                period,
+                old_tier_thresholds,
+                new_tier_thresholds,
+            },
         ));
-        assert!(!StakerInfo::<Test>::contains_key(&account, &delete_too_old));
+    })
+}
 
-        // ---- assert counter reconciliation ----
-        let ledger = Ledger::<Test>::get(&account);
-        let actual_entries = StakerInfo::<Test>::iter_prefix(&account).count() as u32;
+#[test]
+fn preview_dapp_tier_assignment_is_ok() {
+    ExtBuilder::default().build_and_execute(|| {
+        // Register & stake on a few dApps, so they get assigned into tiers
+        let threshold = TierConfig::<Test>::get().tier_thresholds()[0];
+        for x in 1..=3 {
+            let account = x as AccountId;
+            let smart_contract = MockSmartContract::wasm(account);
+            assert_register(account, &smart_contract);
+
+            let amount = threshold + x as Balance;
+            Balances::make_free_balance_be(&account, amount);
+            assert_lock(account, amount);
+            assert_stake(account, &smart_contract, amount);
+        }
+        advance_to_next_subperiod();
 
-        assert_eq!(
-            ledger.contract_stake_count, actual_entries,
-            "contract_stake_count must be equal to remaining entries"
-        );
+        // Preview with the current parameters matches the current tier assignment
+        let tier_params = StaticTierParams::<Test>::get();
+        let preview = DappStaking::preview_dapp_tier_assignment(tier_params.clone())
+            .expect("Parameters are valid.");
+        assert_eq!(preview.dapp_tiers, DappStaking::get_dapp_tier_assignment());
+        assert!(!preview.dapp_tiers.is_empty());
+
+        // Preview with the first tier out of reach, none of the dApps can enter it
+        let mut candidate_params = tier_params.clone();
+        candidate_params.tier_thresholds[0] = TierThreshold::FixedPercentage {
+            required_percentage: Perbill::one(),
+        };
+        let preview = DappStaking::preview_dapp_tier_assignment(candidate_params)
+            .expect("Parameters are valid.");
+        assert!(preview.tier_thresholds[0] > threshold);
+        assert!(preview
+            .dapp_tiers
+            .values()
+            .all(|ranked_tier| ranked_tier.tier() > 0));
+
+        // Nothing is persisted
+        assert_eq!(StaticTierParams::<Test>::get(), tier_params);
 
-        assert_eq!(
-            actual_entries, 2,
-            "only current-period and bonus-eligible past entries should remain"
-        );
-    });
+        // Invalid parameters cannot be previewed
+        let mut invalid_tier_params = tier_params;
+        invalid_tier_params.tier_rank_multipliers[0] = 20_000;
+        assert!(DappStaking::preview_dapp_tier_assignment(invalid_tier_params).is_none());
+    })
 }
@@ -17,7 +17,7 @@
 // along with Astar. If not, see <http://www.gnu.org/licenses/>.
 
 use astar_primitives::{
-    dapp_staking::{RankedTier, FIXED_NUMBER_OF_TIER_SLOTS},
+    dapp_staking::{DAppTierHistoryEntry, RankedTier, FIXED_NUMBER_OF_TIER_SLOTS},
     Balance,
 };
 use frame_support::assert_ok;
@@ -155,13 +155,15 @@ fn protocol_state_basic_checks() {
 
 #[test]
 fn dapp_info_basic_checks() {
+    get_u32_type!(MaxSplits, 3);
     let owner = 1;
     let beneficiary = 3;
 
-    let mut dapp_info = DAppInfo {
+    let mut dapp_info = DAppInfo::<u64, MaxSplits> {
         owner,
         id: 7,
         reward_beneficiary: None,
+        reward_splits: Default::default(),
     };
 
     // Owner receives reward in case no beneficiary is set
@@ -172,6 +174,52 @@ fn dapp_info_basic_checks() {
     assert_eq!(*dapp_info.reward_beneficiary(), beneficiary);
 }
 
+#[test]
+fn dapp_info_reward_payouts() {
+    get_u32_type!(MaxSplits, 3);
+    let owner = 1;
+    let (split_1, split_2) = (5, 7);
+
+    let mut dapp_info = DAppInfo::<u64, MaxSplits> {
+        owner,
+        id: 7,
+        reward_beneficiary: None,
+        reward_splits: Default::default(),
+    };
+
+    // Without splits, entire reward goes to the reward beneficiary
+    assert_eq!(dapp_info.reward_payouts(1000), vec![(owner, 1000)]);
+    assert_eq!(dapp_info.reward_payouts(0), vec![(owner, 0)]);
+
+    // With splits, each beneficiary receives its share & remainder goes to the reward beneficiary
+    dapp_info.reward_splits = BoundedVec::try_from(vec![
+        (split_1, Permill::from_percent(30)),
+        (split_2, Permill::from_percent(20)),
+    ])
+    .unwrap();
+    assert_eq!(
+        dapp_info.reward_payouts(1000),
+        vec![(owner, 500), (split_1, 300), (split_2, 200)]
+    );
+
+    // Rounding dust goes to the reward beneficiary
+    assert_eq!(
+        dapp_info.reward_payouts(7),
+        vec![(owner, 4), (split_1, 2), (split_2, 1)]
+    );
+
+    // Full split, nothing remains for the reward beneficiary
+    dapp_info.reward_splits = BoundedVec::try_from(vec![
+        (split_1, Permill::from_percent(60)),
+        (split_2, Permill::from_percent(40)),
+    ])
+    .unwrap();
+    assert_eq!(
+        dapp_info.reward_payouts(1000),
+        vec![(split_1, 600), (split_2, 400)]
+    );
+}
+
 #[test]
 fn unlocking_chunk_basic_check() {
     // Sanity check
@@ -1309,6 +1357,43 @@ fn account_ledger_consume_unlocking_chunks_works() {
     assert!(acc_ledger.unlocking.is_empty());
 }
 
+#[test]
+fn account_ledger_consume_pending_unlocking_amount_works() {
+    get_u32_type!(UnlockingDummy, 5);
+    let mut acc_ledger = AccountLedger::<UnlockingDummy>::default();
+
+    // Sanity check scenario
+    assert!(acc_ledger.consume_pending_unlocking_amount(10, 0).is_zero());
+
+    // Prepare unlocking chunks
+    let (amount1, amount2, amount3) = (7, 13, 19);
+    let (block1, block2, block3) = (1, 3, 5);
+    assert_ok!(acc_ledger.add_unlocking_chunk(amount1, block1));
+    assert_ok!(acc_ledger.add_unlocking_chunk(amount2, block2));
+    assert_ok!(acc_ledger.add_unlocking_chunk(amount3, block3));
+
+    // Partially consume the last chunk
+    assert_eq!(acc_ledger.consume_pending_unlocking_amount(5, block1), 5);
+    assert_eq!(acc_ledger.unlocking.len(), 3);
+    assert_eq!(acc_ledger.unlocking[2].amount, amount3 - 5);
+
+    // Consume the rest of the last chunk, and part of the second one
+    assert_eq!(
+        acc_ledger.consume_pending_unlocking_amount(amount3 - 5 + 3, block1),
+        amount3 - 5 + 3
+    );
+    assert_eq!(acc_ledger.unlocking.len(), 2);
+    assert_eq!(acc_ledger.unlocking[1].amount, amount2 - 3);
+
+    // Fully unlocked chunks aren't consumed
+    assert_eq!(
+        acc_ledger.consume_pending_unlocking_amount(100, block1),
+        amount2 - 3
+    );
+    assert_eq!(acc_ledger.unlocking.len(), 1);
+    assert_eq!(acc_ledger.unlocking_amount(), amount1);
+}
+
 #[test]
 fn account_ledger_expired_cleanup_works() {
     get_u32_type!(UnlockingDummy, 5);
@@ -3553,6 +3638,33 @@ fn tier_params_check_is_ok() {
     invalid_tier_points.tier_rank_multipliers =
         BoundedVec::try_from(vec![10_000, 100_001, 100_000]).unwrap();
     assert!(!invalid_tier_points.is_valid());
+
+    // 9th scenario - staked percentage & dynamic thresholds are ok
+    let mut new_params = params.clone();
+    new_params.tier_thresholds = BoundedVec::try_from(vec![
+        TierThreshold::DynamicPercentage {
+            percentage: Perbill::from_percent(5),
+            minimum_required_percentage: Perbill::from_percent(2),
+            maximum_possible_percentage: Perbill::from_percent(4),
+        },
+        TierThreshold::StakedPercentage {
+            required_percentage: Perbill::from_percent(3),
+        },
+        TierThreshold::FixedPercentage {
+            required_percentage: Perbill::from_percent(1),
+        },
+    ])
+    .unwrap();
+    assert!(new_params.is_valid());
+
+    // 10th scenario - dynamic threshold minimum exceeds maximum
+    let mut new_params = params.clone();
+    new_params.tier_thresholds[0] = TierThreshold::DynamicPercentage {
+        percentage: Perbill::from_percent(5),
+        minimum_required_percentage: Perbill::from_percent(4),
+        maximum_possible_percentage: Perbill::from_percent(2),
+    };
+    assert!(!new_params.is_valid());
 }
 
 #[test]
@@ -3597,7 +3709,7 @@ fn tier_configuration_basic_tests() {
     let tier_thresholds = params
         .tier_thresholds
         .iter()
-        .map(|t| t.threshold(total_issuance))
+        .map(|t| t.threshold(total_issuance, 0))
         .collect::<Vec<Balance>>()
         .try_into()
         .expect("Invalid number of tier thresholds provided.");
@@ -3609,7 +3721,8 @@ fn tier_configuration_basic_tests() {
     };
     assert!(init_config.is_valid(), "Init config must be valid!");
 
-    let new_config = init_config.calculate_new(&params, total_issuance);
+    let new_config =
+        init_config.calculate_new(&params, FIXED_NUMBER_OF_TIER_SLOTS, total_issuance, 0);
     assert!(new_config.is_valid());
     assert_eq!(
         new_config.total_number_of_slots(),
@@ -3776,7 +3889,7 @@ fn tier_thresholds_conversion_test() {
 
     let tier_thresholds: BoundedVec<Balance, TiersNum> = thresholds
         .iter()
-        .map(|t| t.threshold(total_issuance))
+        .map(|t| t.threshold(total_issuance, 0))
         .collect::<Vec<Balance>>()
         .try_into()
         .expect("Invalid number of tier thresholds provided.");
@@ -3785,6 +3898,33 @@ fn tier_thresholds_conversion_test() {
     assert_eq!(tier_thresholds[1], 50_000); // 5% of total issuance
 }
 
+#[test]
+fn tier_thresholds_with_total_value_staked() {
+    let total_issuance: Balance = 1_000_000;
+
+    // Staked percentage depends only on the total value staked
+    let threshold = TierThreshold::StakedPercentage {
+        required_percentage: Perbill::from_percent(10),
+    };
+    assert_eq!(threshold.threshold(total_issuance, 0), 0);
+    assert_eq!(threshold.threshold(total_issuance, 200_000), 20_000);
+    assert_eq!(threshold.threshold(total_issuance * 2, 200_000), 20_000);
+
+    // Dynamic percentage is clamped between 1% & 3% of the total issuance
+    let threshold = TierThreshold::DynamicPercentage {
+        percentage: Perbill::from_percent(10),
+        minimum_required_percentage: Perbill::from_percent(1),
+        maximum_possible_percentage: Perbill::from_percent(3),
+    };
+    assert_eq!(threshold.threshold(total_issuance, 0), 10_000);
+    assert_eq!(threshold.threshold(total_issuance, 50_000), 10_000);
+    assert_eq!(threshold.threshold(total_issuance, 200_000), 20_000);
+    assert_eq!(threshold.threshold(total_issuance, 500_000), 30_000);
+
+    // Inflating issuance raises the floor
+    assert_eq!(threshold.threshold(total_issuance * 3, 200_000), 30_000);
+}
+
 #[test]
 fn tier_configuration_calculate_new_with_fixed_thresholds() {
     get_u32_type!(TiersNum, 4);
@@ -3837,7 +3977,8 @@ fn tier_configuration_calculate_new_with_fixed_thresholds() {
     };
     assert!(init_config.is_valid(), "Init config must be valid!");
 
-    let new_config = init_config.calculate_new(&params, total_issuance);
+    let new_config =
+        init_config.calculate_new(&params, FIXED_NUMBER_OF_TIER_SLOTS, total_issuance, 0);
 
     assert_eq!(
         new_config.tier_thresholds[0],
@@ -3856,3 +3997,262 @@ fn tier_configuration_calculate_new_with_fixed_thresholds() {
         Perbill::from_parts(5_000_000) * total_issuance
     );
 }
+
+#[test]
+fn tier_configuration_calculate_new_with_staked_thresholds() {
+    get_u32_type!(TiersNum, 3);
+
+    let params = TierParameters::<TiersNum> {
+        reward_portion: BoundedVec::try_from(vec![
+            Permill::from_percent(50),
+            Permill::from_percent(30),
+            Permill::from_percent(20),
+        ])
+        .unwrap(),
+        slot_distribution: BoundedVec::try_from(vec![
+            Permill::from_percent(20),
+            Permill::from_percent(30),
+            Permill::from_percent(50),
+        ])
+        .unwrap(),
+        tier_thresholds: BoundedVec::try_from(vec![
+            TierThreshold::DynamicPercentage {
+                percentage: Perbill::from_percent(20),
+                minimum_required_percentage: Perbill::from_percent(2),
+                maximum_possible_percentage: Perbill::from_percent(5),
+            },
+            TierThreshold::StakedPercentage {
+                required_percentage: Perbill::from_percent(5),
+            },
+            TierThreshold::FixedPercentage {
+                required_percentage: Perbill::from_parts(5_000_000), // 0.5%
+            },
+        ])
+        .unwrap(),
+        tier_rank_multipliers: BoundedVec::try_from(vec![0, 24_000, 46_700]).unwrap(),
+    };
+    assert!(params.is_valid());
+
+    let init_config = TiersConfiguration::<TiersNum> {
+        slots_per_tier: BoundedVec::try_from(vec![10, 20, 30]).unwrap(),
+        reward_portion: params.reward_portion.clone(),
+        tier_thresholds: BoundedVec::try_from(vec![0, 0, 0]).unwrap(),
+    };
+
+    let total_issuance: Balance = 8_400_000_000;
+    let total_value_staked: Balance = 1_500_000_000;
+
+    // Dynamic threshold is within bounds, so staked percentage applies
+    let new_config = init_config.calculate_new(
+        &params,
+        FIXED_NUMBER_OF_TIER_SLOTS,
+        total_issuance,
+        total_value_staked,
+    );
+    assert!(new_config.is_valid());
+    assert_eq!(
+        new_config.tier_thresholds[0],
+        Perbill::from_percent(20) * total_value_staked
+    );
+    assert_eq!(
+        new_config.tier_thresholds[1],
+        Perbill::from_percent(5) * total_value_staked
+    );
+    assert_eq!(
+        new_config.tier_thresholds[2],
+        Perbill::from_parts(5_000_000) * total_issuance
+    );
+
+    // Low staking participation, dynamic threshold is clamped to the minimum
+    let total_value_staked: Balance = 100_000_000;
+    let new_config = init_config.calculate_new(
+        &params,
+        FIXED_NUMBER_OF_TIER_SLOTS,
+        total_issuance,
+        total_value_staked,
+    );
+    assert_eq!(
+        new_config.tier_thresholds[0],
+        Perbill::from_percent(2) * total_issuance
+    );
+    assert_eq!(
+        new_config.tier_thresholds[1],
+        Perbill::from_percent(5) * total_value_staked
+    );
+
+    // High staking participation, dynamic threshold is clamped to the maximum
+    let total_value_staked: Balance = 4_000_000_000;
+    let new_config = init_config.calculate_new(
+        &params,
+        FIXED_NUMBER_OF_TIER_SLOTS,
+        total_issuance,
+        total_value_staked,
+    );
+    assert_eq!(
+        new_config.tier_thresholds[0],
+        Perbill::from_percent(5) * total_issuance
+    );
+}
+
+#[test]
+fn tier_assignment_progress_insert_candidate_works() {
+    get_u32_type!(MaxLen, 4);
+
+    // Limit is capped by the max allowed length
+    let progress = TierAssignmentProgress::<MaxLen>::new(7, 5);
+    assert_eq!(progress.era, 7);
+    assert_eq!(progress.limit, 4);
+    assert!(progress.cursor.is_none());
+    assert!(!progress.finished);
+    assert!(progress.dropped_max_amount.is_zero());
+
+    // Candidates are sorted by the staked amount, in descending order
+    let mut progress = TierAssignmentProgress::<MaxLen>::new(7, 3);
+    progress.insert_candidate(0, 100);
+    progress.insert_candidate(1, 300);
+    progress.insert_candidate(2, 200);
+    assert_eq!(
+        progress.candidates.clone().into_inner(),
+        vec![(1, 300), (2, 200), (0, 100)]
+    );
+    assert!(progress.dropped_max_amount.is_zero());
+
+    // Full list, lowest candidate is dropped
+    progress.insert_candidate(3, 150);
+    assert_eq!(
+        progress.candidates.clone().into_inner(),
+        vec![(1, 300), (2, 200), (3, 150)]
+    );
+    assert_eq!(progress.dropped_max_amount, 100);
+
+    // Candidates with the same amount keep the insertion order
+    progress.insert_candidate(4, 200);
+    assert_eq!(
+        progress.candidates.clone().into_inner(),
+        vec![(1, 300), (2, 200), (4, 200)]
+    );
+
+    assert_eq!(progress.dropped_max_amount, 150);
+
+    // Candidate which doesn't make it into the list is ignored
+    progress.insert_candidate(5, 200);
+    progress.insert_candidate(6, 10);
+    assert_eq!(
+        progress.candidates.clone().into_inner(),
+        vec![(1, 300), (2, 200), (4, 200)]
+    );
+    assert_eq!(progress.dropped_max_amount, 200);
+
+    // Zero limit means no candidates are kept
+    let mut progress = TierAssignmentProgress::<MaxLen>::new(7, 0);
+    progress.insert_candidate(0, 100);
+    assert!(progress.candidates.is_empty());
+    assert_eq!(progress.dropped_max_amount, 100);
+}
+
+#[test]
+fn tier_assignment_progress_refresh_candidates_works() {
+    get_u32_type!(MaxLen, 4);
+
+    let mut progress = TierAssignmentProgress::<MaxLen>::new(7, 4);
+    progress.insert_candidate(0, 400);
+    progress.insert_candidate(1, 300);
+    progress.insert_candidate(2, 200);
+    progress.insert_candidate(3, 100);
+
+    // No dApp was dropped, so re-read is never needed
+    assert!(progress.dropped_max_amount.is_zero());
+    assert!(!progress.refresh_candidates(|dapp_id| 300 - 100 * dapp_id as Balance));
+    assert_eq!(
+        progress.candidates.clone().into_inner(),
+        vec![(0, 300), (1, 200), (2, 100)]
+    );
+
+    // Amounts are updated, candidates without stake are removed & the rest is sorted again
+    let mut progress = TierAssignmentProgress::<MaxLen>::new(7, 4);
+    progress.insert_candidate(0, 400);
+    progress.insert_candidate(1, 300);
+    progress.insert_candidate(2, 200);
+    progress.insert_candidate(3, 100);
+    progress.insert_candidate(4, 50);
+    assert_eq!(progress.dropped_max_amount, 50);
+
+    // Unchanged or increased amounts don't require a re-read
+    assert!(!progress.refresh_candidates(|dapp_id| 500 - 100 * dapp_id as Balance));
+
+    // Decreased amounts which are still above the best dropped amount don't require a re-read
+    assert!(!progress.refresh_candidates(|dapp_id| match dapp_id {
+        0 => 150,
+        1 => 250,
+        2 => 200,
+        _ => 60,
+    }));
+    assert_eq!(
+        progress.candidates.clone().into_inner(),
+        vec![(1, 250), (2, 200), (0, 150), (3, 60)]
+    );
+
+    // Decreasing the amount to the best dropped amount requires a re-read
+    assert!(progress.refresh_candidates(|dapp_id| match dapp_id {
+        3 => 50,
+        _ => 150,
+    }));
+
+    // Removed candidates require a re-read as well
+    assert!(progress.refresh_candidates(|dapp_id| match dapp_id {
+        1 => 0,
+        _ => 150,
+    }));
+    assert_eq!(
+        progress.candidates.into_inner(),
+        vec![(2, 150), (0, 150), (3, 150)]
+    );
+}
+
+#[test]
+fn tier_history_push_works() {
+    get_u32_type!(HistoryLength, 3);
+
+    let entry = |era: EraNumber| DAppTierHistoryEntry {
+        era,
+        ranked_tier: RankedTier::new_saturated(1, 2),
+        stake: 100 * era as Balance,
+        reward: 10 * era as Balance,
+    };
+
+    let mut history = TierHistory::<HistoryLength>::default();
+    assert!(history.is_empty());
+    assert!(history.entries().is_empty());
+
+    // Entries are appended until the history is full
+    for era in 1..=3 {
+        history.push(entry(era));
+    }
+    assert_eq!(history.len(), 3);
+    assert_eq!(history.entries(), vec![entry(1), entry(2), entry(3)]);
+
+    // Oldest entries are overwritten, order is preserved
+    history.push(entry(4));
+    assert_eq!(history.len(), 3);
+    assert_eq!(history.entries(), vec![entry(2), entry(3), entry(4)]);
+
+    history.push(entry(5));
+    history.push(entry(6));
+    history.push(entry(7));
+    assert_eq!(history.len(), 3);
+    assert_eq!(history.entries(), vec![entry(5), entry(6), entry(7)]);
+}
+
+#[test]
+fn tier_history_with_zero_length_is_noop() {
+    get_u32_type!(HistoryLength, 0);
+
+    let mut history = TierHistory::<HistoryLength>::default();
+    history.push(DAppTierHistoryEntry {
+        era: 1,
+        ranked_tier: RankedTier::new_saturated(0, 0),
+        stake: 100,
+        reward: 10,
+    });
+    assert!(history.is_empty());
+}
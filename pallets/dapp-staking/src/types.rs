@@ -40,6 +40,7 @@
 //!
 //! * `DAppId` - a compact unique numeric Id of a dApp.
 //! * `DAppInfo` - contains general information about a dApp, like owner and reward beneficiary, Id and state.
+//! * `BoundedDAppMetadata` - contains owner provided metadata of a dApp, like name, URL, logo & category.
 //! * `ContractStakeAmount` - contains information about how much is staked on a particular contract.
 //!
 //! ## Staker Information
@@ -48,6 +49,8 @@
 //! * `StakeAmount` - contains information about the staked amount in a particular era, and period.
 //! * `AccountLedger` - keeps track of total locked & staked balance, unlocking chunks and number of stake entries.
 //! * `SingularStakingInfo` - contains information about a particular staker's stake on a specific smart contract. Used to track loyalty.
+//! * `DelegatePermissions` - describes which stake-changing actions a delegate can perform on behalf of a staker.
+//! * `CarryOverPlan` - describes stake which is automatically applied at the start of each new period, on behalf of a staker.
 //!
 //! ## Era Information
 //!
@@ -57,11 +60,12 @@
 //!
 //! ## Tier Information
 //!
-//! * `TierThreshold` - an enum describing tier entry thresholds as percentages of the total issuance.
+//! * `TierThreshold` - an enum describing tier entry thresholds as percentages of the total issuance or the total value staked.
 //! * `TierParameters` - contains static information about tiers, like init thresholds, reward & slot distribution.
 //! * `TiersConfiguration` - contains dynamic information about tiers, derived from `TierParameters` and onchain data.
 //! * `DAppTier` - a compact struct describing a dApp's tier.
 //! * `DAppTierRewards` - composite of `DAppTier` objects, describing the entire reward distribution for a particular era.
+//! * `TierAssignmentProgress` - keeps track of the paged tier assignment, spread over multiple blocks, for a particular era.
 //!
 
 use core::ops::Deref;
@@ -75,7 +79,10 @@ use sp_runtime::{
 pub use sp_std::{collections::btree_map::BTreeMap, fmt::Debug, vec::Vec};
 
 use astar_primitives::{
-    dapp_staking::{DAppId, EraNumber, PeriodNumber, RankedTier, FIXED_NUMBER_OF_TIER_SLOTS},
+    dapp_staking::{
+        DAppCategory, DAppId, DAppMetadata, DAppTierHistoryEntry, EraNumber, PeriodNumber,
+        RankedTier,
+    },
     Balance, BlockNumber,
 };
 
@@ -86,13 +93,38 @@ pub type AccountLedgerFor<T> = AccountLedger<<T as Config>::MaxUnlockingChunks>;
 
 // Convenience type for `DAppTierRewards` usage.
 pub type DAppTierRewardsFor<T> =
-    DAppTierRewards<<T as Config>::MaxNumberOfContracts, <T as Config>::NumberOfTiers>;
+    DAppTierRewards<<T as Config>::MaxNumberOfTierSlots, <T as Config>::NumberOfTiers>;
+
+// Convenience type for `TierAssignmentProgress` usage.
+pub type TierAssignmentProgressFor<T> = TierAssignmentProgress<<T as Config>::MaxNumberOfTierSlots>;
+
+// Convenience type for `TierHistory` usage.
+pub type TierHistoryFor<T> = TierHistory<<T as Config>::TierHistoryLength>;
+
+// Convenience type for stake delegates usage.
+pub type DelegatesFor<T> = BoundedBTreeMap<
+    <T as frame_system::Config>::AccountId,
+    DelegatePermissions,
+    <T as Config>::MaxNumberOfDelegates,
+>;
+
+// Convenience type for carry-over plan entries usage.
+pub type CarryOverEntriesFor<T> =
+    BoundedVec<(<T as Config>::SmartContract, Balance), <T as Config>::MaxNumberOfStakedContracts>;
+
+// Convenience type for `CarryOverPlan` usage.
+pub type CarryOverPlanFor<T> =
+    CarryOverPlan<<T as Config>::SmartContract, <T as Config>::MaxNumberOfStakedContracts>;
 
 // Convenience type for `EraRewardSpan` usage.
 pub type EraRewardSpanFor<T> = EraRewardSpan<<T as Config>::EraRewardSpanLength>;
 
 // Convenience type for `DAppInfo` usage.
-pub type DAppInfoFor<T> = DAppInfo<<T as frame_system::Config>::AccountId>;
+pub type DAppInfoFor<T> =
+    DAppInfo<<T as frame_system::Config>::AccountId, <T as Config>::MaxNumberOfRewardSplits>;
+
+// Convenience type for `BoundedDAppMetadata` usage.
+pub type DAppMetadataFor<T> = BoundedDAppMetadata<<T as Config>::MaxDAppMetadataLength>;
 
 // Convenience type for `BonusStatusWrapper` usage.
 pub type BonusStatusWrapperFor<T> = BonusStatusWrapper<<T as Config>::MaxBonusSafeMovesPerPeriod>;
@@ -317,8 +349,18 @@ impl ProtocolState {
 }
 
 /// General information about a dApp.
-#[derive(Encode, Decode, MaxEncodedLen, Clone, Copy, Debug, PartialEq, Eq, TypeInfo)]
-pub struct DAppInfo<AccountId> {
+#[derive(
+    Encode,
+    Decode,
+    MaxEncodedLen,
+    RuntimeDebugNoBound,
+    PartialEqNoBound,
+    EqNoBound,
+    CloneNoBound,
+    TypeInfo,
+)]
+#[scale_info(skip_type_params(MaxSplits))]
+pub struct DAppInfo<AccountId: Clone + PartialEq + Eq + Debug, MaxSplits: Get<u32>> {
     /// Owner of the dApp, default reward beneficiary.
     pub(crate) owner: AccountId,
     /// dApp's unique identifier in dApp staking.
@@ -326,9 +368,14 @@ pub struct DAppInfo<AccountId> {
     pub(crate) id: DAppId,
     // If `None`, rewards goes to the developer account, otherwise to the account Id in `Some`.
     pub(crate) reward_beneficiary: Option<AccountId>,
+    /// Additional reward beneficiaries, each receiving the specified share of the dApp reward.
+    /// Whatever remains after the splits are paid out goes to the reward beneficiary.
+    pub(crate) reward_splits: BoundedVec<(AccountId, Permill), MaxSplits>,
 }
 
-impl<AccountId> DAppInfo<AccountId> {
+impl<AccountId: Clone + PartialEq + Eq + Debug, MaxSplits: Get<u32>>
+    DAppInfo<AccountId, MaxSplits>
+{
     /// dApp's unique identifier.
     pub fn id(&self) -> DAppId {
         self.id
@@ -341,6 +388,75 @@ impl<AccountId> DAppInfo<AccountId> {
             None => &self.owner,
         }
     }
+
+    /// Additional reward beneficiaries and their shares of the dApp reward.
+    pub fn reward_splits(&self) -> &[(AccountId, Permill)] {
+        &self.reward_splits
+    }
+
+    /// Splits the given `reward` between the configured beneficiaries.
+    ///
+    /// Each split beneficiary receives its share of the reward, while the remainder
+    /// (including any rounding dust) goes to the reward beneficiary.
+    /// Beneficiaries with zero payout are omitted.
+    pub fn reward_payouts(&self, reward: Balance) -> Vec<(AccountId, Balance)> {
+        let mut payouts = Vec::with_capacity(self.reward_splits.len().saturating_add(1));
+        let mut remainder = reward;
+
+        for (beneficiary, share) in self.reward_splits.iter() {
+            let amount = (*share * reward).min(remainder);
+            remainder.saturating_reduce(amount);
+            if !amount.is_zero() {
+                payouts.push((beneficiary.clone(), amount));
+            }
+        }
+
+        if !remainder.is_zero() || payouts.is_empty() {
+            payouts.insert(0, (self.reward_beneficiary().clone(), remainder));
+        }
+
+        payouts
+    }
+}
+
+/// Metadata of a dApp, as provided by the dApp owner.
+///
+/// Each of the byte fields is limited to `MaxLength` bytes.
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    MaxEncodedLen,
+    RuntimeDebugNoBound,
+    PartialEqNoBound,
+    DefaultNoBound,
+    EqNoBound,
+    CloneNoBound,
+    TypeInfo,
+)]
+#[scale_info(skip_type_params(MaxLength))]
+pub struct BoundedDAppMetadata<MaxLength: Get<u32>> {
+    /// Human readable name of the dApp.
+    pub name: BoundedVec<u8, MaxLength>,
+    /// URL of the dApp's website.
+    pub url: BoundedVec<u8, MaxLength>,
+    /// IPFS CID of the dApp's logo.
+    pub logo_cid: BoundedVec<u8, MaxLength>,
+    /// Category of the dApp.
+    pub category: DAppCategory,
+}
+
+impl<MaxLength: Get<u32>> BoundedDAppMetadata<MaxLength> {
+    /// Converts the metadata into its unbounded representation, with the deposit held for it.
+    pub fn into_dapp_metadata(self, deposit: Balance) -> DAppMetadata {
+        DAppMetadata {
+            name: self.name.into_inner(),
+            url: self.url.into_inner(),
+            logo_cid: self.logo_cid.into_inner(),
+            category: self.category,
+            deposit,
+        }
+    }
 }
 
 /// How much was unlocked in some block.
@@ -563,6 +679,30 @@ where
         amount
     }
 
+    /// Consumes up to `amount` from the unlocking chunks which haven't been fully unlocked yet,
+    /// starting with the chunk which would be unlocked last. Returns the consumed amount.
+    pub fn consume_pending_unlocking_amount(
+        &mut self,
+        amount: Balance,
+        current_block_number: BlockNumber,
+    ) -> Balance {
+        let mut remaining = amount;
+
+        // Chunks are sorted by the unlock block, in ascending order.
+        for chunk in self.unlocking.iter_mut().rev() {
+            if remaining.is_zero() || chunk.unlock_block <= current_block_number {
+                break;
+            }
+
+            let consumed = chunk.amount.min(remaining);
+            chunk.amount.saturating_reduce(consumed);
+            remaining.saturating_reduce(consumed);
+        }
+        self.unlocking.retain(|chunk| !chunk.amount.is_zero());
+
+        amount.saturating_sub(remaining)
+    }
+
     /// Amount that is available for staking.
     ///
     /// This is equal to the total active locked amount, minus the staked amount already active.
@@ -1651,15 +1791,49 @@ pub enum TierThreshold {
     /// Entry into the tier is mandated by a fixed percentage of the total issuance as staked funds.
     /// This value is constant and does not change between periods.
     FixedPercentage { required_percentage: Perbill },
+    /// Entry into the tier is mandated by a fixed percentage of the era's total value staked.
+    /// The threshold amount follows staking participation, independently of the total issuance.
+    StakedPercentage { required_percentage: Perbill },
+    /// Entry into the tier is mandated by a percentage of the era's total value staked,
+    /// clamped between the minimum & maximum percentages of the total issuance.
+    DynamicPercentage {
+        percentage: Perbill,
+        minimum_required_percentage: Perbill,
+        maximum_possible_percentage: Perbill,
+    },
 }
 
 impl TierThreshold {
     /// Return threshold amount for the tier.
-    pub fn threshold(&self, total_issuance: Balance) -> Balance {
+    pub fn threshold(&self, total_issuance: Balance, total_value_staked: Balance) -> Balance {
         match self {
             Self::FixedPercentage {
                 required_percentage,
             } => *required_percentage * total_issuance,
+            Self::StakedPercentage {
+                required_percentage,
+            } => *required_percentage * total_value_staked,
+            Self::DynamicPercentage {
+                percentage,
+                minimum_required_percentage,
+                maximum_possible_percentage,
+            } => {
+                let minimum = *minimum_required_percentage * total_issuance;
+                let maximum = *maximum_possible_percentage * total_issuance;
+                (*percentage * total_value_staked).clamp(minimum, maximum.max(minimum))
+            }
+        }
+    }
+
+    /// Check if the threshold is valid.
+    pub fn is_valid(&self) -> bool {
+        match self {
+            Self::FixedPercentage { .. } | Self::StakedPercentage { .. } => true,
+            Self::DynamicPercentage {
+                minimum_required_percentage,
+                maximum_possible_percentage,
+                ..
+            } => minimum_required_percentage <= maximum_possible_percentage,
         }
     }
 }
@@ -1744,12 +1918,41 @@ impl<NT: Get<u32>> TierParameters<NT> {
             return false;
         }
 
+        // Each tier threshold must be valid on its own.
+        if !self
+            .tier_thresholds
+            .iter()
+            .all(|threshold| threshold.is_valid())
+        {
+            return false;
+        }
+
         let number_of_tiers: usize = NT::get() as usize;
         number_of_tiers == self.reward_portion.len()
             && number_of_tiers == self.slot_distribution.len()
             && number_of_tiers == self.tier_thresholds.len()
             && number_of_tiers == self.tier_rank_multipliers.len()
     }
+
+    /// Returns the reward distribution portion assigned to each tier.
+    pub fn reward_portion(&self) -> &BoundedVec<Permill, NT> {
+        &self.reward_portion
+    }
+
+    /// Returns the slot distribution portion assigned to each tier.
+    pub fn slot_distribution(&self) -> &BoundedVec<Permill, NT> {
+        &self.slot_distribution
+    }
+
+    /// Returns the entry requirement of each tier.
+    pub fn tier_thresholds(&self) -> &BoundedVec<TierThreshold, NT> {
+        &self.tier_thresholds
+    }
+
+    /// Returns the rank multiplier of each tier, in bips.
+    pub fn tier_rank_multipliers(&self) -> &BoundedVec<u32, NT> {
+        &self.tier_rank_multipliers
+    }
 }
 
 /// Configuration of dApp tiers.
@@ -1810,11 +2013,15 @@ impl<NT: Get<u32>> TiersConfiguration<NT> {
 
     /// Calculate new `TiersConfiguration` based on static tier parameters.
     ///
-    /// NOTE: Dynamic slot number arguments are intentionally ignored in this flow.
-    /// Tier slot count is fixed via `FIXED_NUMBER_OF_TIER_SLOTS`.
-    pub fn calculate_new(&self, params: &TierParameters<NT>, total_issuance: Balance) -> Self {
-        let number_of_slots: u16 = FIXED_NUMBER_OF_TIER_SLOTS;
-
+    /// The total `number_of_slots` is redistributed between tiers according to the slot distribution.
+    /// Tier thresholds are evaluated against the provided total issuance & total value staked.
+    pub fn calculate_new(
+        &self,
+        params: &TierParameters<NT>,
+        number_of_slots: u16,
+        total_issuance: Balance,
+        total_value_staked: Balance,
+    ) -> Self {
         // Calculate how much each tier gets slots.
         let new_slots_per_tier: Vec<u16> = params
             .slot_distribution
@@ -1827,12 +2034,12 @@ impl<NT: Get<u32>> TiersConfiguration<NT> {
         let new_slots_per_tier =
             BoundedVec::<u16, NT>::try_from(new_slots_per_tier).unwrap_or_default();
 
-        // Update tier thresholds using static percentages.
+        // Update tier thresholds based on the current total issuance & total value staked.
         let new_tier_thresholds: BoundedVec<Balance, NT> = params
             .tier_thresholds
             .clone()
             .iter()
-            .map(|threshold| threshold.threshold(total_issuance))
+            .map(|threshold| threshold.threshold(total_issuance, total_value_staked))
             .collect::<Vec<_>>()
             .try_into()
             .unwrap_or_default();
@@ -1893,10 +2100,18 @@ impl<MD: Get<u32>, NT: Get<u32>> DAppTierRewards<MD, NT> {
     /// Consume reward for the specified dapp id, returning its amount and tier Id.
     /// In case dapp isn't applicable for rewards, or they have already been consumed, returns `None`.
     pub fn try_claim(&mut self, dapp_id: DAppId) -> Result<(Balance, RankedTier), DAppTierError> {
+        let reward = self.dapp_reward(dapp_id)?;
+        self.dapps.remove(&dapp_id);
+
+        Ok(reward)
+    }
+
+    /// Reward amount and ranked tier of the specified dapp id, without consuming the reward.
+    pub fn dapp_reward(&self, dapp_id: DAppId) -> Result<(Balance, RankedTier), DAppTierError> {
         // Check if dApp Id exists.
-        let ranked_tier = self
+        let ranked_tier = *self
             .dapps
-            .remove(&dapp_id)
+            .get(&dapp_id)
             .ok_or(DAppTierError::NoDAppInTiers)?;
 
         let (tier_id, rank) = ranked_tier.deconstruct();
@@ -1925,6 +2140,256 @@ pub enum DAppTierError {
     InternalError,
 }
 
+/// Progress of the paged tier assignment for a particular era.
+///
+/// Contract stake entries are read over multiple blocks, page by page, and the best candidates for tier
+/// assignment are kept in a sorted list. Only as many candidates as there are tier slots are kept, since
+/// the rest can never be assigned to any tier.
+#[derive(
+    Encode,
+    Decode,
+    MaxEncodedLen,
+    RuntimeDebugNoBound,
+    PartialEqNoBound,
+    DefaultNoBound,
+    EqNoBound,
+    CloneNoBound,
+    TypeInfo,
+)]
+#[scale_info(skip_type_params(ML))]
+pub struct TierAssignmentProgress<ML: Get<u32>> {
+    /// Era for which the tier assignment is being prepared.
+    #[codec(compact)]
+    pub(crate) era: EraNumber,
+    /// dApp Id of the last read contract stake entry, `None` if no entry has been read yet.
+    pub(crate) cursor: Option<DAppId>,
+    /// `true` if all contract stake entries have been read, `false` otherwise.
+    pub(crate) finished: bool,
+    /// Maximum number of candidates to keep.
+    #[codec(compact)]
+    pub(crate) limit: u32,
+    /// Highest staked amount among the dApps which didn't make it into the list, or were dropped from it.
+    /// Zero if no dApp has been left out.
+    pub(crate) dropped_max_amount: Balance,
+    /// Tier assignment candidates, sorted by the staked amount in descending order.
+    pub(crate) candidates: BoundedVec<(DAppId, Balance), ML>,
+}
+
+impl<ML: Get<u32>> TierAssignmentProgress<ML> {
+    /// Create a new, empty, tier assignment progress for the specified era.
+    ///
+    /// Candidate limit is capped by the maximum allowed length.
+    pub(crate) fn new(era: EraNumber, limit: u32) -> Self {
+        Self {
+            era,
+            cursor: None,
+            finished: false,
+            limit: limit.min(ML::get()),
+            dropped_max_amount: Balance::zero(),
+            candidates: Default::default(),
+        }
+    }
+
+    /// Insert the dApp into the sorted candidates list, if it's among the best candidates.
+    ///
+    /// Candidates with the same staked amount keep the order in which they were inserted.
+    /// In case the list is full, the candidate with the lowest staked amount is dropped.
+    pub(crate) fn insert_candidate(&mut self, dapp_id: DAppId, amount: Balance) {
+        let index = self
+            .candidates
+            .partition_point(|(_, candidate_amount)| *candidate_amount >= amount);
+
+        if index >= self.limit as usize {
+            self.dropped_max_amount = self.dropped_max_amount.max(amount);
+            return;
+        }
+
+        if self.candidates.len() >= self.limit as usize {
+            if let Some((_, dropped_amount)) = self.candidates.pop() {
+                self.dropped_max_amount = self.dropped_max_amount.max(dropped_amount);
+            }
+        }
+
+        // Cannot fail since the length is checked above.
+        let _ = self.candidates.try_insert(index, (dapp_id, amount));
+    }
+
+    /// Replace candidate staked amounts with the provided ones, and sort the candidates again.
+    ///
+    /// Candidates with zero staked amount are removed.
+    ///
+    /// Returns `true` if the staked amount of any candidate has decreased to, or below, the highest staked amount
+    /// among the dropped dApps. In that case, some of the dropped dApps might be better candidates now,
+    /// and all of the contract stake entries need to be read again.
+    pub(crate) fn refresh_candidates(&mut self, amount_of: impl Fn(DAppId) -> Balance) -> bool {
+        let mut needs_rescan = false;
+        let dropped_max_amount = self.dropped_max_amount;
+        self.candidates.iter_mut().for_each(|(dapp_id, amount)| {
+            let new_amount = amount_of(*dapp_id);
+            needs_rescan |= new_amount < *amount
+                && !dropped_max_amount.is_zero()
+                && new_amount <= dropped_max_amount;
+            *amount = new_amount;
+        });
+        self.candidates.retain(|(_, amount)| !amount.is_zero());
+        // Stable sort is required, to keep the insertion order of candidates with equal amounts.
+        self.candidates
+            .sort_by(|(_, amount_1), (_, amount_2)| amount_2.cmp(amount_1));
+
+        needs_rescan
+    }
+}
+
+/// Bounded tier assignment history of a single dApp.
+///
+/// Works as a ring buffer - once full, each new entry overwrites the oldest one.
+#[derive(
+    Encode,
+    Decode,
+    MaxEncodedLen,
+    RuntimeDebugNoBound,
+    PartialEqNoBound,
+    DefaultNoBound,
+    EqNoBound,
+    CloneNoBound,
+    TypeInfo,
+)]
+#[scale_info(skip_type_params(HL))]
+pub struct TierHistory<HL: Get<u32>> {
+    /// History entries, wrapped around the `head` once the buffer is full.
+    pub(crate) entries: BoundedVec<DAppTierHistoryEntry, HL>,
+    /// Index of the oldest entry.
+    #[codec(compact)]
+    pub(crate) head: u32,
+}
+
+impl<HL: Get<u32>> TierHistory<HL> {
+    /// Push a new entry into the history, overwriting the oldest entry if the history is full.
+    pub(crate) fn push(&mut self, entry: DAppTierHistoryEntry) {
+        if self.entries.len() < HL::get() as usize {
+            // Cannot fail since the length is checked above.
+            let _ = self.entries.try_push(entry);
+        } else if let Some(oldest) = self.entries.get_mut(self.head as usize) {
+            *oldest = entry;
+            self.head = self.head.saturating_add(1) % self.entries.len() as u32;
+        }
+    }
+
+    /// History entries, from the oldest to the latest one.
+    pub fn entries(&self) -> Vec<DAppTierHistoryEntry> {
+        let (latest, oldest) = self.entries.split_at(self.head as usize);
+        oldest.iter().chain(latest.iter()).copied().collect()
+    }
+
+    /// Number of entries in the history.
+    pub fn len(&self) -> u32 {
+        self.entries.len() as u32
+    }
+
+    /// `true` if history has no entries, `false` otherwise.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+/// Stake-changing actions which can be performed by a delegate on behalf of a staker.
+#[derive(Encode, Decode, MaxEncodedLen, Clone, Copy, Debug, PartialEq, Eq, TypeInfo)]
+pub enum DelegatedAction {
+    /// Stake some of the staker's locked funds on a smart contract.
+    Stake,
+    /// Unstake some amount from a smart contract.
+    Unstake,
+    /// Move stake between two smart contracts.
+    MoveStake,
+}
+
+/// Permissions granted to a delegate by a staker.
+///
+/// Delegates can only rearrange how the staker's locked funds are staked.
+/// Locking, unlocking and claiming unlocked funds always remains reserved for the staker.
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    MaxEncodedLen,
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    TypeInfo,
+    Default,
+)]
+pub struct DelegatePermissions {
+    /// Delegate is allowed to stake on behalf of the staker.
+    pub stake: bool,
+    /// Delegate is allowed to unstake on behalf of the staker.
+    pub unstake: bool,
+    /// Delegate is allowed to move stake on behalf of the staker.
+    pub move_stake: bool,
+}
+
+impl DelegatePermissions {
+    /// Permissions which allow all of the delegated actions.
+    pub fn all() -> Self {
+        Self {
+            stake: true,
+            unstake: true,
+            move_stake: true,
+        }
+    }
+
+    /// `true` if no action is permitted, `false` otherwise.
+    pub fn is_empty(&self) -> bool {
+        !self.stake && !self.unstake && !self.move_stake
+    }
+
+    /// `true` if the specified action is permitted, `false` otherwise.
+    pub fn allows(&self, action: DelegatedAction) -> bool {
+        match action {
+            DelegatedAction::Stake => self.stake,
+            DelegatedAction::Unstake => self.unstake,
+            DelegatedAction::MoveStake => self.move_stake,
+        }
+    }
+}
+
+/// Stake which is automatically applied on behalf of a staker at the start of each new period's `Voting` subperiod.
+#[derive(
+    Encode,
+    Decode,
+    MaxEncodedLen,
+    RuntimeDebugNoBound,
+    PartialEqNoBound,
+    EqNoBound,
+    CloneNoBound,
+    TypeInfo,
+)]
+#[scale_info(skip_type_params(MaxEntries))]
+pub struct CarryOverPlan<SmartContract: Clone + PartialEq + Eq + Debug, MaxEntries: Get<u32>> {
+    /// Smart contracts & amounts to stake on them.
+    pub(crate) entries: BoundedVec<(SmartContract, Balance), MaxEntries>,
+    /// First period in which the plan is applied.
+    #[codec(compact)]
+    pub(crate) start_period: PeriodNumber,
+}
+
+impl<SmartContract, MaxEntries> CarryOverPlan<SmartContract, MaxEntries>
+where
+    SmartContract: Clone + PartialEq + Eq + Debug,
+    MaxEntries: Get<u32>,
+{
+    /// Smart contracts & amounts to stake on them.
+    pub fn entries(&self) -> &[(SmartContract, Balance)] {
+        &self.entries
+    }
+
+    /// First period in which the plan is applied.
+    pub fn start_period(&self) -> PeriodNumber {
+        self.start_period
+    }
+}
+
 /// Describes which entries are next in line for cleanup.
 #[derive(Encode, Decode, MaxEncodedLen, Copy, Clone, Debug, PartialEq, Eq, TypeInfo, Default)]
 pub struct CleanupMarker {
@@ -63,7 +63,7 @@ pub trait WeightInfo {
 	fn claim_staker_rewards_past_period(x: u32, ) -> Weight;
 	fn claim_staker_rewards_ongoing_period(x: u32, ) -> Weight;
 	fn claim_bonus_reward() -> Weight;
-	fn claim_dapp_reward() -> Weight;
+	fn claim_dapp_reward(x: u32, ) -> Weight;
 	fn unstake_from_unregistered() -> Weight;
 	fn cleanup_expired_entries(x: u32, ) -> Weight;
 	fn force() -> Weight;
@@ -75,6 +75,27 @@ pub trait WeightInfo {
 	fn dapp_tier_assignment(x: u32, ) -> Weight;
 	fn on_idle_cleanup() -> Weight;
 	fn set_static_tier_params() -> Weight;
+	fn set_auto_compound() -> Weight;
+	fn compound_reward(x: u32, ) -> Weight;
+	fn set_dapp_reward_splits() -> Weight;
+	fn claim_dapp_rewards(x: u32, ) -> Weight;
+	fn set_auto_claim() -> Weight;
+	fn dapp_tier_assignment_page(x: u32, ) -> Weight;
+	fn instant_unlock(x: u32, ) -> Weight;
+	fn set_instant_unlock_penalty() -> Weight;
+	fn update_dapp_tier_history(x: u32, ) -> Weight;
+	fn set_staker_reward_destination() -> Weight;
+	fn set_delegate() -> Weight;
+	fn revoke_delegate() -> Weight;
+	fn set_carry_over_plan(x: u32, ) -> Weight;
+	fn remove_carry_over_plan() -> Weight;
+	fn set_dapp_metadata() -> Weight;
+	fn clear_dapp_metadata() -> Weight;
+	fn schedule_static_tier_params() -> Weight;
+	fn cancel_scheduled_tier_params() -> Weight;
+	fn unstake_from_all_unregistered(x: u32, ) -> Weight;
+	fn set_lock_receipts_enabled() -> Weight;
+	fn transfer_lock(x: u32, ) -> Weight;
 }
 
 /// Weights for pallet_dapp_staking using the Substrate node and recommended hardware.
@@ -130,14 +151,18 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `DappStaking::CounterForIntegratedDApps` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `DappStaking::ContractStake` (r:0 w:1)
 	/// Proof: `DappStaking::ContractStake` (`max_values`: Some(65535), `max_size`: Some(91), added: 2071, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::CounterForUnregisteredStakeSweeps` (r:1 w:1)
+	/// Proof: `DappStaking::CounterForUnregisteredStakeSweeps` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::UnregisteredStakeSweeps` (r:0 w:1)
+	/// Proof: `DappStaking::UnregisteredStakeSweeps` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
 	fn unregister() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `97`
 		//  Estimated: `3086`
 		// Minimum execution time: 14_595_000 picoseconds.
 		Weight::from_parts(14_935_000, 3086)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	/// Storage: `DappStaking::Ledger` (r:1 w:1)
 	/// Proof: `DappStaking::Ledger` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
@@ -149,14 +174,22 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `DappStaking::CurrentEraInfo` (r:1 w:1)
 	/// Proof: `DappStaking::CurrentEraInfo` (`max_values`: Some(1), `max_size`: Some(112), added: 607, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::LockReceiptsEnabled` (r:1 w:0)
+	/// Proof: `DappStaking::LockReceiptsEnabled` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::LockReceipts` (r:1 w:1)
+	/// Proof: `DappStaking::LockReceipts` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(222), added: 2697, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:1 w:1)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
 	fn lock_new_account() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `138`
 		//  Estimated: `4764`
 		// Minimum execution time: 31_874_000 picoseconds.
 		Weight::from_parts(32_108_000, 4764)
-			.saturating_add(T::DbWeight::get().reads(5_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
 	}
 	/// Storage: `DappStaking::Ledger` (r:1 w:1)
 	/// Proof: `DappStaking::Ledger` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
@@ -166,14 +199,22 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `DappStaking::CurrentEraInfo` (r:1 w:1)
 	/// Proof: `DappStaking::CurrentEraInfo` (`max_values`: Some(1), `max_size`: Some(112), added: 607, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::LockReceiptsEnabled` (r:1 w:0)
+	/// Proof: `DappStaking::LockReceiptsEnabled` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::LockReceipts` (r:1 w:1)
+	/// Proof: `DappStaking::LockReceipts` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(222), added: 2697, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:1 w:1)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
 	fn lock_existing_account() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `158`
 		//  Estimated: `4764`
 		// Minimum execution time: 32_204_000 picoseconds.
 		Weight::from_parts(32_658_000, 4764)
-			.saturating_add(T::DbWeight::get().reads(4_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
 	}
 	/// Storage: `DappStaking::Ledger` (r:1 w:1)
 	/// Proof: `DappStaking::Ledger` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
@@ -183,14 +224,20 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `DappStaking::CurrentEraInfo` (r:1 w:1)
 	/// Proof: `DappStaking::CurrentEraInfo` (`max_values`: Some(1), `max_size`: Some(112), added: 607, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::LockReceipts` (r:1 w:1)
+	/// Proof: `DappStaking::LockReceipts` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(222), added: 2697, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:1 w:1)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
 	fn unlock() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `158`
 		//  Estimated: `4764`
 		// Minimum execution time: 28_967_000 picoseconds.
 		Weight::from_parts(29_523_000, 4764)
-			.saturating_add(T::DbWeight::get().reads(4_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
 	}
 	/// Storage: `DappStaking::Ledger` (r:1 w:1)
 	/// Proof: `DappStaking::Ledger` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
@@ -220,14 +267,22 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `DappStaking::CurrentEraInfo` (r:1 w:1)
 	/// Proof: `DappStaking::CurrentEraInfo` (`max_values`: Some(1), `max_size`: Some(112), added: 607, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::LockReceiptsEnabled` (r:1 w:0)
+	/// Proof: `DappStaking::LockReceiptsEnabled` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::LockReceipts` (r:1 w:1)
+	/// Proof: `DappStaking::LockReceipts` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(222), added: 2697, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:1 w:1)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
 	fn relock_unlocking() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `200`
 		//  Estimated: `4764`
 		// Minimum execution time: 27_384_000 picoseconds.
 		Weight::from_parts(27_620_000, 4764)
-			.saturating_add(T::DbWeight::get().reads(4_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
 	}
 	/// Storage: `DappStaking::IntegratedDApps` (r:1 w:0)
 	/// Proof: `DappStaking::IntegratedDApps` (`max_values`: Some(65535), `max_size`: Some(116), added: 2096, mode: `MaxEncodedLen`)
@@ -285,6 +340,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:0)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::StakerRewardDestination` (r:1 w:0)
+	/// Proof: `DappStaking::StakerRewardDestination` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
 	/// The range of component `x` is `[1, 16]`.
 	fn claim_staker_rewards_past_period(x: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -294,7 +351,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(50_088_222, 4764)
 			// Standard Error: 4_812
 			.saturating_add(Weight::from_parts(1_932_998, 0).saturating_mul(x.into()))
-			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: `DappStaking::Ledger` (r:1 w:1)
@@ -305,6 +362,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:0)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::StakerRewardDestination` (r:1 w:0)
+	/// Proof: `DappStaking::StakerRewardDestination` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
 	/// The range of component `x` is `[1, 16]`.
 	fn claim_staker_rewards_ongoing_period(x: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -314,7 +373,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(47_718_494, 4764)
 			// Standard Error: 3_774
 			.saturating_add(Weight::from_parts(1_922_497, 0).saturating_mul(x.into()))
-			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: `DappStaking::StakerInfo` (r:1 w:1)
@@ -323,27 +382,37 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `DappStaking::PeriodEnd` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
 	/// Storage: `DappStaking::Ledger` (r:1 w:1)
 	/// Proof: `DappStaking::Ledger` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::StakerRewardDestination` (r:1 w:0)
+	/// Proof: `DappStaking::StakerRewardDestination` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
 	fn claim_bonus_reward() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `275`
 		//  Estimated: `3775`
 		// Minimum execution time: 37_169_000 picoseconds.
 		Weight::from_parts(37_719_000, 3775)
-			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: `DappStaking::IntegratedDApps` (r:1 w:0)
 	/// Proof: `DappStaking::IntegratedDApps` (`max_values`: Some(65535), `max_size`: Some(116), added: 2096, mode: `MaxEncodedLen`)
 	/// Storage: `DappStaking::DAppTiers` (r:1 w:1)
 	/// Proof: `DappStaking::DAppTiers` (`max_values`: None, `max_size`: Some(1648), added: 4123, mode: `MaxEncodedLen`)
-	fn claim_dapp_reward() -> Weight {
+	/// Storage: `System::Account` (r:5 w:5)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[0, 5]`.
+	fn claim_dapp_reward(x: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `2672`
-		//  Estimated: `5113`
+		//  Measured:  `2672 + x * (32 ±0)`
+		//  Estimated: `5113 + x * (2603 ±0)`
 		// Minimum execution time: 54_124_000 picoseconds.
-		Weight::from_parts(54_932_000, 5113)
+		Weight::from_parts(54_773_000, 5113)
+			// Standard Error: 177_698
+			.saturating_add(Weight::from_parts(9_418_317, 0).saturating_mul(x.into()))
 			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(x.into())))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(x.into()))
 	}
 	/// Storage: `DappStaking::IntegratedDApps` (r:1 w:0)
 	/// Proof: `DappStaking::IntegratedDApps` (`max_values`: Some(65535), `max_size`: Some(116), added: 2096, mode: `MaxEncodedLen`)
@@ -552,6 +621,353 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(7_452_000, 0)
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `DappStaking::AutoCompound` (r:0 w:1)
+	/// Proof: `DappStaking::AutoCompound` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	fn set_auto_compound() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 9_112_000 picoseconds.
+		Weight::from_parts(9_221_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `DappStaking::AutoCompound` (r:1 w:0)
+	/// Proof: `DappStaking::AutoCompound` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::Ledger` (r:1 w:1)
+	/// Proof: `DappStaking::Ledger` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:1)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:0)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::CurrentEraInfo` (r:1 w:1)
+	/// Proof: `DappStaking::CurrentEraInfo` (`max_values`: Some(1), `max_size`: Some(112), added: 607, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::StakerInfo` (r:17 w:16)
+	/// Proof: `DappStaking::StakerInfo` (`max_values`: None, `max_size`: Some(178), added: 2653, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::IntegratedDApps` (r:16 w:0)
+	/// Proof: `DappStaking::IntegratedDApps` (`max_values`: Some(65535), `max_size`: Some(116), added: 2096, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::ContractStake` (r:16 w:16)
+	/// Proof: `DappStaking::ContractStake` (`max_values`: Some(65535), `max_size`: Some(91), added: 2071, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[0, 16]`.
+	fn compound_reward(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `298 + x * (170 ±0)`
+		//  Estimated: `4764 + x * (2653 ±0)`
+		// Minimum execution time: 38_240_000 picoseconds.
+		Weight::from_parts(38_698_000, 4764)
+			// Standard Error: 469_264
+			.saturating_add(Weight::from_parts(24_871_317, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 2653).saturating_mul(x.into()))
+	}
+	/// Storage: `DappStaking::IntegratedDApps` (r:1 w:1)
+	/// Proof: `DappStaking::IntegratedDApps` (`max_values`: Some(65535), `max_size`: Some(116), added: 2096, mode: `MaxEncodedLen`)
+	fn set_dapp_reward_splits() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `97`
+		//  Estimated: `3086`
+		// Minimum execution time: 13_614_000 picoseconds.
+		Weight::from_parts(13_777_000, 3086)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `DappStaking::IntegratedDApps` (r:1 w:0)
+	/// Proof: `DappStaking::IntegratedDApps` (`max_values`: Some(65535), `max_size`: Some(116), added: 2096, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::DAppTiers` (r:24 w:24)
+	/// Proof: `DappStaking::DAppTiers` (`max_values`: None, `max_size`: Some(195), added: 2670, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:6 w:6)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[1, 24]`.
+	fn claim_dapp_rewards(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `812 + x * (195 ±0)`
+		//  Estimated: `16278 + x * (2670 ±0)`
+		// Minimum execution time: 74_310_000 picoseconds.
+		Weight::from_parts(75_201_000, 16278)
+			// Standard Error: 115_622
+			.saturating_add(Weight::from_parts(6_128_317, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 2670).saturating_mul(x.into()))
+	}
+	/// Storage: `DappStaking::AutoClaim` (r:0 w:1)
+	/// Proof: `DappStaking::AutoClaim` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	fn set_auto_claim() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 9_086_000 picoseconds.
+		Weight::from_parts(9_195_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `DappStaking::TierAssignmentState` (r:1 w:1)
+	/// Proof: `DappStaking::TierAssignmentState` (`max_values`: Some(1), `max_size`: Some(303), added: 798, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::CounterForIntegratedDApps` (r:1 w:0)
+	/// Proof: `DappStaking::CounterForIntegratedDApps` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::TierConfig` (r:1 w:0)
+	/// Proof: `DappStaking::TierConfig` (`max_values`: Some(1), `max_size`: Some(91), added: 586, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::ContractStake` (r:17 w:0)
+	/// Proof: `DappStaking::ContractStake` (`max_values`: Some(65535), `max_size`: Some(91), added: 2071, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[0, 16]`.
+	fn dapp_tier_assignment_page(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `213 + x * (32 ±0)`
+		//  Estimated: `3061 + x * (2071 ±0)`
+		// Minimum execution time: 11_270_000 picoseconds.
+		Weight::from_parts(11_405_000, 3061)
+			// Standard Error: 47_433
+			.saturating_add(Weight::from_parts(2_514_317, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 2071).saturating_mul(x.into()))
+	}
+	/// Storage: `DappStaking::InstantUnlockPenalty` (r:1 w:0)
+	/// Proof: `DappStaking::InstantUnlockPenalty` (`max_values`: Some(1), `max_size`: Some(5), added: 500, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::Ledger` (r:1 w:1)
+	/// Proof: `DappStaking::Ledger` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:1)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:0)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::CurrentEraInfo` (r:1 w:1)
+	/// Proof: `DappStaking::CurrentEraInfo` (`max_values`: Some(1), `max_size`: Some(112), added: 607, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::LockReceipts` (r:1 w:1)
+	/// Proof: `DappStaking::LockReceipts` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(222), added: 2697, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:1 w:1)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[0, 16]`.
+	fn instant_unlock(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `228`
+		//  Estimated: `4764`
+		// Minimum execution time: 44_913_000 picoseconds.
+		Weight::from_parts(45_451_000, 4764)
+			// Standard Error: 2_471
+			.saturating_add(Weight::from_parts(131_317, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	/// Storage: `DappStaking::InstantUnlockPenalty` (r:0 w:1)
+	/// Proof: `DappStaking::InstantUnlockPenalty` (`max_values`: Some(1), `max_size`: Some(5), added: 500, mode: `MaxEncodedLen`)
+	fn set_instant_unlock_penalty() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 6_410_000 picoseconds.
+		Weight::from_parts(6_486_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `DappStaking::ContractStake` (r:16 w:0)
+	/// Proof: `DappStaking::ContractStake` (`max_values`: Some(65535), `max_size`: Some(91), added: 2071, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::DAppTierHistory` (r:16 w:16)
+	/// Proof: `DappStaking::DAppTierHistory` (`max_values`: Some(65535), `max_size`: Some(1208), added: 3188, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[0, 16]`.
+	fn update_dapp_tier_history(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0 + x * (412 ±0)`
+		//  Estimated: `990 + x * (3188 ±0)`
+		// Minimum execution time: 2_150_000 picoseconds.
+		Weight::from_parts(2_175_000, 990)
+			// Standard Error: 147_396
+			.saturating_add(Weight::from_parts(7_812_317, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 3188).saturating_mul(x.into()))
+	}
+	/// Storage: `DappStaking::StakerRewardDestination` (r:0 w:1)
+	/// Proof: `DappStaking::StakerRewardDestination` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	fn set_staker_reward_destination() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 9_312_000 picoseconds.
+		Weight::from_parts(9_423_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `DappStaking::StakeDelegates` (r:1 w:1)
+	/// Proof: `DappStaking::StakeDelegates` (`max_values`: None, `max_size`: Some(329), added: 2804, mode: `MaxEncodedLen`)
+	fn set_delegate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `327`
+		//  Estimated: `3794`
+		// Minimum execution time: 15_841_000 picoseconds.
+		Weight::from_parts(16_031_000, 3794)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `DappStaking::StakeDelegates` (r:1 w:1)
+	/// Proof: `DappStaking::StakeDelegates` (`max_values`: None, `max_size`: Some(329), added: 2804, mode: `MaxEncodedLen`)
+	fn revoke_delegate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `327`
+		//  Estimated: `3794`
+		// Minimum execution time: 15_027_000 picoseconds.
+		Weight::from_parts(15_207_000, 3794)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `DappStaking::IntegratedDApps` (r:16 w:0)
+	/// Proof: `DappStaking::IntegratedDApps` (`max_values`: Some(65535), `max_size`: Some(116), added: 2096, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::CarryOverPlans` (r:0 w:1)
+	/// Proof: `DappStaking::CarryOverPlans` (`max_values`: None, `max_size`: Some(838), added: 3313, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[1, 16]`.
+	fn set_carry_over_plan(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0 + x * (116 ±0)`
+		//  Estimated: `990 + x * (2096 ±0)`
+		// Minimum execution time: 10_214_000 picoseconds.
+		Weight::from_parts(10_336_000, 990)
+			// Standard Error: 74_849
+			.saturating_add(Weight::from_parts(3_967_317, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 2096).saturating_mul(x.into()))
+	}
+	/// Storage: `DappStaking::CarryOverPlans` (r:1 w:1)
+	/// Proof: `DappStaking::CarryOverPlans` (`max_values`: None, `max_size`: Some(838), added: 3313, mode: `MaxEncodedLen`)
+	fn remove_carry_over_plan() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `789`
+		//  Estimated: `4303`
+		// Minimum execution time: 14_622_000 picoseconds.
+		Weight::from_parts(14_797_000, 4303)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `DappStaking::IntegratedDApps` (r:1 w:0)
+	/// Proof: `DappStaking::IntegratedDApps` (`max_values`: Some(65535), `max_size`: Some(116), added: 2096, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::DAppMetadataOf` (r:1 w:1)
+	/// Proof: `DappStaking::DAppMetadataOf` (`max_values`: None, `max_size`: Some(488), added: 2963, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(211), added: 2686, mode: `MaxEncodedLen`)
+	fn set_dapp_metadata() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `590`
+		//  Estimated: `3953`
+		// Minimum execution time: 31_274_000 picoseconds.
+		Weight::from_parts(31_649_000, 3953)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `DappStaking::IntegratedDApps` (r:1 w:0)
+	/// Proof: `DappStaking::IntegratedDApps` (`max_values`: Some(65535), `max_size`: Some(116), added: 2096, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::DAppMetadataOf` (r:1 w:1)
+	/// Proof: `DappStaking::DAppMetadataOf` (`max_values`: None, `max_size`: Some(488), added: 2963, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(211), added: 2686, mode: `MaxEncodedLen`)
+	fn clear_dapp_metadata() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `590`
+		//  Estimated: `3953`
+		// Minimum execution time: 24_861_000 picoseconds.
+		Weight::from_parts(25_159_000, 3953)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `DappStaking::ActiveProtocolState` (r:1 w:0)
+	/// Proof: `DappStaking::ActiveProtocolState` (`max_values`: Some(1), `max_size`: Some(78), added: 573, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::ScheduledTierParams` (r:0 w:1)
+	/// Proof: `DappStaking::ScheduledTierParams` (`max_values`: Some(1), `max_size`: Some(91), added: 586, mode: `MaxEncodedLen`)
+	fn schedule_static_tier_params() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `76`
+		//  Estimated: `1563`
+		// Minimum execution time: 9_318_000 picoseconds.
+		Weight::from_parts(9_429_000, 1563)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `DappStaking::ScheduledTierParams` (r:1 w:1)
+	/// Proof: `DappStaking::ScheduledTierParams` (`max_values`: Some(1), `max_size`: Some(91), added: 586, mode: `MaxEncodedLen`)
+	fn cancel_scheduled_tier_params() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `149`
+		//  Estimated: `1576`
+		// Minimum execution time: 8_736_000 picoseconds.
+		Weight::from_parts(8_840_000, 1576)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `DappStaking::StakerInfo` (r:16 w:16)
+	/// Proof: `DappStaking::StakerInfo` (`max_values`: None, `max_size`: Some(178), added: 2653, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::IntegratedDApps` (r:16 w:0)
+	/// Proof: `DappStaking::IntegratedDApps` (`max_values`: Some(65535), `max_size`: Some(116), added: 2096, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::Ledger` (r:1 w:1)
+	/// Proof: `DappStaking::Ledger` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::CurrentEraInfo` (r:1 w:1)
+	/// Proof: `DappStaking::CurrentEraInfo` (`max_values`: Some(1), `max_size`: Some(112), added: 607, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:1)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:0)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[1, 16]`.
+	fn unstake_from_all_unregistered(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `322 + x * (117 ±0)`
+		//  Estimated: `4764 + x * (2653 ±0)`
+		// Minimum execution time: 31_488_000 picoseconds.
+		Weight::from_parts(31_865_000, 4764)
+			// Standard Error: 268_603
+			.saturating_add(Weight::from_parts(14_236_317, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 2653).saturating_mul(x.into()))
+	}
+	/// Storage: `Assets::Asset` (r:1 w:0)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(222), added: 2697, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::LockReceiptsEnabled` (r:0 w:1)
+	/// Proof: `DappStaking::LockReceiptsEnabled` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	fn set_lock_receipts_enabled() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `277`
+		//  Estimated: `3687`
+		// Minimum execution time: 11_482_000 picoseconds.
+		Weight::from_parts(11_619_000, 3687)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `DappStaking::ActiveProtocolState` (r:1 w:0)
+	/// Proof: `DappStaking::ActiveProtocolState` (`max_values`: Some(1), `max_size`: Some(78), added: 573, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::Ledger` (r:2 w:2)
+	/// Proof: `DappStaking::Ledger` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:2 w:2)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:2 w:0)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::LockReceipts` (r:2 w:2)
+	/// Proof: `DappStaking::LockReceipts` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(222), added: 2697, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:2 w:2)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::StakerInfo` (r:0 w:16)
+	/// Proof: `DappStaking::StakerInfo` (`max_values`: None, `max_size`: Some(178), added: 2653, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[0, 16]`.
+	fn transfer_lock(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `612`
+		//  Estimated: `6196`
+		// Minimum execution time: 98_713_000 picoseconds.
+		Weight::from_parts(99_897_000, 6196)
+			// Standard Error: 22_773
+			.saturating_add(Weight::from_parts(1_207_317, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(14_u64))
+			.saturating_add(T::DbWeight::get().writes(11_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(x.into())))
+	}
 }
 
 // For backwards compatibility and tests
@@ -606,14 +1022,18 @@ impl WeightInfo for () {
 	/// Proof: `DappStaking::CounterForIntegratedDApps` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `DappStaking::ContractStake` (r:0 w:1)
 	/// Proof: `DappStaking::ContractStake` (`max_values`: Some(65535), `max_size`: Some(91), added: 2071, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::CounterForUnregisteredStakeSweeps` (r:1 w:1)
+	/// Proof: `DappStaking::CounterForUnregisteredStakeSweeps` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::UnregisteredStakeSweeps` (r:0 w:1)
+	/// Proof: `DappStaking::UnregisteredStakeSweeps` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
 	fn unregister() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `97`
 		//  Estimated: `3086`
 		// Minimum execution time: 14_595_000 picoseconds.
 		Weight::from_parts(14_935_000, 3086)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 	/// Storage: `DappStaking::Ledger` (r:1 w:1)
 	/// Proof: `DappStaking::Ledger` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
@@ -625,14 +1045,22 @@ impl WeightInfo for () {
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `DappStaking::CurrentEraInfo` (r:1 w:1)
 	/// Proof: `DappStaking::CurrentEraInfo` (`max_values`: Some(1), `max_size`: Some(112), added: 607, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::LockReceiptsEnabled` (r:1 w:0)
+	/// Proof: `DappStaking::LockReceiptsEnabled` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::LockReceipts` (r:1 w:1)
+	/// Proof: `DappStaking::LockReceipts` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(222), added: 2697, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:1 w:1)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
 	fn lock_new_account() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `138`
 		//  Estimated: `4764`
 		// Minimum execution time: 31_874_000 picoseconds.
 		Weight::from_parts(32_108_000, 4764)
-			.saturating_add(RocksDbWeight::get().reads(5_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
 	}
 	/// Storage: `DappStaking::Ledger` (r:1 w:1)
 	/// Proof: `DappStaking::Ledger` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
@@ -642,14 +1070,22 @@ impl WeightInfo for () {
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `DappStaking::CurrentEraInfo` (r:1 w:1)
 	/// Proof: `DappStaking::CurrentEraInfo` (`max_values`: Some(1), `max_size`: Some(112), added: 607, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::LockReceiptsEnabled` (r:1 w:0)
+	/// Proof: `DappStaking::LockReceiptsEnabled` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::LockReceipts` (r:1 w:1)
+	/// Proof: `DappStaking::LockReceipts` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(222), added: 2697, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:1 w:1)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
 	fn lock_existing_account() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `158`
 		//  Estimated: `4764`
 		// Minimum execution time: 32_204_000 picoseconds.
 		Weight::from_parts(32_658_000, 4764)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
 	}
 	/// Storage: `DappStaking::Ledger` (r:1 w:1)
 	/// Proof: `DappStaking::Ledger` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
@@ -659,14 +1095,20 @@ impl WeightInfo for () {
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `DappStaking::CurrentEraInfo` (r:1 w:1)
 	/// Proof: `DappStaking::CurrentEraInfo` (`max_values`: Some(1), `max_size`: Some(112), added: 607, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::LockReceipts` (r:1 w:1)
+	/// Proof: `DappStaking::LockReceipts` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(222), added: 2697, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:1 w:1)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
 	fn unlock() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `158`
 		//  Estimated: `4764`
 		// Minimum execution time: 28_967_000 picoseconds.
 		Weight::from_parts(29_523_000, 4764)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
 	}
 	/// Storage: `DappStaking::Ledger` (r:1 w:1)
 	/// Proof: `DappStaking::Ledger` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
@@ -696,14 +1138,22 @@ impl WeightInfo for () {
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `DappStaking::CurrentEraInfo` (r:1 w:1)
 	/// Proof: `DappStaking::CurrentEraInfo` (`max_values`: Some(1), `max_size`: Some(112), added: 607, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::LockReceiptsEnabled` (r:1 w:0)
+	/// Proof: `DappStaking::LockReceiptsEnabled` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::LockReceipts` (r:1 w:1)
+	/// Proof: `DappStaking::LockReceipts` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(222), added: 2697, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:1 w:1)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
 	fn relock_unlocking() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `200`
 		//  Estimated: `4764`
 		// Minimum execution time: 27_384_000 picoseconds.
 		Weight::from_parts(27_620_000, 4764)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
 	}
 	/// Storage: `DappStaking::IntegratedDApps` (r:1 w:0)
 	/// Proof: `DappStaking::IntegratedDApps` (`max_values`: Some(65535), `max_size`: Some(116), added: 2096, mode: `MaxEncodedLen`)
@@ -761,6 +1211,8 @@ impl WeightInfo for () {
 	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:0)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::StakerRewardDestination` (r:1 w:0)
+	/// Proof: `DappStaking::StakerRewardDestination` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
 	/// The range of component `x` is `[1, 16]`.
 	fn claim_staker_rewards_past_period(x: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -770,7 +1222,7 @@ impl WeightInfo for () {
 		Weight::from_parts(50_088_222, 4764)
 			// Standard Error: 4_812
 			.saturating_add(Weight::from_parts(1_932_998, 0).saturating_mul(x.into()))
-			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: `DappStaking::Ledger` (r:1 w:1)
@@ -781,6 +1233,8 @@ impl WeightInfo for () {
 	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:0)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::StakerRewardDestination` (r:1 w:0)
+	/// Proof: `DappStaking::StakerRewardDestination` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
 	/// The range of component `x` is `[1, 16]`.
 	fn claim_staker_rewards_ongoing_period(x: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -790,7 +1244,7 @@ impl WeightInfo for () {
 		Weight::from_parts(47_718_494, 4764)
 			// Standard Error: 3_774
 			.saturating_add(Weight::from_parts(1_922_497, 0).saturating_mul(x.into()))
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: `DappStaking::StakerInfo` (r:1 w:1)
@@ -799,27 +1253,37 @@ impl WeightInfo for () {
 	/// Proof: `DappStaking::PeriodEnd` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
 	/// Storage: `DappStaking::Ledger` (r:1 w:1)
 	/// Proof: `DappStaking::Ledger` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::StakerRewardDestination` (r:1 w:0)
+	/// Proof: `DappStaking::StakerRewardDestination` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
 	fn claim_bonus_reward() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `275`
 		//  Estimated: `3775`
 		// Minimum execution time: 37_169_000 picoseconds.
 		Weight::from_parts(37_719_000, 3775)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: `DappStaking::IntegratedDApps` (r:1 w:0)
 	/// Proof: `DappStaking::IntegratedDApps` (`max_values`: Some(65535), `max_size`: Some(116), added: 2096, mode: `MaxEncodedLen`)
 	/// Storage: `DappStaking::DAppTiers` (r:1 w:1)
 	/// Proof: `DappStaking::DAppTiers` (`max_values`: None, `max_size`: Some(1648), added: 4123, mode: `MaxEncodedLen`)
-	fn claim_dapp_reward() -> Weight {
+	/// Storage: `System::Account` (r:5 w:5)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[0, 5]`.
+	fn claim_dapp_reward(x: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `2672`
-		//  Estimated: `5113`
+		//  Measured:  `2672 + x * (32 ±0)`
+		//  Estimated: `5113 + x * (2603 ±0)`
 		// Minimum execution time: 54_124_000 picoseconds.
-		Weight::from_parts(54_932_000, 5113)
+		Weight::from_parts(54_773_000, 5113)
+			// Standard Error: 177_698
+			.saturating_add(Weight::from_parts(9_418_317, 0).saturating_mul(x.into()))
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(x.into())))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(x.into()))
 	}
 	/// Storage: `DappStaking::IntegratedDApps` (r:1 w:0)
 	/// Proof: `DappStaking::IntegratedDApps` (`max_values`: Some(65535), `max_size`: Some(116), added: 2096, mode: `MaxEncodedLen`)
@@ -1028,4 +1492,351 @@ impl WeightInfo for () {
 		Weight::from_parts(7_452_000, 0)
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `DappStaking::AutoCompound` (r:0 w:1)
+	/// Proof: `DappStaking::AutoCompound` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	fn set_auto_compound() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 9_112_000 picoseconds.
+		Weight::from_parts(9_221_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `DappStaking::AutoCompound` (r:1 w:0)
+	/// Proof: `DappStaking::AutoCompound` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::Ledger` (r:1 w:1)
+	/// Proof: `DappStaking::Ledger` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:1)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:0)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::CurrentEraInfo` (r:1 w:1)
+	/// Proof: `DappStaking::CurrentEraInfo` (`max_values`: Some(1), `max_size`: Some(112), added: 607, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::StakerInfo` (r:17 w:16)
+	/// Proof: `DappStaking::StakerInfo` (`max_values`: None, `max_size`: Some(178), added: 2653, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::IntegratedDApps` (r:16 w:0)
+	/// Proof: `DappStaking::IntegratedDApps` (`max_values`: Some(65535), `max_size`: Some(116), added: 2096, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::ContractStake` (r:16 w:16)
+	/// Proof: `DappStaking::ContractStake` (`max_values`: Some(65535), `max_size`: Some(91), added: 2071, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[0, 16]`.
+	fn compound_reward(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `298 + x * (170 ±0)`
+		//  Estimated: `4764 + x * (2653 ±0)`
+		// Minimum execution time: 38_240_000 picoseconds.
+		Weight::from_parts(38_698_000, 4764)
+			// Standard Error: 469_264
+			.saturating_add(Weight::from_parts(24_871_317, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(x.into())))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 2653).saturating_mul(x.into()))
+	}
+	/// Storage: `DappStaking::IntegratedDApps` (r:1 w:1)
+	/// Proof: `DappStaking::IntegratedDApps` (`max_values`: Some(65535), `max_size`: Some(116), added: 2096, mode: `MaxEncodedLen`)
+	fn set_dapp_reward_splits() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `97`
+		//  Estimated: `3086`
+		// Minimum execution time: 13_614_000 picoseconds.
+		Weight::from_parts(13_777_000, 3086)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `DappStaking::IntegratedDApps` (r:1 w:0)
+	/// Proof: `DappStaking::IntegratedDApps` (`max_values`: Some(65535), `max_size`: Some(116), added: 2096, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::DAppTiers` (r:24 w:24)
+	/// Proof: `DappStaking::DAppTiers` (`max_values`: None, `max_size`: Some(195), added: 2670, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:6 w:6)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[1, 24]`.
+	fn claim_dapp_rewards(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `812 + x * (195 ±0)`
+		//  Estimated: `16278 + x * (2670 ±0)`
+		// Minimum execution time: 74_310_000 picoseconds.
+		Weight::from_parts(75_201_000, 16278)
+			// Standard Error: 115_622
+			.saturating_add(Weight::from_parts(6_128_317, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(x.into())))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 2670).saturating_mul(x.into()))
+	}
+	/// Storage: `DappStaking::AutoClaim` (r:0 w:1)
+	/// Proof: `DappStaking::AutoClaim` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	fn set_auto_claim() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 9_086_000 picoseconds.
+		Weight::from_parts(9_195_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `DappStaking::TierAssignmentState` (r:1 w:1)
+	/// Proof: `DappStaking::TierAssignmentState` (`max_values`: Some(1), `max_size`: Some(303), added: 798, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::CounterForIntegratedDApps` (r:1 w:0)
+	/// Proof: `DappStaking::CounterForIntegratedDApps` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::TierConfig` (r:1 w:0)
+	/// Proof: `DappStaking::TierConfig` (`max_values`: Some(1), `max_size`: Some(91), added: 586, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::ContractStake` (r:17 w:0)
+	/// Proof: `DappStaking::ContractStake` (`max_values`: Some(65535), `max_size`: Some(91), added: 2071, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[0, 16]`.
+	fn dapp_tier_assignment_page(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `213 + x * (32 ±0)`
+		//  Estimated: `3061 + x * (2071 ±0)`
+		// Minimum execution time: 11_270_000 picoseconds.
+		Weight::from_parts(11_405_000, 3061)
+			// Standard Error: 47_433
+			.saturating_add(Weight::from_parts(2_514_317, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(x.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 2071).saturating_mul(x.into()))
+	}
+	/// Storage: `DappStaking::InstantUnlockPenalty` (r:1 w:0)
+	/// Proof: `DappStaking::InstantUnlockPenalty` (`max_values`: Some(1), `max_size`: Some(5), added: 500, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::Ledger` (r:1 w:1)
+	/// Proof: `DappStaking::Ledger` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:1)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:0)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::CurrentEraInfo` (r:1 w:1)
+	/// Proof: `DappStaking::CurrentEraInfo` (`max_values`: Some(1), `max_size`: Some(112), added: 607, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::LockReceipts` (r:1 w:1)
+	/// Proof: `DappStaking::LockReceipts` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(222), added: 2697, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:1 w:1)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[0, 16]`.
+	fn instant_unlock(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `228`
+		//  Estimated: `4764`
+		// Minimum execution time: 44_913_000 picoseconds.
+		Weight::from_parts(45_451_000, 4764)
+			// Standard Error: 2_471
+			.saturating_add(Weight::from_parts(131_317, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	/// Storage: `DappStaking::InstantUnlockPenalty` (r:0 w:1)
+	/// Proof: `DappStaking::InstantUnlockPenalty` (`max_values`: Some(1), `max_size`: Some(5), added: 500, mode: `MaxEncodedLen`)
+	fn set_instant_unlock_penalty() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 6_410_000 picoseconds.
+		Weight::from_parts(6_486_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `DappStaking::ContractStake` (r:16 w:0)
+	/// Proof: `DappStaking::ContractStake` (`max_values`: Some(65535), `max_size`: Some(91), added: 2071, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::DAppTierHistory` (r:16 w:16)
+	/// Proof: `DappStaking::DAppTierHistory` (`max_values`: Some(65535), `max_size`: Some(1208), added: 3188, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[0, 16]`.
+	fn update_dapp_tier_history(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0 + x * (412 ±0)`
+		//  Estimated: `990 + x * (3188 ±0)`
+		// Minimum execution time: 2_150_000 picoseconds.
+		Weight::from_parts(2_175_000, 990)
+			// Standard Error: 147_396
+			.saturating_add(Weight::from_parts(7_812_317, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(x.into())))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 3188).saturating_mul(x.into()))
+	}
+	/// Storage: `DappStaking::StakerRewardDestination` (r:0 w:1)
+	/// Proof: `DappStaking::StakerRewardDestination` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	fn set_staker_reward_destination() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 9_312_000 picoseconds.
+		Weight::from_parts(9_423_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `DappStaking::StakeDelegates` (r:1 w:1)
+	/// Proof: `DappStaking::StakeDelegates` (`max_values`: None, `max_size`: Some(329), added: 2804, mode: `MaxEncodedLen`)
+	fn set_delegate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `327`
+		//  Estimated: `3794`
+		// Minimum execution time: 15_841_000 picoseconds.
+		Weight::from_parts(16_031_000, 3794)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `DappStaking::StakeDelegates` (r:1 w:1)
+	/// Proof: `DappStaking::StakeDelegates` (`max_values`: None, `max_size`: Some(329), added: 2804, mode: `MaxEncodedLen`)
+	fn revoke_delegate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `327`
+		//  Estimated: `3794`
+		// Minimum execution time: 15_027_000 picoseconds.
+		Weight::from_parts(15_207_000, 3794)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `DappStaking::IntegratedDApps` (r:16 w:0)
+	/// Proof: `DappStaking::IntegratedDApps` (`max_values`: Some(65535), `max_size`: Some(116), added: 2096, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::CarryOverPlans` (r:0 w:1)
+	/// Proof: `DappStaking::CarryOverPlans` (`max_values`: None, `max_size`: Some(838), added: 3313, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[1, 16]`.
+	fn set_carry_over_plan(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0 + x * (116 ±0)`
+		//  Estimated: `990 + x * (2096 ±0)`
+		// Minimum execution time: 10_214_000 picoseconds.
+		Weight::from_parts(10_336_000, 990)
+			// Standard Error: 74_849
+			.saturating_add(Weight::from_parts(3_967_317, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(x.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 2096).saturating_mul(x.into()))
+	}
+	/// Storage: `DappStaking::CarryOverPlans` (r:1 w:1)
+	/// Proof: `DappStaking::CarryOverPlans` (`max_values`: None, `max_size`: Some(838), added: 3313, mode: `MaxEncodedLen`)
+	fn remove_carry_over_plan() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `789`
+		//  Estimated: `4303`
+		// Minimum execution time: 14_622_000 picoseconds.
+		Weight::from_parts(14_797_000, 4303)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `DappStaking::IntegratedDApps` (r:1 w:0)
+	/// Proof: `DappStaking::IntegratedDApps` (`max_values`: Some(65535), `max_size`: Some(116), added: 2096, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::DAppMetadataOf` (r:1 w:1)
+	/// Proof: `DappStaking::DAppMetadataOf` (`max_values`: None, `max_size`: Some(488), added: 2963, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(211), added: 2686, mode: `MaxEncodedLen`)
+	fn set_dapp_metadata() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `590`
+		//  Estimated: `3953`
+		// Minimum execution time: 31_274_000 picoseconds.
+		Weight::from_parts(31_649_000, 3953)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `DappStaking::IntegratedDApps` (r:1 w:0)
+	/// Proof: `DappStaking::IntegratedDApps` (`max_values`: Some(65535), `max_size`: Some(116), added: 2096, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::DAppMetadataOf` (r:1 w:1)
+	/// Proof: `DappStaking::DAppMetadataOf` (`max_values`: None, `max_size`: Some(488), added: 2963, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(211), added: 2686, mode: `MaxEncodedLen`)
+	fn clear_dapp_metadata() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `590`
+		//  Estimated: `3953`
+		// Minimum execution time: 24_861_000 picoseconds.
+		Weight::from_parts(25_159_000, 3953)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `DappStaking::ActiveProtocolState` (r:1 w:0)
+	/// Proof: `DappStaking::ActiveProtocolState` (`max_values`: Some(1), `max_size`: Some(78), added: 573, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::ScheduledTierParams` (r:0 w:1)
+	/// Proof: `DappStaking::ScheduledTierParams` (`max_values`: Some(1), `max_size`: Some(91), added: 586, mode: `MaxEncodedLen`)
+	fn schedule_static_tier_params() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `76`
+		//  Estimated: `1563`
+		// Minimum execution time: 9_318_000 picoseconds.
+		Weight::from_parts(9_429_000, 1563)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `DappStaking::ScheduledTierParams` (r:1 w:1)
+	/// Proof: `DappStaking::ScheduledTierParams` (`max_values`: Some(1), `max_size`: Some(91), added: 586, mode: `MaxEncodedLen`)
+	fn cancel_scheduled_tier_params() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `149`
+		//  Estimated: `1576`
+		// Minimum execution time: 8_736_000 picoseconds.
+		Weight::from_parts(8_840_000, 1576)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `DappStaking::StakerInfo` (r:16 w:16)
+	/// Proof: `DappStaking::StakerInfo` (`max_values`: None, `max_size`: Some(178), added: 2653, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::IntegratedDApps` (r:16 w:0)
+	/// Proof: `DappStaking::IntegratedDApps` (`max_values`: Some(65535), `max_size`: Some(116), added: 2096, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::Ledger` (r:1 w:1)
+	/// Proof: `DappStaking::Ledger` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::CurrentEraInfo` (r:1 w:1)
+	/// Proof: `DappStaking::CurrentEraInfo` (`max_values`: Some(1), `max_size`: Some(112), added: 607, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:1)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:0)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[1, 16]`.
+	fn unstake_from_all_unregistered(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `322 + x * (117 ±0)`
+		//  Estimated: `4764 + x * (2653 ±0)`
+		// Minimum execution time: 31_488_000 picoseconds.
+		Weight::from_parts(31_865_000, 4764)
+			// Standard Error: 268_603
+			.saturating_add(Weight::from_parts(14_236_317, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(x.into())))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 2653).saturating_mul(x.into()))
+	}
+	/// Storage: `Assets::Asset` (r:1 w:0)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(222), added: 2697, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::LockReceiptsEnabled` (r:0 w:1)
+	/// Proof: `DappStaking::LockReceiptsEnabled` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	fn set_lock_receipts_enabled() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `277`
+		//  Estimated: `3687`
+		// Minimum execution time: 11_482_000 picoseconds.
+		Weight::from_parts(11_619_000, 3687)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `DappStaking::ActiveProtocolState` (r:1 w:0)
+	/// Proof: `DappStaking::ActiveProtocolState` (`max_values`: Some(1), `max_size`: Some(78), added: 573, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::Ledger` (r:2 w:2)
+	/// Proof: `DappStaking::Ledger` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:2 w:2)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:2 w:0)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::LockReceipts` (r:2 w:2)
+	/// Proof: `DappStaking::LockReceipts` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(222), added: 2697, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:2 w:2)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::StakerInfo` (r:0 w:16)
+	/// Proof: `DappStaking::StakerInfo` (`max_values`: None, `max_size`: Some(178), added: 2653, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[0, 16]`.
+	fn transfer_lock(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `612`
+		//  Estimated: `6196`
+		// Minimum execution time: 98_713_000 picoseconds.
+		Weight::from_parts(99_897_000, 6196)
+			// Standard Error: 22_773
+			.saturating_add(Weight::from_parts(1_207_317, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(14_u64))
+			.saturating_add(RocksDbWeight::get().writes(11_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(x.into())))
+	}
 }
@@ -0,0 +1,47 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use astar_primitives::{dapp_staking::InflationRewardProjection, Balance};
+use parity_scale_codec::Codec;
+use sp_arithmetic::Perquintill;
+
+sp_api::decl_runtime_apis! {
+
+    /// Inflation Api.
+    ///
+    /// Used to provide inflation configuration & reward projections, otherwise derived from raw storage.
+    pub trait InflationApi<InflationConfiguration, InflationParameters>
+    where
+        InflationConfiguration: Codec,
+        InflationParameters: Codec,
+    {
+        /// Currently active inflation configuration.
+        fn active_inflation_config() -> InflationConfiguration;
+
+        /// Inflation parameters, used to derive the inflation configuration.
+        fn inflation_params() -> InflationParameters;
+
+        /// Current decay factor, multiplied into rewards when they are paid out.
+        fn decay_factor() -> Perquintill;
+
+        /// Project reward pools & estimated staker APR for the given total value staked.
+        fn reward_projection(total_value_staked: Balance) -> InflationRewardProjection;
+    }
+}
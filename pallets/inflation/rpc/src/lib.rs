@@ -0,0 +1,156 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! RPC interface for the inflation pallet.
+//!
+//! Exposes the inflation configuration & reward projections, computed by the runtime via the `InflationApi`.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use jsonrpsee::{
+    core::RpcResult,
+    proc_macros::rpc,
+    types::error::{ErrorObject, ErrorObjectOwned},
+};
+use parity_scale_codec::Codec;
+use serde::Serialize;
+use sp_api::ProvideRuntimeApi;
+use sp_arithmetic::Perquintill;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Block as BlockT;
+
+use astar_primitives::{dapp_staking::InflationRewardProjection, Balance};
+
+pub use inflation_runtime_api::InflationApi as InflationRuntimeApi;
+
+/// Inflation RPC methods.
+#[rpc(server)]
+pub trait InflationApi<BlockHash, InflationConfiguration, InflationParameters> {
+    /// Currently active inflation configuration.
+    #[method(name = "inflation_activeConfig")]
+    fn active_config(&self, at: Option<BlockHash>) -> RpcResult<InflationConfiguration>;
+
+    /// Inflation parameters, used to derive the inflation configuration.
+    #[method(name = "inflation_params")]
+    fn params(&self, at: Option<BlockHash>) -> RpcResult<InflationParameters>;
+
+    /// Current decay factor, multiplied into rewards when they are paid out.
+    #[method(name = "inflation_decayFactor")]
+    fn decay_factor(&self, at: Option<BlockHash>) -> RpcResult<Perquintill>;
+
+    /// Reward pools & estimated staker APR, projected for the given total value staked.
+    #[method(name = "inflation_rewardProjection")]
+    fn reward_projection(
+        &self,
+        total_value_staked: Balance,
+        at: Option<BlockHash>,
+    ) -> RpcResult<InflationRewardProjection>;
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+    /// The call to runtime failed.
+    RuntimeError,
+}
+
+impl From<Error> for i32 {
+    fn from(e: Error) -> i32 {
+        match e {
+            Error::RuntimeError => 1,
+        }
+    }
+}
+
+/// Provides RPC methods to query inflation information.
+pub struct Inflation<C, B> {
+    client: Arc<C>,
+    _marker: PhantomData<B>,
+}
+
+impl<C, B> Inflation<C, B> {
+    /// Creates a new instance of the inflation RPC helper.
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            _marker: Default::default(),
+        }
+    }
+}
+
+/// Converts a runtime API error into an RPC error.
+fn runtime_error(error: impl ToString) -> ErrorObjectOwned {
+    ErrorObject::owned(
+        Error::RuntimeError.into(),
+        "Unable to query inflation information.",
+        Some(error.to_string()),
+    )
+}
+
+impl<C, Block, InflationConfiguration, InflationParameters>
+    InflationApiServer<<Block as BlockT>::Hash, InflationConfiguration, InflationParameters>
+    for Inflation<C, Block>
+where
+    Block: BlockT,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: InflationRuntimeApi<Block, InflationConfiguration, InflationParameters>,
+    InflationConfiguration: Codec + Serialize + Send + Sync + 'static,
+    InflationParameters: Codec + Serialize + Send + Sync + 'static,
+{
+    fn active_config(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<InflationConfiguration> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        self.client
+            .runtime_api()
+            .active_inflation_config(at)
+            .map_err(runtime_error)
+    }
+
+    fn params(&self, at: Option<<Block as BlockT>::Hash>) -> RpcResult<InflationParameters> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        self.client
+            .runtime_api()
+            .inflation_params(at)
+            .map_err(runtime_error)
+    }
+
+    fn decay_factor(&self, at: Option<<Block as BlockT>::Hash>) -> RpcResult<Perquintill> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        self.client
+            .runtime_api()
+            .decay_factor(at)
+            .map_err(runtime_error)
+    }
+
+    fn reward_projection(
+        &self,
+        total_value_staked: Balance,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<InflationRewardProjection> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        self.client
+            .runtime_api()
+            .reward_projection(at, total_value_staked)
+            .map_err(runtime_error)
+    }
+}
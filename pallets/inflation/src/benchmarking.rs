@@ -43,6 +43,7 @@ fn initial_config<T: Config>() {
         bonus_part: Perquintill::from_percent(12),
         ideal_staking_rate: Perquintill::from_percent(50),
         decay_rate: Perquintill::from_percent(99),
+        emission_curve: BuiltInEmissionCurve::MaxInflationRate,
     };
     assert!(params.is_valid());
 
@@ -0,0 +1,260 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! # Emission Curves
+//!
+//! Emission curve determines the maximum emission of an inflation cycle ('year').
+//! The max emission is later split between collators, treasury, dApps & stakers according to the inflation parameters.
+//!
+//! Built-in curves are:
+//! * [`MaxInflationRateCurve`] - emission is `max_inflation_rate * total_issuance`, recalculated each cycle.
+//! * [`FixedAnnualEmissionCurve`] - the same emission each cycle, regardless of the total issuance.
+//! * [`StepwiseHalvingCurve`] - emission is halved every `cycles_per_halving` cycles.
+//! * [`StakingRateResponsiveCurve`] - inflation rate goes down as staking rate approaches the target staking rate.
+//!
+//! The curve in use is selected via [`BuiltInEmissionCurve`], as part of the inflation parameters.
+
+use super::*;
+use sp_runtime::Saturating;
+
+/// Information about the inflation cycle for which the max emission is being calculated.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct EmissionContext {
+    /// First era of the cycle.
+    pub era: EraNumber,
+    /// Number of eras in a single cycle.
+    pub eras_per_cycle: EraNumber,
+    /// Total issuance at the moment of calculation.
+    pub total_issuance: Balance,
+    /// Total value staked at the moment of calculation.
+    pub total_value_staked: Balance,
+    /// Max inflation rate, as defined by the inflation parameters.
+    pub max_inflation_rate: Perquintill,
+}
+
+/// Emission curve, used to derive the max emission of an inflation cycle.
+pub trait EmissionCurve {
+    /// Max emission of the cycle described by the provided context.
+    fn max_emission(&self, context: &EmissionContext) -> Balance;
+
+    /// `true` if curve parameters are valid, `false` otherwise.
+    fn is_valid(&self) -> bool;
+}
+
+/// Emission is a fixed percentage of the total issuance, as defined by the `max_inflation_rate` inflation parameter.
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    MaxEncodedLen,
+    Default,
+    Copy,
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    TypeInfo,
+    Serialize,
+    Deserialize,
+)]
+pub struct MaxInflationRateCurve;
+
+impl EmissionCurve for MaxInflationRateCurve {
+    fn max_emission(&self, context: &EmissionContext) -> Balance {
+        context.max_inflation_rate * context.total_issuance
+    }
+
+    fn is_valid(&self) -> bool {
+        true
+    }
+}
+
+/// The same emission each cycle, regardless of the total issuance.
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    MaxEncodedLen,
+    Copy,
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    TypeInfo,
+    Serialize,
+    Deserialize,
+)]
+pub struct FixedAnnualEmissionCurve {
+    /// Emission per cycle.
+    #[codec(compact)]
+    pub emission: Balance,
+}
+
+impl EmissionCurve for FixedAnnualEmissionCurve {
+    fn max_emission(&self, _context: &EmissionContext) -> Balance {
+        self.emission
+    }
+
+    fn is_valid(&self) -> bool {
+        !self.emission.is_zero()
+    }
+}
+
+/// Emission is halved every `cycles_per_halving` cycles, counting from the `start_era`.
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    MaxEncodedLen,
+    Copy,
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    TypeInfo,
+    Serialize,
+    Deserialize,
+)]
+pub struct StepwiseHalvingCurve {
+    /// Emission per cycle, before the first halving.
+    #[codec(compact)]
+    pub initial_emission: Balance,
+    /// Era from which the cycles are counted.
+    #[codec(compact)]
+    pub start_era: EraNumber,
+    /// Number of cycles between two consecutive halvings.
+    #[codec(compact)]
+    pub cycles_per_halving: u32,
+}
+
+impl EmissionCurve for StepwiseHalvingCurve {
+    fn max_emission(&self, context: &EmissionContext) -> Balance {
+        let elapsed_cycles =
+            context.era.saturating_sub(self.start_era) / context.eras_per_cycle.max(1);
+        let halvings = elapsed_cycles / self.cycles_per_halving.max(1);
+
+        // Once all bits have been shifted out, there's nothing left to emit.
+        self.initial_emission
+            .checked_shr(halvings)
+            .unwrap_or_default()
+    }
+
+    fn is_valid(&self) -> bool {
+        !self.initial_emission.is_zero() && self.cycles_per_halving > 0
+    }
+}
+
+/// Inflation rate is adjusted based on the staking rate, according to formula:
+/// 'max_inflation_rate - (max_inflation_rate - min_inflation_rate) * min(1, staking_rate / target_staking_rate)'.
+///
+/// The less is staked, the higher the inflation rate, incentivizing stakers.
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    MaxEncodedLen,
+    Copy,
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    TypeInfo,
+    Serialize,
+    Deserialize,
+)]
+pub struct StakingRateResponsiveCurve {
+    /// Inflation rate once the target staking rate has been reached.
+    #[codec(compact)]
+    pub min_inflation_rate: Perquintill,
+    /// Inflation rate when nothing is staked.
+    #[codec(compact)]
+    pub max_inflation_rate: Perquintill,
+    /// Staking rate, in respect to total issuance, at which the inflation rate bottoms out.
+    #[codec(compact)]
+    pub target_staking_rate: Perquintill,
+}
+
+impl EmissionCurve for StakingRateResponsiveCurve {
+    fn max_emission(&self, context: &EmissionContext) -> Balance {
+        // (These operations are overflow & zero-division safe)
+        let staking_rate =
+            Perquintill::from_rational(context.total_value_staked, context.total_issuance);
+        let adjustment_factor = staking_rate / self.target_staking_rate;
+
+        let inflation_rate = self.max_inflation_rate.saturating_sub(
+            adjustment_factor
+                * self
+                    .max_inflation_rate
+                    .saturating_sub(self.min_inflation_rate),
+        );
+
+        inflation_rate * context.total_issuance
+    }
+
+    fn is_valid(&self) -> bool {
+        self.min_inflation_rate <= self.max_inflation_rate && !self.target_staking_rate.is_zero()
+    }
+}
+
+/// Emission curve selected via the inflation parameters.
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    MaxEncodedLen,
+    Default,
+    Copy,
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    TypeInfo,
+    Serialize,
+    Deserialize,
+)]
+pub enum BuiltInEmissionCurve {
+    /// Emission based on the `max_inflation_rate` inflation parameter.
+    #[default]
+    MaxInflationRate,
+    /// Fixed emission per cycle.
+    FixedAnnualEmission(FixedAnnualEmissionCurve),
+    /// Emission halved every few cycles.
+    StepwiseHalving(StepwiseHalvingCurve),
+    /// Emission responsive to the staking rate.
+    StakingRateResponsive(StakingRateResponsiveCurve),
+}
+
+impl EmissionCurve for BuiltInEmissionCurve {
+    fn max_emission(&self, context: &EmissionContext) -> Balance {
+        match self {
+            Self::MaxInflationRate => MaxInflationRateCurve.max_emission(context),
+            Self::FixedAnnualEmission(curve) => curve.max_emission(context),
+            Self::StepwiseHalving(curve) => curve.max_emission(context),
+            Self::StakingRateResponsive(curve) => curve.max_emission(context),
+        }
+    }
+
+    fn is_valid(&self) -> bool {
+        match self {
+            Self::MaxInflationRate => MaxInflationRateCurve.is_valid(),
+            Self::FixedAnnualEmission(curve) => curve.is_valid(),
+            Self::StepwiseHalving(curve) => curve.is_valid(),
+            Self::StakingRateResponsive(curve) => curve.is_valid(),
+        }
+    }
+}
@@ -63,7 +63,10 @@
 //! ## Recalculation
 //!
 //! When new cycle begins, inflation configuration is recalculated according to the inflation parameters & total issuance at that point in time.
-//! Based on the max inflation rate, rewards for different network actors are calculated.
+//! Max emission of the cycle is derived from the emission curve selected in the inflation parameters, e.g. the max inflation rate.
+//! Based on the max emission, rewards for different network actors are calculated.
+//!
+//! Built-in emission curves are described in the [`emission`] module. The curve can be changed via `force_set_inflation_params`.
 //!
 //! Some rewards are calculated to be paid out per block, while some are per era or per period.
 //!
@@ -93,6 +96,11 @@
 //!
 //! This pallet implements `StakingRewardHandler` trait, which is used by the dApp staking protocol to get reward pools & distribute rewards.
 //!
+//! ## Runtime API
+//!
+//! `InflationApi` exposes the active inflation configuration, inflation parameters & current decay factor.
+//! It can also project reward pools & estimated staker APR for any total value staked, via `reward_projection`.
+//!
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -100,7 +108,8 @@ pub use pallet::*;
 
 use astar_primitives::{
     dapp_staking::{
-        CycleConfiguration, EraNumber, Observer as DappStakingObserver, StakingRewardHandler,
+        CycleConfiguration, EraNumber, InflationRewardProjection, Observer as DappStakingObserver,
+        StakingRewardHandler,
     },
     Balance,
 };
@@ -116,13 +125,16 @@ use frame_system::{ensure_root, pallet_prelude::*};
 use serde::{Deserialize, Serialize};
 use sp_runtime::{
     traits::{CheckedAdd, Zero},
-    Perquintill,
+    FixedPointNumber, FixedU128, Perquintill,
 };
 use sp_std::marker::PhantomData;
 
 pub mod weights;
 pub use weights::WeightInfo;
 
+pub mod emission;
+pub use emission::*;
+
 #[cfg(any(feature = "runtime-benchmarks"))]
 pub mod benchmarking;
 
@@ -137,7 +149,7 @@ pub mod pallet {
     use super::*;
 
     /// The current storage version.
-    pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+    pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
 
     #[pallet::pallet]
     #[pallet::storage_version(STORAGE_VERSION)]
@@ -157,6 +169,9 @@ pub mod pallet {
         /// Cycle ('year') configuration - covers periods, subperiods, eras & blocks.
         type CycleConfiguration: CycleConfiguration;
 
+        /// Total value staked, used by emission curves which respond to the staking rate.
+        type TotalValueStaked: Get<Balance>;
+
         /// Weight information for extrinsics in this pallet.
         type WeightInfo: WeightInfo;
     }
@@ -176,6 +191,8 @@ pub mod pallet {
     pub enum Error<T> {
         /// Sum of all parts must be one whole (100%).
         InvalidInflationParameters,
+        /// Max emission of the cycle is either zero, or exceeds the total issuance.
+        InvalidMaxEmission,
     }
 
     /// Active inflation configuration parameters.
@@ -209,7 +226,8 @@ pub mod pallet {
 
             let starting_era = 1;
             let starting_decay_factor = Perquintill::one();
-            let config = Pallet::<T>::recalculate_inflation(starting_era, starting_decay_factor);
+            let config = Pallet::<T>::recalculate_inflation(starting_era, starting_decay_factor)
+                .expect("Emission curve must provide a valid max emission at genesis.");
 
             ActiveInflationConfig::<T>::put(config);
             InflationParams::<T>::put(self.params);
@@ -244,13 +262,25 @@ pub mod pallet {
             // If this was done in `on_initialize`, collator & treasury would receive incorrect rewards for that one block.
             //
             // This should be done as late as possible, to ensure all operations that modify issuance are done.
+            //
+            // In case the emission curve doesn't provide a valid max emission, the active configuration is kept,
+            // and recalculation is attempted again at the next era.
             if let Some(next_era) = DoRecalculation::<T>::get() {
                 let decay_factor = ActiveInflationConfig::<T>::get().decay_factor;
-                let config = Self::recalculate_inflation(next_era, decay_factor);
-                ActiveInflationConfig::<T>::put(config.clone());
+                match Self::recalculate_inflation(next_era, decay_factor) {
+                    Ok(config) => {
+                        ActiveInflationConfig::<T>::put(config.clone());
+                        Self::deposit_event(Event::<T>::NewInflationConfiguration { config });
+                    }
+                    Err(error) => {
+                        log::error!(
+                            "Inflation recalculation for era {} failed with {:?}, keeping the active configuration.",
+                            next_era,
+                            error
+                        );
+                    }
+                }
                 DoRecalculation::<T>::kill();
-
-                Self::deposit_event(Event::<T>::NewInflationConfiguration { config });
             }
 
             // NOTE: weight of the `on_finalize` logic with recalculation has to be covered by the observer notify call.
@@ -290,6 +320,7 @@ pub mod pallet {
 
         /// Used to force inflation recalculation.
         /// This is done in the same way as it would be done in an appropriate block, but this call forces it.
+        /// Fails if the emission curve provides a max emission which is zero, or exceeds the total issuance.
         ///
         /// Must be called by `root` origin.
         ///
@@ -303,7 +334,7 @@ pub mod pallet {
             ensure_root(origin)?;
 
             let decay_factor = ActiveInflationConfig::<T>::get().decay_factor;
-            let config = Self::recalculate_inflation(next_era, decay_factor);
+            let config = Self::recalculate_inflation(next_era, decay_factor)?;
             ActiveInflationConfig::<T>::put(config.clone());
 
             Self::deposit_event(Event::<T>::ForcedInflationRecalculation { config });
@@ -329,7 +360,7 @@ pub mod pallet {
         pub fn force_readjust_config(origin: OriginFor<T>) -> DispatchResult {
             ensure_root(origin)?;
 
-            let config = Self::readjusted_config();
+            let config = Self::readjusted_config()?;
             ActiveInflationConfig::<T>::put(config.clone());
 
             Self::deposit_event(Event::<T>::ForcedInflationRecalculation { config });
@@ -351,20 +382,25 @@ pub mod pallet {
             T::PayoutPerBlock::treasury(treasury_amount);
         }
 
-        /// Recalculates the inflation based on the current total issuance & inflation parameters.
+        /// Recalculates the inflation, with the max emission of the cycle decided by the selected emission curve.
         ///
-        /// Returns the new inflation configuration.
+        /// Returns the new inflation configuration, or an error if the max emission isn't valid.
         pub(crate) fn recalculate_inflation(
             next_era: EraNumber,
             decay_factor: Perquintill,
-        ) -> InflationConfiguration {
-            // Calculate max emission based on the current total issuance.
+        ) -> Result<InflationConfiguration, Error<T>> {
+            // Calculate max emission of the cycle, according to the selected emission curve.
             let params = InflationParams::<T>::get();
-            let total_issuance = T::Currency::total_issuance();
-            let max_emission = params.max_inflation_rate * total_issuance;
+            let eras_per_cycle = T::CycleConfiguration::eras_per_cycle();
+            let max_emission = params.emission_curve.max_emission(&EmissionContext {
+                era: next_era,
+                eras_per_cycle,
+                total_issuance: T::Currency::total_issuance(),
+                total_value_staked: T::TotalValueStaked::get(),
+                max_inflation_rate: params.max_inflation_rate,
+            });
 
-            let recalculation_era =
-                next_era.saturating_add(T::CycleConfiguration::eras_per_cycle());
+            let recalculation_era = next_era.saturating_add(eras_per_cycle);
 
             Self::new_config(recalculation_era, max_emission, decay_factor)
         }
@@ -376,7 +412,7 @@ pub mod pallet {
         /// (The 'force' approach uses the current total issuance)
         ///
         /// This call should be used in case inflation parameters have changed during the cycle, and the configuration should be adjusted now.
-        pub(crate) fn readjusted_config() -> InflationConfiguration {
+        pub(crate) fn readjusted_config() -> Result<InflationConfiguration, Error<T>> {
             // 1. First calculate the params needed to derive the `max_emission` value used to calculate the current inflation config.
             let config = ActiveInflationConfig::<T>::get();
 
@@ -423,11 +459,18 @@ pub mod pallet {
         }
 
         // Calculate new inflation configuration, based on the provided `max_emission`.
+        //
+        // Max emission must neither be zero, nor exceed the total issuance.
         fn new_config(
             recalculation_era: EraNumber,
             max_emission: Balance,
             decay_factor: Perquintill,
-        ) -> InflationConfiguration {
+        ) -> Result<InflationConfiguration, Error<T>> {
+            ensure!(
+                !max_emission.is_zero() && max_emission <= T::Currency::total_issuance(),
+                Error::<T>::InvalidMaxEmission
+            );
+
             let params = InflationParams::<T>::get();
 
             // Invalidated parameter, should be cleaned up in the future.
@@ -492,13 +535,13 @@ pub mod pallet {
             };
             new_inflation_config.sanity_check();
 
-            new_inflation_config
+            Ok(new_inflation_config)
         }
     }
 
-    impl<T: Config> DappStakingObserver for Pallet<T> {
+    impl<T: Config> Pallet<T> {
         /// Informs the pallet that the next block will be the first block of a new era.
-        fn block_before_new_era(new_era: EraNumber) -> Weight {
+        pub fn block_before_new_era(new_era: EraNumber) -> Weight {
             let config = ActiveInflationConfig::<T>::get();
             if config.recalculation_era <= new_era {
                 DoRecalculation::<T>::put(new_era);
@@ -509,6 +552,40 @@ pub mod pallet {
                 Weight::zero()
             }
         }
+
+        /// Projects the reward pools & estimated staker APR for the given total value staked.
+        ///
+        /// Projection is based on the active inflation configuration, current decay factor & total issuance.
+        pub fn reward_projection(total_value_staked: Balance) -> InflationRewardProjection {
+            let (staker_reward_pool_per_era, dapp_reward_pool_per_era) =
+                Self::staker_and_dapp_reward_pools(total_value_staked);
+            let bonus_reward_pool_per_period = Self::bonus_reward_pool();
+
+            // Staker rewards are paid out for each build&earn era of the cycle ('year').
+            let staker_rewards_per_cycle = staker_reward_pool_per_era.saturating_mul(
+                Balance::from(T::CycleConfiguration::build_and_earn_eras_per_cycle()),
+            );
+            let staker_apr = if total_value_staked.is_zero() {
+                FixedU128::zero()
+            } else {
+                FixedU128::saturating_from_rational(staker_rewards_per_cycle, total_value_staked)
+            };
+
+            InflationRewardProjection {
+                staker_reward_pool_per_era,
+                dapp_reward_pool_per_era,
+                bonus_reward_pool_per_period,
+                staker_apr,
+            }
+        }
+    }
+
+    impl<T: Config, AccountId, SmartContract> DappStakingObserver<AccountId, SmartContract>
+        for Pallet<T>
+    {
+        fn block_before_new_era(new_era: EraNumber) -> Weight {
+            Pallet::<T>::block_before_new_era(new_era)
+        }
     }
 
     impl<T: Config> StakingRewardHandler<T::AccountId> for Pallet<T> {
@@ -682,10 +759,13 @@ pub struct InflationParameters {
     /// A value of `Perquintill::one()` means no decay.
     #[codec(compact)]
     pub decay_rate: Perquintill,
+    /// Emission curve used to derive the max emission of each cycle.
+    #[serde(default)]
+    pub emission_curve: BuiltInEmissionCurve,
 }
 
 impl InflationParameters {
-    /// `true` if sum of all percentages is `one whole`, `false` otherwise.
+    /// `true` if sum of all percentages is `one whole` & the emission curve is valid, `false` otherwise.
     pub fn is_valid(&self) -> bool {
         let variables = [
             &self.treasury_part,
@@ -706,6 +786,7 @@ impl InflationParameters {
                 }
             })
             == Some(Perquintill::one())
+            && self.emission_curve.is_valid()
     }
 }
 
@@ -728,6 +809,8 @@ impl Default for InflationParameters {
 
             #[cfg(not(feature = "runtime-benchmarks"))]
             decay_rate: Perquintill::one(),
+
+            emission_curve: BuiltInEmissionCurve::MaxInflationRate,
         }
     }
 }
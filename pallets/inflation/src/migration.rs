@@ -30,38 +30,28 @@ use sp_runtime::TryRuntimeError;
 pub mod versioned_migrations {
     use super::*;
 
-    /// Migration V1 to V2 wrapped in a [`frame_support::migrations::VersionedMigration`], ensuring
-    /// the migration is only performed when on-chain version is 1.
-    pub type V1ToV2<T, DecayRate, DecayFactor> = frame_support::migrations::VersionedMigration<
-        1,
+    /// Migration V2 to V3 wrapped in a [`frame_support::migrations::VersionedMigration`], ensuring
+    /// the migration is only performed when on-chain version is 2.
+    pub type V2ToV3<T> = frame_support::migrations::VersionedMigration<
         2,
-        v2::VersionMigrateV1ToV2<T, DecayRate, DecayFactor>,
+        3,
+        v3::VersionMigrateV2ToV3<T>,
         Pallet<T>,
         <T as frame_system::Config>::DbWeight,
     >;
 }
 
-mod v2 {
+mod v3 {
     use super::*;
-    use crate::migration::v1::{
-        InflationConfiguration as InflationConfigurationV1,
-        InflationParameters as InflationParametersV1,
-    };
+    use crate::migration::v2::InflationParameters as InflationParametersV2;
 
-    pub struct VersionMigrateV1ToV2<T, DecayRate, DecayFactor>(
-        PhantomData<(T, DecayRate, DecayFactor)>,
-    );
+    pub struct VersionMigrateV2ToV3<T>(PhantomData<T>);
 
-    impl<T: Config, DecayRate: Get<Perquintill>, DecayFactor: Get<Perquintill>>
-        UncheckedOnRuntimeUpgrade for VersionMigrateV1ToV2<T, DecayRate, DecayFactor>
-    {
+    impl<T: Config> UncheckedOnRuntimeUpgrade for VersionMigrateV2ToV3<T> {
         fn on_runtime_upgrade() -> Weight {
-            let decay_rate = DecayRate::get();
-            let decay_factor = DecayFactor::get();
-
-            // Add the _decay_rate_ to the inflation params
+            // Add the _emission_curve_ to the inflation params, keeping the existing emission logic
             let result =
-                InflationParams::<T>::translate::<InflationParametersV1, _>(|maybe_old_params| {
+                InflationParams::<T>::translate::<InflationParametersV2, _>(|maybe_old_params| {
                     match maybe_old_params {
                         Some(old_params) => Some(InflationParameters {
                             max_inflation_rate: old_params.max_inflation_rate,
@@ -72,175 +62,96 @@ mod v2 {
                             adjustable_stakers_part: old_params.adjustable_stakers_part,
                             bonus_part: old_params.bonus_part,
                             ideal_staking_rate: old_params.ideal_staking_rate,
-                            decay_rate,
+                            decay_rate: old_params.decay_rate,
+                            emission_curve: BuiltInEmissionCurve::MaxInflationRate,
                         }),
                         _ => None,
                     }
                 });
 
             if result.is_err() {
-                log::error!("Failed to translate InflationParams from previous V1 type to current V2 type. Check InflationParametersV1 decoding.");
+                log::error!("Failed to translate InflationParams from previous V2 type to current V3 type. Check InflationParametersV2 decoding.");
                 return T::DbWeight::get().reads_writes(1, 0);
             }
 
-            // Add the _decay_rate_ and _decay_factor_ to the active config
-            let result = ActiveInflationConfig::<T>::translate::<InflationConfigurationV1, _>(
-                |maybe_old_config| match maybe_old_config {
-                    Some(old_config) => Some(InflationConfiguration {
-                        recalculation_era: old_config.recalculation_era,
-                        issuance_safety_cap: old_config.issuance_safety_cap,
-                        collator_reward_per_block: old_config.collator_reward_per_block,
-                        treasury_reward_per_block: old_config.treasury_reward_per_block,
-                        dapp_reward_pool_per_era: old_config.dapp_reward_pool_per_era,
-                        base_staker_reward_pool_per_era: old_config.base_staker_reward_pool_per_era,
-                        adjustable_staker_reward_pool_per_era: old_config
-                            .adjustable_staker_reward_pool_per_era,
-                        bonus_reward_pool_per_period: old_config.bonus_reward_pool_per_period,
-                        ideal_staking_rate: old_config.ideal_staking_rate,
-                        decay_rate,
-                        decay_factor,
-                    }),
-                    _ => None,
-                },
-            );
-
-            if result.is_err() {
-                log::error!("Failed to translate InflationConfiguration from previous V1 type to current V2 type. Check InflationConfigurationV1 decoding.");
-                return T::DbWeight::get().reads_writes(2, 1);
-            }
-
-            T::DbWeight::get().reads_writes(2, 2)
+            T::DbWeight::get().reads_writes(1, 1)
         }
 
         #[cfg(feature = "try-runtime")]
         fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
-            let old_config = v1::ActiveInflationConfig::<T>::get().ok_or_else(|| {
+            let old_params = v2::InflationParams::<T>::get().ok_or_else(|| {
                 TryRuntimeError::Other(
-                    "pallet-inflation::migration::v2: No old config found for ActiveInflationConfig",
+                    "pallet-inflation::migration::v3: No old params found for InflationParams",
                 )
             })?;
-
-            let old_params = v1::InflationParams::<T>::get().ok_or_else(|| {
-                TryRuntimeError::Other(
-                    "pallet-inflation::migration::v2: No old params found for InflationParams",
-                )
-            })?;
-            Ok((old_config, old_params).encode())
+            Ok(old_params.encode())
         }
 
         #[cfg(feature = "try-runtime")]
         fn post_upgrade(data: Vec<u8>) -> Result<(), TryRuntimeError> {
             // Decode the old values
-            let (old_config, old_params): (InflationConfigurationV1, InflationParametersV1) =
+            let old_params: InflationParametersV2 =
                 Decode::decode(&mut &data[..]).map_err(|_| {
                     TryRuntimeError::Other(
-                        "pallet-inflation::migration::v2: Failed to decode old values",
+                        "pallet-inflation::migration::v3: Failed to decode old values",
                     )
                 })?;
 
             // Get the new values
-            let new_config = ActiveInflationConfig::<T>::get();
             let new_params = InflationParams::<T>::get();
 
-            // Verify that new params and new config are valid
+            // Verify that new params are valid
             assert!(new_params.is_valid());
-            new_config.sanity_check();
-
-            // Verify active config remain unchanged
-            assert_eq!(
-                old_config.recalculation_era, new_config.recalculation_era,
-                "pallet-inflation::migration::v2: Recalculation Era has changed"
-            );
-            assert_eq!(
-                old_config.issuance_safety_cap, new_config.issuance_safety_cap,
-                "pallet-inflation::migration::v2: Issuance Safety Cap has changed"
-            );
-            assert_eq!(
-                old_config.collator_reward_per_block, new_config.collator_reward_per_block,
-                "pallet-inflation::migration::v2: Collator Reward Per Block has changed"
-            );
-            assert_eq!(
-                old_config.treasury_reward_per_block, new_config.treasury_reward_per_block,
-                "pallet-inflation::migration::v2: Treasury Reward Per Block has changed"
-            );
-            assert_eq!(
-                old_config.dapp_reward_pool_per_era, new_config.dapp_reward_pool_per_era,
-                "pallet-inflation::migration::v2: Dapp Reward Per Era has changed"
-            );
-            assert_eq!(
-                old_config.base_staker_reward_pool_per_era,
-                new_config.base_staker_reward_pool_per_era,
-                "pallet-inflation::migration::v2: Staker Reward Pool Per Era has changed"
-            );
-            assert_eq!(
-                old_config.adjustable_staker_reward_pool_per_era, new_config.adjustable_staker_reward_pool_per_era,
-                "pallet-inflation::migration::v2: Adjustable Staker Reward Pool Per Era has changed"
-            );
-            assert_eq!(
-                old_config.bonus_reward_pool_per_period, new_config.bonus_reward_pool_per_period,
-                "pallet-inflation::migration::v2: Bonus Reward Pool Per Period has changed"
-            );
-            assert_eq!(
-                old_config.ideal_staking_rate, new_config.ideal_staking_rate,
-                "pallet-inflation::migration::v2: Ideal staking rate has changed in config"
-            );
 
             // Verify parameters remain unchanged
             assert_eq!(
                 old_params.max_inflation_rate, new_params.max_inflation_rate,
-                "pallet-inflation::migration::v2: Max inflation rate has changed"
+                "pallet-inflation::migration::v3: Max inflation rate has changed"
             );
             assert_eq!(
                 old_params.treasury_part, new_params.treasury_part,
-                "pallet-inflation::migration::v2: Treasury part has changed"
+                "pallet-inflation::migration::v3: Treasury part has changed"
             );
             assert_eq!(
                 old_params.collators_part, new_params.collators_part,
-                "pallet-inflation::migration::v2: Collator part has changed"
+                "pallet-inflation::migration::v3: Collator part has changed"
             );
             assert_eq!(
                 old_params.dapps_part, new_params.dapps_part,
-                "pallet-inflation::migration::v2: Dapps part has changed"
+                "pallet-inflation::migration::v3: Dapps part has changed"
             );
             assert_eq!(
                 old_params.base_stakers_part, new_params.base_stakers_part,
-                "pallet-inflation::migration::v2: Base staker part has changed"
+                "pallet-inflation::migration::v3: Base staker part has changed"
             );
             assert_eq!(
                 old_params.adjustable_stakers_part, new_params.adjustable_stakers_part,
-                "pallet-inflation::migration::v2: Adjustable staker part has changed"
+                "pallet-inflation::migration::v3: Adjustable staker part has changed"
             );
             assert_eq!(
                 old_params.bonus_part, new_params.bonus_part,
-                "pallet-inflation::migration::v2: Bonus staker part has changed"
+                "pallet-inflation::migration::v3: Bonus staker part has changed"
             );
             assert_eq!(
                 old_params.ideal_staking_rate, new_params.ideal_staking_rate,
-                "pallet-inflation::migration::v2: Ideal staking rate has changed in params"
+                "pallet-inflation::migration::v3: Ideal staking rate has changed in params"
             );
-
-            // Verify correct decay rate is initialized
-            let expected_decay_rate = DecayRate::get();
             assert_eq!(
-                expected_decay_rate, new_params.decay_rate,
-                "pallet-inflation::migration::v2: No correct decay rate in params"
-            );
-            assert_eq!(
-                expected_decay_rate, new_config.decay_rate,
-                "pallet-inflation::migration::v2: No correct decay rate in config"
+                old_params.decay_rate, new_params.decay_rate,
+                "pallet-inflation::migration::v3: Decay rate has changed in params"
             );
 
-            // Verify correct decay factor is initialized
-            let expected_decay_factor = DecayFactor::get();
+            // Verify the emission curve preserves the previous emission logic
             assert_eq!(
-                expected_decay_factor, new_config.decay_factor,
-                "pallet-inflation::migration::v2: No correct decay factor in config"
+                new_params.emission_curve,
+                BuiltInEmissionCurve::MaxInflationRate,
+                "pallet-inflation::migration::v3: Emission curve isn't based on the max inflation rate"
             );
 
             // Verify storage version has been updated
             ensure!(
-                Pallet::<T>::on_chain_storage_version() >= 2,
-                "pallet-inflation::migration::v2: Wrong storage version."
+                Pallet::<T>::on_chain_storage_version() >= 3,
+                "pallet-inflation::migration::v3: Wrong storage version."
             );
 
             Ok(())
@@ -248,32 +159,10 @@ mod v2 {
     }
 }
 
-mod v1 {
+mod v2 {
     use super::*;
     use frame_support::storage_alias;
 
-    #[derive(Encode, Decode)]
-    pub struct InflationConfiguration {
-        #[codec(compact)]
-        pub recalculation_era: EraNumber,
-        #[codec(compact)]
-        pub issuance_safety_cap: Balance,
-        #[codec(compact)]
-        pub collator_reward_per_block: Balance,
-        #[codec(compact)]
-        pub treasury_reward_per_block: Balance,
-        #[codec(compact)]
-        pub dapp_reward_pool_per_era: Balance,
-        #[codec(compact)]
-        pub base_staker_reward_pool_per_era: Balance,
-        #[codec(compact)]
-        pub adjustable_staker_reward_pool_per_era: Balance,
-        #[codec(compact)]
-        pub bonus_reward_pool_per_period: Balance,
-        #[codec(compact)]
-        pub ideal_staking_rate: Perquintill,
-    }
-
     #[derive(Encode, Decode)]
     pub struct InflationParameters {
         #[codec(compact)]
@@ -292,13 +181,11 @@ mod v1 {
         pub bonus_part: Perquintill,
         #[codec(compact)]
         pub ideal_staking_rate: Perquintill,
+        #[codec(compact)]
+        pub decay_rate: Perquintill,
     }
 
-    /// v1 type for [`crate::ActiveInflationConfig`]
-    #[storage_alias]
-    pub type ActiveInflationConfig<T: Config> = StorageValue<Pallet<T>, InflationConfiguration>;
-
-    /// v1 type for [`crate::InflationParams`]
+    /// v2 type for [`crate::InflationParams`]
     #[storage_alias]
     pub type InflationParams<T: Config> = StorageValue<Pallet<T>, InflationParameters>;
 }
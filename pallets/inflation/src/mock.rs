@@ -17,8 +17,8 @@
 // along with Astar. If not, see <http://www.gnu.org/licenses/>.
 
 use crate::{
-    self as pallet_inflation, ActiveInflationConfig, CreditOf, CycleConfiguration,
-    InflationParameters, InflationParams, PayoutPerBlock,
+    self as pallet_inflation, ActiveInflationConfig, BuiltInEmissionCurve, CreditOf,
+    CycleConfiguration, InflationParameters, InflationParams, PayoutPerBlock,
 };
 
 use frame_support::{
@@ -43,12 +43,14 @@ pub const INIT_PARAMS: InflationParameters = InflationParameters {
     bonus_part: Perquintill::from_percent(12),
     ideal_staking_rate: Perquintill::from_percent(50),
     decay_rate: Perquintill::one(),
+    emission_curve: BuiltInEmissionCurve::MaxInflationRate,
 };
 
 type Block = frame_system::mocking::MockBlockU32<Test>;
 
 parameter_types! {
     pub const BlockHashCount: BlockNumber = 250;
+    pub static MockTotalValueStaked: Balance = 0;
     pub BlockWeights: frame_system::limits::BlockWeights =
         frame_system::limits::BlockWeights::simple_max(Weight::from_parts(1024, 0));
 }
@@ -105,6 +107,7 @@ impl pallet_inflation::Config for Test {
     type Currency = Balances;
     type PayoutPerBlock = DummyPayoutPerBlock;
     type CycleConfiguration = DummyCycleConfiguration;
+    type TotalValueStaked = MockTotalValueStaked;
     type WeightInfo = ();
 }
 
@@ -136,7 +139,8 @@ impl ExternalityBuilder {
         ext.execute_with(|| {
             // Set initial pallet inflation values
             InflationParams::<Test>::put(INIT_PARAMS);
-            let config = Inflation::recalculate_inflation(1, Perquintill::one());
+            let config = Inflation::recalculate_inflation(1, Perquintill::one())
+                .expect("Max emission must be valid.");
             ActiveInflationConfig::<Test>::put(config);
 
             System::set_block_number(1);
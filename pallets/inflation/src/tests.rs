@@ -20,7 +20,7 @@ use super::{pallet::Error, Event, *};
 use frame_support::{assert_noop, assert_ok, assert_storage_noop, traits::Hooks};
 use mock::*;
 use sp_runtime::{
-    traits::{AccountIdConversion, BadOrigin, Zero},
+    traits::{AccountIdConversion, BadOrigin, One, Zero},
     Perquintill, Saturating,
 };
 
@@ -270,6 +270,189 @@ fn inflation_parameters_validity_check_works() {
     params.base_stakers_part = params.base_stakers_part + params.adjustable_stakers_part;
     params.adjustable_stakers_part = Zero::zero();
     assert!(params.is_valid());
+
+    // Invalid emission curve, it should invalidate the whole config
+    let mut params = base_params;
+    params.emission_curve =
+        BuiltInEmissionCurve::FixedAnnualEmission(FixedAnnualEmissionCurve { emission: 0 });
+    assert!(!params.is_valid(), "Emission curve is invalid, must fail.");
+}
+
+#[test]
+fn emission_curves_are_ok() {
+    let context = EmissionContext {
+        era: 1,
+        eras_per_cycle: 10,
+        total_issuance: 1_000_000,
+        total_value_staked: 250_000,
+        max_inflation_rate: Perquintill::from_percent(7),
+    };
+
+    // Max inflation rate curve
+    assert!(MaxInflationRateCurve.is_valid());
+    assert_eq!(MaxInflationRateCurve.max_emission(&context), 70_000);
+
+    // Fixed annual emission curve
+    let curve = FixedAnnualEmissionCurve { emission: 12_345 };
+    assert!(curve.is_valid());
+    assert_eq!(curve.max_emission(&context), 12_345);
+    assert_eq!(
+        curve.max_emission(&EmissionContext {
+            total_issuance: 1,
+            ..context
+        }),
+        12_345,
+        "Total issuance must not matter."
+    );
+    assert!(!FixedAnnualEmissionCurve { emission: 0 }.is_valid());
+
+    // Stepwise halving curve
+    let curve = StepwiseHalvingCurve {
+        initial_emission: 80_000,
+        start_era: 11,
+        cycles_per_halving: 2,
+    };
+    assert!(curve.is_valid());
+    for (era, expected_emission) in [
+        (1, 80_000),
+        (11, 80_000),
+        (30, 80_000),
+        (31, 40_000),
+        (51, 20_000),
+        (u32::MAX, 0),
+    ] {
+        assert_eq!(
+            curve.max_emission(&EmissionContext { era, ..context }),
+            expected_emission,
+            "Unexpected emission for era {era}."
+        );
+    }
+    assert!(!StepwiseHalvingCurve {
+        cycles_per_halving: 0,
+        ..curve
+    }
+    .is_valid());
+
+    // Staking rate responsive curve
+    let curve = StakingRateResponsiveCurve {
+        min_inflation_rate: Perquintill::from_percent(2),
+        max_inflation_rate: Perquintill::from_percent(10),
+        target_staking_rate: Perquintill::from_percent(50),
+    };
+    assert!(curve.is_valid());
+    for (total_value_staked, expected_emission) in [
+        (0, 100_000),
+        (250_000, 60_000),
+        (500_000, 20_000),
+        (1_000_000, 20_000),
+    ] {
+        assert_eq!(
+            curve.max_emission(&EmissionContext {
+                total_value_staked,
+                ..context
+            }),
+            expected_emission,
+            "Unexpected emission for total value staked {total_value_staked}."
+        );
+    }
+    assert!(!StakingRateResponsiveCurve {
+        min_inflation_rate: Perquintill::from_percent(11),
+        ..curve
+    }
+    .is_valid());
+    assert!(!StakingRateResponsiveCurve {
+        target_staking_rate: Zero::zero(),
+        ..curve
+    }
+    .is_valid());
+}
+
+#[test]
+fn inflation_recalculation_follows_emission_curve() {
+    ExternalityBuilder::build().execute_with(|| {
+        let blocks_per_cycle =
+            Balance::from(<Test as Config>::CycleConfiguration::blocks_per_cycle());
+
+        // Fixed annual emission, independent of the total issuance
+        let emission = 1_000_000_000;
+        let mut params = InflationParams::<Test>::get();
+        params.emission_curve =
+            BuiltInEmissionCurve::FixedAnnualEmission(FixedAnnualEmissionCurve { emission });
+        assert_ok!(Inflation::force_set_inflation_params(
+            RuntimeOrigin::root(),
+            params
+        ));
+
+        let config = Inflation::recalculate_inflation(1, Perquintill::one())
+            .expect("Max emission must be valid.");
+        assert_eq!(
+            config.collator_reward_per_block,
+            params.collators_part * emission / blocks_per_cycle
+        );
+
+        // Staking rate responsive emission, with the target staking rate exceeded
+        let total_issuance = Balances::total_issuance();
+        let min_inflation_rate = Perquintill::from_percent(3);
+        params.emission_curve =
+            BuiltInEmissionCurve::StakingRateResponsive(StakingRateResponsiveCurve {
+                min_inflation_rate,
+                max_inflation_rate: Perquintill::from_percent(9),
+                target_staking_rate: Perquintill::from_percent(40),
+            });
+        assert_ok!(Inflation::force_set_inflation_params(
+            RuntimeOrigin::root(),
+            params
+        ));
+        MockTotalValueStaked::set(total_issuance);
+
+        let config = Inflation::recalculate_inflation(1, Perquintill::one())
+            .expect("Max emission must be valid.");
+        assert_eq!(
+            config.collator_reward_per_block,
+            params.collators_part * (min_inflation_rate * total_issuance) / blocks_per_cycle
+        );
+    })
+}
+
+#[test]
+fn inflation_recalculation_rejects_invalid_max_emission() {
+    ExternalityBuilder::build().execute_with(|| {
+        let init_config = ActiveInflationConfig::<Test>::get();
+        let total_issuance = Balances::total_issuance();
+
+        // Max emission exceeds the total issuance
+        let mut params = InflationParams::<Test>::get();
+        params.emission_curve =
+            BuiltInEmissionCurve::FixedAnnualEmission(FixedAnnualEmissionCurve {
+                emission: total_issuance + 1,
+            });
+        assert_ok!(Inflation::force_set_inflation_params(
+            RuntimeOrigin::root(),
+            params
+        ));
+        assert_noop!(
+            Inflation::force_inflation_recalculation(RuntimeOrigin::root(), 100),
+            Error::<Test>::InvalidMaxEmission
+        );
+
+        // Max emission is zero
+        params.emission_curve = BuiltInEmissionCurve::MaxInflationRate;
+        params.max_inflation_rate = Perquintill::zero();
+        assert_ok!(Inflation::force_set_inflation_params(
+            RuntimeOrigin::root(),
+            params
+        ));
+        assert_noop!(
+            Inflation::force_inflation_recalculation(RuntimeOrigin::root(), 100),
+            Error::<Test>::InvalidMaxEmission
+        );
+
+        // Recalculation at the start of a new cycle keeps the active configuration
+        Inflation::block_before_new_era(init_config.recalculation_era);
+        Inflation::on_finalize(System::block_number());
+        assert_eq!(ActiveInflationConfig::<Test>::get(), init_config);
+        assert!(DoRecalculation::<Test>::get().is_none());
+    })
 }
 
 #[test]
@@ -281,7 +464,8 @@ fn inflation_recalculation_works() {
 
         // Calculate new config
         let decay_factor = Perquintill::one();
-        let new_config = Inflation::recalculate_inflation(now, decay_factor);
+        let new_config = Inflation::recalculate_inflation(now, decay_factor)
+            .expect("Max emission must be valid.");
         let max_emission = params.max_inflation_rate * total_issuance;
 
         // Verify basics are ok
@@ -437,6 +621,70 @@ fn bonus_reward_pool_is_ok() {
     })
 }
 
+#[test]
+fn reward_projection_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        let total_issuance = Balances::total_issuance();
+        let build_and_earn_eras_per_cycle =
+            Balance::from(<Test as Config>::CycleConfiguration::build_and_earn_eras_per_cycle());
+
+        // Pools must match the ones provided to dApp staking, and APR is derived from the staker pool
+        let total_value_staked = Perquintill::from_percent(30) * total_issuance;
+        let (staker_pool, dapp_pool) = Inflation::staker_and_dapp_reward_pools(total_value_staked);
+        let projection = Inflation::reward_projection(total_value_staked);
+
+        assert_eq!(projection.staker_reward_pool_per_era, staker_pool);
+        assert_eq!(projection.dapp_reward_pool_per_era, dapp_pool);
+        assert_eq!(
+            projection.bonus_reward_pool_per_period,
+            Inflation::bonus_reward_pool()
+        );
+        assert_eq!(
+            projection.staker_apr,
+            FixedU128::saturating_from_rational(
+                staker_pool * build_and_earn_eras_per_cycle,
+                total_value_staked
+            )
+        );
+
+        // Decay factor is applied to the projected pools
+        ActiveInflationConfig::<Test>::mutate(|config| {
+            config.decay_factor = Perquintill::from_percent(50);
+        });
+        let decayed_projection = Inflation::reward_projection(total_value_staked);
+        assert!(
+            decayed_projection.staker_reward_pool_per_era < projection.staker_reward_pool_per_era
+        );
+        assert!(decayed_projection.staker_apr < projection.staker_apr);
+
+        // No staked value means no APR, instead of an infinite one
+        assert!(Inflation::reward_projection(Zero::zero())
+            .staker_apr
+            .is_zero());
+    })
+}
+
+#[test]
+fn reward_projection_apr_can_exceed_one_hundred_percent() {
+    ExternalityBuilder::build().execute_with(|| {
+        let build_and_earn_eras_per_cycle =
+            Balance::from(<Test as Config>::CycleConfiguration::build_and_earn_eras_per_cycle());
+
+        // Small total value staked, compared to the staker rewards paid out during the cycle
+        let total_value_staked = 1_000;
+        let (staker_pool, _) = Inflation::staker_and_dapp_reward_pools(total_value_staked);
+        let staker_rewards_per_cycle = staker_pool * build_and_earn_eras_per_cycle;
+        assert!(staker_rewards_per_cycle > total_value_staked);
+
+        let projection = Inflation::reward_projection(total_value_staked);
+        assert!(projection.staker_apr > FixedU128::one());
+        assert_eq!(
+            Some(projection.staker_apr),
+            FixedU128::checked_from_rational(staker_rewards_per_cycle, total_value_staked)
+        );
+    })
+}
+
 #[test]
 fn basic_payout_reward_is_ok() {
     ExternalityBuilder::build().execute_with(|| {
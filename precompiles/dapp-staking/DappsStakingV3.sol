@@ -35,6 +35,184 @@ interface DAppStaking {
         bytes contract_address;
     }
 
+    /// @notice Tier assignment of a dApp in a particular era.
+    /// @param era: Era in which the dApp was assigned to the tier.
+    /// @param tier: Tier the dApp was assigned to.
+    /// @param rank: Rank of the dApp within the tier.
+    /// @param stake: Total amount staked on the dApp, used for the tier assignment.
+    /// @param reward: Reward the dApp earned in the era.
+    struct TierHistoryEntry {
+        uint32 era;
+        uint8 tier;
+        uint8 rank;
+        uint128 stake;
+        uint128 reward;
+    }
+
+    /// @notice Metadata of a dApp, as set by the dApp owner.
+    /// @param name: Human readable name of the dApp.
+    /// @param url: URL of the dApp's website.
+    /// @param logo_cid: IPFS CID of the dApp's logo.
+    /// @param category: Category of the dApp (0: Other, 1: DeFi, 2: NFT, 3: Gaming, 4: Social, 5: Tooling).
+    /// @param deposit: Deposit held for storing the metadata.
+    struct DAppMetadata {
+        bytes name;
+        bytes url;
+        bytes logo_cid;
+        uint8 category;
+        uint128 deposit;
+    }
+
+    /// @notice Chunk of tokens undergoing the unlocking period.
+    /// @param amount: Amount of tokens undergoing the unlocking period.
+    /// @param unlock_block: Block in which the unlocking period is finished for this chunk.
+    struct UnlockingChunk {
+        uint128 amount;
+        uint256 unlock_block;
+    }
+
+    /// @notice Describes the account ledger. Stakes from past periods are reported as zero.
+    /// @param locked: Amount of active locked tokens, which can be used for staking.
+    /// @param unlocking: Chunks of tokens undergoing the unlocking period.
+    /// @param voting_stake: Amount staked during the voting subperiod of the ongoing period.
+    /// @param build_and_earn_stake: Amount staked during the build&earn subperiod of the ongoing period.
+    struct AccountLedger {
+        uint128 locked;
+        UnlockingChunk[] unlocking;
+        uint128 voting_stake;
+        uint128 build_and_earn_stake;
+    }
+
+    /// @notice Describes the stake of a staker on a smart contract. Stake from past periods is reported as zero.
+    /// @param voting_stake: Amount staked during the voting subperiod of the ongoing period.
+    /// @param build_and_earn_stake: Amount staked during the build&earn subperiod of the ongoing period.
+    /// @param bonus_eligible: Whether the stake is eligible for the bonus reward.
+    struct StakerInfo {
+        uint128 voting_stake;
+        uint128 build_and_earn_stake;
+        bool bonus_eligible;
+    }
+
+    /// @notice Describes the total stake on a smart contract. Stake from past periods is reported as zero.
+    /// @param voting_stake: Amount staked during the voting subperiod of the ongoing period.
+    /// @param build_and_earn_stake: Amount staked during the build&earn subperiod of the ongoing period.
+    struct ContractStake {
+        uint128 voting_stake;
+        uint128 build_and_earn_stake;
+    }
+
+    /// @notice Unclaimed tier assignment of a dApp in a particular era.
+    /// @param assigned: Whether the dApp has an unclaimed tier assignment in the era.
+    /// @param tier: Tier the dApp was assigned to.
+    /// @param rank: Rank of the dApp within the tier.
+    /// @param reward: Reward the dApp earned in the era.
+    struct TierAssignment {
+        bool assigned;
+        uint8 tier;
+        uint8 rank;
+        uint128 reward;
+    }
+
+    /// @notice Tier configuration used for the ongoing era.
+    /// @param slots_per_tier: Number of slots per tier.
+    /// @param reward_portion: Reward portion per tier, in parts per million.
+    /// @param tier_thresholds: Amount required to enter each tier.
+    struct TierConfig {
+        uint16[] slots_per_tier;
+        uint32[] reward_portion;
+        uint128[] tier_thresholds;
+    }
+
+    /// @notice Requirement for entry into a tier.
+    /// @param threshold_type: Type of the threshold (0: FixedPercentage, 1: StakedPercentage, 2: DynamicPercentage).
+    /// @param percentage: Required percentage, in parts per billion.
+    /// @param minimum_percentage: Minimum required percentage of total issuance, in parts per billion. Only used by DynamicPercentage.
+    /// @param maximum_percentage: Maximum possible percentage of total issuance, in parts per billion. Only used by DynamicPercentage.
+    struct TierThreshold {
+        uint8 threshold_type;
+        uint32 percentage;
+        uint32 minimum_percentage;
+        uint32 maximum_percentage;
+    }
+
+    /// @notice Static tier parameters, used to calculate the tier configuration.
+    /// @param reward_portion: Reward portion per tier, in parts per million.
+    /// @param slot_distribution: Slot distribution per tier, in parts per million.
+    /// @param tier_thresholds: Requirement for entry into each tier.
+    /// @param tier_rank_multipliers: Rank multiplier per tier, in bips.
+    struct TierParameters {
+        uint32[] reward_portion;
+        uint32[] slot_distribution;
+        TierThreshold[] tier_thresholds;
+        uint32[] tier_rank_multipliers;
+    }
+
+    // Events
+
+    /// @notice Emitted when the caller locks tokens into dApp staking protocol.
+    /// @param account: The caller account.
+    /// @param amount: The amount of tokens which were locked.
+    event Locked(address indexed account, uint128 amount);
+
+    /// @notice Emitted when the caller starts unlocking tokens.
+    /// @param account: The caller account.
+    /// @param amount: The amount of tokens which started unlocking.
+    event Unlocking(address indexed account, uint128 amount);
+
+    /// @notice Emitted when the caller claims unlocked tokens.
+    /// @param account: The caller account.
+    /// @param amount: The amount of tokens which were claimed.
+    event ClaimedUnlocked(address indexed account, uint128 amount);
+
+    /// @notice Emitted when the caller instantly unlocks tokens.
+    /// @param account: The caller account.
+    /// @param amount: The amount of tokens which were unlocked.
+    /// @param penalty: The penalty charged on the unlocked amount.
+    event InstantUnlocked(address indexed account, uint128 amount, uint128 penalty);
+
+    /// @notice Emitted when the caller stakes tokens on a smart contract.
+    /// @param account: The caller account.
+    /// @param smart_contract: The smart contract which was staked on.
+    /// @param amount: The amount of tokens which were staked.
+    event Staked(address indexed account, SmartContract smart_contract, uint128 amount);
+
+    /// @notice Emitted when the caller unstakes tokens from a smart contract.
+    /// @param account: The caller account.
+    /// @param smart_contract: The smart contract which was unstaked from.
+    /// @param amount: The amount of tokens which were unstaked.
+    event Unstaked(address indexed account, SmartContract smart_contract, uint128 amount);
+
+    /// @notice Emitted when the caller moves stake between smart contracts.
+    /// @param account: The caller account.
+    /// @param source_contract: The smart contract from which the stake was moved.
+    /// @param destination_contract: The smart contract to which the stake was moved.
+    /// @param amount: The amount of tokens which were moved.
+    event StakeMoved(address indexed account, SmartContract source_contract, SmartContract destination_contract, uint128 amount);
+
+    /// @notice Emitted when the caller claims staker rewards.
+    /// @param account: The caller account.
+    /// @param amount: The total amount of rewards paid out to the caller's reward destination.
+    event RewardClaimed(address indexed account, uint128 amount);
+
+    /// @notice Emitted when the caller claims a bonus reward.
+    /// @param account: The caller account.
+    /// @param smart_contract: The smart contract for which the bonus reward was claimed.
+    /// @param amount: The amount of reward paid out to the caller's reward destination.
+    event BonusRewardClaimed(address indexed account, SmartContract smart_contract, uint128 amount);
+
+    /// @notice Emitted when the caller claims a dApp reward.
+    /// @param account: The caller account.
+    /// @param smart_contract: The smart contract for which the dApp reward was claimed.
+    /// @param era: The era for which the dApp reward was claimed.
+    /// @param amount: The amount of reward paid out to the dApp's beneficiaries.
+    event DAppRewardClaimed(address indexed account, SmartContract smart_contract, uint256 era, uint128 amount);
+
+    /// @notice Emitted when the caller unstakes everything from an unregistered smart contract.
+    /// @param account: The caller account.
+    /// @param smart_contract: The unregistered smart contract which was unstaked from.
+    /// @param amount: The amount of tokens which were unstaked.
+    event UnstakedFromUnregistered(address indexed account, SmartContract smart_contract, uint128 amount);
+
     // Storage getters
 
     /// @notice Get the current protocol state.
@@ -45,6 +223,47 @@ interface DAppStaking {
     /// @return period: The unlocking period expressed in the number of blocks.
     function unlocking_period() external view returns (uint256);
 
+    /// @notice Get the tier assignment history of the specified smart contract.
+    /// @param smart_contract: The smart contract for which the history is read.
+    /// @return history: Tier assignment history, from the oldest to the latest entry.
+    function dapp_tier_history(SmartContract calldata smart_contract) external view returns (TierHistoryEntry[] memory);
+
+    /// @notice Get the metadata of the specified smart contract.
+    /// @param smart_contract: The smart contract for which the metadata is read.
+    /// @return metadata: dApp metadata, with all fields empty if it hasn't been set.
+    function dapp_metadata(SmartContract calldata smart_contract) external view returns (DAppMetadata memory);
+
+    /// @notice Get the ledger of the specified account.
+    /// @param account: Account, either H160 (20 bytes) or SS58 (32 bytes) address.
+    /// @return ledger: Account ledger, empty if the account has never locked anything.
+    function account_ledger(bytes calldata account) external view returns (AccountLedger memory);
+
+    /// @notice Get the stake of the specified staker on the specified smart contract.
+    /// @param staker: Staker account, either H160 (20 bytes) or SS58 (32 bytes) address.
+    /// @param smart_contract: The smart contract for which the stake is read.
+    /// @return info: Staker's stake on the smart contract.
+    function staker_info(bytes calldata staker, SmartContract calldata smart_contract) external view returns (StakerInfo memory);
+
+    /// @notice Get the total stake on the specified smart contract.
+    /// @param smart_contract: The smart contract for which the stake is read.
+    /// @return stake: Total stake on the smart contract, zero if it isn't registered.
+    function contract_stake(SmartContract calldata smart_contract) external view returns (ContractStake memory);
+
+    /// @notice Get the unclaimed tier assignment of the specified smart contract in the specified era.
+    ///         Once the dApp reward is claimed, the assignment is only available via `dapp_tier_history`.
+    /// @param smart_contract: The smart contract for which the tier assignment is read.
+    /// @param era: The era for which the tier assignment is read.
+    /// @return assignment: Tier assignment of the smart contract.
+    function dapp_tier_assignment(SmartContract calldata smart_contract, uint256 era) external view returns (TierAssignment memory);
+
+    /// @notice Get the tier configuration used for the ongoing era.
+    /// @return config: Tier configuration.
+    function tier_config() external view returns (TierConfig memory);
+
+    /// @notice Get the static tier parameters, used to calculate the tier configuration.
+    /// @return params: Static tier parameters.
+    function static_tier_params() external view returns (TierParameters memory);
+
 
     // Extrinsic calls
 
@@ -59,6 +278,11 @@ interface DAppStaking {
     /// @notice Claims unlocked tokens, if there are any
     function claim_unlocked() external returns (bool);
 
+    /// @notice Instantly unlocks the given amount of tokens, skipping the unlocking period.
+    ///         Pending unlocking chunks are consumed first, and a penalty is charged on the unlocked amount.
+    /// @param amount: The amount of tokens to be instantly unlocked.
+    function instant_unlock(uint128 amount) external returns (bool);
+
     /// @notice Stake the given amount of tokens on the specified smart contract.
     ///         The amount specified must be precise, otherwise the call will fail.
     /// @param smart_contract: The smart contract to be staked on.
@@ -96,4 +320,22 @@ interface DAppStaking {
 
     /// @notice Used to cleanup all expired contract stake entries from the caller.
     function cleanup_expired_entries() external returns (bool);
+
+    /// @notice Set the account to which the caller's staker & bonus rewards are paid out.
+    /// @param destination: Destination account, either H160 (20 bytes) or SS58 (32 bytes) address.
+    ///                     Empty value means rewards are paid out to the caller.
+    function set_staker_reward_destination(bytes calldata destination) external returns (bool);
+
+    /// @notice Set the account to which the rewards of the specified smart contract are paid out.
+    ///         Caller has to be the dApp owner.
+    /// @param smart_contract: The smart contract for which the beneficiary is set.
+    /// @param beneficiary: Beneficiary account, either H160 (20 bytes) or SS58 (32 bytes) address.
+    ///                     Empty value means rewards are paid out to the dApp owner.
+    function set_dapp_reward_beneficiary(SmartContract calldata smart_contract, bytes calldata beneficiary) external returns (bool);
+
+    /// @notice Transfer the ownership of the specified smart contract to the new owner.
+    ///         Caller has to be the dApp owner.
+    /// @param smart_contract: The smart contract for which the owner is changed.
+    /// @param new_owner: New owner account, either H160 (20 bytes) or SS58 (32 bytes) address.
+    function set_dapp_owner(SmartContract calldata smart_contract, bytes calldata new_owner) external returns (bool);
 }
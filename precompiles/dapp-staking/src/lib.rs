@@ -28,7 +28,7 @@ use parity_scale_codec::MaxEncodedLen;
 use frame_support::{
     dispatch::{GetDispatchInfo, PostDispatchInfo},
     ensure,
-    traits::{ConstU32, IsType},
+    traits::{fungible::Inspect as FunInspect, ConstU32, IsType},
 };
 
 use pallet_evm::AddressMapping;
@@ -40,15 +40,20 @@ use precompile_utils::{
     },
 };
 use sp_core::{Get, H160, U256};
-use sp_runtime::traits::{Dispatchable, Zero};
+use sp_runtime::{
+    traits::{Dispatchable, Zero},
+    Perbill,
+};
 use sp_std::{marker::PhantomData, prelude::*};
 extern crate alloc;
 
 use astar_primitives::{dapp_staking::SmartContractHandle, AccountId, Balance, BlockNumber};
 use pallet_dapp_staking::{
     AccountLedgerFor, ActiveProtocolState, ContractStake, ContractStakeAmount, CurrentEraInfo,
-    DAppInfoFor, EraInfo, EraRewardSpanFor, EraRewards, IntegratedDApps, Ledger,
-    Pallet as DAppStaking, ProtocolState, SingularStakingInfo, StakerInfo, Subperiod,
+    DAppInfoFor, DAppMetadataFor, DAppTierRewardsFor, DAppTiers, EraInfo, EraRewardSpanFor,
+    EraRewards, InstantUnlockPenalty, IntegratedDApps, Ledger, Pallet as DAppStaking,
+    ProtocolState, SingularStakingInfo, StakerInfo, StakerRewardDestination, StaticTierParams,
+    Subperiod, TierConfig, TierHistoryFor, TierParameters, TierThreshold, TiersConfiguration,
 };
 
 pub const STAKER_BYTES_LIMIT: u32 = 32;
@@ -59,6 +64,44 @@ pub type DynamicAddress = BoundedBytes<GetStakerBytesLimit>;
 #[cfg(test)]
 mod test;
 
+/// Solidity selector of the Locked log, which is the Keccak of the Log signature.
+pub const SELECTOR_LOG_LOCKED: [u8; 32] = keccak256!("Locked(address,uint128)");
+
+/// Solidity selector of the Unlocking log, which is the Keccak of the Log signature.
+pub const SELECTOR_LOG_UNLOCKING: [u8; 32] = keccak256!("Unlocking(address,uint128)");
+
+/// Solidity selector of the ClaimedUnlocked log, which is the Keccak of the Log signature.
+pub const SELECTOR_LOG_CLAIMED_UNLOCKED: [u8; 32] = keccak256!("ClaimedUnlocked(address,uint128)");
+
+/// Solidity selector of the InstantUnlocked log, which is the Keccak of the Log signature.
+pub const SELECTOR_LOG_INSTANT_UNLOCKED: [u8; 32] =
+    keccak256!("InstantUnlocked(address,uint128,uint128)");
+
+/// Solidity selector of the Staked log, which is the Keccak of the Log signature.
+pub const SELECTOR_LOG_STAKED: [u8; 32] = keccak256!("Staked(address,(uint8,bytes),uint128)");
+
+/// Solidity selector of the Unstaked log, which is the Keccak of the Log signature.
+pub const SELECTOR_LOG_UNSTAKED: [u8; 32] = keccak256!("Unstaked(address,(uint8,bytes),uint128)");
+
+/// Solidity selector of the StakeMoved log, which is the Keccak of the Log signature.
+pub const SELECTOR_LOG_STAKE_MOVED: [u8; 32] =
+    keccak256!("StakeMoved(address,(uint8,bytes),(uint8,bytes),uint128)");
+
+/// Solidity selector of the RewardClaimed log, which is the Keccak of the Log signature.
+pub const SELECTOR_LOG_REWARD_CLAIMED: [u8; 32] = keccak256!("RewardClaimed(address,uint128)");
+
+/// Solidity selector of the BonusRewardClaimed log, which is the Keccak of the Log signature.
+pub const SELECTOR_LOG_BONUS_REWARD_CLAIMED: [u8; 32] =
+    keccak256!("BonusRewardClaimed(address,(uint8,bytes),uint128)");
+
+/// Solidity selector of the DAppRewardClaimed log, which is the Keccak of the Log signature.
+pub const SELECTOR_LOG_DAPP_REWARD_CLAIMED: [u8; 32] =
+    keccak256!("DAppRewardClaimed(address,(uint8,bytes),uint256,uint128)");
+
+/// Solidity selector of the UnstakedFromUnregistered log, which is the Keccak of the Log signature.
+pub const SELECTOR_LOG_UNSTAKED_FROM_UNREGISTERED: [u8; 32] =
+    keccak256!("UnstakedFromUnregistered(address,(uint8,bytes),uint128)");
+
 /// Helper struct used to encode protocol state.
 #[derive(Debug, Clone, solidity::Codec)]
 pub(crate) struct PrecompileProtocolState {
@@ -67,6 +110,92 @@ pub(crate) struct PrecompileProtocolState {
     subperiod: u8,
 }
 
+/// Helper struct used to encode a single dApp tier history entry.
+#[derive(Debug, Clone, solidity::Codec)]
+pub(crate) struct PrecompileTierHistoryEntry {
+    era: u32,
+    tier: u8,
+    rank: u8,
+    stake: u128,
+    reward: u128,
+}
+
+/// Helper struct used to encode dApp metadata.
+#[derive(Debug, Clone, solidity::Codec)]
+pub(crate) struct PrecompileDAppMetadata {
+    name: UnboundedBytes,
+    url: UnboundedBytes,
+    logo_cid: UnboundedBytes,
+    category: u8,
+    deposit: u128,
+}
+
+/// Helper struct used to encode a single unlocking chunk.
+#[derive(Debug, Clone, solidity::Codec)]
+pub(crate) struct PrecompileUnlockingChunk {
+    amount: u128,
+    unlock_block: U256,
+}
+
+/// Helper struct used to encode account ledger.
+#[derive(Debug, Clone, solidity::Codec)]
+pub(crate) struct PrecompileAccountLedger {
+    locked: u128,
+    unlocking: Vec<PrecompileUnlockingChunk>,
+    voting_stake: u128,
+    build_and_earn_stake: u128,
+}
+
+/// Helper struct used to encode the stake of a single staker on a smart contract.
+#[derive(Debug, Clone, solidity::Codec)]
+pub(crate) struct PrecompileStakerInfo {
+    voting_stake: u128,
+    build_and_earn_stake: u128,
+    bonus_eligible: bool,
+}
+
+/// Helper struct used to encode the total stake on a smart contract.
+#[derive(Debug, Clone, solidity::Codec)]
+pub(crate) struct PrecompileContractStake {
+    voting_stake: u128,
+    build_and_earn_stake: u128,
+}
+
+/// Helper struct used to encode the tier assignment of a dApp in a particular era.
+#[derive(Debug, Clone, solidity::Codec)]
+pub(crate) struct PrecompileTierAssignment {
+    assigned: bool,
+    tier: u8,
+    rank: u8,
+    reward: u128,
+}
+
+/// Helper struct used to encode tier configuration.
+#[derive(Debug, Clone, solidity::Codec)]
+pub(crate) struct PrecompileTierConfig {
+    slots_per_tier: Vec<u16>,
+    reward_portion: Vec<u32>,
+    tier_thresholds: Vec<u128>,
+}
+
+/// Helper struct used to encode a single tier threshold.
+#[derive(Debug, Clone, solidity::Codec)]
+pub(crate) struct PrecompileTierThreshold {
+    threshold_type: u8,
+    percentage: u32,
+    minimum_percentage: u32,
+    maximum_percentage: u32,
+}
+
+/// Helper struct used to encode static tier parameters.
+#[derive(Debug, Clone, solidity::Codec)]
+pub(crate) struct PrecompileTierParameters {
+    reward_portion: Vec<u32>,
+    slot_distribution: Vec<u32>,
+    tier_thresholds: Vec<PrecompileTierThreshold>,
+    tier_rank_multipliers: Vec<u32>,
+}
+
 /// Helper struct used to encode different smart contract types for the v2 interface.
 #[derive(Debug, Clone, solidity::Codec)]
 pub struct SmartContractV2 {
@@ -605,13 +734,329 @@ where
         Ok(DAppStaking::<R>::unlocking_period().into())
     }
 
+    /// Read the tier assignment history of the given smart contract, from the oldest to the latest entry.
+    #[precompile::public("dapp_tier_history((uint8,bytes))")]
+    #[precompile::view]
+    fn dapp_tier_history(
+        handle: &mut impl PrecompileHandle,
+        smart_contract: SmartContractV2,
+    ) -> EvmResult<Vec<PrecompileTierHistoryEntry>> {
+        // TODO: benchmark this function so we can measure ref time & PoV correctly
+        // Storage item: IntegratedDApps:
+        // Blake2_128Concat(16 + SmartContract::max_encoded_len) + DAppInfoFor::max_encoded_len
+        // Storage item: DAppTierHistory:
+        // Twox64Concat(8) + DAppId(2) + TierHistoryFor::max_encoded_len
+        handle.record_db_read::<R>(
+            26 + <R as pallet_dapp_staking::Config>::SmartContract::max_encoded_len()
+                + DAppInfoFor::<R>::max_encoded_len()
+                + TierHistoryFor::<R>::max_encoded_len(),
+        )?;
+
+        let smart_contract = Self::decode_smart_contract(smart_contract)?;
+        let dapp_id = match IntegratedDApps::<R>::get(&smart_contract) {
+            Some(dapp_info) => dapp_info.id(),
+            None => return Ok(Vec::new()),
+        };
+
+        Ok(DAppStaking::<R>::get_dapp_tier_history(dapp_id)
+            .into_iter()
+            .map(|entry| PrecompileTierHistoryEntry {
+                era: entry.era,
+                tier: entry.ranked_tier.tier(),
+                rank: entry.ranked_tier.rank(),
+                stake: entry.stake,
+                reward: entry.reward,
+            })
+            .collect())
+    }
+
+    /// Read the metadata of the given smart contract.
+    ///
+    /// If the dApp owner hasn't set any metadata, all of the returned fields are empty.
+    #[precompile::public("dapp_metadata((uint8,bytes))")]
+    #[precompile::view]
+    fn dapp_metadata(
+        handle: &mut impl PrecompileHandle,
+        smart_contract: SmartContractV2,
+    ) -> EvmResult<PrecompileDAppMetadata> {
+        // TODO: benchmark this function so we can measure ref time & PoV correctly
+        // Storage item: DAppMetadataOf:
+        // Blake2_128Concat(16) + SmartContract::max_encoded_len + DAppMetadataFor::max_encoded_len
+        // + AccountId::max_encoded_len + Balance(16)
+        handle.record_db_read::<R>(
+            32 + <R as pallet_dapp_staking::Config>::SmartContract::max_encoded_len()
+                + DAppMetadataFor::<R>::max_encoded_len()
+                + R::AccountId::max_encoded_len(),
+        )?;
+
+        let smart_contract = Self::decode_smart_contract(smart_contract)?;
+
+        Ok(match DAppStaking::<R>::get_dapp_metadata(&smart_contract) {
+            Some(metadata) => PrecompileDAppMetadata {
+                name: metadata.name.into(),
+                url: metadata.url.into(),
+                logo_cid: metadata.logo_cid.into(),
+                category: metadata.category as u8,
+                deposit: metadata.deposit,
+            },
+            None => PrecompileDAppMetadata {
+                name: Vec::new().into(),
+                url: Vec::new().into(),
+                logo_cid: Vec::new().into(),
+                category: 0,
+                deposit: 0,
+            },
+        })
+    }
+
+    /// Read the ledger of the given account.
+    ///
+    /// Stake amounts are checked against the ongoing period, past period stakes are reported as zero.
+    #[precompile::public("account_ledger(bytes)")]
+    #[precompile::view]
+    fn account_ledger(
+        handle: &mut impl PrecompileHandle,
+        account: DynamicAddress,
+    ) -> EvmResult<PrecompileAccountLedger> {
+        // TODO: benchmark this function so we can measure ref time & PoV correctly
+        // Storage item: ActiveProtocolState:
+        // Twox64(8) + ProtocolState::max_encoded_len
+        // Storage item: Ledger:
+        // Blake2_128Concat(16 + AccountId::max_encoded_len) + Ledger::max_encoded_len
+        handle.record_db_read::<R>(
+            24 + ProtocolState::max_encoded_len()
+                + R::AccountId::max_encoded_len()
+                + AccountLedgerFor::<R>::max_encoded_len(),
+        )?;
+
+        let account = Self::parse_input_address(account.into())?;
+
+        let ledger = Ledger::<R>::get(&account);
+        let current_period_number = ActiveProtocolState::<R>::get().period_number();
+
+        Ok(PrecompileAccountLedger {
+            locked: ledger.active_locked_amount(),
+            unlocking: ledger
+                .unlocking_chunks()
+                .iter()
+                .map(|chunk| PrecompileUnlockingChunk {
+                    amount: chunk.amount,
+                    unlock_block: chunk.unlock_block.into(),
+                })
+                .collect(),
+            voting_stake: ledger.staked_amount_for_type(Subperiod::Voting, current_period_number),
+            build_and_earn_stake: ledger
+                .staked_amount_for_type(Subperiod::BuildAndEarn, current_period_number),
+        })
+    }
+
+    /// Read the stake of the given staker on the given smart contract.
+    ///
+    /// Stake is checked against the ongoing period, past period stake is reported as zero.
+    #[precompile::public("staker_info(bytes,(uint8,bytes))")]
+    #[precompile::view]
+    fn staker_info(
+        handle: &mut impl PrecompileHandle,
+        staker: DynamicAddress,
+        smart_contract: SmartContractV2,
+    ) -> EvmResult<PrecompileStakerInfo> {
+        // TODO: benchmark this function so we can measure ref time & PoV correctly
+        // Storage item: ActiveProtocolState:
+        // Twox64(8) + ProtocolState::max_encoded_len
+        // Storage item: StakerInfo:
+        // Blake2_128Concat(16 + AccountId::max_encoded_len) +
+        // Blake2_128Concat(16 + SmartContract::max_encoded_len) + SingularStakingInfo::max_encoded_len
+        handle.record_db_read::<R>(
+            40 + ProtocolState::max_encoded_len()
+                + R::AccountId::max_encoded_len()
+                + <R as pallet_dapp_staking::Config>::SmartContract::max_encoded_len()
+                + SingularStakingInfo::max_encoded_len(),
+        )?;
+
+        let staker = Self::parse_input_address(staker.into())?;
+        let smart_contract = Self::decode_smart_contract(smart_contract)?;
+
+        let current_period_number = ActiveProtocolState::<R>::get().period_number();
+
+        Ok(match StakerInfo::<R>::get(&staker, &smart_contract) {
+            Some(staking_info) if staking_info.period_number() == current_period_number => {
+                PrecompileStakerInfo {
+                    voting_stake: staking_info.staked_amount(Subperiod::Voting),
+                    build_and_earn_stake: staking_info.staked_amount(Subperiod::BuildAndEarn),
+                    bonus_eligible: staking_info.is_bonus_eligible(),
+                }
+            }
+            _ => PrecompileStakerInfo {
+                voting_stake: 0,
+                build_and_earn_stake: 0,
+                bonus_eligible: false,
+            },
+        })
+    }
+
+    /// Read the total stake on the given smart contract.
+    ///
+    /// Stake is checked against the ongoing period, past period stake is reported as zero.
+    #[precompile::public("contract_stake((uint8,bytes))")]
+    #[precompile::view]
+    fn contract_stake(
+        handle: &mut impl PrecompileHandle,
+        smart_contract: SmartContractV2,
+    ) -> EvmResult<PrecompileContractStake> {
+        // TODO: benchmark this function so we can measure ref time & PoV correctly
+        // Storage item: ActiveProtocolState:
+        // Twox64(8) + ProtocolState::max_encoded_len
+        // Storage item: IntegratedDApps:
+        // Blake2_128Concat(16 + SmartContract::max_encoded_len) + DAppInfoFor::max_encoded_len
+        // Storage item: ContractStake:
+        // Twox64Concat(8) + DAppId(2) + ContractStakeAmount::max_encoded_len
+        handle.record_db_read::<R>(
+            34 + ProtocolState::max_encoded_len()
+                + <R as pallet_dapp_staking::Config>::SmartContract::max_encoded_len()
+                + DAppInfoFor::<R>::max_encoded_len()
+                + ContractStakeAmount::max_encoded_len(),
+        )?;
+
+        let smart_contract = Self::decode_smart_contract(smart_contract)?;
+
+        let contract_stake = match IntegratedDApps::<R>::get(&smart_contract) {
+            Some(dapp_info) => ContractStake::<R>::get(&dapp_info.id()),
+            None => Default::default(),
+        };
+        let current_period_number = ActiveProtocolState::<R>::get().period_number();
+
+        Ok(PrecompileContractStake {
+            voting_stake: contract_stake.staked_amount(current_period_number, Subperiod::Voting),
+            build_and_earn_stake: contract_stake
+                .staked_amount(current_period_number, Subperiod::BuildAndEarn),
+        })
+    }
+
+    /// Read the tier assignment of the given smart contract in the given era.
+    ///
+    /// Only unclaimed assignments are available, `dapp_tier_history` covers the claimed ones.
+    #[precompile::public("dapp_tier_assignment((uint8,bytes),uint256)")]
+    #[precompile::view]
+    fn dapp_tier_assignment(
+        handle: &mut impl PrecompileHandle,
+        smart_contract: SmartContractV2,
+        era: U256,
+    ) -> EvmResult<PrecompileTierAssignment> {
+        // TODO: benchmark this function so we can measure ref time & PoV correctly
+        // Storage item: IntegratedDApps:
+        // Blake2_128Concat(16 + SmartContract::max_encoded_len) + DAppInfoFor::max_encoded_len
+        // Storage item: DAppTiers:
+        // Twox64Concat(8) + EraNumber(4) + DAppTierRewards::max_encoded_len
+        handle.record_db_read::<R>(
+            28 + <R as pallet_dapp_staking::Config>::SmartContract::max_encoded_len()
+                + DAppInfoFor::<R>::max_encoded_len()
+                + DAppTierRewardsFor::<R>::max_encoded_len(),
+        )?;
+
+        let smart_contract = Self::decode_smart_contract(smart_contract)?;
+        let era: u32 = era
+            .try_into()
+            .map_err::<Revert, _>(|_| RevertReason::value_is_too_large("Era number.").into())
+            .in_field("era")?;
+
+        let assignment = IntegratedDApps::<R>::get(&smart_contract).and_then(|dapp_info| {
+            DAppTiers::<R>::get(era)
+                .and_then(|dapp_tiers| dapp_tiers.dapp_reward(dapp_info.id()).ok())
+        });
+
+        Ok(match assignment {
+            Some((reward, ranked_tier)) => PrecompileTierAssignment {
+                assigned: true,
+                tier: ranked_tier.tier(),
+                rank: ranked_tier.rank(),
+                reward,
+            },
+            None => PrecompileTierAssignment {
+                assigned: false,
+                tier: 0,
+                rank: 0,
+                reward: 0,
+            },
+        })
+    }
+
+    /// Read the tier configuration used for the ongoing era.
+    ///
+    /// Reward portions are expressed in parts per million.
+    #[precompile::public("tier_config()")]
+    #[precompile::view]
+    fn tier_config(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileTierConfig> {
+        // TODO: benchmark this function so we can measure ref time & PoV correctly
+        // Storage item: TierConfig:
+        // Twox64(8) + TiersConfiguration::max_encoded_len
+        handle
+            .record_db_read::<R>(8 + TiersConfiguration::<R::NumberOfTiers>::max_encoded_len())?;
+
+        let tier_config = TierConfig::<R>::get();
+
+        Ok(PrecompileTierConfig {
+            slots_per_tier: tier_config.slots_per_tier().to_vec(),
+            reward_portion: tier_config
+                .reward_portion()
+                .iter()
+                .map(|portion| portion.deconstruct())
+                .collect(),
+            tier_thresholds: tier_config.tier_thresholds().to_vec(),
+        })
+    }
+
+    /// Read the static tier parameters, used to calculate the tier configuration.
+    ///
+    /// Reward & slot distribution portions are expressed in parts per million,
+    /// threshold percentages in parts per billion, and rank multipliers in bips.
+    #[precompile::public("static_tier_params()")]
+    #[precompile::view]
+    fn static_tier_params(
+        handle: &mut impl PrecompileHandle,
+    ) -> EvmResult<PrecompileTierParameters> {
+        // TODO: benchmark this function so we can measure ref time & PoV correctly
+        // Storage item: StaticTierParams:
+        // Twox64(8) + TierParameters::max_encoded_len
+        handle.record_db_read::<R>(8 + TierParameters::<R::NumberOfTiers>::max_encoded_len())?;
+
+        let tier_params = StaticTierParams::<R>::get();
+
+        Ok(PrecompileTierParameters {
+            reward_portion: tier_params
+                .reward_portion()
+                .iter()
+                .map(|portion| portion.deconstruct())
+                .collect(),
+            slot_distribution: tier_params
+                .slot_distribution()
+                .iter()
+                .map(|portion| portion.deconstruct())
+                .collect(),
+            tier_thresholds: tier_params
+                .tier_thresholds()
+                .iter()
+                .map(tier_threshold_entry)
+                .collect(),
+            tier_rank_multipliers: tier_params.tier_rank_multipliers().to_vec(),
+        })
+    }
+
     /// Attempt to lock the given amount into the dApp staking protocol.
     #[precompile::public("lock(uint128)")]
     fn lock(handle: &mut impl PrecompileHandle, amount: u128) -> EvmResult<bool> {
         // Prepare call & dispatch it
         let origin = R::AddressMapping::into_account_id(handle.context().caller);
+        let locked_before = Self::read_ledger(handle, &origin)?.active_locked_amount();
         let lock_call = pallet_dapp_staking::Call::<R>::lock { amount };
-        RuntimeHelper::<R>::try_dispatch(handle, Some(origin).into(), lock_call, 0)?;
+        RuntimeHelper::<R>::try_dispatch(handle, Some(origin.clone()).into(), lock_call, 0)?;
+
+        // Emit the actually locked amount, which can be lower than the requested one
+        let locked_after = Self::read_ledger(handle, &origin)?.active_locked_amount();
+        Self::emit_log(
+            handle,
+            SELECTOR_LOG_LOCKED,
+            solidity::encode_event_data(locked_after.saturating_sub(locked_before)),
+        )?;
 
         Ok(true)
     }
@@ -621,8 +1066,17 @@ where
     fn unlock(handle: &mut impl PrecompileHandle, amount: u128) -> EvmResult<bool> {
         // Prepare call & dispatch it
         let origin = R::AddressMapping::into_account_id(handle.context().caller);
+        let locked_before = Self::read_ledger(handle, &origin)?.active_locked_amount();
         let unlock_call = pallet_dapp_staking::Call::<R>::unlock { amount };
-        RuntimeHelper::<R>::try_dispatch(handle, Some(origin).into(), unlock_call, 0)?;
+        RuntimeHelper::<R>::try_dispatch(handle, Some(origin.clone()).into(), unlock_call, 0)?;
+
+        // Emit the amount which actually started unlocking, which can differ from the requested one
+        let locked_after = Self::read_ledger(handle, &origin)?.active_locked_amount();
+        Self::emit_log(
+            handle,
+            SELECTOR_LOG_UNLOCKING,
+            solidity::encode_event_data(locked_before.saturating_sub(locked_after)),
+        )?;
 
         Ok(true)
     }
@@ -632,8 +1086,52 @@ where
     fn claim_unlocked(handle: &mut impl PrecompileHandle) -> EvmResult<bool> {
         // Prepare call & dispatch it
         let origin = R::AddressMapping::into_account_id(handle.context().caller);
+        let unlocking_before = Self::read_ledger(handle, &origin)?.unlocking_amount();
         let claim_unlocked_call = pallet_dapp_staking::Call::<R>::claim_unlocked {};
-        RuntimeHelper::<R>::try_dispatch(handle, Some(origin).into(), claim_unlocked_call, 0)?;
+        RuntimeHelper::<R>::try_dispatch(
+            handle,
+            Some(origin.clone()).into(),
+            claim_unlocked_call,
+            0,
+        )?;
+
+        let unlocking_after = Self::read_ledger(handle, &origin)?.unlocking_amount();
+        Self::emit_log(
+            handle,
+            SELECTOR_LOG_CLAIMED_UNLOCKED,
+            solidity::encode_event_data(unlocking_before.saturating_sub(unlocking_after)),
+        )?;
+
+        Ok(true)
+    }
+
+    /// Attempts to instantly unlock the given amount, paying the instant unlock penalty.
+    #[precompile::public("instant_unlock(uint128)")]
+    fn instant_unlock(handle: &mut impl PrecompileHandle, amount: u128) -> EvmResult<bool> {
+        // Prepare call & dispatch it
+        let origin = R::AddressMapping::into_account_id(handle.context().caller);
+        let locked_before = Self::read_ledger(handle, &origin)?.total_locked_amount();
+        let instant_unlock_call = pallet_dapp_staking::Call::<R>::instant_unlock { amount };
+        RuntimeHelper::<R>::try_dispatch(
+            handle,
+            Some(origin.clone()).into(),
+            instant_unlock_call,
+            0,
+        )?;
+
+        let locked_after = Self::read_ledger(handle, &origin)?.total_locked_amount();
+        let unlocked_amount = locked_before.saturating_sub(locked_after);
+
+        // Storage item: InstantUnlockPenalty:
+        // Twox64(8) + Perbill::max_encoded_len
+        handle.record_db_read::<R>(8 + Perbill::max_encoded_len())?;
+        let penalty = InstantUnlockPenalty::<R>::get().unwrap_or_default() * unlocked_amount;
+
+        Self::emit_log(
+            handle,
+            SELECTOR_LOG_INSTANT_UNLOCKED,
+            solidity::encode_event_data((unlocked_amount, penalty)),
+        )?;
 
         Ok(true)
     }
@@ -645,6 +1143,7 @@ where
         smart_contract: SmartContractV2,
         amount: Balance,
     ) -> EvmResult<bool> {
+        let event_contract = smart_contract.clone();
         let smart_contract = Self::decode_smart_contract(smart_contract)?;
 
         // Prepare call & dispatch it
@@ -655,6 +1154,12 @@ where
         };
         RuntimeHelper::<R>::try_dispatch(handle, Some(origin).into(), stake_call, 0)?;
 
+        Self::emit_log(
+            handle,
+            SELECTOR_LOG_STAKED,
+            solidity::encode_event_data((event_contract, amount)),
+        )?;
+
         Ok(true)
     }
 
@@ -665,15 +1170,28 @@ where
         smart_contract: SmartContractV2,
         amount: Balance,
     ) -> EvmResult<bool> {
+        let event_contract = smart_contract.clone();
         let smart_contract = Self::decode_smart_contract(smart_contract)?;
 
         // Prepare call & dispatch it
         let origin = R::AddressMapping::into_account_id(handle.context().caller);
+        let staked_before = Self::read_stake(handle, &origin, &smart_contract)?;
         let unstake_call = pallet_dapp_staking::Call::<R>::unstake {
-            smart_contract,
+            smart_contract: smart_contract.clone(),
             amount,
         };
-        RuntimeHelper::<R>::try_dispatch(handle, Some(origin).into(), unstake_call, 0)?;
+        RuntimeHelper::<R>::try_dispatch(handle, Some(origin.clone()).into(), unstake_call, 0)?;
+
+        // Emit the actually unstaked amount, which can be higher than the requested one
+        let staked_after = Self::read_stake(handle, &origin, &smart_contract)?;
+        Self::emit_log(
+            handle,
+            SELECTOR_LOG_UNSTAKED,
+            solidity::encode_event_data((
+                event_contract,
+                staked_before.saturating_sub(staked_after),
+            )),
+        )?;
 
         Ok(true)
     }
@@ -683,14 +1201,22 @@ where
     fn claim_staker_rewards(handle: &mut impl PrecompileHandle) -> EvmResult<bool> {
         // Prepare call & dispatch it
         let origin = R::AddressMapping::into_account_id(handle.context().caller);
+        let balance_before = Self::read_reward_destination_balance(handle, &origin)?;
         let claim_staker_rewards_call = pallet_dapp_staking::Call::<R>::claim_staker_rewards {};
         RuntimeHelper::<R>::try_dispatch(
             handle,
-            Some(origin).into(),
+            Some(origin.clone()).into(),
             claim_staker_rewards_call,
             0,
         )?;
 
+        let balance_after = Self::read_reward_destination_balance(handle, &origin)?;
+        Self::emit_log(
+            handle,
+            SELECTOR_LOG_REWARD_CLAIMED,
+            solidity::encode_event_data(balance_after.saturating_sub(balance_before)),
+        )?;
+
         Ok(true)
     }
 
@@ -700,30 +1226,71 @@ where
         handle: &mut impl PrecompileHandle,
         smart_contract: SmartContractV2,
     ) -> EvmResult<bool> {
+        let event_contract = smart_contract.clone();
         let smart_contract = Self::decode_smart_contract(smart_contract)?;
 
         // Prepare call & dispatch it
         let origin = R::AddressMapping::into_account_id(handle.context().caller);
+        let balance_before = Self::read_reward_destination_balance(handle, &origin)?;
         let claim_bonus_reward_call =
             pallet_dapp_staking::Call::<R>::claim_bonus_reward { smart_contract };
-        RuntimeHelper::<R>::try_dispatch(handle, Some(origin).into(), claim_bonus_reward_call, 0)?;
+        RuntimeHelper::<R>::try_dispatch(
+            handle,
+            Some(origin.clone()).into(),
+            claim_bonus_reward_call,
+            0,
+        )?;
+
+        let balance_after = Self::read_reward_destination_balance(handle, &origin)?;
+        Self::emit_log(
+            handle,
+            SELECTOR_LOG_BONUS_REWARD_CLAIMED,
+            solidity::encode_event_data((
+                event_contract,
+                balance_after.saturating_sub(balance_before),
+            )),
+        )?;
 
         Ok(true)
     }
 
     /// Attempts to claim dApp reward for the given dApp in the given era.
+    ///
+    /// The `claim_bonus_reward` selector is kept for backward compatibility.
+    #[precompile::public("claim_dapp_reward((uint8,bytes),uint256)")]
     #[precompile::public("claim_bonus_reward((uint8,bytes),uint256)")]
     fn claim_dapp_reward(
         handle: &mut impl PrecompileHandle,
         smart_contract: SmartContractV2,
         era: U256,
     ) -> EvmResult<bool> {
+        let event_contract = smart_contract.clone();
         let smart_contract = Self::decode_smart_contract(smart_contract)?;
+        let event_era = era;
         let era = era
             .try_into()
             .map_err::<Revert, _>(|_| RevertReason::value_is_too_large("Era number.").into())
             .in_field("era")?;
 
+        // TODO: benchmark this function so we can measure ref time & PoV correctly
+        // Storage item: IntegratedDApps:
+        // Blake2_128Concat(16 + SmartContract::max_encoded_len) + DAppInfo::max_encoded_len
+        // Storage item: DAppTiers:
+        // Twox64Concat(8 + EraNumber::max_encoded_len) + DAppTierRewards::max_encoded_len
+        handle.record_db_read::<R>(
+            28 + <R as pallet_dapp_staking::Config>::SmartContract::max_encoded_len()
+                + DAppInfoFor::<R>::max_encoded_len()
+                + DAppTierRewardsFor::<R>::max_encoded_len(),
+        )?;
+
+        // Reward is read before the claim, since it's consumed by it
+        let reward = IntegratedDApps::<R>::get(&smart_contract)
+            .and_then(|dapp_info| {
+                DAppTiers::<R>::get(era)
+                    .and_then(|mut dapp_tiers| dapp_tiers.try_claim(dapp_info.id()).ok())
+            })
+            .map_or(Balance::zero(), |(reward, _)| reward);
+
         // Prepare call & dispatch it
         let origin = R::AddressMapping::into_account_id(handle.context().caller);
         let claim_dapp_reward_call = pallet_dapp_staking::Call::<R>::claim_dapp_reward {
@@ -732,6 +1299,12 @@ where
         };
         RuntimeHelper::<R>::try_dispatch(handle, Some(origin).into(), claim_dapp_reward_call, 0)?;
 
+        Self::emit_log(
+            handle,
+            SELECTOR_LOG_DAPP_REWARD_CLAIMED,
+            solidity::encode_event_data((event_contract, event_era, reward)),
+        )?;
+
         Ok(true)
     }
 
@@ -741,10 +1314,12 @@ where
         handle: &mut impl PrecompileHandle,
         smart_contract: SmartContractV2,
     ) -> EvmResult<bool> {
+        let event_contract = smart_contract.clone();
         let smart_contract = Self::decode_smart_contract(smart_contract)?;
 
         // Prepare call & dispatch it
         let origin = R::AddressMapping::into_account_id(handle.context().caller);
+        let staked_amount = Self::read_stake(handle, &origin, &smart_contract)?;
         let unstake_from_unregistered_call =
             pallet_dapp_staking::Call::<R>::unstake_from_unregistered { smart_contract };
         RuntimeHelper::<R>::try_dispatch(
@@ -754,6 +1329,12 @@ where
             0,
         )?;
 
+        Self::emit_log(
+            handle,
+            SELECTOR_LOG_UNSTAKED_FROM_UNREGISTERED,
+            solidity::encode_event_data((event_contract, staked_amount)),
+        )?;
+
         Ok(true)
     }
 
@@ -781,23 +1362,206 @@ where
         destination_contract: SmartContractV2,
         amount: Balance,
     ) -> EvmResult<bool> {
+        let event_contracts = (source_contract.clone(), destination_contract.clone());
         let source_contract = Self::decode_smart_contract(source_contract)?;
         let destination_contract = Self::decode_smart_contract(destination_contract)?;
 
         // Prepare call & dispatch it
         let origin = R::AddressMapping::into_account_id(handle.context().caller);
+        let staked_before = Self::read_stake(handle, &origin, &destination_contract)?;
         let move_call = pallet_dapp_staking::Call::<R>::move_stake {
             source_contract,
-            destination_contract,
+            destination_contract: destination_contract.clone(),
             amount,
         };
-        RuntimeHelper::<R>::try_dispatch(handle, Some(origin).into(), move_call, 0)?;
+        RuntimeHelper::<R>::try_dispatch(handle, Some(origin.clone()).into(), move_call, 0)?;
+
+        // Emit the actually moved amount, which can differ from the requested one
+        let staked_after = Self::read_stake(handle, &origin, &destination_contract)?;
+        Self::emit_log(
+            handle,
+            SELECTOR_LOG_STAKE_MOVED,
+            solidity::encode_event_data((
+                event_contracts.0,
+                event_contracts.1,
+                staked_after.saturating_sub(staked_before),
+            )),
+        )?;
+
+        Ok(true)
+    }
+
+    /// Attempts to set the account to which the caller's staker & bonus rewards are paid out.
+    ///
+    /// Empty destination means rewards are paid out to the caller.
+    #[precompile::public("set_staker_reward_destination(bytes)")]
+    fn set_staker_reward_destination(
+        handle: &mut impl PrecompileHandle,
+        destination: DynamicAddress,
+    ) -> EvmResult<bool> {
+        let destination: Vec<u8> = destination.into();
+        let destination = if destination.is_empty() {
+            None
+        } else {
+            Some(Self::parse_input_address(destination)?)
+        };
+
+        // Prepare call & dispatch it
+        let origin = R::AddressMapping::into_account_id(handle.context().caller);
+        let set_staker_reward_destination_call =
+            pallet_dapp_staking::Call::<R>::set_staker_reward_destination { destination };
+        RuntimeHelper::<R>::try_dispatch(
+            handle,
+            Some(origin).into(),
+            set_staker_reward_destination_call,
+            0,
+        )?;
+
+        Ok(true)
+    }
+
+    /// Attempts to set the account to which the rewards of the given dApp are paid out.
+    ///
+    /// Caller has to be the dApp owner. Empty beneficiary means rewards are paid out to the dApp owner.
+    #[precompile::public("set_dapp_reward_beneficiary((uint8,bytes),bytes)")]
+    fn set_dapp_reward_beneficiary(
+        handle: &mut impl PrecompileHandle,
+        smart_contract: SmartContractV2,
+        beneficiary: DynamicAddress,
+    ) -> EvmResult<bool> {
+        let smart_contract = Self::decode_smart_contract(smart_contract)?;
+        let beneficiary: Vec<u8> = beneficiary.into();
+        let beneficiary = if beneficiary.is_empty() {
+            None
+        } else {
+            Some(Self::parse_input_address(beneficiary)?)
+        };
+
+        // Prepare call & dispatch it
+        let origin = R::AddressMapping::into_account_id(handle.context().caller);
+        let set_dapp_reward_beneficiary_call =
+            pallet_dapp_staking::Call::<R>::set_dapp_reward_beneficiary {
+                smart_contract,
+                beneficiary,
+            };
+        RuntimeHelper::<R>::try_dispatch(
+            handle,
+            Some(origin).into(),
+            set_dapp_reward_beneficiary_call,
+            0,
+        )?;
+
+        Ok(true)
+    }
+
+    /// Attempts to transfer the ownership of the given dApp to the new owner.
+    ///
+    /// Caller has to be the dApp owner.
+    #[precompile::public("set_dapp_owner((uint8,bytes),bytes)")]
+    fn set_dapp_owner(
+        handle: &mut impl PrecompileHandle,
+        smart_contract: SmartContractV2,
+        new_owner: DynamicAddress,
+    ) -> EvmResult<bool> {
+        let smart_contract = Self::decode_smart_contract(smart_contract)?;
+        let new_owner = Self::parse_input_address(new_owner.into())?;
+
+        // Prepare call & dispatch it
+        let origin = R::AddressMapping::into_account_id(handle.context().caller);
+        let set_dapp_owner_call = pallet_dapp_staking::Call::<R>::set_dapp_owner {
+            smart_contract,
+            new_owner,
+        };
+        RuntimeHelper::<R>::try_dispatch(handle, Some(origin).into(), set_dapp_owner_call, 0)?;
 
         Ok(true)
     }
 
     // Utility functions
 
+    /// Helper method to read the ledger of the given account, recording the DB read.
+    fn read_ledger(
+        handle: &mut impl PrecompileHandle,
+        account: &R::AccountId,
+    ) -> EvmResult<AccountLedgerFor<R>> {
+        // Storage item: Ledger:
+        // Blake2_128Concat(16 + AccountId::max_encoded_len) + Ledger::max_encoded_len
+        handle.record_db_read::<R>(
+            16 + R::AccountId::max_encoded_len() + AccountLedgerFor::<R>::max_encoded_len(),
+        )?;
+
+        Ok(Ledger::<R>::get(account))
+    }
+
+    /// Helper method to read the amount staked by the given account on the given smart contract,
+    /// in the ongoing period, recording the DB reads.
+    fn read_stake(
+        handle: &mut impl PrecompileHandle,
+        account: &R::AccountId,
+        smart_contract: &<R as pallet_dapp_staking::Config>::SmartContract,
+    ) -> EvmResult<Balance> {
+        // Storage item: ActiveProtocolState:
+        // Twox64(8) + ProtocolState::max_encoded_len
+        // Storage item: StakerInfo:
+        // Blake2_128Concat(16 + AccountId::max_encoded_len) +
+        // Blake2_128Concat(16 + SmartContract::max_encoded_len) + SingularStakingInfo::max_encoded_len
+        handle.record_db_read::<R>(
+            40 + ProtocolState::max_encoded_len()
+                + R::AccountId::max_encoded_len()
+                + <R as pallet_dapp_staking::Config>::SmartContract::max_encoded_len()
+                + SingularStakingInfo::max_encoded_len(),
+        )?;
+
+        // Past period stakes are considered to be zero.
+        let current_period_number = ActiveProtocolState::<R>::get().period_number();
+
+        Ok(StakerInfo::<R>::get(account, smart_contract)
+            .filter(|staking_info| staking_info.period_number() == current_period_number)
+            .map_or(Balance::zero(), |staking_info| {
+                staking_info.total_staked_amount()
+            }))
+    }
+
+    /// Helper method to read the total balance of the account to which the staker & bonus rewards
+    /// of the given account are paid out, recording the DB reads.
+    fn read_reward_destination_balance(
+        handle: &mut impl PrecompileHandle,
+        account: &R::AccountId,
+    ) -> EvmResult<Balance> {
+        // Storage item: StakerRewardDestination:
+        // Blake2_128Concat(16 + AccountId::max_encoded_len) + AccountId::max_encoded_len
+        // Storage item: Account:
+        // Blake2_128Concat(16 + AccountId::max_encoded_len) + AccountInfo::max_encoded_len
+        handle.record_db_read::<R>(
+            32 + 3 * R::AccountId::max_encoded_len()
+                + frame_system::AccountInfo::<R::Nonce, R::AccountData>::max_encoded_len(),
+        )?;
+
+        let destination =
+            StakerRewardDestination::<R>::get(account).unwrap_or_else(|| account.clone());
+
+        Ok(<R as pallet_dapp_staking::Config>::Currency::total_balance(
+            &destination,
+        ))
+    }
+
+    /// Helper method to emit a log, with the caller as the only indexed topic, charging for it.
+    fn emit_log(
+        handle: &mut impl PrecompileHandle,
+        selector: [u8; 32],
+        data: Vec<u8>,
+    ) -> EvmResult {
+        handle.record_log_costs_manual(2, data.len())?;
+
+        log2(
+            handle.context().address,
+            selector,
+            handle.context().caller,
+            data,
+        )
+        .record(handle)
+    }
+
     /// Helper method to decode smart contract struct for v2 calls
     pub(crate) fn decode_smart_contract(
         smart_contract: SmartContractV2,
@@ -853,6 +1617,40 @@ where
     }
 }
 
+/// Encodes the tier threshold, with the numeric Id of its type.
+///
+/// Unused percentages are set to zero.
+pub(crate) fn tier_threshold_entry(threshold: &TierThreshold) -> PrecompileTierThreshold {
+    match *threshold {
+        TierThreshold::FixedPercentage {
+            required_percentage,
+        } => PrecompileTierThreshold {
+            threshold_type: 0,
+            percentage: required_percentage.deconstruct(),
+            minimum_percentage: 0,
+            maximum_percentage: 0,
+        },
+        TierThreshold::StakedPercentage {
+            required_percentage,
+        } => PrecompileTierThreshold {
+            threshold_type: 1,
+            percentage: required_percentage.deconstruct(),
+            minimum_percentage: 0,
+            maximum_percentage: 0,
+        },
+        TierThreshold::DynamicPercentage {
+            percentage,
+            minimum_required_percentage,
+            maximum_possible_percentage,
+        } => PrecompileTierThreshold {
+            threshold_type: 2,
+            percentage: percentage.deconstruct(),
+            minimum_percentage: minimum_required_percentage.deconstruct(),
+            maximum_percentage: maximum_possible_percentage.deconstruct(),
+        },
+    }
+}
+
 /// Numeric Id of the subperiod enum value.
 pub(crate) fn subperiod_id(subperiod: &Subperiod) -> u8 {
     match subperiod {
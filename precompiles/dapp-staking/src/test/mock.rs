@@ -42,7 +42,8 @@ extern crate alloc;
 
 use astar_primitives::{
     dapp_staking::{
-        CycleConfiguration, EraNumber, PeriodNumber, SmartContract, StakingRewardHandler,
+        CycleConfiguration, EraNumber, FixedTierSlots, PeriodNumber, SmartContract,
+        StakingRewardHandler,
     },
     AccountId, Balance, BlockNumber,
 };
@@ -215,10 +216,15 @@ impl pallet_dapp_staking::BenchmarkHelper<MockSmartContract, AccountId>
 impl pallet_dapp_staking::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type RuntimeFreezeReason = RuntimeFreezeReason;
+    type RuntimeHoldReason = RuntimeHoldReason;
     type Currency = Balances;
     type SmartContract = MockSmartContract;
     type ContractRegisterOrigin = frame_system::EnsureRoot<AccountId>;
     type ContractUnregisterOrigin = frame_system::EnsureRoot<AccountId>;
+    type DAppMetadataDepositPerByte = ConstU128<1>;
+    type MaxDAppMetadataLength = ConstU32<32>;
+    type InstantUnlockPenaltyHandler = ();
+    type LockReceipt = ();
     type ManagerOrigin = frame_system::EnsureRoot<AccountId>;
     type StakingRewardHandler = DummyStakingRewardHandler;
     type CycleConfiguration = DummyCycleConfiguration;
@@ -227,14 +233,23 @@ impl pallet_dapp_staking::Config for Test {
     type EraRewardSpanLength = ConstU32<8>;
     type RewardRetentionInPeriods = ConstU32<2>;
     type MaxNumberOfContracts = ConstU32<10>;
+    type MaxNumberOfTierSlots = ConstU32<10>;
+    type TierAssignmentPageSize = ConstU32<16>;
+    type TierHistoryLength = ConstU32<10>;
     type MaxUnlockingChunks = ConstU32<5>;
     type MinimumLockedAmount = ConstU128<10>;
     type UnlockingPeriod = ConstU32<2>;
     type MaxNumberOfStakedContracts = ConstU32<5>;
+    type MaxNumberOfDelegates = ConstU32<3>;
     type MinimumStakeAmount = ConstU128<3>;
     type NumberOfTiers = ConstU32<4>;
+    type TierSlotsProvider = FixedTierSlots;
     type RankingEnabled = ConstBool<true>;
     type MaxBonusSafeMovesPerPeriod = ConstU8<0>;
+    type MaxNumberOfRewardSplits = ConstU32<3>;
+    type MaxNumberOfDAppRewardClaimEras = ConstU32<8>;
+    type AutoClaimScanLimit = ConstU32<4>;
+    type UnregisteredStakeGracePeriod = ConstU32<2>;
     type WeightInfo = pallet_dapp_staking::weights::SubstrateWeight<Test>;
     #[cfg(feature = "runtime-benchmarks")]
     type BenchmarkHelper = BenchmarkHelper<MockSmartContract, AccountId>;
@@ -22,14 +22,16 @@ use frame_support::assert_ok;
 use frame_system::RawOrigin;
 use precompile_utils::testing::*;
 use sp_core::H160;
+use sp_runtime::Perbill;
 
 use assert_matches::assert_matches;
 
 use astar_primitives::{
-    dapp_staking::{CycleConfiguration, EraNumber},
+    dapp_staking::{CycleConfiguration, DAppCategory, EraNumber},
     BlockNumber,
 };
-use pallet_dapp_staking::ActiveProtocolState;
+use pallet_dapp_staking::{ActiveProtocolState, BoundedDAppMetadata};
+use parity_scale_codec::Encode;
 
 #[test]
 fn protocol_state_is_ok() {
@@ -92,7 +94,12 @@ fn lock_is_ok() {
         System::reset_events();
         precompiles()
             .prepare_test(ALICE, precompile_address(), PrecompileCall::lock { amount })
-            .expect_no_logs()
+            .expect_log(log2(
+                precompile_address(),
+                SELECTOR_LOG_LOCKED,
+                ALICE,
+                solidity::encode_event_data(amount),
+            ))
             .execute_returns(true);
 
         let events = dapp_staking_events();
@@ -129,7 +136,12 @@ fn unlock_is_ok() {
                     amount: unlock_amount,
                 },
             )
-            .expect_no_logs()
+            .expect_log(log2(
+                precompile_address(),
+                SELECTOR_LOG_UNLOCKING,
+                ALICE,
+                solidity::encode_event_data(unlock_amount),
+            ))
             .execute_returns(true);
 
         let events = dapp_staking_events();
@@ -174,7 +186,12 @@ fn claim_unlocked_is_ok() {
                 precompile_address(),
                 PrecompileCall::claim_unlocked {},
             )
-            .expect_no_logs()
+            .expect_log(log2(
+                precompile_address(),
+                SELECTOR_LOG_CLAIMED_UNLOCKED,
+                ALICE,
+                solidity::encode_event_data(amount),
+            ))
             .execute_returns(true);
 
         let events = dapp_staking_events();
@@ -189,6 +206,52 @@ fn claim_unlocked_is_ok() {
     });
 }
 
+#[test]
+fn instant_unlock_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize();
+
+        // Enable instant unlocking & lock some amount
+        assert_ok!(DappStaking::set_instant_unlock_penalty(
+            RawOrigin::Root.into(),
+            Some(Perbill::from_percent(10)),
+        ));
+        let amount = 1234;
+        assert_ok!(DappStaking::lock(
+            RawOrigin::Signed(AddressMapper::into_account_id(ALICE)).into(),
+            amount,
+        ));
+
+        // Instantly unlock everything and verify event
+        System::reset_events();
+        precompiles()
+            .prepare_test(
+                ALICE,
+                precompile_address(),
+                PrecompileCall::instant_unlock { amount },
+            )
+            .expect_log(log2(
+                precompile_address(),
+                SELECTOR_LOG_INSTANT_UNLOCKED,
+                ALICE,
+                solidity::encode_event_data((amount, Perbill::from_percent(10) * amount)),
+            ))
+            .execute_returns(true);
+
+        let events = dapp_staking_events();
+        assert_eq!(events.len(), 1);
+        assert_matches!(
+            events[0].clone(),
+            pallet_dapp_staking::Event::InstantUnlock {
+                amount: unlocked,
+                penalty,
+                ..
+            } if unlocked == amount && penalty == Perbill::from_percent(10) * amount
+        );
+        assert!(Ledger::<Test>::get(&AddressMapper::into_account_id(ALICE)).is_empty());
+    });
+}
+
 #[test]
 fn stake_is_ok() {
     ExternalityBuilder::build().execute_with(|| {
@@ -224,11 +287,16 @@ fn stake_is_ok() {
                 staker_h160,
                 precompile_address(),
                 PrecompileCall::stake {
-                    smart_contract: smart_contract_v2,
+                    smart_contract: smart_contract_v2.clone(),
                     amount,
                 },
             )
-            .expect_no_logs()
+            .expect_log(log2(
+                precompile_address(),
+                SELECTOR_LOG_STAKED,
+                staker_h160,
+                solidity::encode_event_data((smart_contract_v2, amount)),
+            ))
             .execute_returns(true);
 
         let events = dapp_staking_events();
@@ -285,11 +353,16 @@ fn unstake_is_ok() {
                 staker_h160,
                 precompile_address(),
                 PrecompileCall::unstake {
-                    smart_contract: smart_contract_v2,
+                    smart_contract: smart_contract_v2.clone(),
                     amount,
                 },
             )
-            .expect_no_logs()
+            .expect_log(log2(
+                precompile_address(),
+                SELECTOR_LOG_UNSTAKED,
+                staker_h160,
+                solidity::encode_event_data((smart_contract_v2, amount)),
+            ))
             .execute_returns(true);
 
         let events = dapp_staking_events();
@@ -359,12 +432,21 @@ fn move_is_ok() {
                 staker_h160,
                 precompile_address(),
                 PrecompileCall::move_stake {
-                    source_contract: source_contract_v2,
-                    destination_contract: destination_contract_v2,
+                    source_contract: source_contract_v2.clone(),
+                    destination_contract: destination_contract_v2.clone(),
                     amount,
                 },
             )
-            .expect_no_logs()
+            .expect_log(log2(
+                precompile_address(),
+                SELECTOR_LOG_STAKE_MOVED,
+                staker_h160,
+                solidity::encode_event_data((
+                    source_contract_v2,
+                    destination_contract_v2,
+                    amount,
+                )),
+            ))
             .execute_returns(true);
 
         let events = dapp_staking_events();
@@ -399,6 +481,11 @@ fn claim_staker_rewards_is_ok() {
         let target_era = 7;
         advance_to_era(target_era);
         let number_of_claims = (2..target_era).count();
+        let staker = AddressMapper::into_account_id(staker_h160);
+        let expected_reward: Balance = DappStaking::get_claimable_staker_rewards(&staker)
+            .iter()
+            .map(|(_, reward)| reward)
+            .sum();
 
         // Claim staker rewards and verify events
         System::reset_events();
@@ -408,7 +495,12 @@ fn claim_staker_rewards_is_ok() {
                 precompile_address(),
                 PrecompileCall::claim_staker_rewards {},
             )
-            .expect_no_logs()
+            .expect_log(log2(
+                precompile_address(),
+                SELECTOR_LOG_REWARD_CLAIMED,
+                staker_h160,
+                solidity::encode_event_data(expected_reward),
+            ))
             .execute_returns(true);
 
         // We expect multiple reward to be claimed
@@ -445,6 +537,9 @@ fn claim_bonus_reward_is_ok() {
             address: smart_contract_address.into(),
         };
 
+        let staker = AddressMapper::into_account_id(staker_h160);
+        let expected_reward = DappStaking::get_claimable_bonus_rewards(&staker)[0].1;
+
         // Claim bonus reward and verify event
         System::reset_events();
         precompiles()
@@ -452,10 +547,15 @@ fn claim_bonus_reward_is_ok() {
                 staker_h160,
                 precompile_address(),
                 PrecompileCall::claim_bonus_reward {
-                   smart_contract: smart_contract_v2,
+                   smart_contract: smart_contract_v2.clone(),
                 },
             )
-            .expect_no_logs()
+            .expect_log(log2(
+                precompile_address(),
+                SELECTOR_LOG_BONUS_REWARD_CLAIMED,
+                staker_h160,
+                solidity::encode_event_data((smart_contract_v2, expected_reward)),
+            ))
             .execute_returns(true);
 
         let events = dapp_staking_events();
@@ -491,17 +591,31 @@ fn claim_dapp_reward_is_ok() {
 
         // Claim dApp reward and verify event
         let claim_era: EraNumber = 2;
+        let expected_reward = DappStaking::get_unclaimed_dapp_rewards(&smart_contract)
+            .into_iter()
+            .find(|(era, _)| *era == claim_era)
+            .map(|(_, reward)| reward)
+            .unwrap();
         System::reset_events();
         precompiles()
             .prepare_test(
                 staker_h160,
                 precompile_address(),
                 PrecompileCall::claim_dapp_reward {
-                   smart_contract: smart_contract_v2,
+                   smart_contract: smart_contract_v2.clone(),
                    era: claim_era.into(),
                 },
             )
-            .expect_no_logs()
+            .expect_log(log2(
+                precompile_address(),
+                SELECTOR_LOG_DAPP_REWARD_CLAIMED,
+                staker_h160,
+                solidity::encode_event_data((
+                    smart_contract_v2,
+                    U256::from(claim_era),
+                    expected_reward,
+                )),
+            ))
             .execute_returns(true);
 
         let events = dapp_staking_events();
@@ -513,6 +627,437 @@ fn claim_dapp_reward_is_ok() {
     });
 }
 
+#[test]
+fn dapp_tier_history_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize();
+
+        // Register a dApp and stake on it
+        let smart_contract_address = [0xAF; 32];
+        let smart_contract = <Test as pallet_dapp_staking::Config>::SmartContract::wasm(
+            smart_contract_address.into(),
+        );
+        let amount = 1234;
+        register_and_stake(ALICE, smart_contract.clone(), amount);
+        let smart_contract_v2 = SmartContractV2 {
+            contract_type: SmartContractTypes::Wasm,
+            address: smart_contract_address.into(),
+        };
+
+        // No history before the first tier assignment
+        precompiles()
+            .prepare_test(
+                ALICE,
+                precompile_address(),
+                PrecompileCall::dapp_tier_history {
+                    smart_contract: smart_contract_v2.clone(),
+                },
+            )
+            .expect_no_logs()
+            .execute_returns(Vec::<PrecompileTierHistoryEntry>::new());
+
+        // Advance to 3rd era, so the dApp gets assigned to a tier in the 2nd era
+        advance_to_era(3);
+        let dapp_id = pallet_dapp_staking::IntegratedDApps::<Test>::get(&smart_contract)
+            .expect("Must exist.")
+            .id();
+        let (reward, ranked_tier) = pallet_dapp_staking::DAppTiers::<Test>::get(2)
+            .expect("Must exist.")
+            .try_claim(dapp_id)
+            .expect("dApp must be in a tier.");
+
+        precompiles()
+            .prepare_test(
+                ALICE,
+                precompile_address(),
+                PrecompileCall::dapp_tier_history {
+                    smart_contract: smart_contract_v2,
+                },
+            )
+            .expect_no_logs()
+            .execute_returns(vec![PrecompileTierHistoryEntry {
+                era: 2,
+                tier: ranked_tier.tier(),
+                rank: ranked_tier.rank(),
+                stake: amount,
+                reward,
+            }]);
+    });
+}
+
+#[test]
+fn dapp_metadata_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize();
+
+        // Register a dApp
+        let smart_contract_address = [0xAF; 32];
+        let smart_contract = <Test as pallet_dapp_staking::Config>::SmartContract::wasm(
+            smart_contract_address.into(),
+        );
+        register_and_stake(ALICE, smart_contract.clone(), 1234);
+        let smart_contract_v2 = SmartContractV2 {
+            contract_type: SmartContractTypes::Wasm,
+            address: smart_contract_address.into(),
+        };
+
+        // No metadata before the owner sets it
+        precompiles()
+            .prepare_test(
+                ALICE,
+                precompile_address(),
+                PrecompileCall::dapp_metadata {
+                    smart_contract: smart_contract_v2.clone(),
+                },
+            )
+            .expect_no_logs()
+            .execute_returns(PrecompileDAppMetadata {
+                name: Vec::new().into(),
+                url: Vec::new().into(),
+                logo_cid: Vec::new().into(),
+                category: 0,
+                deposit: 0,
+            });
+
+        // Set the metadata & verify it's returned
+        let metadata = BoundedDAppMetadata {
+            name: b"Astar dApp".to_vec().try_into().unwrap(),
+            url: b"https://astar.network".to_vec().try_into().unwrap(),
+            logo_cid: b"bafybeigdyrzt5sfp7udm7hu76uh7y26"
+                .to_vec()
+                .try_into()
+                .unwrap(),
+            category: DAppCategory::DeFi,
+        };
+        let deposit = metadata.encoded_size() as Balance;
+        assert_ok!(DappStaking::set_dapp_metadata(
+            RawOrigin::Signed(AddressMapper::into_account_id(ALICE)).into(),
+            smart_contract,
+            metadata.clone(),
+        ));
+
+        precompiles()
+            .prepare_test(
+                ALICE,
+                precompile_address(),
+                PrecompileCall::dapp_metadata {
+                    smart_contract: smart_contract_v2,
+                },
+            )
+            .expect_no_logs()
+            .execute_returns(PrecompileDAppMetadata {
+                name: metadata.name.into_inner().into(),
+                url: metadata.url.into_inner().into(),
+                logo_cid: metadata.logo_cid.into_inner().into(),
+                category: DAppCategory::DeFi as u8,
+                deposit,
+            });
+    });
+}
+
+#[test]
+fn account_ledger_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize();
+
+        // Register a dApp and stake on it, lock some more & unlock a part of it
+        let smart_contract_address = [0xAF; 32];
+        let smart_contract = <Test as pallet_dapp_staking::Config>::SmartContract::wasm(
+            smart_contract_address.into(),
+        );
+        let stake_amount = 1000;
+        register_and_stake(ALICE, smart_contract, stake_amount);
+        assert_ok!(DappStaking::lock(
+            RawOrigin::Signed(AddressMapper::into_account_id(ALICE)).into(),
+            500,
+        ));
+        let unlock_amount = 300;
+        assert_ok!(DappStaking::unlock(
+            RawOrigin::Signed(AddressMapper::into_account_id(ALICE)).into(),
+            unlock_amount,
+        ));
+        let unlock_block = Ledger::<Test>::get(&AddressMapper::into_account_id(ALICE))
+            .unlocking_chunks()[0]
+            .unlock_block;
+
+        precompiles()
+            .prepare_test(
+                ALICE,
+                precompile_address(),
+                PrecompileCall::account_ledger {
+                    account: ALICE.as_bytes().try_into().unwrap(),
+                },
+            )
+            .expect_no_logs()
+            .execute_returns(PrecompileAccountLedger {
+                locked: 1200,
+                unlocking: vec![PrecompileUnlockingChunk {
+                    amount: unlock_amount,
+                    unlock_block: unlock_block.into(),
+                }],
+                voting_stake: stake_amount,
+                build_and_earn_stake: 0,
+            });
+
+        // Stakes from the past period are reported as zero
+        advance_to_next_period();
+        precompiles()
+            .prepare_test(
+                ALICE,
+                precompile_address(),
+                PrecompileCall::account_ledger {
+                    account: ALICE.as_bytes().try_into().unwrap(),
+                },
+            )
+            .expect_no_logs()
+            .execute_returns(PrecompileAccountLedger {
+                locked: 1200,
+                unlocking: vec![PrecompileUnlockingChunk {
+                    amount: unlock_amount,
+                    unlock_block: unlock_block.into(),
+                }],
+                voting_stake: 0,
+                build_and_earn_stake: 0,
+            });
+    });
+}
+
+#[test]
+fn staker_info_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize();
+
+        // Register a dApp and stake on it during the voting subperiod
+        let smart_contract_address = [0xAF; 32];
+        let smart_contract = <Test as pallet_dapp_staking::Config>::SmartContract::wasm(
+            smart_contract_address.into(),
+        );
+        let amount = 1234;
+        register_and_stake(ALICE, smart_contract, amount);
+        let smart_contract_v2 = SmartContractV2 {
+            contract_type: SmartContractTypes::Wasm,
+            address: smart_contract_address.into(),
+        };
+
+        precompiles()
+            .prepare_test(
+                ALICE,
+                precompile_address(),
+                PrecompileCall::staker_info {
+                    staker: ALICE.as_bytes().try_into().unwrap(),
+                    smart_contract: smart_contract_v2.clone(),
+                },
+            )
+            .expect_no_logs()
+            .execute_returns(PrecompileStakerInfo {
+                voting_stake: amount,
+                build_and_earn_stake: 0,
+                bonus_eligible: true,
+            });
+
+        // Stake from the past period is reported as zero
+        advance_to_next_period();
+        precompiles()
+            .prepare_test(
+                ALICE,
+                precompile_address(),
+                PrecompileCall::staker_info {
+                    staker: ALICE.as_bytes().try_into().unwrap(),
+                    smart_contract: smart_contract_v2,
+                },
+            )
+            .expect_no_logs()
+            .execute_returns(PrecompileStakerInfo {
+                voting_stake: 0,
+                build_and_earn_stake: 0,
+                bonus_eligible: false,
+            });
+    });
+}
+
+#[test]
+fn contract_stake_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize();
+
+        // Register a dApp and stake on it during the voting subperiod
+        let smart_contract_address = [0xAF; 32];
+        let smart_contract = <Test as pallet_dapp_staking::Config>::SmartContract::wasm(
+            smart_contract_address.into(),
+        );
+        let voting_amount = 1234;
+        register_and_stake(ALICE, smart_contract.clone(), voting_amount);
+        let smart_contract_v2 = SmartContractV2 {
+            contract_type: SmartContractTypes::Wasm,
+            address: smart_contract_address.into(),
+        };
+
+        // Stake some more during the build&earn subperiod
+        advance_to_next_subperiod();
+        let build_and_earn_amount = 567;
+        assert_ok!(DappStaking::lock(
+            RawOrigin::Signed(AddressMapper::into_account_id(ALICE)).into(),
+            build_and_earn_amount,
+        ));
+        assert_ok!(DappStaking::stake(
+            RawOrigin::Signed(AddressMapper::into_account_id(ALICE)).into(),
+            smart_contract,
+            build_and_earn_amount,
+        ));
+
+        precompiles()
+            .prepare_test(
+                ALICE,
+                precompile_address(),
+                PrecompileCall::contract_stake {
+                    smart_contract: smart_contract_v2,
+                },
+            )
+            .expect_no_logs()
+            .execute_returns(PrecompileContractStake {
+                voting_stake: voting_amount,
+                build_and_earn_stake: build_and_earn_amount,
+            });
+
+        // Unregistered contract has no stake
+        precompiles()
+            .prepare_test(
+                ALICE,
+                precompile_address(),
+                PrecompileCall::contract_stake {
+                    smart_contract: SmartContractV2 {
+                        contract_type: SmartContractTypes::Wasm,
+                        address: [0xBF; 32].into(),
+                    },
+                },
+            )
+            .expect_no_logs()
+            .execute_returns(PrecompileContractStake {
+                voting_stake: 0,
+                build_and_earn_stake: 0,
+            });
+    });
+}
+
+#[test]
+fn dapp_tier_assignment_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize();
+
+        // Register a dApp and stake on it
+        let smart_contract_address = [0xAF; 32];
+        let smart_contract = <Test as pallet_dapp_staking::Config>::SmartContract::wasm(
+            smart_contract_address.into(),
+        );
+        register_and_stake(ALICE, smart_contract.clone(), 1234);
+        let smart_contract_v2 = SmartContractV2 {
+            contract_type: SmartContractTypes::Wasm,
+            address: smart_contract_address.into(),
+        };
+
+        // Advance to 3rd era, so the dApp gets assigned to a tier in the 2nd era
+        advance_to_era(3);
+        let dapp_id = pallet_dapp_staking::IntegratedDApps::<Test>::get(&smart_contract)
+            .expect("Must exist.")
+            .id();
+        let (reward, ranked_tier) = pallet_dapp_staking::DAppTiers::<Test>::get(2)
+            .expect("Must exist.")
+            .dapp_reward(dapp_id)
+            .expect("dApp must be in a tier.");
+
+        precompiles()
+            .prepare_test(
+                ALICE,
+                precompile_address(),
+                PrecompileCall::dapp_tier_assignment {
+                    smart_contract: smart_contract_v2.clone(),
+                    era: 2_u32.into(),
+                },
+            )
+            .expect_no_logs()
+            .execute_returns(PrecompileTierAssignment {
+                assigned: true,
+                tier: ranked_tier.tier(),
+                rank: ranked_tier.rank(),
+                reward,
+            });
+
+        // Once the reward is claimed, the assignment is no longer available
+        assert_ok!(DappStaking::claim_dapp_reward(
+            RawOrigin::Signed(AddressMapper::into_account_id(ALICE)).into(),
+            smart_contract,
+            2,
+        ));
+        precompiles()
+            .prepare_test(
+                ALICE,
+                precompile_address(),
+                PrecompileCall::dapp_tier_assignment {
+                    smart_contract: smart_contract_v2,
+                    era: 2_u32.into(),
+                },
+            )
+            .expect_no_logs()
+            .execute_returns(PrecompileTierAssignment {
+                assigned: false,
+                tier: 0,
+                rank: 0,
+                reward: 0,
+            });
+    });
+}
+
+#[test]
+fn tier_config_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize();
+
+        let tier_config = pallet_dapp_staking::TierConfig::<Test>::get();
+
+        precompiles()
+            .prepare_test(ALICE, precompile_address(), PrecompileCall::tier_config {})
+            .expect_no_logs()
+            .execute_returns(PrecompileTierConfig {
+                slots_per_tier: tier_config.slots_per_tier().to_vec(),
+                reward_portion: vec![400_000, 300_000, 200_000, 100_000],
+                tier_thresholds: tier_config.tier_thresholds().to_vec(),
+            });
+    });
+}
+
+#[test]
+fn static_tier_params_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize();
+
+        let tier_threshold = |percent| PrecompileTierThreshold {
+            threshold_type: 0,
+            percentage: Perbill::from_percent(percent).deconstruct(),
+            minimum_percentage: 0,
+            maximum_percentage: 0,
+        };
+
+        precompiles()
+            .prepare_test(
+                ALICE,
+                precompile_address(),
+                PrecompileCall::static_tier_params {},
+            )
+            .expect_no_logs()
+            .execute_returns(PrecompileTierParameters {
+                reward_portion: vec![400_000, 300_000, 200_000, 100_000],
+                slot_distribution: vec![100_000, 200_000, 300_000, 400_000],
+                tier_thresholds: vec![
+                    tier_threshold(4),
+                    tier_threshold(3),
+                    tier_threshold(2),
+                    tier_threshold(1),
+                ],
+                tier_rank_multipliers: vec![10_000, 20_000, 20_000, 20_000],
+            });
+    });
+}
+
 #[test]
 fn unstake_from_unregistered_is_ok() {
     ExternalityBuilder::build().execute_with(|| {
@@ -545,10 +1090,15 @@ fn unstake_from_unregistered_is_ok() {
                 staker_h160,
                 precompile_address(),
                 PrecompileCall::unstake_from_unregistered {
-                   smart_contract: smart_contract_v2,
+                   smart_contract: smart_contract_v2.clone(),
                 },
             )
-            .expect_no_logs()
+            .expect_log(log2(
+                precompile_address(),
+                SELECTOR_LOG_UNSTAKED_FROM_UNREGISTERED,
+                staker_h160,
+                solidity::encode_event_data((smart_contract_v2, amount)),
+            ))
             .execute_returns(true);
 
         let events = dapp_staking_events();
@@ -604,3 +1154,228 @@ fn cleanup_expired_entries_is_ok() {
         );
     });
 }
+
+#[test]
+fn set_staker_reward_destination_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize();
+
+        let staker = AddressMapper::into_account_id(ALICE);
+        let destination_h160 = H160::repeat_byte(0xBB);
+
+        // Both H160 & SS58 destination addresses are supported
+        for destination in into_dynamic_addresses(destination_h160) {
+            System::reset_events();
+            precompiles()
+                .prepare_test(
+                    ALICE,
+                    precompile_address(),
+                    PrecompileCall::set_staker_reward_destination { destination },
+                )
+                .expect_no_logs()
+                .execute_returns(true);
+
+            assert_eq!(
+                pallet_dapp_staking::StakerRewardDestination::<Test>::get(&staker),
+                Some(AddressMapper::into_account_id(destination_h160))
+            );
+            let events = dapp_staking_events();
+            assert_eq!(events.len(), 1);
+            assert_matches!(
+                events[0].clone(),
+                pallet_dapp_staking::Event::StakerRewardDestinationSet {
+                    destination: Some(_),
+                    ..
+                }
+            );
+        }
+
+        // Empty destination removes it
+        precompiles()
+            .prepare_test(
+                ALICE,
+                precompile_address(),
+                PrecompileCall::set_staker_reward_destination {
+                    destination: Vec::<u8>::new().into(),
+                },
+            )
+            .expect_no_logs()
+            .execute_returns(true);
+        assert!(!pallet_dapp_staking::StakerRewardDestination::<Test>::contains_key(&staker));
+    });
+}
+
+#[test]
+fn claim_dapp_reward_selectors_are_ok() {
+    assert!(PrecompileCall::claim_dapp_reward_selectors().contains(&0x2398fe01));
+    // Legacy selector, kept for backward compatibility
+    assert!(PrecompileCall::claim_dapp_reward_selectors().contains(&0x1dfc4a43));
+}
+
+#[test]
+fn set_dapp_reward_beneficiary_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize();
+
+        // Register a dApp, owned by the caller
+        let owner = AddressMapper::into_account_id(ALICE);
+        let smart_contract_address = [0xAF; 32];
+        let smart_contract = <Test as pallet_dapp_staking::Config>::SmartContract::wasm(
+            smart_contract_address.into(),
+        );
+        assert_ok!(DappStaking::register(
+            RawOrigin::Root.into(),
+            owner.clone(),
+            smart_contract.clone()
+        ));
+        let smart_contract_v2 = SmartContractV2 {
+            contract_type: SmartContractTypes::Wasm,
+            address: smart_contract_address.into(),
+        };
+        let beneficiary_h160 = H160::repeat_byte(0xBB);
+
+        // Both H160 & SS58 beneficiary addresses are supported
+        for beneficiary in into_dynamic_addresses(beneficiary_h160) {
+            System::reset_events();
+            precompiles()
+                .prepare_test(
+                    ALICE,
+                    precompile_address(),
+                    PrecompileCall::set_dapp_reward_beneficiary {
+                        smart_contract: smart_contract_v2.clone(),
+                        beneficiary,
+                    },
+                )
+                .expect_no_logs()
+                .execute_returns(true);
+
+            assert_eq!(
+                IntegratedDApps::<Test>::get(&smart_contract)
+                    .unwrap()
+                    .reward_beneficiary(),
+                &AddressMapper::into_account_id(beneficiary_h160)
+            );
+            let events = dapp_staking_events();
+            assert_eq!(events.len(), 1);
+            assert_matches!(
+                events[0].clone(),
+                pallet_dapp_staking::Event::DAppRewardDestinationUpdated {
+                    beneficiary: Some(_),
+                    ..
+                }
+            );
+        }
+
+        // Empty beneficiary resets it to the owner
+        precompiles()
+            .prepare_test(
+                ALICE,
+                precompile_address(),
+                PrecompileCall::set_dapp_reward_beneficiary {
+                    smart_contract: smart_contract_v2.clone(),
+                    beneficiary: Vec::<u8>::new().into(),
+                },
+            )
+            .expect_no_logs()
+            .execute_returns(true);
+        assert_eq!(
+            IntegratedDApps::<Test>::get(&smart_contract)
+                .unwrap()
+                .reward_beneficiary(),
+            &owner
+        );
+
+        // Only the owner can set the beneficiary
+        precompiles()
+            .prepare_test(
+                beneficiary_h160,
+                precompile_address(),
+                PrecompileCall::set_dapp_reward_beneficiary {
+                    smart_contract: smart_contract_v2,
+                    beneficiary: Vec::<u8>::new().into(),
+                },
+            )
+            .expect_no_logs()
+            .execute_reverts(|output| {
+                core::str::from_utf8(output)
+                    .unwrap()
+                    .contains("OriginNotOwner")
+            });
+    });
+}
+
+#[test]
+fn set_dapp_owner_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize();
+
+        // Register a dApp, owned by the caller
+        let smart_contract_address = [0xAF; 32];
+        let smart_contract = <Test as pallet_dapp_staking::Config>::SmartContract::wasm(
+            smart_contract_address.into(),
+        );
+        assert_ok!(DappStaking::register(
+            RawOrigin::Root.into(),
+            AddressMapper::into_account_id(ALICE),
+            smart_contract.clone()
+        ));
+        let smart_contract_v2 = SmartContractV2 {
+            contract_type: SmartContractTypes::Wasm,
+            address: smart_contract_address.into(),
+        };
+
+        // Transfer the ownership and verify event
+        let new_owner_h160 = H160::repeat_byte(0xBB);
+        let new_owner = AddressMapper::into_account_id(new_owner_h160);
+        System::reset_events();
+        precompiles()
+            .prepare_test(
+                ALICE,
+                precompile_address(),
+                PrecompileCall::set_dapp_owner {
+                    smart_contract: smart_contract_v2.clone(),
+                    new_owner: new_owner_h160.as_bytes().try_into().unwrap(),
+                },
+            )
+            .expect_no_logs()
+            .execute_returns(true);
+
+        let events = dapp_staking_events();
+        assert_eq!(events.len(), 1);
+        assert_matches!(
+            events[0].clone(),
+            pallet_dapp_staking::Event::DAppOwnerChanged {
+                new_owner: owner,
+                ..
+            } if owner == new_owner
+        );
+
+        // Previous owner can no longer manage the dApp, but the new one can
+        precompiles()
+            .prepare_test(
+                ALICE,
+                precompile_address(),
+                PrecompileCall::set_dapp_owner {
+                    smart_contract: smart_contract_v2.clone(),
+                    new_owner: ALICE.as_bytes().try_into().unwrap(),
+                },
+            )
+            .expect_no_logs()
+            .execute_reverts(|output| {
+                core::str::from_utf8(output)
+                    .unwrap()
+                    .contains("OriginNotOwner")
+            });
+        precompiles()
+            .prepare_test(
+                new_owner_h160,
+                precompile_address(),
+                PrecompileCall::set_dapp_owner {
+                    smart_contract: smart_contract_v2,
+                    new_owner: ALICE.as_bytes().try_into().unwrap(),
+                },
+            )
+            .expect_no_logs()
+            .execute_returns(true);
+    });
+}
@@ -20,11 +20,22 @@ use super::{Balance, BlockNumber};
 
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 
-use frame_support::pallet_prelude::{RuntimeDebug, Weight};
+use frame_support::{
+    pallet_prelude::{DispatchResult, RuntimeDebug, Weight},
+    traits::{
+        fungibles,
+        tokens::{Fortitude, Precision, Preservation},
+    },
+};
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
 use sp_arithmetic::ArithmeticError;
 use sp_core::{DecodeWithMemTracking, H160};
-use sp_runtime::traits::Zero;
-use sp_std::hash::Hash;
+use sp_runtime::{
+    traits::{Get, SaturatedConversion, Zero},
+    DispatchError, FixedU128, Permill,
+};
+use sp_std::{collections::btree_map::BTreeMap, hash::Hash, marker::PhantomData, vec::Vec};
 
 /// Era number type
 pub type EraNumber = u32;
@@ -97,7 +108,19 @@ pub trait CycleConfiguration {
 }
 
 /// Trait for observers (listeners) of various events related to dApp staking protocol.
-pub trait Observer {
+///
+/// All callbacks are no-ops by default, and return the weight consumed by the call.
+/// Callbacks triggered by extrinsics are invoked after the protocol state has been updated,
+/// and the returned weight is included in the actual weight of the extrinsic.
+pub trait Observer<AccountId, SmartContract> {
+    /// Upper bound of the weight consumed by any single callback triggered by an extrinsic,
+    /// i.e. all callbacks except `block_before_new_era` and `on_new_period`.
+    ///
+    /// Included in the pre-dispatch weight of the extrinsics which trigger the callbacks.
+    fn max_weight() -> Weight {
+        Weight::zero()
+    }
+
     /// Called in the block right before the next era starts.
     ///
     /// Returns the weight consumed by the call.
@@ -107,9 +130,68 @@ pub trait Observer {
     fn block_before_new_era(_next_era: EraNumber) -> Weight {
         Weight::zero()
     }
+
+    /// Called in the first block of a new period.
+    ///
+    /// Returns the weight consumed by the call.
+    ///
+    /// # Arguments
+    /// * `period` - Period number of the new period.
+    fn on_new_period(_period: PeriodNumber) -> Weight {
+        Weight::zero()
+    }
+
+    /// Called after an account has staked on a smart contract.
+    ///
+    /// Returns the weight consumed by the call.
+    ///
+    /// # Arguments
+    /// * `account` - Account which staked.
+    /// * `smart_contract` - Smart contract on which the stake was made.
+    /// * `amount` - Staked amount.
+    fn on_stake(_account: &AccountId, _smart_contract: &SmartContract, _amount: Balance) -> Weight {
+        Weight::zero()
+    }
+
+    /// Called after an account has unstaked from a smart contract, registered or not.
+    ///
+    /// Returns the weight consumed by the call.
+    ///
+    /// # Arguments
+    /// * `account` - Account which unstaked.
+    /// * `smart_contract` - Smart contract from which the stake was removed.
+    /// * `amount` - Unstaked amount.
+    fn on_unstake(
+        _account: &AccountId,
+        _smart_contract: &SmartContract,
+        _amount: Balance,
+    ) -> Weight {
+        Weight::zero()
+    }
+
+    /// Called after a staker, bonus or dApp reward has been paid out.
+    ///
+    /// Returns the weight consumed by the call.
+    ///
+    /// # Arguments
+    /// * `beneficiary` - Account which received the reward.
+    /// * `amount` - Paid out reward amount.
+    fn on_reward_paid(_beneficiary: &AccountId, _amount: Balance) -> Weight {
+        Weight::zero()
+    }
+
+    /// Called after a dApp has been unregistered from the protocol.
+    ///
+    /// Returns the weight consumed by the call.
+    ///
+    /// # Arguments
+    /// * `smart_contract` - Smart contract of the unregistered dApp.
+    fn on_dapp_unregistered(_smart_contract: &SmartContract) -> Weight {
+        Weight::zero()
+    }
 }
 
-impl Observer for () {}
+impl<AccountId, SmartContract> Observer<AccountId, SmartContract> for () {}
 
 /// Interface for staking reward handler.
 ///
@@ -152,6 +234,7 @@ pub trait SmartContractHandle<AccountId> {
     Hash,
     scale_info::TypeInfo,
 )]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub enum SmartContract<AccountId> {
     /// EVM smart contract instance.
     Evm(H160),
@@ -181,9 +264,127 @@ impl<AccountId> AccountCheck<AccountId> for () {
     }
 }
 
+/// Interface for the receipts minted for the funds locked in dApp staking.
+///
+/// Receipts represent the locked amount, and are expected to be non-transferable on their own.
+pub trait LockReceiptHandler<AccountId> {
+    /// `true` if receipts can be minted, `false` otherwise.
+    fn is_available() -> bool;
+
+    /// Mints the specified amount of receipts to the account.
+    fn mint(who: &AccountId, amount: Balance) -> DispatchResult;
+
+    /// Burns up to the specified amount of receipts from the account, regardless of any restrictions on them.
+    ///
+    /// Returns the actually burned amount, which is limited by the account's receipt balance.
+    fn burn(who: &AccountId, amount: Balance) -> Result<Balance, DispatchError>;
+
+    /// Receipt balance of the account.
+    fn balance(who: &AccountId) -> Balance;
+
+    /// Total amount of receipts in existence.
+    fn total_issuance() -> Balance;
+}
+
+impl<AccountId> LockReceiptHandler<AccountId> for () {
+    fn is_available() -> bool {
+        false
+    }
+
+    fn mint(_who: &AccountId, _amount: Balance) -> DispatchResult {
+        Err(DispatchError::Unavailable)
+    }
+
+    fn burn(_who: &AccountId, _amount: Balance) -> Result<Balance, DispatchError> {
+        Err(DispatchError::Unavailable)
+    }
+
+    fn balance(_who: &AccountId) -> Balance {
+        Balance::zero()
+    }
+
+    fn total_issuance() -> Balance {
+        Balance::zero()
+    }
+}
+
+/// Lock receipts represented by a fungible asset, e.g. one from `pallet-assets`.
+pub struct FungiblesLockReceipt<Assets, ReceiptAssetId>(PhantomData<(Assets, ReceiptAssetId)>);
+impl<AccountId, Assets, ReceiptAssetId> LockReceiptHandler<AccountId>
+    for FungiblesLockReceipt<Assets, ReceiptAssetId>
+where
+    Assets: fungibles::Mutate<AccountId, Balance = Balance>,
+    ReceiptAssetId: Get<Assets::AssetId>,
+{
+    fn is_available() -> bool {
+        Assets::asset_exists(ReceiptAssetId::get())
+    }
+
+    fn mint(who: &AccountId, amount: Balance) -> DispatchResult {
+        Assets::mint_into(ReceiptAssetId::get(), who, amount).map(|_| ())
+    }
+
+    fn burn(who: &AccountId, amount: Balance) -> Result<Balance, DispatchError> {
+        let asset_id = ReceiptAssetId::get();
+        let amount = amount.min(Assets::balance(asset_id.clone(), who));
+        if amount.is_zero() {
+            return Ok(Balance::zero());
+        }
+
+        Assets::burn_from(
+            asset_id,
+            who,
+            amount,
+            Preservation::Expendable,
+            Precision::BestEffort,
+            Fortitude::Force,
+        )
+    }
+
+    fn balance(who: &AccountId) -> Balance {
+        Assets::balance(ReceiptAssetId::get(), who)
+    }
+
+    fn total_issuance() -> Balance {
+        Assets::total_issuance(ReceiptAssetId::get())
+    }
+}
+
 /// Fixed number of tier slots used by dApp-staking recalculation.
 pub const FIXED_NUMBER_OF_TIER_SLOTS: u16 = 16;
 
+/// Interface for deriving the total number of tier slots available to dApps.
+///
+/// Called at the start of each new period. Implementations can rely on market data,
+/// e.g. the native token price provided by a local oracle, or on on-chain data like the number of integrated dApps.
+pub trait TierSlotsProvider {
+    /// Total number of tier slots for the upcoming period.
+    ///
+    /// # Arguments
+    /// * `number_of_integrated_dapps` - Number of dApps currently registered in dApp staking.
+    fn number_of_slots(number_of_integrated_dapps: u32) -> u16;
+}
+
+/// Always provides `FIXED_NUMBER_OF_TIER_SLOTS` tier slots.
+pub struct FixedTierSlots;
+impl TierSlotsProvider for FixedTierSlots {
+    fn number_of_slots(_number_of_integrated_dapps: u32) -> u16 {
+        FIXED_NUMBER_OF_TIER_SLOTS
+    }
+}
+
+/// Provides a number of tier slots proportional to the number of integrated dApps,
+/// but never less than `Min` slots.
+pub struct ProportionalTierSlots<Portion, Min>(PhantomData<(Portion, Min)>);
+impl<Portion: Get<Permill>, Min: Get<u16>> TierSlotsProvider
+    for ProportionalTierSlots<Portion, Min>
+{
+    fn number_of_slots(number_of_integrated_dapps: u32) -> u16 {
+        let slots: u16 = (Portion::get() * number_of_integrated_dapps).saturated_into();
+        slots.max(Min::get())
+    }
+}
+
 /// RankedTier is wrapper around u8 to hold both tier and rank. u8 has 2 bytes (8bits) and they're using in this order `0xrank_tier`.
 /// First 4 bits are used to hold rank and second 4 bits are used to hold tier.
 /// i.e: 0xa1 will hold rank: 10 and tier: 1 (0xa1 & 0xf == 1; 0xa1 >> 4 == 10;)
@@ -260,10 +461,136 @@ impl RankedTier {
     }
 }
 
+/// Tier assignment of a dApp in a particular era, as kept in the dApp's tier history.
+#[derive(
+    Copy, Clone, Encode, Decode, Eq, PartialEq, MaxEncodedLen, RuntimeDebug, scale_info::TypeInfo,
+)]
+pub struct DAppTierHistoryEntry {
+    /// Era in which the dApp was assigned to the tier.
+    #[codec(compact)]
+    pub era: EraNumber,
+    /// Tier & rank assigned to the dApp.
+    pub ranked_tier: RankedTier,
+    /// Total amount staked on the dApp, used for the tier assignment.
+    #[codec(compact)]
+    pub stake: Balance,
+    /// Reward the dApp earned in the era.
+    #[codec(compact)]
+    pub reward: Balance,
+}
+
+/// Category of a dApp, as declared by the dApp owner.
+#[derive(
+    PartialEq,
+    Eq,
+    Copy,
+    Clone,
+    Default,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    RuntimeDebug,
+    MaxEncodedLen,
+    scale_info::TypeInfo,
+)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum DAppCategory {
+    /// dApp doesn't fit into any of the other categories.
+    #[default]
+    Other = 0,
+    /// Decentralized finance.
+    DeFi = 1,
+    /// NFTs & marketplaces.
+    NFT = 2,
+    /// Gaming.
+    Gaming = 3,
+    /// Social networks & communities.
+    Social = 4,
+    /// Tooling & infrastructure for other dApps.
+    Tooling = 5,
+}
+
+impl TryFrom<u8> for DAppCategory {
+    type Error = ();
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Other),
+            1 => Ok(Self::DeFi),
+            2 => Ok(Self::NFT),
+            3 => Ok(Self::Gaming),
+            4 => Ok(Self::Social),
+            5 => Ok(Self::Tooling),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Metadata of a dApp, as set by the dApp owner.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, scale_info::TypeInfo)]
+pub struct DAppMetadata {
+    /// Human readable name of the dApp.
+    pub name: Vec<u8>,
+    /// URL of the dApp's website.
+    pub url: Vec<u8>,
+    /// IPFS CID of the dApp's logo.
+    pub logo_cid: Vec<u8>,
+    /// Category of the dApp.
+    pub category: DAppCategory,
+    /// Deposit held for storing the metadata.
+    pub deposit: Balance,
+}
+
+/// Preview of the dApp tier assignment, based on some candidate tier parameters.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, scale_info::TypeInfo)]
+pub struct TierAssignmentPreview {
+    /// Number of slots per tier, derived from the candidate parameters.
+    pub slots_per_tier: Vec<u16>,
+    /// Entry threshold of each tier, derived from the candidate parameters.
+    pub tier_thresholds: Vec<Balance>,
+    /// Tier & rank each dApp would be assigned to.
+    pub dapp_tiers: BTreeMap<DAppId, RankedTier>,
+    /// Reward of each tier.
+    pub tier_rewards: Vec<Balance>,
+    /// Reward per rank of each tier.
+    pub rank_rewards: Vec<Balance>,
+}
+
+/// Reward pools & estimated staker APR, projected for some total value staked.
+#[derive(Copy, Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, scale_info::TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct InflationRewardProjection {
+    /// Staker reward pool per era, adjusted according to the total value staked.
+    pub staker_reward_pool_per_era: Balance,
+    /// dApp reward pool per era.
+    pub dapp_reward_pool_per_era: Balance,
+    /// Bonus reward pool per period.
+    pub bonus_reward_pool_per_period: Balance,
+    /// Estimated staker APR, based on the staker reward pools paid out during one cycle.
+    /// Bonus rewards aren't included. Value of one represents 100%, and can be exceeded for a small total value staked.
+    pub staker_apr: FixedU128,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn dapp_category_from_u8() {
+        for category in [
+            DAppCategory::Other,
+            DAppCategory::DeFi,
+            DAppCategory::NFT,
+            DAppCategory::Gaming,
+            DAppCategory::Social,
+            DAppCategory::Tooling,
+        ] {
+            assert_eq!(DAppCategory::try_from(category as u8), Ok(category));
+        }
+
+        assert_eq!(DAppCategory::try_from(6), Err(()));
+    }
+
     #[test]
     fn tier_and_rank() {
         let t = RankedTier::new(0, 0).unwrap();
@@ -289,6 +616,28 @@ mod tests {
         assert_eq!(t.deconstruct(), (15, 10));
     }
 
+    #[test]
+    fn tier_slots_providers() {
+        assert_eq!(
+            FixedTierSlots::number_of_slots(0),
+            FIXED_NUMBER_OF_TIER_SLOTS
+        );
+        assert_eq!(
+            FixedTierSlots::number_of_slots(100),
+            FIXED_NUMBER_OF_TIER_SLOTS
+        );
+
+        frame_support::parameter_types! {
+            pub const Portion: Permill = Permill::from_percent(50);
+            pub const Min: u16 = 4;
+        }
+        type Proportional = ProportionalTierSlots<Portion, Min>;
+        assert_eq!(Proportional::number_of_slots(0), 4);
+        assert_eq!(Proportional::number_of_slots(9), 4);
+        assert_eq!(Proportional::number_of_slots(10), 5);
+        assert_eq!(Proportional::number_of_slots(101), 50);
+    }
+
     #[test]
     fn find_rank() {
         assert_eq!(RankedTier::find_rank(0, 0, 0), 0);
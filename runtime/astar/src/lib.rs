@@ -87,8 +87,10 @@ use xcm_runtime_apis::{
 
 use astar_primitives::{
     dapp_staking::{
-        AccountCheck as DappStakingAccountCheck, CycleConfiguration, DAppId, EraNumber,
-        PeriodNumber, RankedTier, SmartContract, FIXED_NUMBER_OF_TIER_SLOTS,
+        AccountCheck as DappStakingAccountCheck, CycleConfiguration, DAppId, DAppMetadata,
+        DAppTierHistoryEntry, EraNumber, FixedTierSlots, FungiblesLockReceipt,
+        InflationRewardProjection, PeriodNumber, RankedTier, SmartContract, TierAssignmentPreview,
+        FIXED_NUMBER_OF_TIER_SLOTS,
     },
     evm::{EVMFungibleAdapterWrapper, EvmRevertCodeHandler, TX_MAX_GAS_LIMIT},
     governance::{
@@ -263,6 +265,14 @@ impl Contains<RuntimeCall> for BaseFilter {
             // Custom asset's `id` should fit in `u32` as not to mix with service assets.
             RuntimeCall::Assets(method) => match method {
                 pallet_assets::Call::create { id, .. } => *id < (u32::MAX as AssetId).into(),
+                // dApp staking lock receipts can only be moved together with the locked funds.
+                pallet_assets::Call::transfer { id, .. }
+                | pallet_assets::Call::transfer_keep_alive { id, .. }
+                | pallet_assets::Call::transfer_all { id, .. }
+                | pallet_assets::Call::approve_transfer { id, .. }
+                | pallet_assets::Call::transfer_approved { id, .. } => {
+                    id.0 != DappStakingLockReceiptAssetId::get()
+                }
 
                 _ => true,
             },
@@ -424,6 +434,9 @@ impl pallet_preimage::Config for Runtime {
 
 parameter_types! {
     pub const MinimumStakingAmount: Balance = 500 * ASTR;
+    pub const DAppMetadataDepositPerByte: Balance = deposit(0, 1);
+    /// Service asset used for dApp staking lock receipts.
+    pub const DappStakingLockReceiptAssetId: AssetId = u32::MAX as AssetId + 1;
 }
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -445,6 +458,17 @@ impl pallet_dapp_staking::BenchmarkHelper<SmartContract<AccountId>, AccountId>
         Balances::write_balance(account, amount)
             .expect("Must succeed in test/benchmark environment.");
     }
+
+    fn create_lock_receipt_asset() {
+        use frame_support::traits::fungibles::Create;
+        <Assets as Create<AccountId>>::create(
+            DappStakingLockReceiptAssetId::get(),
+            AccountId::from([0u8; 32]),
+            true,
+            1,
+        )
+        .expect("Must succeed in test/benchmark environment.");
+    }
 }
 
 pub struct AccountCheck;
@@ -457,10 +481,15 @@ impl DappStakingAccountCheck<AccountId> for AccountCheck {
 impl pallet_dapp_staking::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type RuntimeFreezeReason = RuntimeFreezeReason;
+    type RuntimeHoldReason = RuntimeHoldReason;
     type Currency = Balances;
     type SmartContract = SmartContract<AccountId>;
     type ContractRegisterOrigin = EnsureRootOrTwoThirdsCommunityCouncil;
     type ContractUnregisterOrigin = EnsureRootOrFourFifthsCommunityCouncil;
+    type DAppMetadataDepositPerByte = DAppMetadataDepositPerByte;
+    type MaxDAppMetadataLength = ConstU32<128>;
+    type InstantUnlockPenaltyHandler = InstantUnlockPenaltyToTreasury;
+    type LockReceipt = FungiblesLockReceipt<Assets, DappStakingLockReceiptAssetId>;
     type ManagerOrigin = EnsureRootOrHalfTechCommitteeOrTwoThirdCouncil;
     type StakingRewardHandler = Inflation;
     type CycleConfiguration = InflationCycleConfig;
@@ -469,19 +498,36 @@ impl pallet_dapp_staking::Config for Runtime {
     type EraRewardSpanLength = ConstU32<16>;
     type RewardRetentionInPeriods = ConstU32<4>;
     type MaxNumberOfContracts = ConstU32<{ FIXED_NUMBER_OF_TIER_SLOTS as u32 }>;
+    type MaxNumberOfTierSlots = ConstU32<{ FIXED_NUMBER_OF_TIER_SLOTS as u32 }>;
+    type TierAssignmentPageSize = ConstU32<64>;
+    type TierHistoryLength = ConstU32<30>;
     type MaxUnlockingChunks = ConstU32<8>;
     type MinimumLockedAmount = MinimumStakingAmount;
     type UnlockingPeriod = ConstU32<9>;
     type MaxNumberOfStakedContracts = ConstU32<16>;
+    type MaxNumberOfDelegates = ConstU32<8>;
     type MinimumStakeAmount = MinimumStakingAmount;
     type NumberOfTiers = ConstU32<4>;
+    type TierSlotsProvider = FixedTierSlots;
     type RankingEnabled = ConstBool<true>;
     type MaxBonusSafeMovesPerPeriod = ConstU8<2>;
+    type MaxNumberOfRewardSplits = ConstU32<5>;
+    type MaxNumberOfDAppRewardClaimEras = ConstU32<24>;
+    type AutoClaimScanLimit = ConstU32<16>;
+    type UnregisteredStakeGracePeriod = ConstU32<7>;
     type WeightInfo = weights::pallet_dapp_staking::SubstrateWeight<Runtime>;
     #[cfg(feature = "runtime-benchmarks")]
     type BenchmarkHelper = DAppStakingBenchmarkHelper<SmartContract<AccountId>, AccountId>;
 }
 
+/// Routes the dApp staking instant unlock penalty to the treasury.
+pub struct InstantUnlockPenaltyToTreasury;
+impl OnUnbalanced<Credit<AccountId, Balances>> for InstantUnlockPenaltyToTreasury {
+    fn on_nonzero_unbalanced(amount: Credit<AccountId, Balances>) {
+        let _ = Balances::resolve(&TreasuryPalletId::get().into_account_truncating(), amount);
+    }
+}
+
 pub struct InflationPayoutPerBlock;
 impl pallet_inflation::PayoutPerBlock<Credit<AccountId, Balances>> for InflationPayoutPerBlock {
     fn treasury(reward: Credit<AccountId, Balances>) {
@@ -493,6 +539,13 @@ impl pallet_inflation::PayoutPerBlock<Credit<AccountId, Balances>> for Inflation
     }
 }
 
+pub struct InflationTotalValueStaked;
+impl Get<Balance> for InflationTotalValueStaked {
+    fn get() -> Balance {
+        pallet_dapp_staking::CurrentEraInfo::<Runtime>::get().total_staked_amount()
+    }
+}
+
 pub struct InflationCycleConfig;
 impl CycleConfiguration for InflationCycleConfig {
     fn periods_per_cycle() -> u32 {
@@ -516,6 +569,7 @@ impl pallet_inflation::Config for Runtime {
     type Currency = Balances;
     type PayoutPerBlock = InflationPayoutPerBlock;
     type CycleConfiguration = InflationCycleConfig;
+    type TotalValueStaked = InflationTotalValueStaked;
     type WeightInfo = weights::pallet_inflation::SubstrateWeight<Runtime>;
 }
 
@@ -672,7 +726,9 @@ impl AddressToAssetId<AssetId> for Runtime {
         let address_bytes: [u8; 20] = address.into();
         if ASSET_PRECOMPILE_ADDRESS_PREFIX.eq(&address_bytes[0..4]) {
             data.copy_from_slice(&address_bytes[4..20]);
-            Some(u128::from_be_bytes(data))
+            let asset_id = u128::from_be_bytes(data);
+            // dApp staking lock receipts are non-transferable, so they aren't exposed via ERC20 precompile.
+            (asset_id != DappStakingLockReceiptAssetId::get()).then_some(asset_id)
         } else {
             None
         }
@@ -1742,7 +1798,10 @@ pub type Executive = frame_executive::Executive<
 pub type Migrations = (Unreleased, Permanent);
 
 /// Unreleased migrations. Add new ones here:
-pub type Unreleased = ();
+pub type Unreleased = (
+    pallet_dapp_staking::migration::versioned_migrations::V12ToV13<Runtime>,
+    pallet_inflation::migration::versioned_migrations::V2ToV3<Runtime>,
+);
 
 /// Migrations/checks that do not need to be versioned and can run on every upgrade.
 pub type Permanent = (pallet_xcm::migration::MigrateToLatestXcmVersion<Runtime>,);
@@ -2311,7 +2370,7 @@ impl_runtime_apis! {
         }
     }
 
-    impl dapp_staking_runtime_api::DappStakingApi<Block> for Runtime {
+    impl dapp_staking_runtime_api::DappStakingApi<Block, AccountId, SmartContract<AccountId>> for Runtime {
         fn periods_per_cycle() -> PeriodNumber {
             InflationCycleConfig::periods_per_cycle()
         }
@@ -2331,6 +2390,52 @@ impl_runtime_apis! {
         fn get_dapp_tier_assignment() -> BTreeMap<DAppId, RankedTier> {
             DappStaking::get_dapp_tier_assignment()
         }
+
+        fn get_claimable_staker_rewards(account: AccountId) -> Vec<(EraNumber, Balance)> {
+            DappStaking::get_claimable_staker_rewards(&account)
+        }
+
+        fn get_claimable_bonus_rewards(account: AccountId) -> Vec<(SmartContract<AccountId>, Balance)> {
+            DappStaking::get_claimable_bonus_rewards(&account)
+        }
+
+        fn get_unclaimed_dapp_rewards(smart_contract: SmartContract<AccountId>) -> Vec<(EraNumber, Balance)> {
+            DappStaking::get_unclaimed_dapp_rewards(&smart_contract)
+        }
+
+        fn get_dapp_tier_history(dapp_id: DAppId) -> Vec<DAppTierHistoryEntry> {
+            DappStaking::get_dapp_tier_history(dapp_id)
+        }
+
+        fn get_dapp_metadata(smart_contract: SmartContract<AccountId>) -> Option<DAppMetadata> {
+            DappStaking::get_dapp_metadata(&smart_contract)
+        }
+    }
+
+    impl dapp_staking_runtime_api::DappStakingTierParamsApi<Block, pallet_dapp_staking::TierParameters<<Runtime as pallet_dapp_staking::Config>::NumberOfTiers>> for Runtime {
+        fn preview_dapp_tier_assignment(
+            params: pallet_dapp_staking::TierParameters<<Runtime as pallet_dapp_staking::Config>::NumberOfTiers>,
+        ) -> Option<TierAssignmentPreview> {
+            DappStaking::preview_dapp_tier_assignment(params)
+        }
+    }
+
+    impl inflation_runtime_api::InflationApi<Block, pallet_inflation::InflationConfiguration, pallet_inflation::InflationParameters> for Runtime {
+        fn active_inflation_config() -> pallet_inflation::InflationConfiguration {
+            pallet_inflation::ActiveInflationConfig::<Runtime>::get()
+        }
+
+        fn inflation_params() -> pallet_inflation::InflationParameters {
+            pallet_inflation::InflationParams::<Runtime>::get()
+        }
+
+        fn decay_factor() -> Perquintill {
+            pallet_inflation::ActiveInflationConfig::<Runtime>::get().decay_factor
+        }
+
+        fn reward_projection(total_value_staked: Balance) -> InflationRewardProjection {
+            Inflation::reward_projection(total_value_staked)
+        }
     }
 
     impl xcm_runtime_apis::fees::XcmPaymentApi<Block> for Runtime {
@@ -106,6 +106,10 @@ impl<T: frame_system::Config> pallet_dapp_staking::WeightInfo for SubstrateWeigh
 	/// Proof: `DappStaking::CounterForIntegratedDApps` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `DappStaking::ContractStake` (r:0 w:1)
 	/// Proof: `DappStaking::ContractStake` (`max_values`: Some(65535), `max_size`: Some(91), added: 2071, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::CounterForUnregisteredStakeSweeps` (r:1 w:1)
+	/// Proof: `DappStaking::CounterForUnregisteredStakeSweeps` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::UnregisteredStakeSweeps` (r:0 w:1)
+	/// Proof: `DappStaking::UnregisteredStakeSweeps` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
 	fn unregister() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `97`
@@ -113,8 +117,8 @@ impl<T: frame_system::Config> pallet_dapp_staking::WeightInfo for SubstrateWeigh
 		// Minimum execution time: 18_170_000 picoseconds.
 		Weight::from_parts(18_578_000, 0)
 			.saturating_add(Weight::from_parts(0, 3086))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(3))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(5))
 	}
 	/// Storage: `DappStaking::Ledger` (r:1 w:1)
 	/// Proof: `DappStaking::Ledger` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
@@ -126,6 +130,14 @@ impl<T: frame_system::Config> pallet_dapp_staking::WeightInfo for SubstrateWeigh
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `DappStaking::CurrentEraInfo` (r:1 w:1)
 	/// Proof: `DappStaking::CurrentEraInfo` (`max_values`: Some(1), `max_size`: Some(112), added: 607, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::LockReceiptsEnabled` (r:1 w:0)
+	/// Proof: `DappStaking::LockReceiptsEnabled` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::LockReceipts` (r:1 w:1)
+	/// Proof: `DappStaking::LockReceipts` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(222), added: 2697, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:1 w:1)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
 	fn lock_new_account() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `138`
@@ -133,8 +145,8 @@ impl<T: frame_system::Config> pallet_dapp_staking::WeightInfo for SubstrateWeigh
 		// Minimum execution time: 33_059_000 picoseconds.
 		Weight::from_parts(33_722_000, 0)
 			.saturating_add(Weight::from_parts(0, 4764))
-			.saturating_add(T::DbWeight::get().reads(5))
-			.saturating_add(T::DbWeight::get().writes(3))
+			.saturating_add(T::DbWeight::get().reads(9))
+			.saturating_add(T::DbWeight::get().writes(6))
 	}
 	/// Storage: `DappStaking::Ledger` (r:1 w:1)
 	/// Proof: `DappStaking::Ledger` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
@@ -144,6 +156,14 @@ impl<T: frame_system::Config> pallet_dapp_staking::WeightInfo for SubstrateWeigh
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `DappStaking::CurrentEraInfo` (r:1 w:1)
 	/// Proof: `DappStaking::CurrentEraInfo` (`max_values`: Some(1), `max_size`: Some(112), added: 607, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::LockReceiptsEnabled` (r:1 w:0)
+	/// Proof: `DappStaking::LockReceiptsEnabled` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::LockReceipts` (r:1 w:1)
+	/// Proof: `DappStaking::LockReceipts` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(222), added: 2697, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:1 w:1)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
 	fn lock_existing_account() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `158`
@@ -151,8 +171,8 @@ impl<T: frame_system::Config> pallet_dapp_staking::WeightInfo for SubstrateWeigh
 		// Minimum execution time: 32_129_000 picoseconds.
 		Weight::from_parts(32_518_000, 0)
 			.saturating_add(Weight::from_parts(0, 4764))
-			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(3))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(6))
 	}
 	/// Storage: `DappStaking::Ledger` (r:1 w:1)
 	/// Proof: `DappStaking::Ledger` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
@@ -162,6 +182,12 @@ impl<T: frame_system::Config> pallet_dapp_staking::WeightInfo for SubstrateWeigh
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `DappStaking::CurrentEraInfo` (r:1 w:1)
 	/// Proof: `DappStaking::CurrentEraInfo` (`max_values`: Some(1), `max_size`: Some(112), added: 607, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::LockReceipts` (r:1 w:1)
+	/// Proof: `DappStaking::LockReceipts` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(222), added: 2697, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:1 w:1)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
 	fn unlock() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `158`
@@ -169,8 +195,8 @@ impl<T: frame_system::Config> pallet_dapp_staking::WeightInfo for SubstrateWeigh
 		// Minimum execution time: 29_639_000 picoseconds.
 		Weight::from_parts(30_259_000, 0)
 			.saturating_add(Weight::from_parts(0, 4764))
-			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(3))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(6))
 	}
 	/// Storage: `DappStaking::Ledger` (r:1 w:1)
 	/// Proof: `DappStaking::Ledger` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
@@ -201,6 +227,14 @@ impl<T: frame_system::Config> pallet_dapp_staking::WeightInfo for SubstrateWeigh
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `DappStaking::CurrentEraInfo` (r:1 w:1)
 	/// Proof: `DappStaking::CurrentEraInfo` (`max_values`: Some(1), `max_size`: Some(112), added: 607, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::LockReceiptsEnabled` (r:1 w:0)
+	/// Proof: `DappStaking::LockReceiptsEnabled` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::LockReceipts` (r:1 w:1)
+	/// Proof: `DappStaking::LockReceipts` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(222), added: 2697, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:1 w:1)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
 	fn relock_unlocking() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `200`
@@ -208,8 +242,8 @@ impl<T: frame_system::Config> pallet_dapp_staking::WeightInfo for SubstrateWeigh
 		// Minimum execution time: 29_446_000 picoseconds.
 		Weight::from_parts(29_875_000, 0)
 			.saturating_add(Weight::from_parts(0, 4764))
-			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(3))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(6))
 	}
 	/// Storage: `DappStaking::IntegratedDApps` (r:1 w:0)
 	/// Proof: `DappStaking::IntegratedDApps` (`max_values`: Some(65535), `max_size`: Some(116), added: 2096, mode: `MaxEncodedLen`)
@@ -269,6 +303,8 @@ impl<T: frame_system::Config> pallet_dapp_staking::WeightInfo for SubstrateWeigh
 	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:0)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::StakerRewardDestination` (r:1 w:0)
+	/// Proof: `DappStaking::StakerRewardDestination` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
 	/// The range of component `x` is `[1, 16]`.
 	fn claim_staker_rewards_past_period(x: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -279,7 +315,7 @@ impl<T: frame_system::Config> pallet_dapp_staking::WeightInfo for SubstrateWeigh
 			.saturating_add(Weight::from_parts(0, 4764))
 			// Standard Error: 13_115
 			.saturating_add(Weight::from_parts(1_610_924, 0).saturating_mul(x.into()))
-			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().reads(6))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `DappStaking::Ledger` (r:1 w:1)
@@ -290,6 +326,8 @@ impl<T: frame_system::Config> pallet_dapp_staking::WeightInfo for SubstrateWeigh
 	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:0)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::StakerRewardDestination` (r:1 w:0)
+	/// Proof: `DappStaking::StakerRewardDestination` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
 	/// The range of component `x` is `[1, 16]`.
 	fn claim_staker_rewards_ongoing_period(x: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -300,7 +338,7 @@ impl<T: frame_system::Config> pallet_dapp_staking::WeightInfo for SubstrateWeigh
 			.saturating_add(Weight::from_parts(0, 4764))
 			// Standard Error: 2_968
 			.saturating_add(Weight::from_parts(1_610_031, 0).saturating_mul(x.into()))
-			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `DappStaking::StakerInfo` (r:1 w:1)
@@ -309,6 +347,8 @@ impl<T: frame_system::Config> pallet_dapp_staking::WeightInfo for SubstrateWeigh
 	/// Proof: `DappStaking::PeriodEnd` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
 	/// Storage: `DappStaking::Ledger` (r:1 w:1)
 	/// Proof: `DappStaking::Ledger` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::StakerRewardDestination` (r:1 w:0)
+	/// Proof: `DappStaking::StakerRewardDestination` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
 	fn claim_bonus_reward() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `275`
@@ -316,22 +356,30 @@ impl<T: frame_system::Config> pallet_dapp_staking::WeightInfo for SubstrateWeigh
 		// Minimum execution time: 39_271_000 picoseconds.
 		Weight::from_parts(40_198_000, 0)
 			.saturating_add(Weight::from_parts(0, 3775))
-			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `DappStaking::IntegratedDApps` (r:1 w:0)
 	/// Proof: `DappStaking::IntegratedDApps` (`max_values`: Some(65535), `max_size`: Some(116), added: 2096, mode: `MaxEncodedLen`)
 	/// Storage: `DappStaking::DAppTiers` (r:1 w:1)
 	/// Proof: `DappStaking::DAppTiers` (`max_values`: None, `max_size`: Some(195), added: 2670, mode: `MaxEncodedLen`)
-	fn claim_dapp_reward() -> Weight {
+	/// Storage: `System::Account` (r:5 w:5)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[0, 5]`.
+	fn claim_dapp_reward(x: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `647`
-		//  Estimated: `3660`
+		//  Measured:  `647 + x * (32 ±0)`
+		//  Estimated: `3660 + x * (2603 ±0)`
 		// Minimum execution time: 25_362_000 picoseconds.
-		Weight::from_parts(25_683_000, 0)
+		Weight::from_parts(25_666_000, 0)
 			.saturating_add(Weight::from_parts(0, 3660))
+			// Standard Error: 184_792
+			.saturating_add(Weight::from_parts(9_794_317, 0).saturating_mul(x.into()))
 			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(x.into())))
 			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(x.into()))
 	}
 	/// Storage: `DappStaking::IntegratedDApps` (r:1 w:0)
 	/// Proof: `DappStaking::IntegratedDApps` (`max_values`: Some(65535), `max_size`: Some(116), added: 2096, mode: `MaxEncodedLen`)
@@ -547,4 +595,372 @@ impl<T: frame_system::Config> pallet_dapp_staking::WeightInfo for SubstrateWeigh
 			.saturating_add(Weight::from_parts(0, 0))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: `DappStaking::AutoCompound` (r:0 w:1)
+	/// Proof: `DappStaking::AutoCompound` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	fn set_auto_compound() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 9_476_000 picoseconds.
+		Weight::from_parts(9_589_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `DappStaking::AutoCompound` (r:1 w:0)
+	/// Proof: `DappStaking::AutoCompound` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::Ledger` (r:1 w:1)
+	/// Proof: `DappStaking::Ledger` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:1)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:0)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::CurrentEraInfo` (r:1 w:1)
+	/// Proof: `DappStaking::CurrentEraInfo` (`max_values`: Some(1), `max_size`: Some(112), added: 607, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::StakerInfo` (r:17 w:16)
+	/// Proof: `DappStaking::StakerInfo` (`max_values`: None, `max_size`: Some(178), added: 2653, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::IntegratedDApps` (r:16 w:0)
+	/// Proof: `DappStaking::IntegratedDApps` (`max_values`: Some(65535), `max_size`: Some(116), added: 2096, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::ContractStake` (r:16 w:16)
+	/// Proof: `DappStaking::ContractStake` (`max_values`: Some(65535), `max_size`: Some(91), added: 2071, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[0, 16]`.
+	fn compound_reward(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `298 + x * (170 ±0)`
+		//  Estimated: `4764 + x * (2653 ±0)`
+		// Minimum execution time: 39_769_000 picoseconds.
+		Weight::from_parts(40_246_000, 0)
+			.saturating_add(Weight::from_parts(0, 4764))
+			// Standard Error: 488_034
+			.saturating_add(Weight::from_parts(25_865_317, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes(3))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 2653).saturating_mul(x.into()))
+	}
+	/// Storage: `DappStaking::IntegratedDApps` (r:1 w:1)
+	/// Proof: `DappStaking::IntegratedDApps` (`max_values`: Some(65535), `max_size`: Some(116), added: 2096, mode: `MaxEncodedLen`)
+	fn set_dapp_reward_splits() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `97`
+		//  Estimated: `3086`
+		// Minimum execution time: 14_158_000 picoseconds.
+		Weight::from_parts(14_327_000, 0)
+			.saturating_add(Weight::from_parts(0, 3086))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `DappStaking::IntegratedDApps` (r:1 w:0)
+	/// Proof: `DappStaking::IntegratedDApps` (`max_values`: Some(65535), `max_size`: Some(116), added: 2096, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::DAppTiers` (r:24 w:24)
+	/// Proof: `DappStaking::DAppTiers` (`max_values`: None, `max_size`: Some(195), added: 2670, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:6 w:6)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[1, 24]`.
+	fn claim_dapp_rewards(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `812 + x * (195 ±0)`
+		//  Estimated: `16278 + x * (2670 ±0)`
+		// Minimum execution time: 77_282_000 picoseconds.
+		Weight::from_parts(78_209_000, 0)
+			.saturating_add(Weight::from_parts(0, 16278))
+			// Standard Error: 120_247
+			.saturating_add(Weight::from_parts(6_373_317, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes(6))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 2670).saturating_mul(x.into()))
+	}
+	/// Storage: `DappStaking::AutoClaim` (r:0 w:1)
+	/// Proof: `DappStaking::AutoClaim` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	fn set_auto_claim() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 9_449_000 picoseconds.
+		Weight::from_parts(9_562_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `DappStaking::TierAssignmentState` (r:1 w:1)
+	/// Proof: `DappStaking::TierAssignmentState` (`max_values`: Some(1), `max_size`: Some(303), added: 798, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::CounterForIntegratedDApps` (r:1 w:0)
+	/// Proof: `DappStaking::CounterForIntegratedDApps` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::TierConfig` (r:1 w:0)
+	/// Proof: `DappStaking::TierConfig` (`max_values`: Some(1), `max_size`: Some(91), added: 586, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::ContractStake` (r:17 w:0)
+	/// Proof: `DappStaking::ContractStake` (`max_values`: Some(65535), `max_size`: Some(91), added: 2071, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[0, 16]`.
+	fn dapp_tier_assignment_page(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `213 + x * (32 ±0)`
+		//  Estimated: `3061 + x * (2071 ±0)`
+		// Minimum execution time: 11_720_000 picoseconds.
+		Weight::from_parts(11_860_000, 0)
+			.saturating_add(Weight::from_parts(0, 3061))
+			// Standard Error: 49_331
+			.saturating_add(Weight::from_parts(2_614_317, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(Weight::from_parts(0, 2071).saturating_mul(x.into()))
+	}
+	/// Storage: `DappStaking::InstantUnlockPenalty` (r:1 w:0)
+	/// Proof: `DappStaking::InstantUnlockPenalty` (`max_values`: Some(1), `max_size`: Some(5), added: 500, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::Ledger` (r:1 w:1)
+	/// Proof: `DappStaking::Ledger` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:1)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:0)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::CurrentEraInfo` (r:1 w:1)
+	/// Proof: `DappStaking::CurrentEraInfo` (`max_values`: Some(1), `max_size`: Some(112), added: 607, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::LockReceipts` (r:1 w:1)
+	/// Proof: `DappStaking::LockReceipts` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(222), added: 2697, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:1 w:1)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[0, 16]`.
+	fn instant_unlock(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `228`
+		//  Estimated: `4764`
+		// Minimum execution time: 46_709_000 picoseconds.
+		Weight::from_parts(47_269_000, 0)
+			.saturating_add(Weight::from_parts(0, 4764))
+			// Standard Error: 2_570
+			.saturating_add(Weight::from_parts(136_317, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(9))
+			.saturating_add(T::DbWeight::get().writes(7))
+	}
+	/// Storage: `DappStaking::InstantUnlockPenalty` (r:0 w:1)
+	/// Proof: `DappStaking::InstantUnlockPenalty` (`max_values`: Some(1), `max_size`: Some(5), added: 500, mode: `MaxEncodedLen`)
+	fn set_instant_unlock_penalty() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 6_666_000 picoseconds.
+		Weight::from_parts(6_745_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `DappStaking::ContractStake` (r:16 w:0)
+	/// Proof: `DappStaking::ContractStake` (`max_values`: Some(65535), `max_size`: Some(91), added: 2071, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::DAppTierHistory` (r:16 w:16)
+	/// Proof: `DappStaking::DAppTierHistory` (`max_values`: Some(65535), `max_size`: Some(1208), added: 3188, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[0, 16]`.
+	fn update_dapp_tier_history(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0 + x * (412 ±0)`
+		//  Estimated: `990 + x * (3188 ±0)`
+		// Minimum execution time: 2_236_000 picoseconds.
+		Weight::from_parts(2_262_000, 0)
+			.saturating_add(Weight::from_parts(0, 990))
+			// Standard Error: 153_292
+			.saturating_add(Weight::from_parts(8_124_317, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 3188).saturating_mul(x.into()))
+	}
+	/// Storage: `DappStaking::StakerRewardDestination` (r:0 w:1)
+	/// Proof: `DappStaking::StakerRewardDestination` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	fn set_staker_reward_destination() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 9_684_000 picoseconds.
+		Weight::from_parts(9_800_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `DappStaking::StakeDelegates` (r:1 w:1)
+	/// Proof: `DappStaking::StakeDelegates` (`max_values`: None, `max_size`: Some(329), added: 2804, mode: `MaxEncodedLen`)
+	fn set_delegate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `327`
+		//  Estimated: `3794`
+		// Minimum execution time: 16_474_000 picoseconds.
+		Weight::from_parts(16_671_000, 0)
+			.saturating_add(Weight::from_parts(0, 3794))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `DappStaking::StakeDelegates` (r:1 w:1)
+	/// Proof: `DappStaking::StakeDelegates` (`max_values`: None, `max_size`: Some(329), added: 2804, mode: `MaxEncodedLen`)
+	fn revoke_delegate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `327`
+		//  Estimated: `3794`
+		// Minimum execution time: 15_628_000 picoseconds.
+		Weight::from_parts(15_815_000, 0)
+			.saturating_add(Weight::from_parts(0, 3794))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `DappStaking::IntegratedDApps` (r:16 w:0)
+	/// Proof: `DappStaking::IntegratedDApps` (`max_values`: Some(65535), `max_size`: Some(116), added: 2096, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::CarryOverPlans` (r:0 w:1)
+	/// Proof: `DappStaking::CarryOverPlans` (`max_values`: None, `max_size`: Some(838), added: 3313, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[1, 16]`.
+	fn set_carry_over_plan(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0 + x * (116 ±0)`
+		//  Estimated: `990 + x * (2096 ±0)`
+		// Minimum execution time: 10_622_000 picoseconds.
+		Weight::from_parts(10_749_000, 0)
+			.saturating_add(Weight::from_parts(0, 990))
+			// Standard Error: 77_843
+			.saturating_add(Weight::from_parts(4_125_317, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(Weight::from_parts(0, 2096).saturating_mul(x.into()))
+	}
+	/// Storage: `DappStaking::CarryOverPlans` (r:1 w:1)
+	/// Proof: `DappStaking::CarryOverPlans` (`max_values`: None, `max_size`: Some(838), added: 3313, mode: `MaxEncodedLen`)
+	fn remove_carry_over_plan() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `789`
+		//  Estimated: `4303`
+		// Minimum execution time: 15_206_000 picoseconds.
+		Weight::from_parts(15_388_000, 0)
+			.saturating_add(Weight::from_parts(0, 4303))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `DappStaking::IntegratedDApps` (r:1 w:0)
+	/// Proof: `DappStaking::IntegratedDApps` (`max_values`: Some(65535), `max_size`: Some(116), added: 2096, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::DAppMetadataOf` (r:1 w:1)
+	/// Proof: `DappStaking::DAppMetadataOf` (`max_values`: None, `max_size`: Some(488), added: 2963, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(211), added: 2686, mode: `MaxEncodedLen`)
+	fn set_dapp_metadata() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `590`
+		//  Estimated: `3953`
+		// Minimum execution time: 32_524_000 picoseconds.
+		Weight::from_parts(32_914_000, 0)
+			.saturating_add(Weight::from_parts(0, 3953))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `DappStaking::IntegratedDApps` (r:1 w:0)
+	/// Proof: `DappStaking::IntegratedDApps` (`max_values`: Some(65535), `max_size`: Some(116), added: 2096, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::DAppMetadataOf` (r:1 w:1)
+	/// Proof: `DappStaking::DAppMetadataOf` (`max_values`: None, `max_size`: Some(488), added: 2963, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(211), added: 2686, mode: `MaxEncodedLen`)
+	fn clear_dapp_metadata() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `590`
+		//  Estimated: `3953`
+		// Minimum execution time: 25_855_000 picoseconds.
+		Weight::from_parts(26_165_000, 0)
+			.saturating_add(Weight::from_parts(0, 3953))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `DappStaking::ActiveProtocolState` (r:1 w:0)
+	/// Proof: `DappStaking::ActiveProtocolState` (`max_values`: Some(1), `max_size`: Some(78), added: 573, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::ScheduledTierParams` (r:0 w:1)
+	/// Proof: `DappStaking::ScheduledTierParams` (`max_values`: Some(1), `max_size`: Some(91), added: 586, mode: `MaxEncodedLen`)
+	fn schedule_static_tier_params() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `76`
+		//  Estimated: `1563`
+		// Minimum execution time: 9_690_000 picoseconds.
+		Weight::from_parts(9_806_000, 0)
+			.saturating_add(Weight::from_parts(0, 1563))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `DappStaking::ScheduledTierParams` (r:1 w:1)
+	/// Proof: `DappStaking::ScheduledTierParams` (`max_values`: Some(1), `max_size`: Some(91), added: 586, mode: `MaxEncodedLen`)
+	fn cancel_scheduled_tier_params() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `149`
+		//  Estimated: `1576`
+		// Minimum execution time: 9_085_000 picoseconds.
+		Weight::from_parts(9_194_000, 0)
+			.saturating_add(Weight::from_parts(0, 1576))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `DappStaking::StakerInfo` (r:16 w:16)
+	/// Proof: `DappStaking::StakerInfo` (`max_values`: None, `max_size`: Some(178), added: 2653, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::IntegratedDApps` (r:16 w:0)
+	/// Proof: `DappStaking::IntegratedDApps` (`max_values`: Some(65535), `max_size`: Some(116), added: 2096, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::Ledger` (r:1 w:1)
+	/// Proof: `DappStaking::Ledger` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::CurrentEraInfo` (r:1 w:1)
+	/// Proof: `DappStaking::CurrentEraInfo` (`max_values`: Some(1), `max_size`: Some(112), added: 607, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:1)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:0)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[1, 16]`.
+	fn unstake_from_all_unregistered(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `322 + x * (117 ±0)`
+		//  Estimated: `4764 + x * (2653 ±0)`
+		// Minimum execution time: 32_747_000 picoseconds.
+		Weight::from_parts(33_139_000, 0)
+			.saturating_add(Weight::from_parts(0, 4764))
+			// Standard Error: 279_347
+			.saturating_add(Weight::from_parts(14_805_317, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes(3))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 2653).saturating_mul(x.into()))
+	}
+	/// Storage: `Assets::Asset` (r:1 w:0)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(222), added: 2697, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::LockReceiptsEnabled` (r:0 w:1)
+	/// Proof: `DappStaking::LockReceiptsEnabled` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	fn set_lock_receipts_enabled() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `277`
+		//  Estimated: `3687`
+		// Minimum execution time: 11_941_000 picoseconds.
+		Weight::from_parts(12_084_000, 0)
+			.saturating_add(Weight::from_parts(0, 3687))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `DappStaking::ActiveProtocolState` (r:1 w:0)
+	/// Proof: `DappStaking::ActiveProtocolState` (`max_values`: Some(1), `max_size`: Some(78), added: 573, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::Ledger` (r:2 w:2)
+	/// Proof: `DappStaking::Ledger` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:2 w:2)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:2 w:0)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::LockReceipts` (r:2 w:2)
+	/// Proof: `DappStaking::LockReceipts` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(222), added: 2697, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:2 w:2)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
+	/// Storage: `DappStaking::StakerInfo` (r:0 w:16)
+	/// Proof: `DappStaking::StakerInfo` (`max_values`: None, `max_size`: Some(178), added: 2653, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[0, 16]`.
+	fn transfer_lock(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `612`
+		//  Estimated: `6196`
+		// Minimum execution time: 102_661_000 picoseconds.
+		Weight::from_parts(103_892_000, 0)
+			.saturating_add(Weight::from_parts(0, 6196))
+			// Standard Error: 23_684
+			.saturating_add(Weight::from_parts(1_255_317, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(14))
+			.saturating_add(T::DbWeight::get().writes(11))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(x.into())))
+	}
 }
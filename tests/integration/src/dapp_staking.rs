@@ -19,8 +19,16 @@
 #![cfg(test)]
 
 use crate::setup::*;
-use astar_primitives::dapp_staking::{DAppId, RankedTier, SmartContract};
-use frame_support::traits::BuildGenesisConfig;
+use astar_primitives::{
+    dapp_staking::{DAppId, RankedTier, SmartContract},
+    evm::EVM_REVERT_CODE,
+};
+use fp_evm::ExitReason;
+use frame_support::traits::{BuildGenesisConfig, Currency};
+use pallet_evm::{AddressMapping, Runner};
+use pallet_evm_precompile_assets_erc20::AddressToAssetId;
+use precompile_utils::{prelude::Address, solidity};
+use sp_core::U256;
 use sp_runtime::Perquintill;
 
 use pallet_collator_selection::{CandidateInfo, Candidates};
@@ -244,6 +252,7 @@ fn full_period_transition_recalculation_and_reward_distribution() {
                 bonus_part: Perquintill::zero(),
                 ideal_staking_rate: Perquintill::from_percent(50),
                 decay_rate: Perquintill::one(),
+                emission_curve: pallet_inflation::BuiltInEmissionCurve::MaxInflationRate,
             },
         ));
         assert_ok!(Inflation::force_inflation_recalculation(
@@ -479,3 +488,68 @@ fn full_period_transition_recalculation_and_reward_distribution() {
         );
     });
 }
+
+#[test]
+fn lock_receipts_cannot_be_transferred_via_erc20_precompile() {
+    new_test_ext().execute_with(|| {
+        let receipt_id = DappStakingLockReceiptAssetId::get();
+        assert_ok!(Assets::force_create(
+            RuntimeOrigin::root(),
+            receipt_id.into(),
+            ALICE.into(),
+            true,
+            1,
+        ));
+        assert_ok!(DappStaking::set_lock_receipts_enabled(
+            RuntimeOrigin::root(),
+            true
+        ));
+
+        // Lock funds from an account controlled by an EVM address
+        let address = H160::repeat_byte(0xbe);
+        let account = <Runtime as pallet_evm::Config>::AddressMapping::into_account_id(address);
+        let amount = <Runtime as pallet_dapp_staking::Config>::MinimumLockedAmount::get();
+        Balances::make_free_balance_be(&account, amount * 10);
+        assert_ok!(DappStaking::lock(
+            RuntimeOrigin::signed(account.clone()),
+            amount
+        ));
+        assert_eq!(Assets::balance(receipt_id, &account), amount);
+
+        // Receipt asset isn't exposed via the ERC20 precompile, only the revert code is left at its address
+        let receipt_address = Runtime::asset_id_to_address(receipt_id);
+        assert!(Runtime::address_to_asset_id(receipt_address).is_none());
+        assert_eq!(
+            pallet_evm::AccountCodes::<Runtime>::get(&receipt_address),
+            EVM_REVERT_CODE.to_vec()
+        );
+
+        // ERC20 transfer of the receipt reverts
+        let input = solidity::encode_with_selector(
+            0xa9059cbb_u32, // transfer(address,uint256)
+            (Address(H160::repeat_byte(0x01)), U256::from(amount)),
+        );
+        let result = <Runtime as pallet_evm::Config>::Runner::call(
+            address,
+            receipt_address,
+            input,
+            U256::zero(),
+            1_000_000,
+            None,
+            None,
+            None,
+            Vec::new(),
+            Vec::new(),
+            false,
+            false,
+            None,
+            None,
+            <Runtime as pallet_evm::Config>::config(),
+        );
+        assert!(
+            matches!(result, Ok(ref info) if matches!(info.exit_reason, ExitReason::Revert(_))),
+            "ERC20 transfer of the lock receipt must revert."
+        );
+        assert_eq!(Assets::balance(receipt_id, &account), amount);
+    });
+}
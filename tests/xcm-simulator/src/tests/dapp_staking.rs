@@ -0,0 +1,352 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! Tests for dApp staking participation of sibling parachain accounts.
+//!
+//! An account on parachain A participates in dApp staking on parachain B by sending an XCM
+//! which pays for its own execution and dispatches dApp staking calls via `Transact`.
+//!
+//! The calls are dispatched by the account derived from the sender's location via
+//! `LocationToAccountId`. The outcome of each dispatch is reported back to parachain A via
+//! `ReportTransactStatus`, and funds are sent home using a reserve transfer.
+
+use crate::mocks::{parachain, *};
+
+use frame_support::{assert_ok, weights::Weight};
+use parity_scale_codec::Encode;
+use xcm::prelude::*;
+use xcm_executor::traits::{QueryHandler, QueryResponseStatus};
+use xcm_simulator::TestExt;
+
+use astar_primitives::dapp_staking::SmartContractHandle;
+
+/// Amount of parachain B native currency withdrawn from the remote account to pay for execution.
+const REMOTE_EXECUTION_FEE: u128 = 100_000_000_000;
+
+/// Dispatch `call` on parachain B on behalf of `ALICE` from parachain A.
+///
+/// Execution is paid from the account derived for `ALICE` on parachain B, and the dispatch outcome is
+/// reported back to parachain A. Returns the query Id under which the report is expected.
+///
+/// Must be called from parachain A context.
+fn remote_dapp_staking_transact(call: parachain::RuntimeCall) -> QueryId {
+    let para_b = Location::new(1, [Parachain(2)]);
+
+    // Location of `ALICE`, as seen by parachain A after parachain B reanchors the querier.
+    let querier = Location::new(
+        0,
+        [AccountId32 {
+            network: Some(Kusama),
+            id: ALICE.into(),
+        }],
+    );
+    let query_id = <ParachainPalletXcm as QueryHandler>::new_query(
+        para_b.clone(),
+        parachain::System::block_number() + 100,
+        querier,
+    );
+
+    assert_ok!(ParachainPalletXcm::send(
+        parachain::RuntimeOrigin::signed(ALICE),
+        Box::new(para_b.into()),
+        Box::new(VersionedXcm::from(Xcm(vec![
+            WithdrawAsset((Here, REMOTE_EXECUTION_FEE).into()),
+            BuyExecution {
+                fees: (Here, REMOTE_EXECUTION_FEE).into(),
+                weight_limit: Unlimited
+            },
+            SetAppendix(Xcm(vec![ReportTransactStatus(QueryResponseInfo {
+                destination: Location::new(1, [Parachain(1)]),
+                query_id,
+                max_weight: Weight::zero(),
+            })])),
+            Transact {
+                origin_kind: OriginKind::SovereignAccount,
+                fallback_max_weight: Some(Weight::from_parts(1_000_000_000, 1024 * 1024)),
+                call: call.encode().into(),
+            },
+        ]))),
+    ));
+
+    query_id
+}
+
+/// Take the dispatch outcome reported for `query_id`.
+///
+/// Must be called from parachain A context.
+fn take_reported_status(query_id: QueryId) -> MaybeErrorCode {
+    match <ParachainPalletXcm as QueryHandler>::take_response(query_id) {
+        QueryResponseStatus::Ready {
+            response: Response::DispatchResult(status),
+            ..
+        } => status,
+        other => panic!("Unexpected response status: {:?}", other),
+    }
+}
+
+/// Collect all dApp staking events deposited on the current parachain.
+fn dapp_staking_events() -> Vec<pallet_dapp_staking::Event<parachain::Runtime>> {
+    parachain::System::events()
+        .into_iter()
+        .map(|r| r.event)
+        .filter_map(|e| e.try_into().ok())
+        .collect()
+}
+
+#[test]
+fn remote_dapp_staking_lifecycle() {
+    MockNet::reset();
+
+    // The idea of this test case is to go through the entire dApp staking lifecycle remotely.
+    // Alice on parachain A locks, stakes, claims, unstakes & unlocks on parachain B, and then
+    // sends the funds back home.
+
+    let smart_contract =
+        parachain::MockSmartContract::wasm(parachain::AccountId::from([13 as u8; 32]));
+    let stake_amount = 100_000_000;
+    let remote_alice = sibling_para_account_account_id(1, ALICE);
+
+    // 1st step
+    // Register contract on parachain B.
+    ParaB::execute_with(|| {
+        assert_ok!(parachain::DappStaking::register(
+            parachain::RuntimeOrigin::root(),
+            ALICE,
+            smart_contract.clone(),
+        ));
+    });
+
+    // 2nd step
+    // Remotely lock & stake on the contract, in a single batch.
+    let query_id = ParaA::execute_with(|| {
+        remote_dapp_staking_transact(parachain::RuntimeCall::Utility(pallet_utility::Call::<
+            parachain::Runtime,
+        >::batch_all {
+            calls: vec![
+                parachain::RuntimeCall::DappStaking(pallet_dapp_staking::Call::lock {
+                    amount: stake_amount,
+                }),
+                parachain::RuntimeCall::DappStaking(pallet_dapp_staking::Call::stake {
+                    smart_contract: smart_contract.clone(),
+                    amount: stake_amount,
+                }),
+            ],
+        }))
+    });
+
+    ParaB::execute_with(|| {
+        let ledger = pallet_dapp_staking::Ledger::<parachain::Runtime>::get(&remote_alice);
+        assert_eq!(ledger.active_locked_amount(), stake_amount);
+        assert!(pallet_dapp_staking::StakerInfo::<parachain::Runtime>::get(
+            &remote_alice,
+            &smart_contract
+        )
+        .is_some());
+    });
+    ParaA::execute_with(|| {
+        assert_eq!(take_reported_status(query_id), MaybeErrorCode::Success);
+    });
+
+    // 3rd step
+    // Advance enough blocks so we at least get to era 5 - this gives us era 2, 3 and 4 for claiming.
+    ParaB::execute_with(|| {
+        while pallet_dapp_staking::ActiveProtocolState::<parachain::Runtime>::get().era() < 5 {
+            advance_parachain_block_to(parachain::System::block_number() + 1);
+        }
+        // Ensure it's not first block so event storage is clear
+        advance_parachain_block_to(parachain::System::block_number() + 1);
+    });
+
+    // Remotely claim all of the staker rewards.
+    let claim_staker_call = parachain::RuntimeCall::DappStaking(pallet_dapp_staking::Call::<
+        parachain::Runtime,
+    >::claim_staker_rewards {});
+    let query_id = ParaA::execute_with(|| {
+        remote_dapp_staking_transact(parachain::RuntimeCall::Utility(pallet_utility::Call::<
+            parachain::Runtime,
+        >::batch_all {
+            calls: vec![claim_staker_call.clone(); 3],
+        }))
+    });
+
+    ParaB::execute_with(|| {
+        let reward_events = dapp_staking_events()
+            .into_iter()
+            .filter(|e| {
+                matches!(
+                    e,
+                    pallet_dapp_staking::Event::Reward { account, destination, .. }
+                    if *account == remote_alice && *destination == remote_alice
+                )
+            })
+            .count();
+        assert_eq!(reward_events, 3);
+
+        parachain::System::reset_events();
+    });
+    ParaA::execute_with(|| {
+        assert_eq!(take_reported_status(query_id), MaybeErrorCode::Success);
+    });
+
+    // 4th step
+    // Remotely unstake from the contract & start unlocking the funds.
+    let query_id = ParaA::execute_with(|| {
+        remote_dapp_staking_transact(parachain::RuntimeCall::Utility(pallet_utility::Call::<
+            parachain::Runtime,
+        >::batch_all {
+            calls: vec![
+                parachain::RuntimeCall::DappStaking(pallet_dapp_staking::Call::unstake {
+                    smart_contract: smart_contract.clone(),
+                    amount: stake_amount,
+                }),
+                parachain::RuntimeCall::DappStaking(pallet_dapp_staking::Call::unlock {
+                    amount: stake_amount,
+                }),
+            ],
+        }))
+    });
+
+    ParaB::execute_with(|| {
+        let ledger = pallet_dapp_staking::Ledger::<parachain::Runtime>::get(&remote_alice);
+        assert_eq!(ledger.active_locked_amount(), 0);
+        assert_eq!(ledger.unlocking_amount(), stake_amount);
+    });
+    ParaA::execute_with(|| {
+        assert_eq!(take_reported_status(query_id), MaybeErrorCode::Success);
+    });
+
+    // 5th step
+    // Wait for the unlocking period to pass, and remotely claim the unlocked funds.
+    ParaB::execute_with(|| {
+        let unlocking_period =
+            pallet_dapp_staking::Pallet::<parachain::Runtime>::unlocking_period() as u64;
+        advance_parachain_block_to(parachain::System::block_number() + unlocking_period);
+    });
+
+    let query_id = ParaA::execute_with(|| {
+        remote_dapp_staking_transact(parachain::RuntimeCall::DappStaking(
+            pallet_dapp_staking::Call::claim_unlocked {},
+        ))
+    });
+
+    ParaB::execute_with(|| {
+        assert!(
+            dapp_staking_events().contains(&pallet_dapp_staking::Event::ClaimedUnlocked {
+                account: remote_alice.clone(),
+                amount: stake_amount,
+            })
+        );
+        let ledger = pallet_dapp_staking::Ledger::<parachain::Runtime>::get(&remote_alice);
+        assert_eq!(ledger.unlocking_amount(), 0);
+    });
+    ParaA::execute_with(|| {
+        assert_eq!(take_reported_status(query_id), MaybeErrorCode::Success);
+    });
+
+    // 6th step
+    // Send the funds back home, to Alice on parachain A, via a reserve transfer.
+    let para_b_asset_id = 123 as u128;
+    ParaA::execute_with(|| {
+        assert_ok!(register_and_setup_xcm_asset::<parachain::Runtime, _>(
+            parachain::RuntimeOrigin::root(),
+            para_b_asset_id,
+            (Parent, Parachain(2)),
+            sibling_para_account_id(2),
+            Some(true),
+            Some(1),
+            Some(1_000_000_000_000)
+        ));
+    });
+
+    let query_id = ParaA::execute_with(|| {
+        remote_dapp_staking_transact(parachain::RuntimeCall::Xtokens(orml_xtokens::Call::<
+            parachain::Runtime,
+        >::transfer_multiasset {
+            asset: Box::new((Here, stake_amount).into()),
+            dest: Box::new(
+                (
+                    Parent,
+                    Parachain(1),
+                    AccountId32 {
+                        network: None,
+                        id: ALICE.into(),
+                    },
+                )
+                    .into(),
+            ),
+            dest_weight_limit: Unlimited,
+        }))
+    });
+
+    // Parachain A should receive parachain B native assets and should mint their local derivate.
+    // Portion of those assets should be taken as the XCM execution fee.
+    ParaA::execute_with(|| {
+        assert_eq!(take_reported_status(query_id), MaybeErrorCode::Success);
+
+        let five_instructions_execution_cost =
+            (parachain::UnitWeightCost::get() * 5).ref_time() as u128;
+        assert_eq!(
+            parachain::Assets::balance(para_b_asset_id, ALICE),
+            stake_amount - five_instructions_execution_cost
+        );
+    });
+}
+
+#[test]
+fn remote_dapp_staking_failure_is_reported() {
+    MockNet::reset();
+
+    let smart_contract =
+        parachain::MockSmartContract::wasm(parachain::AccountId::from([13 as u8; 32]));
+    let remote_alice = sibling_para_account_account_id(1, ALICE);
+
+    ParaB::execute_with(|| {
+        assert_ok!(parachain::DappStaking::register(
+            parachain::RuntimeOrigin::root(),
+            ALICE,
+            smart_contract.clone(),
+        ));
+    });
+
+    // Try to stake remotely without locking anything first.
+    let query_id = ParaA::execute_with(|| {
+        remote_dapp_staking_transact(parachain::RuntimeCall::DappStaking(
+            pallet_dapp_staking::Call::stake {
+                smart_contract: smart_contract.clone(),
+                amount: 100_000_000,
+            },
+        ))
+    });
+
+    // Nothing should be staked on parachain B.
+    ParaB::execute_with(|| {
+        assert!(pallet_dapp_staking::StakerInfo::<parachain::Runtime>::get(
+            &remote_alice,
+            &smart_contract
+        )
+        .is_none());
+    });
+
+    // The dispatch error is reported back to parachain A.
+    ParaA::execute_with(|| {
+        assert!(matches!(
+            take_reported_status(query_id),
+            MaybeErrorCode::Error(_)
+        ));
+    });
+}
@@ -16,6 +16,9 @@
 // You should have received a copy of the GNU General Public License
 // along with Astar. If not, see <http://www.gnu.org/licenses/>.
 
+#[cfg(test)]
+mod dapp_staking;
+
 #[cfg(test)]
 mod experimental;
 